@@ -3,16 +3,23 @@
 [OUTPUT]: Signed messages and wallet address for EVM chains
 [POS]:    Auth layer - EVM wallet implementation
 [UPDATE]: When signing logic or EVM address formatting changes
+[UPDATE]: 2026-10-16 Add EIP-712 typed-data signing
+[UPDATE]: 2026-10-16 Load signers from encrypted V3 keystore files
+[UPDATE]: 2026-10-16 Derive signers from BIP-39 mnemonics
 */
 
+use std::path::Path;
 use std::str::FromStr;
 
-use alloy_signer::Signer;
-use alloy_signer_local::PrivateKeySigner;
+use alloy_dyn_abi::TypedData;
+use alloy_signer::{Signature, Signer};
+use alloy_signer_local::{MnemonicBuilder, PrivateKeySigner, coins_bip39::English};
 use async_trait::async_trait;
+use zeroize::Zeroizing;
 
 use crate::auth::WalletSigner;
-use crate::http::Result;
+use crate::auth::mnemonic::normalize_phrase;
+use crate::http::{Result, StandxError};
 use crate::types::Chain;
 
 /// Signer for EVM-compatible wallets (e.g., BSC)
@@ -37,6 +44,105 @@ impl EvmWalletSigner {
 
         Ok(Self { signer, address })
     }
+
+    /// Create a signer from a Geth/Web3 V3 keystore file
+    ///
+    /// Supports scrypt and pbkdf2 key derivation with aes-128-ctr. The
+    /// decrypted secret is zeroized once the signer is constructed.
+    pub fn from_keystore(path: impl AsRef<Path>, passphrase: &str) -> Result<Self> {
+        let path = path.as_ref();
+        let secret = Zeroizing::new(eth_keystore::decrypt_key(path, passphrase).map_err(|e| {
+            StandxError::Config(format!(
+                "Failed to decrypt keystore {}: {}",
+                path.display(),
+                e
+            ))
+        })?);
+        let signer = PrivateKeySigner::from_slice(&secret)
+            .map_err(|e| StandxError::Config(format!("Invalid keystore private key: {}", e)))?;
+
+        let address = signer.address().to_checksum(None);
+
+        Ok(Self { signer, address })
+    }
+
+    /// Derive a signer from a BIP-39 mnemonic and BIP-32 derivation path
+    ///
+    /// Use `mnemonic::evm_derivation_path(i)` for the standard
+    /// `m/44'/60'/0'/0/i` accounts.
+    pub fn from_mnemonic(phrase: &str, path: &str) -> Result<Self> {
+        let phrase = normalize_phrase(phrase)?;
+        let signer = MnemonicBuilder::<English>::default()
+            .phrase(phrase.as_str())
+            .derivation_path(path)
+            .map_err(|e| {
+                StandxError::Config(format!("Unsupported derivation path {}: {}", path, e))
+            })?
+            .build()
+            .map_err(|e| StandxError::Config(format!("Invalid mnemonic: {}", e)))?;
+
+        let address = signer.address().to_checksum(None);
+
+        Ok(Self { signer, address })
+    }
+
+    /// Sign EIP-712 typed data, matching `eth_signTypedData_v4`
+    ///
+    /// `domain`, `types` and `message` use the JSON shapes of the RPC payload.
+    /// The primary type is the only struct in `types` not referenced by another
+    /// struct. `Signature::as_bytes()` yields the 65-byte r||s||v encoding.
+    pub async fn sign_typed_data(
+        &self,
+        domain: &serde_json::Value,
+        types: &serde_json::Value,
+        message: &serde_json::Value,
+    ) -> Result<Signature> {
+        let typed_data = build_typed_data(domain, types, message)?;
+        self.signer
+            .sign_dynamic_typed_data(&typed_data)
+            .await
+            .map_err(|e| StandxError::Internal(format!("Failed to sign EIP-712 data: {}", e)))
+    }
+}
+
+fn build_typed_data(
+    domain: &serde_json::Value,
+    types: &serde_json::Value,
+    message: &serde_json::Value,
+) -> Result<TypedData> {
+    let primary_type = primary_type(types)?;
+    let payload = serde_json::json!({
+        "domain": domain,
+        "types": types,
+        "primaryType": primary_type,
+        "message": message,
+    });
+    serde_json::from_value(payload)
+        .map_err(|e| StandxError::Config(format!("Invalid EIP-712 typed data: {}", e)))
+}
+
+fn primary_type(types: &serde_json::Value) -> Result<String> {
+    let types = types
+        .as_object()
+        .ok_or_else(|| StandxError::Config("EIP-712 types must be an object".to_string()))?;
+
+    let referenced: Vec<&str> = types
+        .values()
+        .filter_map(|fields| fields.as_array())
+        .flatten()
+        .filter_map(|field| field.get("type").and_then(|ty| ty.as_str()))
+        .map(|ty| ty.split('[').next().unwrap_or(ty))
+        .collect();
+
+    let mut candidates = types
+        .keys()
+        .filter(|name| name.as_str() != "EIP712Domain" && !referenced.contains(&name.as_str()));
+    match (candidates.next(), candidates.next()) {
+        (Some(name), None) => Ok(name.clone()),
+        _ => Err(StandxError::Config(
+            "EIP-712 types must have exactly one primary type".to_string(),
+        )),
+    }
 }
 
 #[async_trait]
@@ -87,6 +193,137 @@ mod tests {
         assert_eq!(signature.len(), 132); // 0x + 65 bytes * 2 = 132
     }
 
+    fn mail_fixture() -> (serde_json::Value, serde_json::Value, serde_json::Value) {
+        // Example from the EIP-712 specification.
+        let domain = serde_json::json!({
+            "name": "Ether Mail",
+            "version": "1",
+            "chainId": 1,
+            "verifyingContract": "0xCcCCccccCCCCcCCCCCCcCcCccCcCCCcCcccccccC"
+        });
+        let types = serde_json::json!({
+            "EIP712Domain": [
+                {"name": "name", "type": "string"},
+                {"name": "version", "type": "string"},
+                {"name": "chainId", "type": "uint256"},
+                {"name": "verifyingContract", "type": "address"}
+            ],
+            "Person": [
+                {"name": "name", "type": "string"},
+                {"name": "wallet", "type": "address"}
+            ],
+            "Mail": [
+                {"name": "from", "type": "Person"},
+                {"name": "to", "type": "Person"},
+                {"name": "contents", "type": "string"}
+            ]
+        });
+        let message = serde_json::json!({
+            "from": {"name": "Cow", "wallet": "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"},
+            "to": {"name": "Bob", "wallet": "0xbBbBBBBbbBBBbbbBbbBbbbbBBbBbbbbBbBbbBBbB"},
+            "contents": "Hello, Bob!"
+        });
+        (domain, types, message)
+    }
+
+    #[tokio::test]
+    async fn test_evm_wallet_sign_typed_data_matches_spec_fixture() {
+        // keccak256("cow"), the signer used in the EIP-712 specification.
+        let pk = "0xc85ef7d79691fe79573b1a7064c19c1a9819ebdbd1faaab1a8ec92344438aaf4";
+        let signer = EvmWalletSigner::new(pk).unwrap();
+        assert_eq!(
+            signer.address(),
+            "0xCD2a3d9F938E13CD947Ec05AbC7FE734Df8DD826"
+        );
+
+        let (domain, types, message) = mail_fixture();
+        let signature = signer
+            .sign_typed_data(&domain, &types, &message)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            hex::encode(signature.as_bytes()),
+            "4355c47d63924e8a72e509b65029052eb6c299d53a04e167c5775fd466751c9d\
+             07299936d304c153f6443dfa05f40ff007d72911b6f72307f996231605b91562\
+             1c"
+        );
+
+        let hash = build_typed_data(&domain, &types, &message)
+            .unwrap()
+            .eip712_signing_hash()
+            .unwrap();
+        let recovered = signature.recover_address_from_prehash(&hash).unwrap();
+        assert_eq!(recovered.to_checksum(None), signer.address());
+    }
+
+    #[test]
+    fn test_primary_type_requires_single_root() {
+        let (_, types, _) = mail_fixture();
+        assert_eq!(primary_type(&types).unwrap(), "Mail");
+
+        let ambiguous = serde_json::json!({
+            "A": [{"name": "x", "type": "uint256"}],
+            "B": [{"name": "y", "type": "uint256"}]
+        });
+        assert!(primary_type(&ambiguous).is_err());
+    }
+
+    #[test]
+    fn test_evm_wallet_signer_from_keystore() {
+        let pk = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        let dir = std::env::temp_dir().join(format!("standx-keystore-{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        eth_keystore::encrypt_key(
+            &dir,
+            &mut rand::thread_rng(),
+            hex::decode(pk).unwrap(),
+            "correct horse",
+            Some("account.json"),
+        )
+        .unwrap();
+        let path = dir.join("account.json");
+
+        let signer = EvmWalletSigner::from_keystore(&path, "correct horse").unwrap();
+        assert_eq!(
+            signer.address(),
+            "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+        );
+
+        let err = EvmWalletSigner::from_keystore(&path, "wrong")
+            .err()
+            .unwrap();
+        assert!(matches!(err, StandxError::Config(_)));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_evm_wallet_signer_from_mnemonic() {
+        let phrase = "test test test test test test test test test test test junk";
+        let path = crate::auth::mnemonic::evm_derivation_path(0);
+        let signer = EvmWalletSigner::from_mnemonic(phrase, &path).unwrap();
+        assert_eq!(
+            signer.address(),
+            "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266"
+        );
+
+        let second = EvmWalletSigner::from_mnemonic(phrase, "m/44'/60'/0'/0/1").unwrap();
+        assert_eq!(
+            second.address(),
+            "0x70997970C51812dc3A010C7d01b50e0d17dc79C8"
+        );
+
+        let err = EvmWalletSigner::from_mnemonic("test test junk", &path)
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("Invalid mnemonic"));
+        let err = EvmWalletSigner::from_mnemonic(phrase, "m/not/a/path")
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("Unsupported derivation path"));
+    }
+
     #[test]
     fn test_evm_wallet_signer_no_prefix() {
         let pk = "ac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
@@ -3,22 +3,129 @@
 [OUTPUT]: Token retrieval and expiration status
 [POS]:    Auth layer - token lifecycle management
 [UPDATE]: When adding token refresh or changing storage strategy
+[UPDATE]: 2026-10-16 Track issue time and expose refresh window helpers
+[UPDATE]: 2026-10-16 Decode exp/iat/sub claims to detect expired tokens
+[UPDATE]: 2026-10-16 Decode address claim and expose the token's wallet address
+[UPDATE]: 2026-10-17 Expose TokenData::expires_at() and read JwtManager expiry from the same claim
 */
 
-use chrono::{DateTime, Duration, Utc};
+use base64::{
+    Engine as _,
+    engine::general_purpose::{URL_SAFE, URL_SAFE_NO_PAD},
+};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde::Deserialize;
 use std::sync::{Arc, RwLock};
+use std::time::SystemTime;
 
+use crate::http::{Result, StandxError};
 use crate::types::Chain;
 
+/// Standard claims decoded from a JWT payload.
+///
+/// The signature is not verified; the server key is not available to clients.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct JwtClaims {
+    #[serde(default)]
+    pub sub: Option<String>,
+    #[serde(default)]
+    pub address: Option<String>,
+    #[serde(default)]
+    pub iat: Option<i64>,
+    #[serde(default)]
+    pub exp: Option<i64>,
+}
+
+impl JwtClaims {
+    /// Decode the payload segment of a compact JWT.
+    pub fn decode(token: &str) -> Result<Self> {
+        let payload_b64 = token.split('.').nth(1).ok_or_else(|| {
+            StandxError::InvalidResponse("JWT missing payload segment".to_string())
+        })?;
+        let payload_bytes = URL_SAFE_NO_PAD
+            .decode(payload_b64)
+            .or_else(|_| URL_SAFE.decode(payload_b64))
+            .map_err(|e| {
+                StandxError::InvalidResponse(format!("Invalid JWT payload base64: {e}"))
+            })?;
+        Ok(serde_json::from_slice(&payload_bytes)?)
+    }
+
+    /// Expiry as a UTC timestamp, if the token carries `exp`.
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.exp.and_then(|exp| Utc.timestamp_opt(exp, 0).single())
+    }
+
+    /// Issue time as a UTC timestamp, if the token carries `iat`.
+    pub fn issued_at(&self) -> Option<DateTime<Utc>> {
+        self.iat.and_then(|iat| Utc.timestamp_opt(iat, 0).single())
+    }
+
+    /// Wallet address the token was issued to: `address`, falling back to `sub`.
+    pub fn wallet_address(&self) -> Option<&str> {
+        [self.address.as_deref(), self.sub.as_deref()]
+            .into_iter()
+            .flatten()
+            .map(str::trim)
+            .find(|value| !value.is_empty())
+    }
+
+    /// Returns true when `exp` is present and not after `now`.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at()
+            .is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
 /// Stored token data with metadata
 #[derive(Debug, Clone)]
 pub struct TokenData {
     pub token: String,
+    pub issued_at: DateTime<Utc>,
     pub expires_at: DateTime<Utc>,
     pub wallet_address: String,
     pub chain: Chain,
 }
 
+impl TokenData {
+    /// Decoded claims of the stored token, if it is a well-formed JWT.
+    pub fn claims(&self) -> Option<JwtClaims> {
+        JwtClaims::decode(&self.token).ok()
+    }
+
+    /// Expiry taken from the token's `exp` claim, falling back to the stored deadline.
+    pub fn expires_at(&self) -> SystemTime {
+        self.expiry().into()
+    }
+
+    /// Returns true once `now` has reached the token expiry.
+    pub fn is_expired(&self, now: SystemTime) -> bool {
+        now >= self.expires_at()
+    }
+
+    fn expiry(&self) -> DateTime<Utc> {
+        self.claims()
+            .and_then(|claims| claims.expires_at())
+            .unwrap_or(self.expires_at)
+    }
+
+    /// Total lifetime of the token.
+    pub fn ttl(&self) -> Duration {
+        self.expires_at - self.issued_at
+    }
+
+    /// Time left before expiry (negative once expired).
+    pub fn remaining(&self, now: DateTime<Utc>) -> Duration {
+        self.expiry() - now
+    }
+
+    /// Returns true when the remaining lifetime is within `window_percent` of the TTL.
+    pub fn needs_refresh(&self, now: DateTime<Utc>, window_percent: u32) -> bool {
+        let window = self.ttl() * window_percent.min(100) as i32 / 100;
+        self.remaining(now) <= window
+    }
+}
+
 /// Thread-safe JWT token manager
 #[derive(Debug, Clone)]
 pub struct JwtManager {
@@ -41,9 +148,14 @@ impl JwtManager {
         wallet_address: String,
         chain: Chain,
     ) {
-        let expires_at = Utc::now() + Duration::seconds(expires_seconds as i64);
+        let claims = JwtClaims::decode(&token).unwrap_or_default();
+        let issued_at = claims.issued_at().unwrap_or_else(Utc::now);
+        let expires_at = claims
+            .expires_at()
+            .unwrap_or_else(|| issued_at + Duration::seconds(expires_seconds as i64));
         let token_data = TokenData {
             token,
+            issued_at,
             expires_at,
             wallet_address,
             chain,
@@ -63,7 +175,22 @@ impl JwtManager {
     pub fn is_expired(&self) -> bool {
         let guard = self.data.read().unwrap();
         match guard.as_ref() {
-            Some(data) => Utc::now() > data.expires_at,
+            Some(data) => data.is_expired(SystemTime::now()),
+            None => true,
+        }
+    }
+
+    /// Get the token expiry if a token is stored
+    pub fn expires_at(&self) -> Option<DateTime<Utc>> {
+        let guard = self.data.read().unwrap();
+        guard.as_ref().map(TokenData::expiry)
+    }
+
+    /// Check if the token is missing or inside the refresh window
+    pub fn needs_refresh(&self, window_percent: u32) -> bool {
+        let guard = self.data.read().unwrap();
+        match guard.as_ref() {
+            Some(data) => data.needs_refresh(Utc::now(), window_percent),
             None => true,
         }
     }
@@ -112,6 +239,82 @@ mod tests {
         assert!(!manager.is_expired());
     }
 
+    #[test]
+    fn test_needs_refresh_within_window() {
+        let issued_at = Utc::now();
+        let data = TokenData {
+            token: "test_token".to_string(),
+            issued_at,
+            expires_at: issued_at + Duration::hours(10),
+            wallet_address: "0x123".to_string(),
+            chain: Chain::Bsc,
+        };
+
+        assert_eq!(data.ttl(), Duration::hours(10));
+        assert!(!data.needs_refresh(issued_at + Duration::hours(8), 10));
+        assert!(data.needs_refresh(issued_at + Duration::hours(9), 10));
+        assert!(data.needs_refresh(issued_at + Duration::hours(11), 10));
+    }
+
+    fn make_jwt(payload: serde_json::Value) -> String {
+        let header = URL_SAFE_NO_PAD.encode(br#"{"alg":"none","typ":"JWT"}"#);
+        let payload = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&payload).unwrap());
+        format!("{header}.{payload}.signature")
+    }
+
+    #[test]
+    fn test_decode_claims_and_detect_expiry() {
+        let token = make_jwt(serde_json::json!({
+            "sub": "0xabc",
+            "iat": 1_700_000_000,
+            "exp": 1_700_003_600,
+        }));
+
+        let claims = JwtClaims::decode(&token).unwrap();
+        assert_eq!(claims.sub.as_deref(), Some("0xabc"));
+        assert_eq!(claims.iat, Some(1_700_000_000));
+        assert_eq!(claims.exp, Some(1_700_003_600));
+
+        let manager = JwtManager::new();
+        manager.set_token(token, 7 * 24 * 3600, "0xabc".to_string(), Chain::Bsc);
+        let data = manager.token_data().unwrap();
+
+        let exp = SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_003_600);
+        assert_eq!(data.expires_at(), exp);
+        assert_eq!(data.ttl(), Duration::hours(1));
+        assert!(!data.is_expired(exp - std::time::Duration::from_secs(1)));
+        assert!(data.is_expired(exp));
+        assert!(manager.is_expired());
+        assert_eq!(manager.expires_at().map(SystemTime::from), Some(exp));
+    }
+
+    #[test]
+    fn test_manager_expiry_follows_claim_over_stored_deadline() {
+        let now = Utc::now();
+        let exp = now - Duration::minutes(5);
+        let data = TokenData {
+            token: make_jwt(serde_json::json!({ "exp": exp.timestamp() })),
+            issued_at: now - Duration::hours(1),
+            expires_at: now + Duration::hours(1),
+            wallet_address: "0x123".to_string(),
+            chain: Chain::Bsc,
+        };
+        let manager = JwtManager {
+            data: Arc::new(RwLock::new(Some(data))),
+        };
+
+        let expires_at = manager.expires_at().unwrap();
+        assert_eq!(expires_at.timestamp(), exp.timestamp());
+        assert!(expires_at <= now);
+        assert!(manager.is_expired());
+    }
+
+    #[test]
+    fn test_decode_claims_rejects_malformed_token() {
+        assert!(JwtClaims::decode("not-a-jwt").is_err());
+        assert!(JwtClaims::decode("a.!!!.c").is_err());
+    }
+
     #[test]
     fn test_clear_token() {
         let manager = JwtManager::new();
@@ -126,4 +329,17 @@ mod tests {
         assert!(manager.get_token().is_none());
         assert!(manager.is_expired());
     }
+
+    #[test]
+    fn test_wallet_address_prefers_address_claim() {
+        let token = make_jwt(serde_json::json!({ "sub": "user-1", "address": "0xabc" }));
+        let claims = JwtClaims::decode(&token).unwrap();
+        assert_eq!(claims.wallet_address(), Some("0xabc"));
+
+        let token = make_jwt(serde_json::json!({ "sub": "0xdef", "address": "" }));
+        let claims = JwtClaims::decode(&token).unwrap();
+        assert_eq!(claims.wallet_address(), Some("0xdef"));
+
+        assert_eq!(JwtClaims::default().wallet_address(), None);
+    }
 }
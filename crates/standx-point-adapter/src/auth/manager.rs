@@ -3,10 +3,14 @@
 [OUTPUT]: Authenticated credentials (JWT token)
 [POS]:    Auth layer - orchestrates complete authentication flow
 [UPDATE]: When auth endpoints or flow steps change
+[UPDATE]: 2026-10-16 Add maybe_refresh and background auto refresh
+[UPDATE]: 2026-10-16 Retry transient authenticate failures with backoff
 */
 
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
 
 use base64::{
     Engine as _,
@@ -14,13 +18,15 @@ use base64::{
 };
 use reqwest::Method;
 use serde::Deserialize;
+use tokio::task::JoinHandle;
 
-use crate::http::{Result, StandxClient, StandxError};
+use crate::http::{BackoffPolicy, Result, StandxClient, StandxError};
 use crate::types::Chain;
 
 use super::{EvmWalletSigner, JwtManager, PersistentKeyManager, SolanaWalletSigner, WalletSigner};
 
 const DEFAULT_EXPIRES_SECONDS: u64 = 7 * 24 * 60 * 60;
+const DEFAULT_REFRESH_WINDOW_PERCENT: u32 = 10;
 
 /// Data returned from prepare-signin endpoint
 #[derive(Debug, Deserialize)]
@@ -47,6 +53,7 @@ pub struct AuthManager {
     client: StandxClient,
     key_manager: PersistentKeyManager,
     jwt_manager: JwtManager,
+    refresh_window_percent: u32,
 }
 
 impl AuthManager {
@@ -63,9 +70,15 @@ impl AuthManager {
             client,
             key_manager: PersistentKeyManager::new(key_dir),
             jwt_manager: JwtManager::new(),
+            refresh_window_percent: DEFAULT_REFRESH_WINDOW_PERCENT,
         }
     }
 
+    /// Set the share of the token TTL (in percent) left before `maybe_refresh` re-authenticates.
+    pub fn set_refresh_window_percent(&mut self, percent: u32) {
+        self.refresh_window_percent = percent.min(100);
+    }
+
     /// Get the JWT manager
     pub fn jwt_manager(&self) -> &JwtManager {
         &self.jwt_manager
@@ -133,6 +146,36 @@ impl AuthManager {
         self.client.send_json(builder).await
     }
 
+    /// Authenticate, retrying transient failures with backoff
+    ///
+    /// Network errors, 5xx and 429 responses are retried up to
+    /// `policy.max_attempts`; permanent errors are returned immediately.
+    pub async fn authenticate_with_retry(
+        &self,
+        wallet: &dyn WalletSigner,
+        expires_seconds: u64,
+        policy: &BackoffPolicy,
+    ) -> Result<LoginResponse> {
+        let mut attempt = 1;
+        loop {
+            match self.authenticate(wallet, expires_seconds).await {
+                Ok(login) => return Ok(login),
+                Err(err) if err.is_transient() && policy.should_retry(attempt) => {
+                    let delay = policy.delay_for(attempt);
+                    tracing::warn!(
+                        address = %wallet.address(),
+                        attempt,
+                        delay_ms = delay.as_millis() as u64,
+                        "authenticate failed, retrying: {err}"
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
     /// Authenticate using wallet private key + chain, while verifying the address.
     pub async fn authenticate_with_wallet(
         &self,
@@ -193,6 +236,50 @@ impl AuthManager {
 
         Ok(login_response)
     }
+
+    /// Re-authenticate when the stored token is missing or close to expiry.
+    ///
+    /// Returns `Ok(None)` when the current token is still outside the refresh
+    /// window. The new token keeps the previous TTL; clients that share this
+    /// manager's `JwtManager` via `StandxClient::set_jwt_source` pick it up.
+    pub async fn maybe_refresh(&self, wallet: &dyn WalletSigner) -> Result<Option<LoginResponse>> {
+        if !self.jwt_manager.needs_refresh(self.refresh_window_percent) {
+            return Ok(None);
+        }
+
+        let expires_seconds = self
+            .jwt_manager
+            .token_data()
+            .and_then(|data| u64::try_from(data.ttl().num_seconds()).ok())
+            .filter(|seconds| *seconds > 0)
+            .unwrap_or(DEFAULT_EXPIRES_SECONDS);
+
+        self.authenticate(wallet, expires_seconds).await.map(Some)
+    }
+
+    /// Spawn a background task that calls `maybe_refresh` every `check_interval`.
+    pub fn spawn_auto_refresh(
+        self: Arc<Self>,
+        wallet: Arc<dyn WalletSigner>,
+        check_interval: Duration,
+    ) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(check_interval);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                interval.tick().await;
+                match self.maybe_refresh(wallet.as_ref()).await {
+                    Ok(Some(login)) => {
+                        tracing::info!(address = %login.address, "jwt refreshed");
+                    }
+                    Ok(None) => {}
+                    Err(err) => {
+                        tracing::warn!(address = %wallet.address(), "jwt refresh failed: {err}");
+                    }
+                }
+            }
+        })
+    }
 }
 
 fn default_key_dir() -> PathBuf {
@@ -296,7 +383,7 @@ mod tests {
 
     use base64::engine::general_purpose::URL_SAFE_NO_PAD;
     use uuid::Uuid;
-    use wiremock::matchers::{body_json, method, path, query_param};
+    use wiremock::matchers::{body_json, header, method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
     fn temp_dir() -> PathBuf {
@@ -347,6 +434,104 @@ mod tests {
         fs::remove_dir_all(dir).unwrap();
     }
 
+    fn fast_backoff() -> BackoffPolicy {
+        BackoffPolicy {
+            initial_delay: Duration::from_millis(1),
+            max_delay: Duration::from_millis(5),
+            max_attempts: 3,
+        }
+    }
+
+    // send_json makes up to four tries per request before surfacing an error.
+    const SEND_JSON_TRIES: u64 = 4;
+
+    #[tokio::test]
+    async fn test_auth_manager_authenticate_with_retry_recovers_from_transient_failure() {
+        let server = MockServer::start().await;
+        let client = StandxClient::with_config_and_base_urls(
+            crate::http::ClientConfig::default(),
+            &server.uri(),
+            &server.uri(),
+        )
+        .unwrap();
+        let dir = temp_dir();
+        let auth_manager = AuthManager::new_with_key_dir(client, &dir);
+
+        let pk = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        let wallet = EvmWalletSigner::new(pk).unwrap();
+
+        Mock::given(method("POST"))
+            .and(path("/v1/offchain/prepare-signin"))
+            .respond_with(ResponseTemplate::new(503).set_body_json(serde_json::json!({
+                "message": "service unavailable",
+            })))
+            .up_to_n_times(SEND_JSON_TRIES)
+            .with_priority(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/offchain/prepare-signin"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "signedData": make_test_jwt("hello"),
+            })))
+            .with_priority(2)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/v1/offchain/login"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "token": "jwt-token",
+                "address": wallet.address(),
+                "chain": "bsc",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let login = auth_manager
+            .authenticate_with_retry(&wallet, DEFAULT_EXPIRES_SECONDS, &fast_backoff())
+            .await
+            .unwrap();
+        assert_eq!(login.token, "jwt-token");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_auth_manager_authenticate_with_retry_fails_fast_on_permanent_error() {
+        let server = MockServer::start().await;
+        let client = StandxClient::with_config_and_base_urls(
+            crate::http::ClientConfig::default(),
+            &server.uri(),
+            &server.uri(),
+        )
+        .unwrap();
+        let dir = temp_dir();
+        let auth_manager = AuthManager::new_with_key_dir(client, &dir);
+
+        let pk = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        let wallet = EvmWalletSigner::new(pk).unwrap();
+
+        // A single authenticate attempt only; no backoff retries.
+        Mock::given(method("POST"))
+            .and(path("/v1/offchain/prepare-signin"))
+            .respond_with(ResponseTemplate::new(400).set_body_json(serde_json::json!({
+                "message": "address mismatch",
+            })))
+            .expect(SEND_JSON_TRIES)
+            .mount(&server)
+            .await;
+
+        let err = auth_manager
+            .authenticate_with_retry(&wallet, DEFAULT_EXPIRES_SECONDS, &fast_backoff())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, StandxError::Api { code: 400, .. }));
+        assert!(!err.is_transient());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
     #[tokio::test]
     async fn test_auth_manager_authenticate_with_wallet_happy_path_bsc() {
         let server = MockServer::start().await;
@@ -427,4 +612,81 @@ mod tests {
 
         fs::remove_dir_all(dir).unwrap();
     }
+
+    #[tokio::test]
+    async fn test_auth_manager_maybe_refresh_updates_shared_jwt() {
+        let server = MockServer::start().await;
+
+        let client = StandxClient::with_config_and_base_urls(
+            crate::http::ClientConfig::default(),
+            &server.uri(),
+            &server.uri(),
+        )
+        .unwrap();
+        let dir = temp_dir();
+        let auth_manager = AuthManager::new_with_key_dir(client, &dir);
+
+        let pk = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        let wallet = EvmWalletSigner::new(pk).unwrap();
+
+        auth_manager.jwt_manager().set_token(
+            "old-token".to_string(),
+            3600,
+            wallet.address().to_string(),
+            Chain::Bsc,
+        );
+        assert!(auth_manager.maybe_refresh(&wallet).await.unwrap().is_none());
+
+        Mock::given(method("POST"))
+            .and(path("/v1/offchain/prepare-signin"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "signedData": make_test_jwt("hello"),
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/v1/offchain/login"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "token": "new-token",
+                "address": wallet.address(),
+                "chain": "bsc",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/query_positions"))
+            .and(header("authorization", "Bearer new-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        auth_manager.jwt_manager().set_token(
+            "old-token".to_string(),
+            0,
+            wallet.address().to_string(),
+            Chain::Bsc,
+        );
+        let refreshed = auth_manager.maybe_refresh(&wallet).await.unwrap();
+        assert_eq!(
+            refreshed.map(|login| login.token).as_deref(),
+            Some("new-token")
+        );
+
+        let mut trading_client = StandxClient::with_config_and_base_urls(
+            crate::http::ClientConfig::default(),
+            &server.uri(),
+            &server.uri(),
+        )
+        .unwrap();
+        trading_client.set_jwt_source(auth_manager.jwt_manager().clone());
+        let positions = trading_client.query_positions(None).await.unwrap();
+        assert!(positions.is_empty());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
 }
@@ -0,0 +1,138 @@
+/*
+[INPUT]:  BIP-39 mnemonic phrases and derivation paths
+[OUTPUT]: Validated mnemonics, BIP-39 seeds and SLIP-0010 ed25519 keys
+[POS]:    Auth layer - shared HD wallet derivation helpers
+[UPDATE]: When adding wordlists or derivation schemes
+[UPDATE]: 2026-10-16 Add BIP-39 parsing and SLIP-0010 ed25519 derivation
+*/
+
+use coins_bip39::{English, Mnemonic};
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use zeroize::Zeroizing;
+
+use crate::http::{Result, StandxError};
+
+const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+/// Standard EVM derivation path for account `index` (`m/44'/60'/0'/0/{index}`)
+pub fn evm_derivation_path(index: u32) -> String {
+    format!("m/44'/60'/0'/0/{index}")
+}
+
+/// Standard Solana derivation path for account `index` (`m/44'/501'/{index}'/0'`)
+pub fn solana_derivation_path(index: u32) -> String {
+    format!("m/44'/501'/{index}'/0'")
+}
+
+/// Collapse whitespace and validate the English wordlist and checksum.
+pub(crate) fn normalize_phrase(phrase: &str) -> Result<Zeroizing<String>> {
+    let normalized = Zeroizing::new(
+        phrase
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>()
+            .join(" "),
+    );
+    Mnemonic::<English>::new_from_phrase(&normalized)
+        .map_err(|e| StandxError::Config(format!("Invalid mnemonic: {}", e)))?;
+    Ok(normalized)
+}
+
+/// BIP-39 seed (no passphrase) for a validated phrase.
+pub(crate) fn mnemonic_seed(phrase: &str) -> Result<Zeroizing<[u8; 64]>> {
+    let normalized = normalize_phrase(phrase)?;
+    let mnemonic = Mnemonic::<English>::new_from_phrase(&normalized)
+        .map_err(|e| StandxError::Config(format!("Invalid mnemonic: {}", e)))?;
+    let seed = mnemonic
+        .to_seed(None)
+        .map_err(|e| StandxError::Config(format!("Invalid mnemonic: {}", e)))?;
+    Ok(Zeroizing::new(seed))
+}
+
+/// Derive an ed25519 secret key with SLIP-0010 (hardened segments only).
+pub(crate) fn slip10_ed25519_key(seed: &[u8], path: &str) -> Result<Zeroizing<[u8; 32]>> {
+    let segments = parse_hardened_path(path)?;
+
+    let (mut key, mut chain_code) = hmac_halves(b"ed25519 seed", &[seed]);
+    for index in segments {
+        let data_index = (index | HARDENED_OFFSET).to_be_bytes();
+        (key, chain_code) = hmac_halves(
+            chain_code.as_slice(),
+            &[&[0u8], key.as_slice(), &data_index],
+        );
+    }
+    Ok(key)
+}
+
+fn parse_hardened_path(path: &str) -> Result<Vec<u32>> {
+    let unsupported = || StandxError::Config(format!("Unsupported derivation path: {path}"));
+
+    let mut parts = path.trim().split('/');
+    if parts.next() != Some("m") {
+        return Err(unsupported());
+    }
+    parts
+        .map(|part| {
+            let index = part
+                .strip_suffix('\'')
+                .or_else(|| part.strip_suffix('h'))
+                .ok_or_else(unsupported)?;
+            index
+                .parse::<u32>()
+                .ok()
+                .filter(|index| *index < HARDENED_OFFSET)
+                .ok_or_else(unsupported)
+        })
+        .collect()
+}
+
+fn hmac_halves(key: &[u8], parts: &[&[u8]]) -> (Zeroizing<[u8; 32]>, Zeroizing<[u8; 32]>) {
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts any key length");
+    for part in parts {
+        mac.update(part);
+    }
+    let output = Zeroizing::new(<[u8; 64]>::from(mac.finalize().into_bytes()));
+
+    let mut left = Zeroizing::new([0u8; 32]);
+    let mut right = Zeroizing::new([0u8; 32]);
+    left.copy_from_slice(&output[..32]);
+    right.copy_from_slice(&output[32..]);
+    (left, right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_slip10_ed25519_vector() {
+        // SLIP-0010 test vector 1 for ed25519
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap();
+
+        let key = slip10_ed25519_key(&seed, "m/0'").unwrap();
+        assert_eq!(
+            hex::encode(key.as_slice()),
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3"
+        );
+
+        let key = slip10_ed25519_key(&seed, "m/0'/1'").unwrap();
+        assert_eq!(
+            hex::encode(key.as_slice()),
+            "b1d0bad404bf35da785a64ca1ac54b2617211d2777696fbffaf208f746ae84f2"
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_phrase_and_path() {
+        let bad_checksum = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon";
+        let err = normalize_phrase(bad_checksum).unwrap_err();
+        assert!(err.to_string().contains("Invalid mnemonic"));
+
+        let seed = [0u8; 64];
+        for path in ["44'/501'/0'", "m/44'/501'/0/0'", "m/x'"] {
+            let err = slip10_ed25519_key(&seed, path).unwrap_err();
+            assert!(err.to_string().contains("Unsupported derivation path"));
+        }
+    }
+}
@@ -8,13 +8,14 @@
 pub mod evm_wallet;
 pub mod jwt;
 pub mod manager;
+pub mod mnemonic;
 pub mod persistent_key;
 pub mod signer;
 pub mod solana_wallet;
 pub mod wallet;
 
 pub use evm_wallet::EvmWalletSigner;
-pub use jwt::{JwtManager, TokenData};
+pub use jwt::{JwtClaims, JwtManager, TokenData};
 pub use manager::{AuthManager, LoginResponse, SigninData};
 pub use persistent_key::PersistentKeyManager;
 pub use signer::Ed25519Signer;
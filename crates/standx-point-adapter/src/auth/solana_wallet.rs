@@ -3,6 +3,7 @@
 [OUTPUT]: Base64-encoded JSON signature for Solana authentication
 [POS]:    Auth layer - Solana wallet implementation
 [UPDATE]: When Solana signature format or SDK version changes
+[UPDATE]: 2026-10-16 Derive signers from BIP-39 mnemonics (SLIP-0010)
 */
 
 use async_trait::async_trait;
@@ -12,6 +13,7 @@ use serde::Serialize;
 use solana_keypair::{Keypair, keypair_from_seed};
 use solana_signer::Signer;
 
+use crate::auth::mnemonic::{mnemonic_seed, slip10_ed25519_key};
 use crate::auth::wallet::WalletSigner;
 use crate::http::{Result, StandxError};
 use crate::types::Chain;
@@ -67,6 +69,21 @@ impl SolanaWalletSigner {
 
         Ok(Self { keypair, address })
     }
+
+    /// Derive a signer from a BIP-39 mnemonic and SLIP-0010 derivation path
+    ///
+    /// Only hardened paths are valid for ed25519; use
+    /// `mnemonic::solana_derivation_path(i)` for `m/44'/501'/i'/0'`.
+    pub fn from_mnemonic(phrase: &str, path: &str) -> Result<Self> {
+        let seed = mnemonic_seed(phrase)?;
+        let secret = slip10_ed25519_key(seed.as_slice(), path)?;
+        let keypair = keypair_from_seed(secret.as_slice())
+            .map_err(|e| StandxError::Config(format!("Invalid seed bytes: {}", e)))?;
+
+        let address = keypair.pubkey().to_string();
+
+        Ok(Self { keypair, address })
+    }
 }
 
 #[async_trait]
@@ -139,4 +156,31 @@ mod tests {
         let result = SolanaWalletSigner::new("bs58tooShort");
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_solana_signer_from_mnemonic() {
+        let phrase = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon about";
+        let path = crate::auth::mnemonic::solana_derivation_path(0);
+        let signer = SolanaWalletSigner::from_mnemonic(phrase, &path).unwrap();
+        // Matches the first account Solana wallets derive for this phrase.
+        assert_eq!(
+            signer.address(),
+            "HAgk14JpMQLgt6rVgv7cBQFJWFto5Dqxi472uT3DKpqk"
+        );
+
+        let again = SolanaWalletSigner::from_mnemonic(phrase, &path).unwrap();
+        assert_eq!(signer.address(), again.address());
+
+        let next = SolanaWalletSigner::from_mnemonic(
+            phrase,
+            &crate::auth::mnemonic::solana_derivation_path(1),
+        )
+        .unwrap();
+        assert_ne!(signer.address(), next.address());
+
+        let err = SolanaWalletSigner::from_mnemonic(phrase, "m/44'/501'/0/0")
+            .err()
+            .unwrap();
+        assert!(err.to_string().contains("Unsupported derivation path"));
+    }
 }
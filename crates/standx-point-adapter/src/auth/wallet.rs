@@ -14,6 +14,7 @@ use crate::types::Chain;
 ///
 /// Implement this trait for your wallet type (EVM, Solana, etc.)
 /// The trait is async to support hardware wallets and external signers.
+#[allow(clippy::double_must_use)]
 #[async_trait]
 pub trait WalletSigner: Send + Sync {
     /// Get the blockchain chain type
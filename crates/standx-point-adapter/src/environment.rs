@@ -0,0 +1,152 @@
+/*
+[INPUT]:  Deployment selection (mainnet, testnet, or explicit endpoints)
+[OUTPUT]: Coherent REST and WebSocket endpoint set
+[POS]:    Crate root - endpoint presets shared by http and ws
+[UPDATE]: When StandX hosts change or a new deployment is added
+[UPDATE]: 2026-10-17 Add StandxEnvironment presets for REST and WS endpoints
+*/
+
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+use crate::http::client::base_url_from_env;
+use crate::http::{
+    AUTH_BASE_URL, AUTH_BASE_URL_ENV, Result, StandxError, TRADING_BASE_URL, TRADING_BASE_URL_ENV,
+};
+
+/// Public market data stream on mainnet
+pub const MARKET_STREAM_URL: &str = "wss://perps.standx.com/ws-stream/v1";
+/// Order response stream on mainnet
+pub const ORDER_STREAM_URL: &str = "wss://perps.standx.com/ws-api/v1";
+
+const TESTNET_AUTH_BASE_URL: &str = "https://api-testnet.standx.com";
+const TESTNET_TRADING_BASE_URL: &str = "https://perps-testnet.standx.com";
+const TESTNET_MARKET_STREAM_URL: &str = "wss://perps-testnet.standx.com/ws-stream/v1";
+const TESTNET_ORDER_STREAM_URL: &str = "wss://perps-testnet.standx.com/ws-api/v1";
+
+/// StandX deployment; picks REST and WebSocket endpoints together so they
+/// cannot point at different deployments.
+///
+/// Serialized as `"mainnet"`, `"testnet"`, or `{ custom = { ... } }`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StandxEnvironment {
+    #[default]
+    Mainnet,
+    Testnet,
+    /// Explicit endpoints, e.g. a staging deployment
+    Custom {
+        auth_base_url: String,
+        trading_base_url: String,
+        market_stream_url: String,
+        order_stream_url: String,
+    },
+}
+
+impl StandxEnvironment {
+    pub fn auth_base_url(&self) -> &str {
+        match self {
+            Self::Mainnet => AUTH_BASE_URL,
+            Self::Testnet => TESTNET_AUTH_BASE_URL,
+            Self::Custom { auth_base_url, .. } => auth_base_url,
+        }
+    }
+
+    pub fn trading_base_url(&self) -> &str {
+        match self {
+            Self::Mainnet => TRADING_BASE_URL,
+            Self::Testnet => TESTNET_TRADING_BASE_URL,
+            Self::Custom {
+                trading_base_url, ..
+            } => trading_base_url,
+        }
+    }
+
+    pub fn market_stream_url(&self) -> &str {
+        match self {
+            Self::Mainnet => MARKET_STREAM_URL,
+            Self::Testnet => TESTNET_MARKET_STREAM_URL,
+            Self::Custom {
+                market_stream_url, ..
+            } => market_stream_url,
+        }
+    }
+
+    pub fn order_stream_url(&self) -> &str {
+        match self {
+            Self::Mainnet => ORDER_STREAM_URL,
+            Self::Testnet => TESTNET_ORDER_STREAM_URL,
+            Self::Custom {
+                order_stream_url, ..
+            } => order_stream_url,
+        }
+    }
+
+    /// REST base URLs, with `STANDX_AUTH_BASE_URL` / `STANDX_TRADING_BASE_URL`
+    /// taking precedence when set.
+    pub fn base_urls(&self) -> Result<(Url, Url)> {
+        Ok((
+            base_url_from_env(AUTH_BASE_URL_ENV, self.auth_base_url())?,
+            base_url_from_env(TRADING_BASE_URL_ENV, self.trading_base_url())?,
+        ))
+    }
+
+    /// Check that every endpoint parses with the expected scheme.
+    pub fn validate(&self) -> Result<()> {
+        let check = |name: &str, raw: &str, schemes: [&str; 2]| {
+            let url = Url::parse(raw.trim())
+                .map_err(|err| StandxError::Config(format!("invalid {name}: {err}")))?;
+            if !schemes.contains(&url.scheme()) || url.host_str().is_none() {
+                return Err(StandxError::Config(format!(
+                    "invalid {name}: expected a {} URL with a host",
+                    schemes.join("/")
+                )));
+            }
+            Ok(())
+        };
+        check("auth_base_url", self.auth_base_url(), ["http", "https"])?;
+        check(
+            "trading_base_url",
+            self.trading_base_url(),
+            ["http", "https"],
+        )?;
+        check("market_stream_url", self.market_stream_url(), ["ws", "wss"])?;
+        check("order_stream_url", self.order_stream_url(), ["ws", "wss"])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_environment_serde_and_validation() {
+        let parsed: StandxEnvironment = serde_json::from_str("\"testnet\"").unwrap();
+        assert_eq!(parsed, StandxEnvironment::Testnet);
+        assert!(parsed.market_stream_url().contains("testnet"));
+
+        let custom: StandxEnvironment = serde_json::from_value(serde_json::json!({
+            "custom": {
+                "auth_base_url": "https://auth.staging.example.com",
+                "trading_base_url": "https://perps.staging.example.com",
+                "market_stream_url": "wss://perps.staging.example.com/ws-stream/v1",
+                "order_stream_url": "wss://perps.staging.example.com/ws-api/v1"
+            }
+        }))
+        .unwrap();
+        custom.validate().unwrap();
+        assert_eq!(
+            custom.market_stream_url(),
+            "wss://perps.staging.example.com/ws-stream/v1"
+        );
+
+        let mixed = StandxEnvironment::Custom {
+            auth_base_url: AUTH_BASE_URL.to_string(),
+            trading_base_url: TRADING_BASE_URL.to_string(),
+            market_stream_url: "https://perps.standx.com/ws-stream/v1".to_string(),
+            order_stream_url: ORDER_STREAM_URL.to_string(),
+        };
+        let err = mixed.validate().unwrap_err().to_string();
+        assert!(err.contains("market_stream_url"), "{err}");
+    }
+}
@@ -0,0 +1,67 @@
+/*
+[INPUT]:  Retry attempt numbers
+[OUTPUT]: Exponential backoff delays bounded by attempt count and max delay
+[POS]:    HTTP layer - shared retry pacing
+[UPDATE]: When changing retry pacing or adding jitter
+[UPDATE]: 2026-10-16 Add BackoffPolicy for bounded exponential retries
+*/
+
+use std::time::Duration;
+
+/// Bounded exponential backoff shared by retry loops.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BackoffPolicy {
+    /// Delay before the first retry
+    pub initial_delay: Duration,
+    /// Upper bound for any single delay
+    pub max_delay: Duration,
+    /// Total attempts including the first one
+    pub max_attempts: u32,
+}
+
+impl Default for BackoffPolicy {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            max_attempts: 4,
+        }
+    }
+}
+
+impl BackoffPolicy {
+    /// Delay to wait after the given failed attempt (1-based), doubling each time.
+    pub fn delay_for(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        self.initial_delay
+            .saturating_mul(1u32 << exponent)
+            .min(self.max_delay)
+    }
+
+    /// Returns true if another attempt is allowed after `attempt` failures.
+    pub fn should_retry(&self, attempt: u32) -> bool {
+        attempt < self.max_attempts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_doubles_and_caps() {
+        let policy = BackoffPolicy {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+            max_attempts: 5,
+        };
+
+        assert_eq!(policy.delay_for(1), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(2), Duration::from_millis(200));
+        assert_eq!(policy.delay_for(3), Duration::from_millis(400));
+        assert_eq!(policy.delay_for(4), Duration::from_millis(500));
+        assert_eq!(policy.delay_for(40), Duration::from_millis(500));
+        assert!(policy.should_retry(4));
+        assert!(!policy.should_retry(5));
+    }
+}
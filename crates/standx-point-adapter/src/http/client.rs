@@ -3,31 +3,120 @@
 [OUTPUT]: Configured reqwest client ready for API calls
 [POS]:    HTTP layer - core client implementation
 [UPDATE]: When adding connection options or changing client behavior
+[UPDATE]: 2026-10-16 Add optional order limiter shared across clients
+[UPDATE]: 2026-10-16 Allow a shared JwtManager to supply the bearer token
+[UPDATE]: 2026-10-16 Add optional gzip request/response compression
+[UPDATE]: 2026-10-16 Export default base URLs for startup reporting
+[UPDATE]: 2026-10-17 Pace every request through an optional shared RateLimiter
+[UPDATE]: 2026-10-17 Route requests through an optional HTTP/SOCKS proxy (STANDX_HTTPS_PROXY overrides)
+[UPDATE]: 2026-10-17 Correct signed request timestamps by a server clock offset
+[UPDATE]: 2026-10-17 Add send_json_once for requests unsafe to retry blindly
+[UPDATE]: 2026-10-17 Read base URL overrides from STANDX_AUTH_BASE_URL / STANDX_TRADING_BASE_URL
+[UPDATE]: 2026-10-17 Add with_environment; clients remember their StandxEnvironment
+[UPDATE]: 2026-10-17 Add self_test_signature to catch signer key-loading bugs
+[UPDATE]: 2026-10-17 Time each request attempt into an optional shared LatencyTracker
+[UPDATE]: 2026-10-17 Add ClientConfig::pinned_cert to pin the server TLS certificate
+[UPDATE]: 2026-10-17 Negotiate gzip/deflate responses by default (decompress_responses)
+[UPDATE]: 2026-10-17 Sign order actions after the order permit and each rate-limiter wait
 */
 
+use super::clock::ClockSync;
 use super::error::{Result as HttpResult, StandxError};
+use super::latency::LatencyTracker;
+use super::pinning::{map_send_error, pinned_tls_config};
+use super::rate_limit::{RateLimitConfig, RateLimiter};
 use super::signature::{
     BodySignature, DEFAULT_SIGNATURE_VERSION, HEADER_REQUEST_ID, HEADER_REQUEST_SIGNATURE,
     HEADER_REQUEST_TIMESTAMP, HEADER_REQUEST_VERSION, RequestSigner,
 };
-use crate::auth::Ed25519Signer;
+use crate::auth::{Ed25519Signer, JwtManager};
+use crate::environment::StandxEnvironment;
 use crate::types::Chain;
-use reqwest::header::{AUTHORIZATION, CONTENT_TYPE};
-use reqwest::{Client, Method, RequestBuilder, Url};
+use flate2::Compression;
+use flate2::write::GzEncoder;
+use reqwest::header::{AUTHORIZATION, CONTENT_ENCODING, CONTENT_TYPE};
+use reqwest::{Client, Method, Proxy, RequestBuilder, Url};
 use serde::de::DeserializeOwned;
 use serde_json::Value as JsonValue;
-use std::time::Duration;
-use tracing::error;
+use std::io::Write as _;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tracing::{error, warn};
 
 /// Base URLs for StandX API
-const AUTH_BASE_URL: &str = "https://api.standx.com";
-const TRADING_BASE_URL: &str = "https://perps.standx.com";
+pub const AUTH_BASE_URL: &str = "https://api.standx.com";
+pub const TRADING_BASE_URL: &str = "https://perps.standx.com";
+
+/// Environment variable overriding [`AUTH_BASE_URL`]
+pub const AUTH_BASE_URL_ENV: &str = "STANDX_AUTH_BASE_URL";
+
+/// Environment variable overriding [`TRADING_BASE_URL`]
+pub const TRADING_BASE_URL_ENV: &str = "STANDX_TRADING_BASE_URL";
+
+/// Mainnet auth and trading base URLs, from the environment when set
+///
+/// Blank variables fall back to the production defaults; set ones must be
+/// absolute `http`/`https` URLs.
+pub fn base_urls_from_env() -> HttpResult<(Url, Url)> {
+    StandxEnvironment::Mainnet.base_urls()
+}
+
+pub(crate) fn base_url_from_env(var: &str, default: &str) -> HttpResult<Url> {
+    let raw = std::env::var(var)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| default.to_string());
+    let url =
+        Url::parse(&raw).map_err(|err| StandxError::Config(format!("invalid {var}: {err}")))?;
+    if !matches!(url.scheme(), "http" | "https") || url.host_str().is_none() {
+        return Err(StandxError::Config(format!(
+            "invalid {var}: expected an http(s) URL with a host"
+        )));
+    }
+    Ok(url)
+}
+
+/// Environment variable holding a proxy URL that overrides `ClientConfig::proxy`
+pub const PROXY_ENV: &str = "STANDX_HTTPS_PROXY";
+
+const PROXY_SCHEMES: [&str; 4] = ["http", "https", "socks5", "socks5h"];
+
+/// Retries after the first attempt for requests that may be repeated.
+const SEND_MAX_RETRIES: usize = 3;
+
+/// Request handed to the send loop.
+enum Outgoing<'a> {
+    /// Fully built request, cloned for each attempt
+    Built(Box<RequestBuilder>),
+    /// Trading POST signed afresh for each attempt
+    Signed { endpoint: &'a str, payload: &'a str },
+}
 
 /// HTTP client configuration
 #[derive(Debug, Clone)]
 pub struct ClientConfig {
     pub timeout: Duration,
     pub connect_timeout: Duration,
+    /// Gzip signed request bodies.
+    pub compression: bool,
+    /// Send `Accept-Encoding: gzip, deflate` and decode compressed responses
+    /// transparently; disable to see raw bodies on the wire when debugging.
+    pub decompress_responses: bool,
+    /// Token-bucket budget for all requests; `None` sends unthrottled.
+    pub rate_limit: Option<RateLimitConfig>,
+    /// Outbound proxy for all requests; `STANDX_HTTPS_PROXY` takes precedence.
+    pub proxy: Option<ProxyConfig>,
+    /// Resync the signing clock against server time this often; `None`
+    /// keeps the offset fixed (zero unless set explicitly).
+    pub time_sync_interval: Option<Duration>,
+    /// Pin the server's leaf certificate: a DER certificate, or the 32-byte
+    /// SHA-256 of its DER SubjectPublicKeyInfo. Checked after standard
+    /// WebPKI verification. When StandX rotates its certificate (or key, for
+    /// an SPKI pin) every request fails closed with a `Config` error naming
+    /// the pin until it is updated. `None` keeps standard verification only.
+    pub pinned_cert: Option<Vec<u8>>,
 }
 
 impl Default for ClientConfig {
@@ -35,8 +124,85 @@ impl Default for ClientConfig {
         Self {
             timeout: Duration::from_secs(30),
             connect_timeout: Duration::from_secs(10),
+            compression: false,
+            decompress_responses: true,
+            rate_limit: None,
+            proxy: None,
+            time_sync_interval: None,
+            pinned_cert: None,
+        }
+    }
+}
+
+/// Outbound proxy settings
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProxyConfig {
+    /// Proxy URL: `http://`, `https://`, `socks5://` or `socks5h://`
+    pub url: String,
+    /// Basic auth username (optional)
+    pub username: Option<String>,
+    /// Basic auth password, used with `username`
+    pub password: Option<String>,
+}
+
+impl ProxyConfig {
+    /// Proxy without authentication
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            username: None,
+            password: None,
+        }
+    }
+
+    /// Proxy from `STANDX_HTTPS_PROXY`, if set and non-empty
+    pub fn from_env() -> Option<Self> {
+        std::env::var(PROXY_ENV)
+            .ok()
+            .map(|url| url.trim().to_string())
+            .filter(|url| !url.is_empty())
+            .map(Self::new)
+    }
+
+    /// Validate the URL and build the reqwest proxy.
+    ///
+    /// Errors never echo the URL, which may carry credentials.
+    fn to_reqwest(&self) -> HttpResult<Proxy> {
+        let url = Url::parse(self.url.trim())
+            .map_err(|err| StandxError::Config(format!("invalid proxy url: {err}")))?;
+        if !PROXY_SCHEMES.contains(&url.scheme()) {
+            return Err(StandxError::Config(format!(
+                "unsupported proxy scheme {:?}; expected one of {}",
+                url.scheme(),
+                PROXY_SCHEMES.join(", ")
+            )));
+        }
+        if url.host_str().is_none() {
+            return Err(StandxError::Config("proxy url has no host".to_string()));
         }
+        let proxy = Proxy::all(url)
+            .map_err(|err| StandxError::Config(format!("invalid proxy url: {err}")))?;
+        Ok(match &self.username {
+            Some(username) => proxy.basic_auth(username, self.password.as_deref().unwrap_or("")),
+            None => proxy,
+        })
+    }
+}
+
+/// Build the reqwest client shared by all constructors.
+fn build_http_client(config: &ClientConfig) -> Result<Client, Box<dyn std::error::Error>> {
+    let mut builder = Client::builder()
+        .timeout(config.timeout)
+        .connect_timeout(config.connect_timeout)
+        .gzip(config.decompress_responses)
+        .deflate(config.decompress_responses);
+    if let Some(proxy) = ProxyConfig::from_env().or_else(|| config.proxy.clone()) {
+        builder = builder.proxy(proxy.to_reqwest()?);
     }
+    if let Some(pinned_cert) = &config.pinned_cert {
+        builder = builder.use_preconfigured_tls(pinned_tls_config(pinned_cert)?);
+    }
+    Ok(builder.build()?)
 }
 
 /// Credentials for authenticated requests
@@ -56,6 +222,14 @@ pub struct StandxClient {
     trading_base_url: Url,
     credentials: Option<Credentials>,
     request_signer: Option<RequestSigner>,
+    order_limiter: Option<Arc<Semaphore>>,
+    jwt_source: Option<JwtManager>,
+    compression: bool,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    clock: Arc<ClockSync>,
+    time_sync_interval: Option<Duration>,
+    environment: StandxEnvironment,
+    latency: Option<Arc<LatencyTracker>>,
 }
 
 #[allow(dead_code)]
@@ -66,31 +240,57 @@ impl StandxClient {
     }
 
     /// Create a new client with custom configuration
+    ///
+    /// Base URLs come from [`base_urls_from_env`].
     pub fn with_config(config: ClientConfig) -> Result<Self, Box<dyn std::error::Error>> {
-        let http_client = Client::builder()
-            .timeout(config.timeout)
-            .connect_timeout(config.connect_timeout)
-            .build()?;
+        let http_client = build_http_client(&config)?;
+        let (auth_base_url, trading_base_url) = base_urls_from_env()?;
 
         Ok(Self {
             http_client,
-            auth_base_url: Url::parse(AUTH_BASE_URL)?,
-            trading_base_url: Url::parse(TRADING_BASE_URL)?,
+            auth_base_url,
+            trading_base_url,
             credentials: None,
             request_signer: None,
+            order_limiter: None,
+            jwt_source: None,
+            compression: config.compression,
+            rate_limiter: config
+                .rate_limit
+                .map(|limit| Arc::new(RateLimiter::new(limit))),
+            clock: Arc::new(ClockSync::default()),
+            time_sync_interval: config.time_sync_interval,
+            environment: StandxEnvironment::Mainnet,
+            latency: None,
         })
     }
 
+    /// Create a new client for `environment`'s REST endpoints
+    ///
+    /// Base URL env vars still take precedence. The environment is kept so
+    /// WebSocket streams can connect to the same deployment.
+    pub fn with_environment(
+        config: ClientConfig,
+        environment: &StandxEnvironment,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        environment.validate()?;
+        let (auth_base_url, trading_base_url) = environment.base_urls()?;
+        let mut client = Self::with_config_and_base_urls(
+            config,
+            auth_base_url.as_str(),
+            trading_base_url.as_str(),
+        )?;
+        client.environment = environment.clone();
+        Ok(client)
+    }
+
     /// Create a new client with custom base URLs (useful for tests).
     pub fn with_config_and_base_urls(
         config: ClientConfig,
         auth_base_url: &str,
         trading_base_url: &str,
     ) -> Result<Self, Box<dyn std::error::Error>> {
-        let http_client = Client::builder()
-            .timeout(config.timeout)
-            .connect_timeout(config.connect_timeout)
-            .build()?;
+        let http_client = build_http_client(&config)?;
 
         Ok(Self {
             http_client,
@@ -98,6 +298,16 @@ impl StandxClient {
             trading_base_url: Url::parse(trading_base_url)?,
             credentials: None,
             request_signer: None,
+            order_limiter: None,
+            jwt_source: None,
+            compression: config.compression,
+            rate_limiter: config
+                .rate_limit
+                .map(|limit| Arc::new(RateLimiter::new(limit))),
+            clock: Arc::new(ClockSync::default()),
+            time_sync_interval: config.time_sync_interval,
+            environment: StandxEnvironment::Mainnet,
+            latency: None,
         })
     }
 
@@ -117,6 +327,67 @@ impl StandxClient {
         self.request_signer = Some(RequestSigner::new(signer));
     }
 
+    /// Bound concurrent order-mutating requests with a shared semaphore.
+    ///
+    /// Clients for tasks on the same account can share one limiter so their
+    /// order actions do not race each other on the exchange.
+    pub fn set_order_limiter(&mut self, limiter: Arc<Semaphore>) {
+        self.order_limiter = Some(limiter);
+    }
+
+    /// Pace requests with a shared token bucket, replacing any limiter
+    /// built from `ClientConfig::rate_limit`.
+    ///
+    /// Clients sharing one limiter draw from a single request budget.
+    pub fn set_rate_limiter(&mut self, limiter: Arc<RateLimiter>) {
+        self.rate_limiter = Some(limiter);
+    }
+
+    /// Record every request's round-trip time, keyed by endpoint path.
+    ///
+    /// Each attempt is timed separately, after any rate-limiter wait.
+    pub fn set_latency_tracker(&mut self, tracker: Arc<LatencyTracker>) {
+        self.latency = Some(tracker);
+    }
+
+    /// Share a clock offset with other clients, replacing this client's own.
+    pub fn set_clock_sync(&mut self, clock: Arc<ClockSync>) {
+        self.clock = clock;
+    }
+
+    /// Server-minus-local clock offset applied to signed timestamps, in ms.
+    pub fn clock_offset_millis(&self) -> i64 {
+        self.clock.offset_millis()
+    }
+
+    /// Override the clock offset applied to signed timestamps, in ms.
+    pub fn set_clock_offset_millis(&self, offset_millis: i64) {
+        self.clock.set_offset_millis(offset_millis);
+    }
+
+    /// Read the bearer token from a shared JWT manager.
+    ///
+    /// The stored token takes precedence over `Credentials::jwt_token`, so a
+    /// refresh through `AuthManager` reaches every client sharing the manager.
+    pub fn set_jwt_source(&mut self, jwt_manager: JwtManager) {
+        self.jwt_source = Some(jwt_manager);
+    }
+
+    /// Deployment this client's WebSocket streams should connect to
+    pub fn environment(&self) -> &StandxEnvironment {
+        &self.environment
+    }
+
+    /// Base URL for auth endpoints
+    pub fn auth_base_url(&self) -> &Url {
+        &self.auth_base_url
+    }
+
+    /// Base URL for trading endpoints
+    pub fn trading_base_url(&self) -> &Url {
+        &self.trading_base_url
+    }
+
     /// Get credentials if set
     pub fn credentials(&self) -> Option<&Credentials> {
         self.credentials.as_ref()
@@ -127,6 +398,32 @@ impl StandxClient {
         self.request_signer.as_ref()
     }
 
+    /// Sign and verify a canonical payload with the configured signer.
+    ///
+    /// Meant for startup, so a badly loaded key fails before the first order.
+    pub fn self_test_signature(&self) -> HttpResult<()> {
+        const REQUEST_ID: &str = "self-test";
+        const PAYLOAD: &str = r#"{"self_test":true}"#;
+
+        let signer = self.require_request_signer()?;
+        let timestamp = RequestSigner::timestamp_millis();
+        let signature =
+            signer.sign_request(DEFAULT_SIGNATURE_VERSION, REQUEST_ID, timestamp, PAYLOAD);
+        if signer.verify(
+            DEFAULT_SIGNATURE_VERSION,
+            REQUEST_ID,
+            timestamp,
+            PAYLOAD,
+            &signature,
+        ) {
+            Ok(())
+        } else {
+            Err(StandxError::Config(
+                "request signer self-test failed: signature did not verify".to_string(),
+            ))
+        }
+    }
+
     pub(crate) fn require_credentials(&self) -> HttpResult<&Credentials> {
         self.credentials
             .as_ref()
@@ -139,14 +436,39 @@ impl StandxClient {
             .ok_or_else(|| StandxError::Config("request signer not set".to_string()))
     }
 
+    pub(crate) async fn acquire_order_permit(&self) -> Option<OwnedSemaphorePermit> {
+        let limiter = self.order_limiter.as_ref()?;
+        limiter.clone().acquire_owned().await.ok()
+    }
+
+    pub(crate) fn clock_sync(&self) -> &ClockSync {
+        &self.clock
+    }
+
+    /// Resync the clock offset used for signed timestamps when due.
+    ///
+    /// A failed resync keeps the previous offset; the next attempt waits a
+    /// full interval.
+    async fn resync_clock_if_due(&self) {
+        if let Some(interval) = self.time_sync_interval
+            && self.clock.claim_resync(interval)
+            && let Err(err) = self.sync_server_time().await
+        {
+            warn!("server time sync failed; keeping clock offset: {err}");
+        }
+    }
+
     pub(crate) fn trading_request_with_jwt(
         &self,
         method: Method,
         endpoint: &str,
     ) -> HttpResult<RequestBuilder> {
-        let credentials = self.require_credentials()?;
+        let jwt_token = match self.jwt_source.as_ref().and_then(JwtManager::get_token) {
+            Some(token) => token,
+            None => self.require_credentials()?.jwt_token.clone(),
+        };
         let builder = self.trading_request(method, endpoint)?;
-        Ok(builder.header(AUTHORIZATION, format!("Bearer {}", credentials.jwt_token)))
+        Ok(builder.header(AUTHORIZATION, format!("Bearer {jwt_token}")))
     }
 
     pub(crate) fn trading_post_with_jwt_and_signature(
@@ -169,22 +491,94 @@ impl StandxClient {
         Ok((builder, signature))
     }
 
+    /// Attach a signed JSON payload, gzipping it when compression is enabled.
+    ///
+    /// The body signature always covers the uncompressed payload.
+    pub(crate) fn with_signed_body(
+        &self,
+        builder: RequestBuilder,
+        payload: String,
+    ) -> HttpResult<RequestBuilder> {
+        if !self.compression {
+            return Ok(builder.body(payload));
+        }
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(payload.as_bytes())
+            .and_then(|_| encoder.finish())
+            .map(|compressed| builder.header(CONTENT_ENCODING, "gzip").body(compressed))
+            .map_err(|err| StandxError::Internal(format!("gzip request body failed: {err}")))
+    }
+
     pub(crate) async fn send_json<T: DeserializeOwned>(
         &self,
         builder: RequestBuilder,
     ) -> HttpResult<T> {
-        const MAX_RETRIES: usize = 3;
+        self.send_json_with_retries(Outgoing::Built(Box::new(builder)), SEND_MAX_RETRIES)
+            .await
+    }
+
+    /// Send exactly once, for requests that must not be repeated blindly.
+    pub(crate) async fn send_json_once<T: DeserializeOwned>(
+        &self,
+        builder: RequestBuilder,
+    ) -> HttpResult<T> {
+        self.send_json_with_retries(Outgoing::Built(Box::new(builder)), 0)
+            .await
+    }
+
+    /// Sign `payload` and POST it to the trading `endpoint` while holding the
+    /// order permit; `once` disables retries.
+    ///
+    /// Each attempt is timestamped and signed after the permit and its
+    /// rate-limiter wait, so time spent queued never stales the signature.
+    pub(crate) async fn send_signed_json<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        payload: &str,
+        once: bool,
+    ) -> HttpResult<T> {
+        let _permit = self.acquire_order_permit().await;
+        self.resync_clock_if_due().await;
+        let max_retries = if once { 0 } else { SEND_MAX_RETRIES };
+        self.send_json_with_retries(Outgoing::Signed { endpoint, payload }, max_retries)
+            .await
+    }
+
+    async fn send_json_with_retries<T: DeserializeOwned>(
+        &self,
+        outgoing: Outgoing<'_>,
+        max_retries: usize,
+    ) -> HttpResult<T> {
         let mut retries = 0;
 
         loop {
+            if let Some(limiter) = &self.rate_limiter {
+                limiter.acquire().await;
+            }
             let result = async {
-                let response = builder
-                    .try_clone()
-                    .ok_or_else(|| StandxError::Internal("Builder cannot be cloned".to_string()))?
-                    .send()
-                    .await?;
+                let builder = match &outgoing {
+                    Outgoing::Built(builder) => builder.try_clone().ok_or_else(|| {
+                        StandxError::Internal("Builder cannot be cloned".to_string())
+                    })?,
+                    Outgoing::Signed { endpoint, payload } => {
+                        let (builder, _signature) = self.trading_post_with_jwt_and_signature(
+                            endpoint,
+                            payload,
+                            self.clock.now_millis(),
+                        )?;
+                        self.with_signed_body(builder, payload.to_string())?
+                    }
+                };
+                let sent_at = Instant::now();
+                let response = builder.send().await.map_err(map_send_error)?;
                 let status = response.status();
+                let endpoint = response.url().path().to_string();
                 let body = response.text().await?;
+                if let Some(latency) = &self.latency {
+                    latency.record(&endpoint, sent_at.elapsed());
+                }
 
                 if status.is_success() {
                     return serde_json::from_str::<T>(&body).map_err(|err| {
@@ -236,7 +630,7 @@ impl StandxClient {
                 Ok(v) => return Ok(v),
                 Err(e) => {
                     retries += 1;
-                    if retries > MAX_RETRIES {
+                    if retries > max_retries {
                         return Err(e);
                     }
                     // Wait for a short time before retrying
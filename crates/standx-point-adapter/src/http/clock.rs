@@ -0,0 +1,116 @@
+/*
+[INPUT]:  Local clock and server time samples
+[OUTPUT]: Skew-corrected timestamps for signed requests
+[POS]:    HTTP layer - clock offset tracking
+[UPDATE]: When changing how server time is sampled or applied
+[UPDATE]: 2026-10-17 Add ClockSync offset with claimable periodic resync
+[UPDATE]: 2026-10-17 Read local time through an injectable clock source
+*/
+
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::time::Duration;
+use tokio::time::Instant;
+
+use super::signature::RequestSigner;
+
+/// Server-minus-local clock offset applied to signed request timestamps.
+#[derive(Debug)]
+pub struct ClockSync {
+    offset_millis: AtomicI64,
+    /// Last time a resync was claimed; `None` until the first one
+    synced_at: Mutex<Option<Instant>>,
+    /// Local unix time in milliseconds; the system clock outside tests
+    local_millis: fn() -> u64,
+}
+
+impl Default for ClockSync {
+    fn default() -> Self {
+        Self::with_local_clock(RequestSigner::timestamp_millis)
+    }
+}
+
+impl ClockSync {
+    /// Clock sync that reads local time from `local_millis` instead of the
+    /// system clock, e.g. a fixed instant in tests.
+    pub fn with_local_clock(local_millis: fn() -> u64) -> Self {
+        Self {
+            offset_millis: AtomicI64::new(0),
+            synced_at: Mutex::new(None),
+            local_millis,
+        }
+    }
+
+    /// Uncorrected local unix time in milliseconds.
+    pub fn local_millis(&self) -> u64 {
+        (self.local_millis)()
+    }
+
+    /// Current offset in milliseconds (positive when the local clock is behind).
+    pub fn offset_millis(&self) -> i64 {
+        self.offset_millis.load(Ordering::Relaxed)
+    }
+
+    /// Override the offset, e.g. from an external time source.
+    pub fn set_offset_millis(&self, offset_millis: i64) {
+        self.offset_millis.store(offset_millis, Ordering::Relaxed);
+    }
+
+    /// Local unix time in milliseconds corrected by the offset.
+    pub fn now_millis(&self) -> u64 {
+        self.local_millis()
+            .saturating_add_signed(self.offset_millis())
+    }
+
+    /// Store the offset implied by a server time sample.
+    ///
+    /// The server stamp is assumed to fall halfway through the round trip.
+    pub fn record_sample(
+        &self,
+        sent_at_millis: u64,
+        server_millis: i64,
+        received_at_millis: u64,
+    ) -> i64 {
+        let midpoint = sent_at_millis + received_at_millis.saturating_sub(sent_at_millis) / 2;
+        let offset_millis = server_millis - midpoint as i64;
+        self.set_offset_millis(offset_millis);
+        offset_millis
+    }
+
+    /// Claim the next resync if `interval` has passed since the last one.
+    ///
+    /// Only one concurrent caller wins the claim; the rest keep signing with
+    /// the current offset.
+    pub fn claim_resync(&self, interval: Duration) -> bool {
+        let mut synced_at = self.synced_at.lock().unwrap_or_else(|err| err.into_inner());
+        let now = Instant::now();
+        if synced_at.is_some_and(|at| now.duration_since(at) < interval) {
+            return false;
+        }
+        *synced_at = Some(now);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_offset_uses_round_trip_midpoint() {
+        let clock = ClockSync::default();
+        assert_eq!(clock.record_sample(1_000, 6_050, 1_100), 5_000);
+        assert_eq!(clock.offset_millis(), 5_000);
+
+        assert_eq!(clock.record_sample(10_000, 9_000, 10_000), -1_000);
+    }
+
+    #[test]
+    fn test_resync_claimed_once_per_interval() {
+        let clock = ClockSync::default();
+        let interval = Duration::from_secs(60);
+        assert!(clock.claim_resync(interval));
+        assert!(!clock.claim_resync(interval));
+        assert!(clock.claim_resync(Duration::ZERO));
+    }
+}
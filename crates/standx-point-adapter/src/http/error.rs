@@ -3,6 +3,7 @@
 [OUTPUT]: Structured error types with context and retry hints
 [POS]:    Error handling layer - unified error types for entire crate
 [UPDATE]: When adding new error sources or improving error messages
+[UPDATE]: 2026-10-17 Classify API failures (not found, rate limited, auth, insufficient balance)
 */
 
 use reqwest::StatusCode;
@@ -77,6 +78,25 @@ impl StandxError {
         )
     }
 
+    /// Check if the error is a transient failure worth retrying
+    ///
+    /// Extends `is_retryable` with server-side (5xx) and throttling (429)
+    /// API responses; client errors such as an address mismatch are permanent.
+    pub fn is_transient(&self) -> bool {
+        self.is_retryable()
+            || self.is_rate_limited()
+            || matches!(self, StandxError::Api { code, .. } if *code >= 500)
+    }
+
+    /// Check if the request timed out, so it may have reached the server
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            StandxError::Timeout { .. } => true,
+            StandxError::Http(err) => err.is_timeout(),
+            _ => false,
+        }
+    }
+
     /// Get retry delay in seconds (if retryable)
     pub fn retry_delay(&self) -> Option<u64> {
         match self {
@@ -93,9 +113,52 @@ impl StandxError {
             StandxError::Authentication { .. }
                 | StandxError::TokenExpired
                 | StandxError::InvalidSignature
+                | StandxError::Api {
+                    code: 401 | 403,
+                    ..
+                }
         )
     }
 
+    /// Check if the requested resource does not exist (404)
+    ///
+    /// Several list endpoints answer 404 instead of an empty page.
+    pub fn is_not_found(&self) -> bool {
+        self.api_code() == Some(404)
+    }
+
+    /// Check if the request was throttled
+    pub fn is_rate_limited(&self) -> bool {
+        matches!(self, StandxError::RateLimit { .. }) || self.api_code() == Some(429)
+    }
+
+    /// Check if the account lacks balance or margin for the request
+    ///
+    /// StandX reports this with a generic client error code, so the message
+    /// is inspected.
+    pub fn is_insufficient_balance(&self) -> bool {
+        self.api_message().is_some_and(|message| {
+            let message = message.to_ascii_lowercase();
+            message.contains("insufficient") || message.contains("not enough")
+        })
+    }
+
+    /// Raw API error code, if this is an API error
+    pub fn api_code(&self) -> Option<i32> {
+        match self {
+            StandxError::Api { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+
+    /// Raw API error message, if this is an API error
+    pub fn api_message(&self) -> Option<&str> {
+        match self {
+            StandxError::Api { message, .. } => Some(message),
+            _ => None,
+        }
+    }
+
     /// Create an API error from status code and message
     pub fn api_error(status: StatusCode, message: impl Into<String>) -> Self {
         StandxError::Api {
@@ -129,6 +192,28 @@ mod tests {
         assert!(!StandxError::Timeout { duration: 30 }.is_auth_error());
     }
 
+    #[test]
+    fn test_api_error_classification() {
+        let not_found = StandxError::api_error(StatusCode::NOT_FOUND, "no orders");
+        assert!(not_found.is_not_found());
+        assert_eq!(not_found.api_code(), Some(404));
+        assert_eq!(not_found.api_message(), Some("no orders"));
+        assert!(!not_found.is_transient());
+
+        let throttled = StandxError::api_error(StatusCode::TOO_MANY_REQUESTS, "slow down");
+        assert!(throttled.is_rate_limited());
+        assert!(throttled.is_transient());
+        assert!(StandxError::RateLimit { retry_after: 1 }.is_rate_limited());
+
+        assert!(StandxError::api_error(StatusCode::FORBIDDEN, "forbidden").is_auth_error());
+        assert!(
+            StandxError::api_error(StatusCode::BAD_REQUEST, "Insufficient balance")
+                .is_insufficient_balance()
+        );
+        assert!(!not_found.is_insufficient_balance());
+        assert_eq!(StandxError::TokenExpired.api_code(), None);
+    }
+
     #[test]
     fn test_api_error_creation() {
         let err = StandxError::api_error(StatusCode::BAD_REQUEST, "Invalid symbol");
@@ -0,0 +1,126 @@
+/*
+[INPUT]:  Per-attempt REST round-trip durations keyed by endpoint path
+[OUTPUT]: Rolling min/p50/p95/max and last sample per endpoint
+[POS]:    HTTP layer - shared request latency tracking
+[UPDATE]: When changing how latency samples are windowed or summarized
+[UPDATE]: 2026-10-17 Add LatencyTracker recording REST round-trips per endpoint
+*/
+
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Samples kept per endpoint; older ones fall out of the percentiles.
+pub const LATENCY_WINDOW: usize = 256;
+
+/// Summary of the recent round-trips to one endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatencyStats {
+    /// Round-trips recorded since the tracker was created
+    pub count: u64,
+    pub last: Duration,
+    pub min: Duration,
+    pub p50: Duration,
+    pub p95: Duration,
+    pub max: Duration,
+}
+
+#[derive(Debug, Default)]
+struct EndpointSamples {
+    count: u64,
+    recent: VecDeque<Duration>,
+}
+
+/// Rolling request latencies; share one via `Arc` to pool samples.
+///
+/// Only attempts that got an HTTP response are recorded, so timeouts and
+/// connection errors do not skew the venue's latency.
+#[derive(Debug, Default)]
+pub struct LatencyTracker {
+    endpoints: Mutex<HashMap<String, EndpointSamples>>,
+}
+
+impl LatencyTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one round-trip to `endpoint` (a request path like `/api/new_order`).
+    pub fn record(&self, endpoint: &str, elapsed: Duration) {
+        let mut endpoints = self.endpoints.lock().unwrap_or_else(|err| err.into_inner());
+        let samples = endpoints.entry(endpoint.to_string()).or_default();
+        samples.count += 1;
+        if samples.recent.len() == LATENCY_WINDOW {
+            samples.recent.pop_front();
+        }
+        samples.recent.push_back(elapsed);
+    }
+
+    /// Stats for one endpoint, or `None` before its first round-trip.
+    pub fn stats(&self, endpoint: &str) -> Option<LatencyStats> {
+        let endpoints = self.endpoints.lock().unwrap_or_else(|err| err.into_inner());
+        endpoints.get(endpoint).and_then(summarize)
+    }
+
+    /// Stats for every endpoint seen so far, in path order.
+    pub fn snapshot(&self) -> BTreeMap<String, LatencyStats> {
+        let endpoints = self.endpoints.lock().unwrap_or_else(|err| err.into_inner());
+        endpoints
+            .iter()
+            .filter_map(|(endpoint, samples)| {
+                summarize(samples).map(|stats| (endpoint.clone(), stats))
+            })
+            .collect()
+    }
+}
+
+fn summarize(samples: &EndpointSamples) -> Option<LatencyStats> {
+    let last = *samples.recent.back()?;
+    let mut sorted: Vec<Duration> = samples.recent.iter().copied().collect();
+    sorted.sort_unstable();
+    // Nearest-rank percentile over the window.
+    let rank = |pct: usize| sorted[(sorted.len() * pct).div_ceil(100).max(1) - 1];
+    Some(LatencyStats {
+        count: samples.count,
+        last,
+        min: sorted[0],
+        p50: rank(50),
+        p95: rank(95),
+        max: sorted[sorted.len() - 1],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_summarize_the_recent_window() {
+        let tracker = LatencyTracker::new();
+        assert_eq!(tracker.stats("/api/new_order"), None);
+
+        for ms in (1..=100).rev() {
+            tracker.record("/api/new_order", Duration::from_millis(ms));
+        }
+        tracker.record("/api/cancel_order", Duration::from_millis(7));
+
+        let stats = tracker.stats("/api/new_order").unwrap();
+        assert_eq!(stats.count, 100);
+        assert_eq!(stats.last, Duration::from_millis(1));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.p50, Duration::from_millis(50));
+        assert_eq!(stats.p95, Duration::from_millis(95));
+        assert_eq!(stats.max, Duration::from_millis(100));
+        assert_eq!(
+            tracker.snapshot().keys().collect::<Vec<_>>(),
+            ["/api/cancel_order", "/api/new_order"]
+        );
+
+        for _ in 0..LATENCY_WINDOW {
+            tracker.record("/api/new_order", Duration::from_millis(3));
+        }
+        let stats = tracker.stats("/api/new_order").unwrap();
+        assert_eq!(stats.count, 100 + LATENCY_WINDOW as u64);
+        assert_eq!(stats.max, Duration::from_millis(3));
+    }
+}
@@ -3,16 +3,33 @@
 [OUTPUT]: HTTP responses and typed API results
 [POS]:    HTTP layer - REST API communication
 [UPDATE]: When adding new endpoints or changing client behavior
+[UPDATE]: 2026-10-17 Export LatencyTracker
+[UPDATE]: 2026-10-17 Add pinning module for optional TLS certificate pins
+[UPDATE]: 2026-10-17 Add last_id cursor pagination
 */
 
+pub mod backoff;
 pub mod client;
+pub mod clock;
 pub mod error;
+pub mod latency;
+pub mod pagination;
+mod pinning;
 pub mod public;
+pub mod rate_limit;
 pub mod signature;
 pub mod trade;
 pub mod user;
 
+pub use backoff::BackoffPolicy;
+pub use clock::ClockSync;
 pub use error::{Result, StandxError};
+pub use latency::{LatencyStats, LatencyTracker};
+pub use rate_limit::{RateLimitConfig, RateLimiter};
 pub use signature::RequestSigner;
+pub use trade::OrderSubmission;
 
-pub use client::{ClientConfig, Credentials, StandxClient};
+pub use client::{
+    AUTH_BASE_URL, AUTH_BASE_URL_ENV, ClientConfig, Credentials, PROXY_ENV, ProxyConfig,
+    StandxClient, TRADING_BASE_URL, TRADING_BASE_URL_ENV, base_urls_from_env,
+};
@@ -0,0 +1,102 @@
+/*
+[INPUT]:  A page fetcher taking the previous page's last id as cursor
+[OUTPUT]: Stream of pages until the listing is exhausted
+[POS]:    HTTP layer - `last_id` cursor pagination shared by list endpoints
+[UPDATE]: When changing page termination rules or cursor handling
+[UPDATE]: 2026-10-17 Add last_id cursor paging for query_orders / query_trades
+*/
+
+use std::future::Future;
+
+use futures_util::Stream;
+use futures_util::stream;
+
+use super::error::Result;
+use crate::types::{Order, PaginatedOrders, PaginatedTrades, Trade};
+
+/// Largest `limit` the list endpoints accept.
+pub const MAX_PAGE_SIZE: u32 = 500;
+
+/// A listed item whose id is the cursor for the next page.
+pub(crate) trait CursorItem {
+    fn cursor_id(&self) -> i64;
+}
+
+impl CursorItem for Order {
+    fn cursor_id(&self) -> i64 {
+        self.id
+    }
+}
+
+impl CursorItem for Trade {
+    fn cursor_id(&self) -> i64 {
+        self.id
+    }
+}
+
+/// One page of results plus the server's reported total.
+pub(crate) trait Page {
+    type Item: CursorItem;
+
+    fn into_parts(self) -> (Vec<Self::Item>, u32);
+}
+
+impl Page for PaginatedOrders {
+    type Item = Order;
+
+    fn into_parts(self) -> (Vec<Order>, u32) {
+        (self.result, self.total)
+    }
+}
+
+impl Page for PaginatedTrades {
+    type Item = Trade;
+
+    fn into_parts(self) -> (Vec<Trade>, u32) {
+        (self.result, self.total)
+    }
+}
+
+struct Cursor<F> {
+    fetch: F,
+    last_id: Option<i64>,
+    fetched: u64,
+    done: bool,
+}
+
+/// Yield pages from `fetch(last_id, limit)` until one comes back short,
+/// empty, or the running count reaches the reported `total`.
+///
+/// A page whose last id repeats the cursor also ends the stream, so a server
+/// that ignores `last_id` cannot loop forever.
+pub(crate) fn paginate<P, F, Fut>(limit: u32, fetch: F) -> impl Stream<Item = Result<Vec<P::Item>>>
+where
+    P: Page,
+    F: FnMut(Option<i64>, u32) -> Fut,
+    Fut: Future<Output = Result<P>>,
+{
+    let limit = limit.clamp(1, MAX_PAGE_SIZE);
+    let cursor = Cursor {
+        fetch,
+        last_id: None,
+        fetched: 0,
+        done: false,
+    };
+    stream::try_unfold(cursor, move |mut cursor| async move {
+        if cursor.done {
+            return Ok(None);
+        }
+        let (items, total) = (cursor.fetch)(cursor.last_id, limit).await?.into_parts();
+        let next_id = items.last().map(CursorItem::cursor_id);
+        cursor.fetched += items.len() as u64;
+        cursor.done = items.len() < limit as usize
+            || next_id.is_none()
+            || next_id == cursor.last_id
+            || (total > 0 && cursor.fetched >= u64::from(total));
+        cursor.last_id = next_id;
+        if items.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some((items, cursor)))
+    })
+}
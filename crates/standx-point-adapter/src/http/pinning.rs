@@ -0,0 +1,183 @@
+/*
+[INPUT]:  Pinned leaf certificate (DER) or SPKI SHA-256 hash from ClientConfig
+[OUTPUT]: rustls client config that also requires the server leaf to match the pin
+[POS]:    HTTP layer - optional TLS certificate pinning
+[UPDATE]: When changing pin formats or how pin failures are reported
+[UPDATE]: 2026-10-17 Add leaf certificate / SPKI pinning on top of WebPKI verification
+*/
+
+use std::sync::Arc;
+
+use rustls::client::WebPkiServerVerifier;
+use rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use rustls::{DigitallySignedStruct, RootCertStore, SignatureScheme};
+use sha2::{Digest, Sha256};
+
+use super::error::{Result, StandxError};
+
+/// Prefix of the handshake error raised when the leaf does not match the pin.
+const PIN_MISMATCH: &str = "TLS certificate pin mismatch";
+
+/// What the server's leaf certificate must match.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum CertPin {
+    /// SHA-256 of the leaf's DER SubjectPublicKeyInfo (survives re-issuance
+    /// with the same key)
+    SpkiSha256([u8; 32]),
+    /// The exact DER-encoded leaf certificate
+    Der(Vec<u8>),
+}
+
+impl CertPin {
+    /// 32 bytes are an SPKI hash; anything else must parse as a certificate.
+    fn parse(raw: &[u8]) -> Result<Self> {
+        if let Ok(hash) = <[u8; 32]>::try_from(raw) {
+            return Ok(Self::SpkiSha256(hash));
+        }
+        let der = CertificateDer::from(raw);
+        webpki::EndEntityCert::try_from(&der).map_err(|err| {
+            StandxError::Config(format!(
+                "pinned_cert is neither a DER certificate nor a 32-byte SPKI SHA-256 hash: {err}"
+            ))
+        })?;
+        Ok(Self::Der(raw.to_vec()))
+    }
+
+    fn matches(&self, leaf: &CertificateDer<'_>) -> bool {
+        match self {
+            Self::Der(der) => der.as_slice() == leaf.as_ref(),
+            Self::SpkiSha256(hash) => webpki::EndEntityCert::try_from(leaf).is_ok_and(|cert| {
+                Sha256::digest(cert.subject_public_key_info().as_ref()).as_slice() == hash
+            }),
+        }
+    }
+}
+
+/// Standard WebPKI verification, then the pin check on the leaf.
+#[derive(Debug)]
+struct PinnedCertVerifier {
+    inner: Arc<WebPkiServerVerifier>,
+    pin: CertPin,
+}
+
+impl ServerCertVerifier for PinnedCertVerifier {
+    fn verify_server_cert(
+        &self,
+        end_entity: &CertificateDer<'_>,
+        intermediates: &[CertificateDer<'_>],
+        server_name: &ServerName<'_>,
+        ocsp_response: &[u8],
+        now: UnixTime,
+    ) -> std::result::Result<ServerCertVerified, rustls::Error> {
+        let verified = self.inner.verify_server_cert(
+            end_entity,
+            intermediates,
+            server_name,
+            ocsp_response,
+            now,
+        )?;
+        if !self.pin.matches(end_entity) {
+            return Err(rustls::Error::General(format!(
+                "{PIN_MISMATCH} for {}: the server certificate does not match \
+                 ClientConfig::pinned_cert (if StandX rotated its certificate, update the pin)",
+                server_name.to_str()
+            )));
+        }
+        Ok(verified)
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls12_signature(message, cert, dss)
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> std::result::Result<HandshakeSignatureValid, rustls::Error> {
+        self.inner.verify_tls13_signature(message, cert, dss)
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.inner.supported_verify_schemes()
+    }
+}
+
+/// TLS config trusting the bundled web roots and requiring `pinned_cert`.
+pub(crate) fn pinned_tls_config(pinned_cert: &[u8]) -> Result<rustls::ClientConfig> {
+    let pin = CertPin::parse(pinned_cert)?;
+    let provider = Arc::new(rustls::crypto::ring::default_provider());
+    let roots = Arc::new(RootCertStore {
+        roots: webpki_roots::TLS_SERVER_ROOTS.to_vec(),
+    });
+    let inner = WebPkiServerVerifier::builder_with_provider(roots, provider.clone())
+        .build()
+        .map_err(|err| StandxError::Config(format!("build TLS verifier failed: {err}")))?;
+    let config = rustls::ClientConfig::builder_with_provider(provider)
+        .with_safe_default_protocol_versions()
+        .map_err(|err| StandxError::Config(format!("build TLS config failed: {err}")))?
+        .dangerous()
+        .with_custom_certificate_verifier(Arc::new(PinnedCertVerifier { inner, pin }))
+        .with_no_client_auth();
+    Ok(config)
+}
+
+/// Surface a pin mismatch as a configuration error instead of a generic
+/// connection failure; other errors pass through unchanged.
+pub(crate) fn map_send_error(err: reqwest::Error) -> StandxError {
+    let mut source: Option<&(dyn std::error::Error + 'static)> = Some(&err);
+    while let Some(current) = source {
+        // io::Error::source skips the wrapped error, so unwrap it explicitly.
+        let inner = current
+            .downcast_ref::<std::io::Error>()
+            .and_then(|io| io.get_ref())
+            .map(|inner| inner as &(dyn std::error::Error + 'static));
+        for candidate in [Some(current), inner].into_iter().flatten() {
+            if let Some(rustls::Error::General(message)) = candidate.downcast_ref()
+                && message.starts_with(PIN_MISMATCH)
+            {
+                return StandxError::Config(message.clone());
+            }
+        }
+        source = current.source();
+    }
+    StandxError::Http(err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::Engine as _;
+    use base64::engine::general_purpose::STANDARD as BASE64;
+
+    /// Self-signed P-256 certificate for `pin.standx.test`.
+    const TEST_CERT: &str = "MIIBizCCATGgAwIBAgIUVgoVJgd9eM3UNpORxkbxP1oG7FowCgYIKoZIzj0EAwIwGjEYMBYGA1UEAwwPcGluLnN0YW5keC50ZXN0MCAXDTI2MTAxNzAzMjgxOVoYDzIxMjYwOTIzMDMyODE5WjAaMRgwFgYDVQQDDA9waW4uc3RhbmR4LnRlc3QwWTATBgcqhkjOPQIBBggqhkjOPQMBBwNCAARFkr1mIRiX0qd+oHMadovgioTKyxlpJonbDcfdlSnBJ+HoVdARuftsHzR6Mkq6l0Fwg2wr1UvTKQSVqWzXA9Aho1MwUTAdBgNVHQ4EFgQUqNsdjyqAYSRDWdJP7yLNXpQxa64wHwYDVR0jBBgwFoAUqNsdjyqAYSRDWdJP7yLNXpQxa64wDwYDVR0TAQH/BAUwAwEB/zAKBggqhkjOPQQDAgNIADBFAiBWjUWJQV943VejQrqLRHb9b5sYT6icvYXtTAcTgfgwJwIhAIjI3YuuoARluQ2AZgYj4Ao5ZwgpuOzD/LPvct0znfci";
+    /// `openssl x509 -pubkey -noout | openssl pkey -pubin -outform der | sha256`
+    const TEST_SPKI_SHA256: &str =
+        "257c749fbe3767857dc0a9958775da502c2bffab7c0af5fa5d783411b21359b9";
+
+    #[test]
+    fn pins_match_leaf_by_der_or_spki_hash() {
+        let der = BASE64.decode(TEST_CERT).unwrap();
+        let leaf = CertificateDer::from(der.as_slice());
+        let spki = hex::decode(TEST_SPKI_SHA256).unwrap();
+
+        assert!(CertPin::parse(&der).unwrap().matches(&leaf));
+        assert!(CertPin::parse(&spki).unwrap().matches(&leaf));
+
+        let mut other = spki.clone();
+        other[0] ^= 0xff;
+        assert!(!CertPin::parse(&other).unwrap().matches(&leaf));
+
+        let err = CertPin::parse(b"not a certificate").unwrap_err();
+        assert!(err.to_string().contains("pinned_cert"), "{err}");
+        assert!(pinned_tls_config(&spki).is_ok());
+    }
+}
@@ -1,16 +1,61 @@
 /*
 [INPUT]:  Symbol identifiers and query parameters
-[OUTPUT]: Market data (symbol info, prices, depth, klines)
+[OUTPUT]: Market data (symbol info, prices, depth, klines, funding)
 [POS]:    HTTP layer - public market data endpoints (no auth required)
 [UPDATE]: When adding new public endpoints or changing response format
 [UPDATE]: 2026-02-07 Added public endpoint GET implementations and tests
+[UPDATE]: 2026-10-16 Add query_funding_rate (latest funding record)
+[UPDATE]: 2026-10-17 Add query_server_time and sync_server_time for clock skew correction
+[UPDATE]: 2026-10-17 Add query_orderbook (top-N levels, best first)
+[UPDATE]: 2026-10-17 Sample the local side of sync_server_time from ClockSync
 */
 
-use crate::http::{Result, StandxClient};
-use crate::types::{DepthBook, KlineData, SymbolInfo, SymbolPrice};
+use crate::http::{Result, StandxClient, StandxError};
+use crate::types::{DepthBook, FundingRate, KlineData, SymbolInfo, SymbolPrice};
 use reqwest::Method;
+use serde::Deserialize;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Look-back window used to find the latest funding record.
+const FUNDING_RATE_LOOKBACK: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Server time body: a bare millisecond timestamp or an object carrying one.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum ServerTimeBody {
+    Millis(i64),
+    Object {
+        #[serde(alias = "time", alias = "timestamp")]
+        server_time: i64,
+    },
+}
 
 impl StandxClient {
+    /// Query the server clock in unix milliseconds
+    ///
+    /// GET /api/query_server_time
+    pub async fn query_server_time(&self) -> Result<i64> {
+        let builder = self.trading_request(Method::GET, "/api/query_server_time")?;
+        let body: ServerTimeBody = self.send_json(builder).await?;
+        Ok(match body {
+            ServerTimeBody::Millis(millis)
+            | ServerTimeBody::Object {
+                server_time: millis,
+            } => millis,
+        })
+    }
+
+    /// Measure the server clock offset and apply it to signed requests
+    ///
+    /// Returns the new offset in milliseconds (server minus local).
+    pub async fn sync_server_time(&self) -> Result<i64> {
+        let clock = self.clock_sync();
+        let sent_at = clock.local_millis();
+        let server_millis = self.query_server_time().await?;
+        let received_at = clock.local_millis();
+        Ok(clock.record_sample(sent_at, server_millis, received_at))
+    }
+
     /// Query symbol information
     ///
     /// GET /api/query_symbol_info?symbol={symbol}
@@ -38,6 +83,41 @@ impl StandxClient {
         self.send_json(builder).await
     }
 
+    /// Query the top `depth` levels per side of the order book
+    ///
+    /// Same endpoint as [`Self::query_depth_book`]; levels are sorted best
+    /// price first and empty levels dropped, so a side may hold fewer than
+    /// `depth` levels (or none).
+    pub async fn query_orderbook(&self, symbol: &str, depth: usize) -> Result<DepthBook> {
+        let mut book = self.query_depth_book(symbol).await?;
+        book.truncate_levels(depth);
+        Ok(book)
+    }
+
+    /// Query the most recent funding rate record
+    ///
+    /// GET /api/query_funding_rates?symbol={symbol}&start_time={ms}&end_time={ms}
+    ///
+    /// Looks back 24h and returns the record with the latest id.
+    pub async fn query_funding_rate(&self, symbol: &str) -> Result<FundingRate> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+        let start = now.saturating_sub(FUNDING_RATE_LOOKBACK);
+        let endpoint = format!(
+            "/api/query_funding_rates?symbol={}&start_time={}&end_time={}",
+            symbol,
+            start.as_millis(),
+            now.as_millis()
+        );
+        let builder = self.trading_request(Method::GET, &endpoint)?;
+        let rates: Vec<FundingRate> = self.send_json(builder).await?;
+        rates
+            .into_iter()
+            .max_by_key(|rate| rate.id)
+            .ok_or_else(|| StandxError::InvalidResponse(format!("no funding rate for {symbol}")))
+    }
+
     /// Get kline/candlestick history
     ///
     /// GET /api/kline/history?symbol={symbol}&from={from}&to={to}&resolution={resolution}
@@ -59,11 +139,43 @@ impl StandxClient {
 
 #[cfg(test)]
 mod tests {
-    use crate::http::{ClientConfig, StandxClient};
+    use crate::http::StandxError;
+    use crate::http::{ClientConfig, ClockSync, StandxClient};
     use crate::types::{DepthBook, DepthLevel, KlineData, SymbolInfo, SymbolPrice};
+    use rust_decimal::Decimal;
     use wiremock::matchers::{method, path, query_param};
     use wiremock::{Mock, MockServer, ResponseTemplate};
 
+    #[tokio::test]
+    async fn test_sync_server_time_sets_clock_offset() {
+        let server = MockServer::start().await;
+        let server_millis = 1_700_000_090_000;
+
+        let _mock = Mock::given(method("GET"))
+            .and(path("/api/query_server_time"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({ "server_time": server_millis })),
+            )
+            .mount(&server)
+            .await;
+
+        let mut client = StandxClient::with_config_and_base_urls(
+            ClientConfig::default(),
+            &server.uri(),
+            &server.uri(),
+        )
+        .expect("client init");
+        client.set_clock_sync(std::sync::Arc::new(ClockSync::with_local_clock(|| {
+            1_700_000_000_000
+        })));
+
+        assert_eq!(client.query_server_time().await.unwrap(), server_millis);
+
+        assert_eq!(client.sync_server_time().await.unwrap(), 90_000);
+        assert_eq!(client.clock_offset_millis(), 90_000);
+    }
+
     #[tokio::test]
     async fn test_query_symbol_info() {
         let server = MockServer::start().await;
@@ -260,6 +372,64 @@ mod tests {
         assert_eq!(response, expected);
     }
 
+    #[tokio::test]
+    async fn query_orderbook_keeps_best_levels_and_handles_thin_books() {
+        let server = MockServer::start().await;
+        let books = [
+            (
+                "BTC-USD",
+                serde_json::json!({
+                    "asks": [["101.0", "2.0"], ["100.5", "1.2"], ["102", "0"]],
+                    "bids": [["99.0", "3.0"], ["99.5", "1.0"], ["98", "4"]],
+                    "symbol": "BTC-USD"
+                }),
+            ),
+            (
+                "ETH-USD",
+                serde_json::json!({ "asks": [], "bids": [], "symbol": "ETH-USD" }),
+            ),
+            (
+                "SOL-USD",
+                serde_json::json!({ "asks": [], "bids": [["20", "1"]], "symbol": "SOL-USD" }),
+            ),
+        ];
+        for (symbol, body) in books {
+            Mock::given(method("GET"))
+                .and(path("/api/query_depth_book"))
+                .and(query_param("symbol", symbol))
+                .respond_with(ResponseTemplate::new(200).set_body_json(body))
+                .mount(&server)
+                .await;
+        }
+
+        let client = StandxClient::with_config_and_base_urls(
+            ClientConfig::default(),
+            &server.uri(),
+            &server.uri(),
+        )
+        .expect("client init");
+
+        let book = client.query_orderbook("BTC-USD", 2).await.unwrap();
+        let prices = |levels: &[DepthLevel]| levels.iter().map(|l| l.0).collect::<Vec<_>>();
+        assert_eq!(
+            prices(&book.bids),
+            ["99.5".parse().unwrap(), Decimal::from(99)]
+        );
+        assert_eq!(
+            prices(&book.asks),
+            ["100.5".parse().unwrap(), Decimal::from(101)]
+        );
+        assert_eq!(book.mid_price(), Some("100".parse().unwrap()));
+
+        let empty = client.query_orderbook("ETH-USD", 5).await.unwrap();
+        assert_eq!((empty.best_bid(), empty.best_ask()), (None, None));
+        assert_eq!(empty.mid_price(), None);
+
+        let one_sided = client.query_orderbook("SOL-USD", 5).await.unwrap();
+        assert_eq!(one_sided.best_bid(), Some(Decimal::from(20)));
+        assert_eq!(one_sided.mid_price(), None);
+    }
+
     #[tokio::test]
     async fn test_get_kline_history() {
         let server = MockServer::start().await;
@@ -312,4 +482,55 @@ mod tests {
 
         assert_eq!(response, expected);
     }
+
+    #[tokio::test]
+    async fn test_query_funding_rate_returns_latest_record() {
+        let server = MockServer::start().await;
+        let record = |id: i64, rate: &str, time: &str| {
+            format!(
+                r#"{{"id":{id},"symbol":"BTC-USD","funding_rate":"{rate}","index_price":"100",
+                "mark_price":"100.1","premium":"0.001","time":"{time}",
+                "created_at":"{time}","updated_at":"{time}"}}"#
+            )
+        };
+        let mock_response = format!(
+            "[{},{}]",
+            record(8, "0.00012", "2026-10-16T08:00:00Z"),
+            record(7, "-0.00003", "2026-10-16T07:00:00Z")
+        );
+
+        let _mock = Mock::given(method("GET"))
+            .and(path("/api/query_funding_rates"))
+            .and(query_param("symbol", "BTC-USD"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_raw(mock_response, "application/json"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        let _empty = Mock::given(method("GET"))
+            .and(path("/api/query_funding_rates"))
+            .and(query_param("symbol", "ETH-USD"))
+            .respond_with(ResponseTemplate::new(200).set_body_raw("[]", "application/json"))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let client = StandxClient::with_config_and_base_urls(
+            ClientConfig::default(),
+            &server.uri(),
+            &server.uri(),
+        )
+        .expect("client init");
+
+        let latest = client
+            .query_funding_rate("BTC-USD")
+            .await
+            .expect("query_funding_rate failed");
+        assert_eq!(latest.id, 8);
+        assert_eq!(latest.funding_rate, "0.00012".parse().unwrap());
+
+        let err = client.query_funding_rate("ETH-USD").await.unwrap_err();
+        assert!(matches!(err, StandxError::InvalidResponse(_)));
+    }
 }
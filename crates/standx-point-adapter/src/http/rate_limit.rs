@@ -0,0 +1,104 @@
+/*
+[INPUT]:  Request rate and burst settings
+[OUTPUT]: Async permits pacing REST requests to a token-bucket budget
+[POS]:    HTTP layer - shared request pacing
+[UPDATE]: When changing rate-limit semantics or configuration
+[UPDATE]: 2026-10-17 Add token-bucket RateLimiter shareable across clients
+*/
+
+use serde::{Deserialize, Serialize};
+use std::sync::Mutex;
+use std::time::Duration;
+use tokio::time::Instant;
+
+/// Token-bucket settings for REST requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RateLimitConfig {
+    /// Sustained requests per second (refill rate)
+    pub requests_per_second: u32,
+    /// Requests allowed back-to-back when the bucket is full
+    pub burst: u32,
+}
+
+/// Token-bucket limiter; share one via `Arc` to pool a request budget.
+///
+/// Tracks the time the bucket would next be full instead of a token count,
+/// so a permit is a reservation and callers never contend for the lock
+/// while they sleep.
+#[derive(Debug)]
+pub struct RateLimiter {
+    /// Time to refill one token
+    interval: Duration,
+    /// Time to refill the whole burst minus one token
+    tolerance: Duration,
+    /// When the bucket is next full; `None` until the first request
+    full_at: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    /// Zero rates or bursts are treated as 1.
+    pub fn new(config: RateLimitConfig) -> Self {
+        let interval = Duration::from_secs(1) / config.requests_per_second.max(1);
+        Self {
+            interval,
+            tolerance: interval * (config.burst.max(1) - 1),
+            full_at: Mutex::new(None),
+        }
+    }
+
+    /// Wait until a request may be sent.
+    pub async fn acquire(&self) {
+        let send_at = self.reserve(Instant::now());
+        tokio::time::sleep_until(send_at).await;
+    }
+
+    /// Take the next token and return when it becomes available.
+    fn reserve(&self, now: Instant) -> Instant {
+        let mut full_at = self.full_at.lock().unwrap_or_else(|err| err.into_inner());
+        let current = full_at.map_or(now, |at| at.max(now));
+        let send_at = current
+            .checked_sub(self.tolerance)
+            .map_or(now, |at| at.max(now));
+        *full_at = Some(current + self.interval);
+        send_at
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burst_then_paced_at_rate() {
+        let limiter = RateLimiter::new(RateLimitConfig {
+            requests_per_second: 10,
+            burst: 3,
+        });
+        let now = Instant::now();
+
+        let slots: Vec<Duration> = (0..5).map(|_| limiter.reserve(now) - now).collect();
+        assert_eq!(
+            slots,
+            [
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::from_millis(100),
+                Duration::from_millis(200),
+            ]
+        );
+
+        // One idle second refills the whole burst, not more.
+        let later = now + Duration::from_secs(1);
+        let slots: Vec<Duration> = (0..4).map(|_| limiter.reserve(later) - later).collect();
+        assert_eq!(
+            slots,
+            [
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::ZERO,
+                Duration::from_millis(100),
+            ]
+        );
+    }
+}
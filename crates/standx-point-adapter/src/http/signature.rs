@@ -3,10 +3,12 @@
 [OUTPUT]: Signed request headers (x-request-signature)
 [POS]:    HTTP layer - request signing for authenticated endpoints
 [UPDATE]: When changing signing algorithm or header format
+[UPDATE]: 2026-10-17 Add verify for checking signatures against the signer's public key
 */
 
 use crate::auth::Ed25519Signer;
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use ed25519_dalek::Signature;
 use std::time::{SystemTime, UNIX_EPOCH};
 use uuid::Uuid;
 
@@ -75,6 +77,28 @@ impl RequestSigner {
         BASE64.encode(signature.to_bytes())
     }
 
+    /// Check a base64 signature produced by [`Self::sign_request`] against
+    /// this signer's public key.
+    ///
+    /// Malformed signatures verify as `false`.
+    pub fn verify(
+        &self,
+        version: &str,
+        request_id: &str,
+        timestamp: u64,
+        payload: &str,
+        signature: &str,
+    ) -> bool {
+        let Ok(bytes) = BASE64.decode(signature) else {
+            return false;
+        };
+        let Ok(signature) = Signature::from_slice(&bytes) else {
+            return false;
+        };
+        let message = format!("{version},{request_id},{timestamp},{payload}");
+        self.signer.verify(message.as_bytes(), &signature)
+    }
+
     /// Create a [`BodySignature`] for the given request `payload`.
     ///
     /// This uses [`DEFAULT_SIGNATURE_VERSION`], a UUID v4 request id and the provided `timestamp`.
@@ -144,4 +168,19 @@ mod tests {
 
         assert_eq!(got, expected);
     }
+
+    #[test]
+    fn test_verify_round_trip() {
+        let request_signer = RequestSigner::new(Ed25519Signer::from_secret_key(&[3u8; 32]));
+        let payload = r#"{"symbol":"BTC-USD"}"#;
+        let signature = request_signer.sign_request("v1", "rid", 42, payload);
+
+        assert!(request_signer.verify("v1", "rid", 42, payload, &signature));
+        assert!(!request_signer.verify("v1", "rid", 43, payload, &signature));
+        assert!(!request_signer.verify("v1", "rid", 42, "{}", &signature));
+        assert!(!request_signer.verify("v1", "rid", 42, payload, "not-base64"));
+
+        let other = RequestSigner::new(Ed25519Signer::from_secret_key(&[4u8; 32]));
+        assert!(!other.verify("v1", "rid", 42, payload, &signature));
+    }
 }
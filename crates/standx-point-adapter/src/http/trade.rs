@@ -3,28 +3,98 @@
 [OUTPUT]: Order responses and confirmation
 [POS]:    HTTP layer - trading endpoints (require auth + body signature)
 [UPDATE]: When adding new trading endpoints or changing order flow
+[UPDATE]: 2026-10-16 Add bulk cancel_orders and cancel_all_orders
+[UPDATE]: 2026-10-16 Hold the client order limiter permit while sending
+[UPDATE]: 2026-10-16 Attach bodies through with_signed_body for compression
+[UPDATE]: 2026-10-17 Add amend_order for in-place price/qty changes
+[UPDATE]: 2026-10-17 Add set_leverage and set_margin_mode; keep change_leverage as an alias
+[UPDATE]: 2026-10-17 Sign with skew-corrected request timestamps
+[UPDATE]: 2026-10-17 Give every new order a stable cl_ord_id; add new_order_checked timeout recovery
+[UPDATE]: 2026-10-17 Match 404s through StandxError::is_not_found
+[UPDATE]: 2026-10-17 Sign order actions only after the order permit is acquired
+[UPDATE]: 2026-10-17 Page cancel_all_orders through query_all_open_orders and cancel in chunks
 */
 
-use crate::http::{Result, StandxClient};
+use uuid::Uuid;
+
+use crate::http::{Result, StandxClient, StandxError};
 use crate::types::{
-    CancelOrderRequest, CancelOrderResponse, ChangeLeverageRequest, ChangeLeverageResponse,
-    NewOrderRequest, NewOrderResponse,
+    AmendOrderRequest, CancelOrderRequest, CancelOrderResponse, CancelOrdersRequest,
+    CancelOrdersResponse, ChangeLeverageRequest, ChangeLeverageResponse, ChangeMarginModeRequest,
+    ChangeMarginModeResponse, MarginMode, NewOrderRequest, NewOrderResponse, Order,
 };
 
+/// Attempts `new_order_checked` resends after a timeout that did not land.
+const NEW_ORDER_TIMEOUT_RETRIES: usize = 2;
+
+/// Most order ids sent in one `cancel_orders` request by `cancel_all_orders`.
+const CANCEL_ORDERS_CHUNK_SIZE: usize = 100;
+
+/// Outcome of [`StandxClient::new_order_checked`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum OrderSubmission {
+    /// The exchange answered the submission
+    Accepted(NewOrderResponse),
+    /// The submission timed out, but the order was found by `cl_ord_id`
+    Landed(Box<Order>),
+}
+
+/// Fresh client order id for an order intent
+fn new_cl_ord_id() -> String {
+    Uuid::new_v4().to_string()
+}
+
 impl StandxClient {
     /// Create a new order
     ///
     /// POST /api/new_order
     /// Requires: Authorization header + body signature headers
+    ///
+    /// A missing `cl_ord_id` is generated once, so internal retries of this
+    /// request reuse it.
     pub async fn new_order(&self, req: NewOrderRequest) -> Result<NewOrderResponse> {
-        let payload = serde_json::to_string(&req)?;
-        let timestamp = crate::http::RequestSigner::timestamp_millis();
+        self.send_new_order(req, false).await
+    }
 
-        let (builder, _signature) =
-            self.trading_post_with_jwt_and_signature("/api/new_order", &payload, timestamp)?;
+    /// Create a new order without duplicating it when a request times out
+    ///
+    /// Each attempt is sent once. After a timeout the order is looked up by
+    /// `cl_ord_id`: if it landed it is returned as
+    /// [`OrderSubmission::Landed`], otherwise the same request is resent, up
+    /// to `NEW_ORDER_TIMEOUT_RETRIES` times. If the lookup itself fails the
+    /// original timeout is returned, since resending could duplicate the order.
+    pub async fn new_order_checked(&self, mut req: NewOrderRequest) -> Result<OrderSubmission> {
+        let cl_ord_id = req.cl_ord_id.get_or_insert_with(new_cl_ord_id).clone();
+        let mut retries = 0;
+        loop {
+            let err = match self.send_new_order(req.clone(), true).await {
+                Ok(resp) => return Ok(OrderSubmission::Accepted(resp)),
+                Err(err) if err.is_timeout() && retries < NEW_ORDER_TIMEOUT_RETRIES => err,
+                Err(err) => return Err(err),
+            };
+            match self.query_order(None, Some(&cl_ord_id)).await {
+                Ok(order) => return Ok(OrderSubmission::Landed(Box::new(order))),
+                Err(lookup_err) if lookup_err.is_not_found() => {
+                    tracing::warn!(cl_ord_id = %cl_ord_id, "new_order timed out and did not land; resending: {err}");
+                    retries += 1;
+                }
+                Err(lookup_err) => {
+                    tracing::warn!(cl_ord_id = %cl_ord_id, "order lookup after new_order timeout failed: {lookup_err}");
+                    return Err(err);
+                }
+            }
+        }
+    }
 
-        let builder = builder.body(payload);
-        self.send_json(builder).await
+    async fn send_new_order(
+        &self,
+        mut req: NewOrderRequest,
+        once: bool,
+    ) -> Result<NewOrderResponse> {
+        req.cl_ord_id.get_or_insert_with(new_cl_ord_id);
+        let payload = serde_json::to_string(&req)?;
+        self.send_signed_json("/api/new_order", &payload, once)
+            .await
     }
 
     /// Cancel an existing order
@@ -33,20 +103,72 @@ impl StandxClient {
     /// Requires: Authorization header + body signature headers
     pub async fn cancel_order(&self, req: CancelOrderRequest) -> Result<CancelOrderResponse> {
         let payload = serde_json::to_string(&req)?;
-        let timestamp = crate::http::RequestSigner::timestamp_millis();
+        self.send_signed_json("/api/cancel_order", &payload, false)
+            .await
+    }
 
-        let (builder, _signature) =
-            self.trading_post_with_jwt_and_signature("/api/cancel_order", &payload, timestamp)?;
+    /// Amend a resting order's price and/or quantity in place
+    ///
+    /// POST /api/amend_order
+    /// Requires: Authorization header + body signature headers
+    ///
+    /// Not every StandX environment serves this endpoint; callers should
+    /// treat a 404 as "unsupported" and fall back to cancel + new order.
+    pub async fn amend_order(&self, req: AmendOrderRequest) -> Result<NewOrderResponse> {
+        let payload = serde_json::to_string(&req)?;
+        self.send_signed_json("/api/amend_order", &payload, false)
+            .await
+    }
 
-        let builder = builder.body(payload);
-        self.send_json(builder).await
+    /// Cancel multiple orders in one request
+    ///
+    /// POST /api/cancel_orders
+    /// Requires: Authorization header + body signature headers
+    pub async fn cancel_orders(&self, req: CancelOrdersRequest) -> Result<CancelOrdersResponse> {
+        let payload = serde_json::to_string(&req)?;
+        self.send_signed_json("/api/cancel_orders", &payload, false)
+            .await
     }
 
-    /// Change leverage for a symbol
+    /// Cancel all open orders, optionally scoped to a symbol
+    ///
+    /// The API has no dedicated cancel-all endpoint, so this lists every open
+    /// order through [`Self::query_all_open_orders`] and submits their ids to
+    /// `POST /api/cancel_orders` in chunks of [`CANCEL_ORDERS_CHUNK_SIZE`].
+    ///
+    /// Returns an error when the listing carries fewer orders than its
+    /// reported total (paging failed), after cancelling the ones it has, so
+    /// callers know orders may still be resting.
+    pub async fn cancel_all_orders(&self, symbol: Option<&str>) -> Result<CancelOrdersResponse> {
+        let open_orders = self.query_all_open_orders(symbol).await?;
+        let order_ids: Vec<i64> = open_orders.result.iter().map(|order| order.id).collect();
+
+        let mut response = CancelOrdersResponse::default();
+        for chunk in order_ids.chunks(CANCEL_ORDERS_CHUNK_SIZE) {
+            let req = CancelOrdersRequest {
+                order_id_list: chunk.to_vec(),
+                cl_ord_id_list: Vec::new(),
+            };
+            response.0.extend(self.cancel_orders(req).await?.0);
+        }
+
+        if (order_ids.len() as u32) < open_orders.total {
+            return Err(StandxError::InvalidResponse(format!(
+                "cancel_all_orders listed {} of {} open orders",
+                order_ids.len(),
+                open_orders.total
+            )));
+        }
+        Ok(response)
+    }
+
+    /// Set leverage for a symbol
     ///
     /// POST /api/change_leverage
     /// Requires: Authorization header + body signature headers
-    pub async fn change_leverage(
+    ///
+    /// Does not check `SymbolInfo::max_leverage`; callers validate first.
+    pub async fn set_leverage(
         &self,
         symbol: &str,
         leverage: u32,
@@ -56,12 +178,34 @@ impl StandxClient {
             leverage,
         };
         let payload = serde_json::to_string(&req)?;
-        let timestamp = crate::http::RequestSigner::timestamp_millis();
+        self.send_signed_json("/api/change_leverage", &payload, false)
+            .await
+    }
 
-        let (builder, _signature) =
-            self.trading_post_with_jwt_and_signature("/api/change_leverage", &payload, timestamp)?;
+    /// Change leverage for a symbol; same as [`StandxClient::set_leverage`]
+    pub async fn change_leverage(
+        &self,
+        symbol: &str,
+        leverage: u32,
+    ) -> Result<ChangeLeverageResponse> {
+        self.set_leverage(symbol, leverage).await
+    }
 
-        let builder = builder.body(payload);
-        self.send_json(builder).await
+    /// Set the margin mode for a symbol
+    ///
+    /// POST /api/change_margin_mode
+    /// Requires: Authorization header + body signature headers
+    pub async fn set_margin_mode(
+        &self,
+        symbol: &str,
+        margin_mode: MarginMode,
+    ) -> Result<ChangeMarginModeResponse> {
+        let req = ChangeMarginModeRequest {
+            symbol: symbol.to_string(),
+            margin_mode,
+        };
+        let payload = serde_json::to_string(&req)?;
+        self.send_signed_json("/api/change_margin_mode", &payload, false)
+            .await
     }
 }
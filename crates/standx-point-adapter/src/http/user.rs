@@ -3,13 +3,23 @@
 [OUTPUT]: User account data (orders, positions, balances)
 [POS]:    HTTP layer - user data endpoints (require JWT auth)
 [UPDATE]: When adding new user endpoints or changing query parameters
+[UPDATE]: 2026-10-17 Add query_trades for executed fills (404 reads as empty)
+[UPDATE]: 2026-10-17 Add query_order lookup by order id or cl_ord_id
+[UPDATE]: 2026-10-17 Match 404s through StandxError::is_not_found
+[UPDATE]: 2026-10-17 Add iter_orders / iter_trades paging with the last_id cursor
+[UPDATE]: 2026-10-17 Add query_all_open_orders (404 as empty, pages truncated listings)
 */
 
 // ### User Endpoints
 
-use crate::http::{Result, StandxClient};
-use crate::types::{Balance, OrderStatus, PaginatedOrders, Position};
+use crate::http::pagination::{MAX_PAGE_SIZE, paginate};
+use crate::http::{Result, StandxClient, StandxError};
+use crate::types::{
+    Balance, Order, OrderStatus, PaginatedOrders, PaginatedTrades, Position, Trade,
+};
+use futures_util::{Stream, TryStreamExt as _};
 use reqwest::Method;
+use tracing::warn;
 
 impl StandxClient {
     /// Query user orders with optional filters
@@ -20,6 +30,33 @@ impl StandxClient {
         symbol: Option<&str>,
         status: Option<OrderStatus>,
         limit: Option<u32>,
+    ) -> Result<PaginatedOrders> {
+        self.query_orders_page(symbol, status, None, limit).await
+    }
+
+    /// Stream every matching order, one page per item.
+    ///
+    /// Follows the `last_id` cursor with `page_size` orders per request
+    /// (capped at the endpoint's 500), stopping once a page comes back short
+    /// or the reported `total` has been reached. Collect with
+    /// `TryStreamExt::try_concat` when all orders are needed at once.
+    pub fn iter_orders<'a>(
+        &'a self,
+        symbol: Option<&'a str>,
+        status: Option<OrderStatus>,
+        page_size: u32,
+    ) -> impl Stream<Item = Result<Vec<Order>>> + 'a {
+        paginate(page_size, move |last_id, limit| {
+            self.query_orders_page(symbol, status, last_id, Some(limit))
+        })
+    }
+
+    async fn query_orders_page(
+        &self,
+        symbol: Option<&str>,
+        status: Option<OrderStatus>,
+        last_id: Option<i64>,
+        limit: Option<u32>,
     ) -> Result<PaginatedOrders> {
         let mut params = Vec::new();
         if let Some(s) = symbol {
@@ -32,6 +69,9 @@ impl StandxClient {
                 .to_string();
             params.push(format!("status={}", status_value));
         }
+        if let Some(id) = last_id {
+            params.push(format!("last_id={}", id));
+        }
         if let Some(l) = limit {
             params.push(format!("limit={}", l));
         }
@@ -46,6 +86,31 @@ impl StandxClient {
         self.send_json(builder).await
     }
 
+    /// Query a single order by exchange id or client order id
+    ///
+    /// GET /api/query_order?order_id={id}&cl_ord_id={cl_ord_id}
+    ///
+    /// An unknown order surfaces as an error where `is_not_found()` holds.
+    pub async fn query_order(
+        &self,
+        order_id: Option<i64>,
+        cl_ord_id: Option<&str>,
+    ) -> Result<Order> {
+        if order_id.is_none() && cl_ord_id.is_none() {
+            return Err(StandxError::Config(
+                "query_order needs order_id or cl_ord_id".to_string(),
+            ));
+        }
+        let mut builder = self.trading_request_with_jwt(Method::GET, "/api/query_order")?;
+        if let Some(order_id) = order_id {
+            builder = builder.query(&[("order_id", order_id)]);
+        }
+        if let Some(cl_ord_id) = cl_ord_id {
+            builder = builder.query(&[("cl_ord_id", cl_ord_id)]);
+        }
+        self.send_json(builder).await
+    }
+
     /// Query open orders for a symbol
     ///
     /// GET /api/query_open_orders?symbol={symbol}
@@ -60,6 +125,118 @@ impl StandxClient {
         self.send_json(builder).await
     }
 
+    /// Every open order, optionally for one symbol
+    ///
+    /// A 404 reads as no open orders. When the listing reports more orders
+    /// than it carries, all of them are paged in through
+    /// [`Self::iter_orders`] with status `open`; if paging fails the first
+    /// page is returned and a warning logged. Other errors are returned.
+    pub async fn query_all_open_orders(&self, symbol: Option<&str>) -> Result<PaginatedOrders> {
+        let first_page = match self.query_open_orders(symbol).await {
+            Err(err) if err.is_not_found() => {
+                warn!(
+                    symbol = symbol.unwrap_or("*"),
+                    "query_open_orders returned 404; treating as no open orders: {err}"
+                );
+                return Ok(PaginatedOrders::default());
+            }
+            result => result?,
+        };
+        if first_page.total <= first_page.result.len() as u32 {
+            return Ok(first_page);
+        }
+
+        match self
+            .iter_orders(symbol, Some(OrderStatus::Open), MAX_PAGE_SIZE)
+            .try_concat()
+            .await
+        {
+            Ok(result) => Ok(PaginatedOrders {
+                page_size: result.len() as u32,
+                total: first_page.total.max(result.len() as u32),
+                result,
+            }),
+            Err(err) => {
+                warn!(
+                    symbol = symbol.unwrap_or("*"),
+                    total = first_page.total,
+                    page_size = first_page.page_size,
+                    "query_orders failed while expanding open orders: {err}"
+                );
+                Ok(first_page)
+            }
+        }
+    }
+
+    /// Query the user's executed trades (fills), newest first
+    ///
+    /// GET /api/query_trades?symbol={symbol}&start_time={ms}&end_time={ms}&limit={limit}
+    ///
+    /// Times are unix milliseconds. A 404 means no trades and is returned
+    /// as an empty page.
+    pub async fn query_trades(
+        &self,
+        symbol: Option<&str>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedTrades> {
+        self.query_trades_page(symbol, start_time, end_time, None, limit)
+            .await
+    }
+
+    /// Stream every matching trade, one page per item, following the
+    /// `last_id` cursor like [`Self::iter_orders`].
+    pub fn iter_trades<'a>(
+        &'a self,
+        symbol: Option<&'a str>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        page_size: u32,
+    ) -> impl Stream<Item = Result<Vec<Trade>>> + 'a {
+        paginate(page_size, move |last_id, limit| {
+            self.query_trades_page(symbol, start_time, end_time, last_id, Some(limit))
+        })
+    }
+
+    async fn query_trades_page(
+        &self,
+        symbol: Option<&str>,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        last_id: Option<i64>,
+        limit: Option<u32>,
+    ) -> Result<PaginatedTrades> {
+        let mut params = Vec::new();
+        if let Some(s) = symbol {
+            params.push(format!("symbol={}", s));
+        }
+        if let Some(id) = last_id {
+            params.push(format!("last_id={}", id));
+        }
+        if let Some(start) = start_time {
+            params.push(format!("start_time={}", start));
+        }
+        if let Some(end) = end_time {
+            params.push(format!("end_time={}", end));
+        }
+        if let Some(l) = limit {
+            params.push(format!("limit={}", l));
+        }
+
+        let endpoint = if params.is_empty() {
+            "/api/query_trades".to_string()
+        } else {
+            format!("/api/query_trades?{}", params.join("&"))
+        };
+
+        let builder = self.trading_request_with_jwt(Method::GET, &endpoint)?;
+        match self.send_json(builder).await {
+            Err(err) if err.is_not_found() => Ok(PaginatedTrades::default()),
+            result => result,
+        }
+    }
+
     /// Query user positions
     ///
     /// GET /api/query_positions?symbol={symbol}
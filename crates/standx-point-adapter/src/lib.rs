@@ -6,18 +6,29 @@
 */
 
 pub mod auth;
+pub mod environment;
 pub mod http;
 pub mod types;
 pub mod ws;
 
 // Re-export commonly used types from auth
-pub use auth::{AuthManager, Ed25519Signer, JwtManager, MockWalletSigner, TokenData, WalletSigner};
+pub use auth::{
+    AuthManager, Ed25519Signer, JwtClaims, JwtManager, MockWalletSigner, TokenData, WalletSigner,
+};
+
+pub use environment::StandxEnvironment;
 
 // Re-export commonly used types from http
-pub use http::{ClientConfig, Credentials, RequestSigner, Result, StandxClient, StandxError};
+pub use http::{
+    BackoffPolicy, ClientConfig, ClockSync, Credentials, LatencyStats, LatencyTracker,
+    OrderSubmission, ProxyConfig, RateLimitConfig, RateLimiter, RequestSigner, Result,
+    StandxClient, StandxError,
+};
 
 // Re-export all types
 pub use types::*;
 
 // Re-export commonly used types from ws
-pub use ws::{DepthBookData, OrderUpdateData, PriceData, StandxWebSocket, WebSocketMessage};
+pub use ws::{
+    DepthBookData, DepthUpdate, OrderUpdateData, PriceData, StandxWebSocket, WebSocketMessage,
+};
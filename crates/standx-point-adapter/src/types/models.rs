@@ -4,10 +4,13 @@
 [POS]:    Data layer - type definitions for API communication
 [UPDATE]: When API schema changes or new types added
 [UPDATE]: 2026-02-08 allow missing Order.avail_locked in deserialization
+[UPDATE]: 2026-10-17 Add DepthBook best bid/ask, mid, and top-N helpers
+[UPDATE]: 2026-10-17 Add typed Spread parsed leniently from the price feed
+[UPDATE]: 2026-10-17 Reject malformed or non-positive SymbolPrice.mark_price; name bad decimals
 */
 
 use rust_decimal::Decimal;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use super::enums::{MarginMode, OrderStatus, OrderType, Side, TimeInForce};
 
@@ -262,7 +265,12 @@ pub struct SymbolPrice {
     pub index_price: Decimal,
     #[serde(with = "rust_decimal::serde::str_option")]
     pub last_price: Option<Decimal>,
-    #[serde(with = "rust_decimal::serde::str")]
+    /// Rejected when empty, malformed, or not positive, so a bad mark never
+    /// reaches quoting as zero
+    #[serde(
+        deserialize_with = "serde_helpers::deserialize_positive_decimal",
+        serialize_with = "serde_helpers::serialize_decimal"
+    )]
     pub mark_price: Decimal,
     #[serde(with = "rust_decimal::serde::str_option")]
     pub mid_price: Option<Decimal>,
@@ -275,6 +283,72 @@ pub struct SymbolPrice {
     pub time: String,
 }
 
+impl SymbolPrice {
+    /// Top-of-book bid/ask as one value.
+    pub fn spread(&self) -> Spread {
+        Spread {
+            bid: self.spread_bid,
+            ask: self.spread_ask,
+        }
+    }
+}
+
+/// Best bid and ask carried on price updates.
+///
+/// The feed sends `spread` as a `[bid, ask]` array of decimal strings.
+/// Missing, empty, unparsable, or non-positive entries become `None` (a
+/// single entry is the bid), and a pair with `bid > ask` is swapped so the
+/// sides are never crossed. An object with `bid`/`ask` keys is also accepted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Spread {
+    pub bid: Option<Decimal>,
+    pub ask: Option<Decimal>,
+}
+
+impl Spread {
+    /// `(bid + ask) / 2` when both sides are present
+    pub fn mid(&self) -> Option<Decimal> {
+        Some((self.bid? + self.ask?) / Decimal::TWO)
+    }
+}
+
+impl<'de> Deserialize<'de> for Spread {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (bid, ask) = match serde_json::Value::deserialize(deserializer)? {
+            serde_json::Value::Array(levels) => (
+                levels.first().and_then(serde_helpers::positive_decimal),
+                levels.get(1).and_then(serde_helpers::positive_decimal),
+            ),
+            serde_json::Value::Object(sides) => (
+                sides.get("bid").and_then(serde_helpers::positive_decimal),
+                sides.get("ask").and_then(serde_helpers::positive_decimal),
+            ),
+            _ => (None, None),
+        };
+        Ok(match (bid, ask) {
+            (Some(bid), Some(ask)) if bid > ask => Self {
+                bid: Some(ask),
+                ask: Some(bid),
+            },
+            _ => Self { bid, ask },
+        })
+    }
+}
+
+impl Serialize for Spread {
+    /// Written back in the feed's `[bid, ask]` form, `""` for a missing side.
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let side = |value: Option<Decimal>| value.map(|v| v.to_string()).unwrap_or_default();
+        [side(self.bid), side(self.ask)].serialize(serializer)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct DepthLevel(
     #[serde(with = "rust_decimal::serde::str")] pub Decimal,
@@ -288,6 +362,49 @@ pub struct DepthBook {
     pub symbol: String,
 }
 
+impl DepthBook {
+    /// Highest bid with a positive price and quantity
+    pub fn best_bid(&self) -> Option<Decimal> {
+        live_prices(&self.bids).max()
+    }
+
+    /// Lowest ask with a positive price and quantity
+    pub fn best_ask(&self) -> Option<Decimal> {
+        live_prices(&self.asks).min()
+    }
+
+    /// `(best_bid + best_ask) / 2`; `None` for an empty, one-sided, or
+    /// crossed book
+    pub fn mid_price(&self) -> Option<Decimal> {
+        let (bid, ask) = (self.best_bid()?, self.best_ask()?);
+        (bid < ask).then(|| (bid + ask) / Decimal::TWO)
+    }
+
+    /// Keep the best `depth` live levels per side, best price first
+    pub fn truncate_levels(&mut self, depth: usize) {
+        let keep = |levels: &mut Vec<DepthLevel>, descending: bool| {
+            levels.retain(|level| level.0 > Decimal::ZERO && level.1 > Decimal::ZERO);
+            levels.sort_by(|a, b| {
+                if descending {
+                    b.0.cmp(&a.0)
+                } else {
+                    a.0.cmp(&b.0)
+                }
+            });
+            levels.truncate(depth);
+        };
+        keep(&mut self.bids, true);
+        keep(&mut self.asks, false);
+    }
+}
+
+fn live_prices(levels: &[DepthLevel]) -> impl Iterator<Item = Decimal> + '_ {
+    levels
+        .iter()
+        .filter(|level| level.0 > Decimal::ZERO && level.1 > Decimal::ZERO)
+        .map(|level| level.0)
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct KlineData {
     pub s: String,
@@ -370,18 +487,47 @@ mod serde_helpers {
             return Ok(Decimal::ZERO);
         }
 
-        if let Some(raw) = value.as_str() {
-            if raw.trim().is_empty() {
-                return Ok(Decimal::ZERO);
-            }
-            return Decimal::from_str(raw).map_err(serde::de::Error::custom);
+        if let Some(raw) = value.as_str()
+            && raw.trim().is_empty()
+        {
+            return Ok(Decimal::ZERO);
         }
+        decimal_from_value(&value).map_err(serde::de::Error::custom)
+    }
 
-        if value.is_number() {
-            return Decimal::from_str(&value.to_string()).map_err(serde::de::Error::custom);
+    /// A decimal that must be present and positive; anything else errors.
+    pub fn deserialize_positive_decimal<'de, D>(deserializer: D) -> Result<Decimal, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        let decimal = decimal_from_value(&value).map_err(serde::de::Error::custom)?;
+        if decimal <= Decimal::ZERO {
+            return Err(serde::de::Error::custom(format!(
+                "expected a positive decimal, got {decimal}"
+            )));
         }
+        Ok(decimal)
+    }
+
+    /// Parse a JSON string or number, naming the raw value on failure.
+    fn decimal_from_value(value: &Value) -> Result<Decimal, String> {
+        let raw = match value {
+            Value::String(raw) => raw.trim().to_string(),
+            Value::Number(number) => number.to_string(),
+            other => return Err(format!("invalid decimal value: {other}")),
+        };
+        Decimal::from_str(&raw).map_err(|err| format!("invalid decimal {raw:?}: {err}"))
+    }
 
-        Err(serde::de::Error::custom("invalid decimal value"))
+    /// A positive decimal from a JSON string or number; anything else is `None`.
+    pub fn positive_decimal(value: &Value) -> Option<Decimal> {
+        let decimal = match value {
+            Value::String(raw) => Decimal::from_str(raw.trim()).ok()?,
+            Value::Number(number) => Decimal::from_str(&number.to_string()).ok()?,
+            _ => return None,
+        };
+        (decimal > Decimal::ZERO).then_some(decimal)
     }
 
     pub fn serialize_decimal<S>(value: &Decimal, serializer: S) -> Result<S::Ok, S::Error>
@@ -496,4 +642,120 @@ mod tests {
         assert_eq!(order.tp_price, Some(Decimal::from(101)));
         assert_eq!(order.sl_price, Some(Decimal::from(99)));
     }
+
+    #[test]
+    fn malformed_numeric_strings_fail_with_the_raw_value() {
+        let price = |mark: serde_json::Value| {
+            serde_json::from_value::<SymbolPrice>(json!({
+                "base": "BTC",
+                "index_price": "100",
+                "last_price": null,
+                "mark_price": mark,
+                "mid_price": null,
+                "quote": "USD",
+                "spread_ask": null,
+                "spread_bid": null,
+                "symbol": "BTC-USD",
+                "time": "0",
+            }))
+        };
+        assert_eq!(
+            price(json!("100.5")).unwrap().mark_price,
+            "100.5".parse::<Decimal>().unwrap()
+        );
+        assert_eq!(
+            price(json!(100.5)).unwrap().mark_price,
+            "100.5".parse::<Decimal>().unwrap()
+        );
+        for bad in [
+            json!(""),
+            json!("abc"),
+            json!("0"),
+            json!("-1"),
+            json!(null),
+        ] {
+            assert!(price(bad.clone()).is_err(), "{bad}");
+        }
+        let err = price(json!("12x")).unwrap_err();
+        assert!(err.to_string().contains("\"12x\""), "{err}");
+
+        let position = |mark: &str| {
+            serde_json::from_value::<Position>(json!({
+                "bankruptcy_price": "0",
+                "created_at": "0",
+                "entry_price": "100",
+                "entry_value": "100",
+                "holding_margin": "0",
+                "id": 1,
+                "initial_margin": "0",
+                "leverage": "1",
+                "liq_price": "0",
+                "maint_margin": "0",
+                "margin_asset": "USD",
+                "margin_mode": "cross",
+                "mark_price": mark,
+                "mmr": "0",
+                "position_value": "0",
+                "qty": "1",
+                "realized_pnl": "0",
+                "status": "open",
+                "symbol": "BTC-USD",
+                "time": "0",
+                "updated_at": "0",
+                "upnl": "0",
+                "user": "user",
+            }))
+        };
+        assert_eq!(position("").unwrap().mark_price, Decimal::ZERO);
+        let err = position("1.2.3").unwrap_err();
+        assert!(err.to_string().contains("\"1.2.3\""), "{err}");
+    }
+
+    #[test]
+    fn spread_parses_partial_and_misordered_arrays() {
+        let parse = |value: serde_json::Value| serde_json::from_value::<Spread>(value).unwrap();
+        let dec = |raw: &str| Some(raw.parse::<Decimal>().unwrap());
+
+        assert_eq!(parse(json!([])), Spread::default());
+        assert_eq!(parse(json!(null)), Spread::default());
+        assert_eq!(
+            parse(json!(["100.5"])),
+            Spread {
+                bid: dec("100.5"),
+                ask: None
+            }
+        );
+        assert_eq!(
+            parse(json!(["", "101"])),
+            Spread {
+                bid: None,
+                ask: dec("101")
+            }
+        );
+
+        let well_formed = parse(json!(["100.5", "101.5"]));
+        assert_eq!(
+            well_formed,
+            Spread {
+                bid: dec("100.5"),
+                ask: dec("101.5")
+            }
+        );
+        assert_eq!(well_formed.mid(), dec("101"));
+        assert_eq!(parse(json!(["101.5", 100.5])), well_formed);
+        assert_eq!(
+            parse(json!({ "bid": "100.5", "ask": "101.5" })),
+            well_formed
+        );
+        assert_eq!(parse(json!(["0", "nan"])), Spread::default());
+
+        assert_eq!(
+            serde_json::to_value(Spread {
+                bid: dec("100.5"),
+                ask: None
+            })
+            .unwrap(),
+            json!(["100.5", ""])
+        );
+    }
 }
@@ -44,12 +44,47 @@ pub struct CancelOrderRequest {
     pub cl_ord_id: Option<String>,
 }
 
+/// Amend a resting order in place; unset fields keep their current value.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AmendOrderRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cl_ord_id: Option<String>,
+    #[serde(with = "rust_decimal::serde::str_option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::str_option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qty: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::str_option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tp_price: Option<Decimal>,
+    #[serde(with = "rust_decimal::serde::str_option")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sl_price: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CancelOrdersRequest {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub order_id_list: Vec<i64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cl_ord_id_list: Vec<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChangeLeverageRequest {
     pub symbol: String,
     pub leverage: u32,
 }
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangeMarginModeRequest {
+    pub symbol: String,
+    pub margin_mode: MarginMode,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct QueryOrdersRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
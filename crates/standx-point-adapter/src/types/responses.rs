@@ -8,7 +8,7 @@
 use serde::{Deserialize, Serialize};
 
 use super::enums::Chain;
-use super::models::{Balance, Order, Position};
+use super::models::{Balance, Order, Position, Trade};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct NewOrderResponse {
@@ -26,6 +26,11 @@ pub struct CancelOrderResponse {
     pub request_id: String,
 }
 
+/// Response for bulk cancel; the API currently returns an empty array.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct CancelOrdersResponse(pub Vec<serde_json::Value>);
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ChangeLeverageResponse {
     pub code: i32,
@@ -35,6 +40,14 @@ pub struct ChangeLeverageResponse {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChangeMarginModeResponse {
+    pub code: i32,
+    pub message: String,
+    #[serde(rename = "request_id")]
+    pub request_id: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct PaginatedOrders {
     #[serde(rename = "page_size")]
     pub page_size: u32,
@@ -43,6 +56,15 @@ pub struct PaginatedOrders {
     pub total: u32,
 }
 
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PaginatedTrades {
+    #[serde(rename = "page_size", default)]
+    pub page_size: u32,
+    pub result: Vec<Trade>,
+    #[serde(default)]
+    pub total: u32,
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(transparent)]
 pub struct PositionsResponse(pub Vec<Position>);
@@ -4,24 +4,35 @@
 [POS]:    WebSocket layer - real-time data stream handling
 [UPDATE]: When adding new channels or changing connection logic
 [UPDATE]: 2026-02-07 Add auth header for order stream and position subscriptions
+[UPDATE]: 2026-10-16 Track active subscriptions and replay them on auto-reconnect
+[UPDATE]: 2026-10-16 Add ping heartbeat with stale-connection detection; close receiver on disconnect
+[UPDATE]: 2026-10-16 Emit typed Depth messages with per-symbol level limits
+[UPDATE]: 2026-10-16 Track per-channel sequence numbers and emit Gap on dropped frames
+[UPDATE]: 2026-10-17 Document that streams do not yet honor the REST proxy setting
+[UPDATE]: 2026-10-17 Take stream URLs from a StandxEnvironment instead of hardcoding mainnet
+[UPDATE]: 2026-10-17 Emit typed OrderUpdate messages from order frames
 */
 
 use futures_util::{SinkExt, StreamExt};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
 use tokio::sync::{Mutex, mpsc};
 use tokio_tungstenite::connect_async;
 use tokio_tungstenite::tungstenite::Message as WsMessage;
 use tokio_tungstenite::tungstenite::client::IntoClientRequest;
 use tokio_tungstenite::tungstenite::http::HeaderValue;
 use tokio_tungstenite::tungstenite::http::header::AUTHORIZATION;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 use uuid::Uuid;
 
-const MARKET_STREAM_URL: &str = "wss://perps.standx.com/ws-stream/v1";
-const ORDER_STREAM_URL: &str = "wss://perps.standx.com/ws-api/v1";
+use super::message::{DepthUpdate, OrderUpdateData, sequence_number};
+use crate::environment::StandxEnvironment;
+use crate::http::BackoffPolicy;
+
 const MESSAGE_SAMPLE_LIMIT: usize = 3;
 const SUBSCRIPTION_LOG_LIMIT: usize = 10;
 const AUTH_LOG_LIMIT: usize = 5;
@@ -37,6 +48,9 @@ static OTHER_LOG_COUNT: AtomicUsize = AtomicUsize::new(0);
 static PARSE_FAIL_LOG_COUNT: AtomicUsize = AtomicUsize::new(0);
 static ERROR_RESPONSE_LOG_COUNT: AtomicUsize = AtomicUsize::new(0);
 
+type WsStream =
+    tokio_tungstenite::WebSocketStream<tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>>;
+
 /// WebSocket message types
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(tag = "channel")]
@@ -46,44 +60,239 @@ pub enum WebSocketMessage {
         symbol: String,
         data: serde_json::Value,
     },
+    /// Raw depth book frame whose levels could not be parsed
     #[serde(rename = "depth_book")]
     DepthBook {
         symbol: String,
         data: serde_json::Value,
     },
+    /// Parsed depth book update (produced from `depth_book` frames)
+    #[serde(rename = "depth", skip_deserializing)]
+    Depth(DepthUpdate),
+    /// Raw order frame that did not match `OrderUpdateData`
     #[serde(rename = "order")]
     Order { data: serde_json::Value },
+    /// Parsed order update (produced from `order` frames)
+    #[serde(rename = "order_update", skip_deserializing)]
+    OrderUpdate(OrderUpdateData),
     #[serde(rename = "position")]
     Position { data: serde_json::Value },
     #[serde(rename = "balance")]
     Balance { data: serde_json::Value },
+    /// Sequence gap on `channel`: `got` arrived where `expected` was due.
+    ///
+    /// Emitted just before the offending frame; local state built from that
+    /// channel should be resynced over REST.
+    #[serde(rename = "gap", skip_deserializing)]
+    Gap {
+        #[serde(rename = "stream")]
+        channel: String,
+        expected: u64,
+        got: u64,
+    },
     #[serde(other)]
     Other,
 }
 
+/// Endpoint remembered so a dropped connection can be re-established.
+#[derive(Debug, Clone)]
+struct StreamTarget {
+    url: String,
+    authorization: Option<HeaderValue>,
+}
+
+impl StreamTarget {
+    /// Connects directly. This should honor the same `ProxyConfig` /
+    /// `STANDX_HTTPS_PROXY` as `StandxClient`, but `connect_async` has no
+    /// proxy support; until the tunnel is dialed by hand here, streams
+    /// bypass the proxy that REST requests use.
+    async fn connect(&self) -> Result<WsStream, Box<tokio_tungstenite::tungstenite::Error>> {
+        let mut request = self.url.as_str().into_client_request().map_err(Box::new)?;
+        if let Some(value) = &self.authorization {
+            request.headers_mut().insert(AUTHORIZATION, value.clone());
+        }
+        let (ws_stream, _response) = connect_async(request).await.map_err(Box::new)?;
+        Ok(ws_stream)
+    }
+}
+
+/// Frames that must be re-sent after a reconnect: auth first, then every
+/// subscription still active, in the order they were made.
+#[derive(Debug, Default)]
+struct ReplayState {
+    auth: Option<Value>,
+    subscriptions: Vec<Value>,
+}
+
+impl ReplayState {
+    fn record(&mut self, message: &Value) {
+        if message.get("auth").is_some() {
+            self.auth = Some(message.clone());
+        } else if let Some(payload) = message.get("subscribe") {
+            if !self
+                .subscriptions
+                .iter()
+                .any(|entry| entry.get("subscribe") == Some(payload))
+            {
+                self.subscriptions.push(message.clone());
+            }
+        } else if let Some(payload) = message.get("unsubscribe") {
+            self.subscriptions
+                .retain(|entry| entry.get("subscribe") != Some(payload));
+        }
+    }
+
+    fn frames(&self) -> Vec<Value> {
+        self.auth
+            .iter()
+            .chain(self.subscriptions.iter())
+            .cloned()
+            .collect()
+    }
+}
+
+/// Last sequence number seen per channel within one connection.
+#[derive(Debug, Default)]
+struct SequenceTracker {
+    last: HashMap<String, u64>,
+}
+
+impl SequenceTracker {
+    /// Record the frame's sequence number; returns a `Gap` when it does not
+    /// directly follow the previous one on the same channel.
+    fn observe(&mut self, message: &WebSocketMessage) -> Option<WebSocketMessage> {
+        let (channel, got) = sequenced_channel(message)?;
+        let last = match self.last.entry(channel.clone()) {
+            std::collections::hash_map::Entry::Vacant(entry) => {
+                entry.insert(got);
+                return None;
+            }
+            std::collections::hash_map::Entry::Occupied(mut entry) => {
+                let last = *entry.get();
+                if got > last {
+                    entry.insert(got);
+                }
+                last
+            }
+        };
+        let expected = last + 1;
+        (got != expected).then_some(WebSocketMessage::Gap {
+            channel,
+            expected,
+            got,
+        })
+    }
+}
+
+/// Channel key (per symbol where applicable) and sequence number of a frame.
+fn sequenced_channel(message: &WebSocketMessage) -> Option<(String, u64)> {
+    match message {
+        WebSocketMessage::Depth(update) => {
+            Some((format!("depth_book:{}", update.symbol), update.sequence?))
+        }
+        WebSocketMessage::DepthBook { symbol, data } => {
+            Some((format!("depth_book:{symbol}"), sequence_number(data)?))
+        }
+        WebSocketMessage::Price { symbol, data } => {
+            Some((format!("price:{symbol}"), sequence_number(data)?))
+        }
+        WebSocketMessage::Order { data } => Some(("order".to_string(), sequence_number(data)?)),
+        WebSocketMessage::Position { data } => {
+            Some(("position".to_string(), sequence_number(data)?))
+        }
+        WebSocketMessage::Balance { data } => Some(("balance".to_string(), sequence_number(data)?)),
+        // Typed after gap detection, so the raw `Order` frame carries the sequence.
+        WebSocketMessage::OrderUpdate(_)
+        | WebSocketMessage::Gap { .. }
+        | WebSocketMessage::Other => None,
+    }
+}
+
+/// Ping cadence and the silence after which the connection is declared dead.
+#[derive(Debug, Clone, Copy)]
+struct Heartbeat {
+    interval: Duration,
+    timeout: Duration,
+}
+
+enum SessionExit {
+    Closed,
+    Dropped(String),
+}
+
 /// WebSocket client for StandX API
 #[derive(Debug)]
 #[allow(dead_code)]
 pub struct StandxWebSocket {
-    message_tx: mpsc::Sender<WebSocketMessage>,
+    message_tx: std::sync::Mutex<Option<mpsc::Sender<WebSocketMessage>>>,
     message_rx: Option<mpsc::Receiver<WebSocketMessage>>,
     outbound_tx: Arc<Mutex<Option<mpsc::Sender<WsMessage>>>>,
     stream_kind: Arc<Mutex<Option<&'static str>>>,
+    target: Arc<Mutex<Option<StreamTarget>>>,
+    replay: Arc<Mutex<ReplayState>>,
+    reconnect: Option<BackoffPolicy>,
+    heartbeat: Option<Heartbeat>,
+    disconnect_reason: Arc<Mutex<Option<String>>>,
+    depth_levels: DepthLevels,
+    environment: StandxEnvironment,
 }
 
+/// Per-symbol cap on depth levels, applied to parsed `Depth` messages.
+type DepthLevels = Arc<std::sync::Mutex<HashMap<String, usize>>>;
+
 #[allow(dead_code)]
 impl StandxWebSocket {
     /// Create a new WebSocket client
     pub fn new() -> Self {
         let (tx, rx) = mpsc::channel(100);
         Self {
-            message_tx: tx,
+            message_tx: std::sync::Mutex::new(Some(tx)),
             message_rx: Some(rx),
             outbound_tx: Arc::new(Mutex::new(None)),
             stream_kind: Arc::new(Mutex::new(None)),
+            target: Arc::new(Mutex::new(None)),
+            replay: Arc::new(Mutex::new(ReplayState::default())),
+            reconnect: None,
+            heartbeat: None,
+            disconnect_reason: Arc::new(Mutex::new(None)),
+            depth_levels: Arc::new(std::sync::Mutex::new(HashMap::new())),
+            environment: StandxEnvironment::Mainnet,
         }
     }
 
+    /// Connect `connect_market_stream` / `connect_order_stream` to
+    /// `environment` instead of mainnet.
+    pub fn with_environment(mut self, environment: &StandxEnvironment) -> Self {
+        self.environment = environment.clone();
+        self
+    }
+
+    /// Reconnect automatically when the socket drops, pacing attempts with `policy`.
+    ///
+    /// After each reconnect the last auth frame and all active subscriptions
+    /// are re-sent, so the message receiver keeps flowing without the caller
+    /// subscribing again. Without this the connection simply ends on a drop.
+    pub fn with_reconnect(mut self, policy: BackoffPolicy) -> Self {
+        self.reconnect = Some(policy);
+        self
+    }
+
+    /// Ping every `interval` and treat the socket as dead when nothing (pong
+    /// or data) arrives for `timeout`.
+    ///
+    /// A stale socket is handled like any other drop: it is reconnected when
+    /// `with_reconnect` is set, otherwise the message receiver closes and
+    /// `disconnect_reason` reports why.
+    pub fn with_heartbeat(mut self, interval: Duration, timeout: Duration) -> Self {
+        self.heartbeat = Some(Heartbeat { interval, timeout });
+        self
+    }
+
+    /// Why the connection ended, once the message receiver has closed.
+    pub async fn disconnect_reason(&self) -> Option<String> {
+        self.disconnect_reason.lock().await.clone()
+    }
+
     /// Get the message receiver
     pub fn take_receiver(&mut self) -> Option<mpsc::Receiver<WebSocketMessage>> {
         self.message_rx.take()
@@ -91,8 +300,21 @@ impl StandxWebSocket {
 
     /// Connect to market data stream (public)
     pub async fn connect_market_stream(&self) -> Result<(), Box<dyn std::error::Error>> {
+        let url = self.environment.market_stream_url().to_string();
+        self.connect_market_stream_url(&url).await
+    }
+
+    /// Connect to a market data stream at a custom URL (e.g. staging)
+    pub async fn connect_market_stream_url(
+        &self,
+        url: &str,
+    ) -> Result<(), Box<dyn std::error::Error>> {
         self.set_stream_kind("market").await;
-        self.connect_stream(MARKET_STREAM_URL).await
+        self.connect_target(StreamTarget {
+            url: url.to_string(),
+            authorization: None,
+        })
+        .await
     }
 
     /// Connect to order response stream (authenticated)
@@ -101,10 +323,12 @@ impl StandxWebSocket {
         token: &str,
     ) -> Result<(), Box<dyn std::error::Error>> {
         self.set_stream_kind("order").await;
-        let mut request = ORDER_STREAM_URL.to_string().into_client_request()?;
         let value = HeaderValue::from_str(&format!("Bearer {token}"))?;
-        request.headers_mut().insert(AUTHORIZATION, value);
-        self.connect_stream_with_request(request).await
+        self.connect_target(StreamTarget {
+            url: self.environment.order_stream_url().to_string(),
+            authorization: Some(value),
+        })
+        .await
     }
 
     /// Subscribe to price updates for a symbol
@@ -118,8 +342,20 @@ impl StandxWebSocket {
         self.send_subscription(msg).await
     }
 
-    /// Subscribe to depth book updates
-    pub async fn subscribe_depth(&self, symbol: &str) -> Result<(), Box<dyn std::error::Error>> {
+    /// Subscribe to depth book updates, delivered as parsed `WebSocketMessage::Depth`.
+    ///
+    /// `levels` caps the number of levels kept per side (`None` keeps the full book).
+    pub async fn subscribe_depth(
+        &self,
+        symbol: &str,
+        levels: Option<usize>,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        if let Ok(mut guard) = self.depth_levels.lock() {
+            match levels {
+                Some(levels) => guard.insert(symbol.to_string(), levels),
+                None => guard.remove(symbol),
+            };
+        }
         let msg = serde_json::json!({
             "subscribe": {
                 "channel": "depth_book",
@@ -129,7 +365,8 @@ impl StandxWebSocket {
         self.send_subscription(msg).await
     }
 
-    /// Subscribe to order updates (requires auth)
+    /// Subscribe to order updates (requires auth), delivered as parsed
+    /// `WebSocketMessage::OrderUpdate`
     pub async fn subscribe_orders(&self) -> Result<(), Box<dyn std::error::Error>> {
         let msg = serde_json::json!({
             "subscribe": {
@@ -191,6 +428,9 @@ impl StandxWebSocket {
 
     /// Unsubscribe from depth book updates
     pub async fn unsubscribe_depth(&self, symbol: &str) -> Result<(), Box<dyn std::error::Error>> {
+        if let Ok(mut guard) = self.depth_levels.lock() {
+            guard.remove(symbol);
+        }
         let msg = serde_json::json!({
             "unsubscribe": {
                 "channel": "depth_book",
@@ -220,26 +460,16 @@ impl StandxWebSocket {
         self.send_subscription(msg).await
     }
 
-    async fn connect_stream(&self, url: &str) -> Result<(), Box<dyn std::error::Error>> {
-        let (ws_stream, _response) = connect_async(url).await?;
-        self.connect_stream_with_socket(ws_stream).await
-    }
-
-    async fn connect_stream_with_request(
-        &self,
-        request: tokio_tungstenite::tungstenite::http::Request<()>,
-    ) -> Result<(), Box<dyn std::error::Error>> {
-        let (ws_stream, _response) = connect_async(request).await?;
+    async fn connect_target(&self, target: StreamTarget) -> Result<(), Box<dyn std::error::Error>> {
+        let ws_stream = target.connect().await?;
+        *self.target.lock().await = Some(target);
         self.connect_stream_with_socket(ws_stream).await
     }
 
     async fn connect_stream_with_socket(
         &self,
-        ws_stream: tokio_tungstenite::WebSocketStream<
-            tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
-        >,
+        ws_stream: WsStream,
     ) -> Result<(), Box<dyn std::error::Error>> {
-        let (mut write, mut read) = ws_stream.split();
         let (outbound_tx, mut outbound_rx) = mpsc::channel(100);
         let outbound_state = self.outbound_tx.clone();
 
@@ -254,52 +484,208 @@ impl StandxWebSocket {
             *guard = Some(outbound_tx);
         }
 
-        let message_tx = self.message_tx.clone();
+        // The session task owns the only sender, so the receiver closes when
+        // the connection is gone for good.
+        let taken = self
+            .message_tx
+            .lock()
+            .map(|mut guard| guard.take())
+            .unwrap_or_default();
+        let Some(message_tx) = taken else {
+            *outbound_state.lock().await = None;
+            return Err(Box::new(std::io::Error::new(
+                std::io::ErrorKind::NotConnected,
+                "WebSocket message channel already closed",
+            )));
+        };
         let outbound_state_for_task = outbound_state.clone();
+        let target = self.target.clone();
+        let replay = self.replay.clone();
+        let stream_kind = *self.stream_kind.lock().await;
+        let reconnect = self.reconnect;
+        let heartbeat = self.heartbeat;
+        let disconnect_reason = self.disconnect_reason.clone();
+        let depth_levels = self.depth_levels.clone();
 
         tokio::spawn(async move {
-            loop {
-                tokio::select! {
-                    outbound = outbound_rx.recv() => {
-                        match outbound {
-                            Some(message) => {
-                                if write.send(message).await.is_err() {
-                                    break;
-                                }
-                            }
-                            None => {
-                                let _ = write.send(WsMessage::Close(None)).await;
-                                break;
+            let mut ws_stream = ws_stream;
+            let reason = loop {
+                let exit = Self::run_session(
+                    ws_stream,
+                    &mut outbound_rx,
+                    &message_tx,
+                    heartbeat,
+                    &depth_levels,
+                )
+                .await;
+                let reason = match exit {
+                    SessionExit::Closed => break None,
+                    SessionExit::Dropped(reason) => reason,
+                };
+                let Some(policy) = reconnect else {
+                    break Some(reason);
+                };
+                let target = target.lock().await.clone();
+                let Some(target) = target else {
+                    break Some(reason);
+                };
+                warn!(stream = stream_kind, %reason, "ws connection dropped");
+                let frames = replay.lock().await.frames();
+                match Self::reconnect_with_replay(&target, &frames, stream_kind, &policy).await {
+                    Some(stream) => ws_stream = stream,
+                    None => break Some(format!("{reason}; reconnect attempts exhausted")),
+                }
+            };
+
+            if let Some(reason) = reason {
+                warn!(stream = stream_kind, %reason, "ws connection closed");
+                *disconnect_reason.lock().await = Some(reason);
+            }
+            let mut guard = outbound_state_for_task.lock().await;
+            *guard = None;
+        });
+
+        Ok(())
+    }
+
+    async fn run_session(
+        ws_stream: WsStream,
+        outbound_rx: &mut mpsc::Receiver<WsMessage>,
+        message_tx: &mpsc::Sender<WebSocketMessage>,
+        heartbeat: Option<Heartbeat>,
+        depth_levels: &DepthLevels,
+    ) -> SessionExit {
+        let (mut write, mut read) = ws_stream.split();
+        // Sequences restart with each connection, so gaps are tracked per session.
+        let mut sequences = SequenceTracker::default();
+        let mut last_seen = tokio::time::Instant::now();
+        let mut ping = heartbeat.map(|heartbeat| {
+            let mut ping =
+                tokio::time::interval_at(last_seen + heartbeat.interval, heartbeat.interval);
+            ping.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            ping
+        });
+
+        loop {
+            tokio::select! {
+                outbound = outbound_rx.recv() => {
+                    match outbound {
+                        Some(message) => {
+                            if let Err(err) = write.send(message).await {
+                                return SessionExit::Dropped(format!("send failed: {err}"));
                             }
                         }
+                        None => {
+                            let _ = write.send(WsMessage::Close(None)).await;
+                            return SessionExit::Closed;
+                        }
                     }
-                    incoming = read.next() => {
-                        match incoming {
-                            Some(Ok(WsMessage::Close(_))) => {
-                                let _ = write.send(WsMessage::Close(None)).await;
-                                break;
-                            }
-                            Some(Ok(WsMessage::Ping(_))) | Some(Ok(WsMessage::Pong(_))) => {}
-                            Some(Ok(message)) => {
-                                if let Some(parsed) = Self::parse_message(message)
-                                    && message_tx.send(parsed).await.is_err()
-                                {
-                                    break;
+                }
+                _ = async { ping.as_mut()?.tick().await; Some(()) }, if ping.is_some() => {
+                    let Some(heartbeat) = heartbeat else {
+                        continue;
+                    };
+                    let silent = last_seen.elapsed();
+                    if silent >= heartbeat.timeout {
+                        return SessionExit::Dropped(format!(
+                            "heartbeat timeout: no frames for {}ms",
+                            silent.as_millis()
+                        ));
+                    }
+                    if let Err(err) = write.send(WsMessage::Ping(Default::default())).await {
+                        return SessionExit::Dropped(format!("ping failed: {err}"));
+                    }
+                }
+                incoming = read.next() => {
+                    last_seen = tokio::time::Instant::now();
+                    match incoming {
+                        Some(Ok(WsMessage::Close(_))) => {
+                            let _ = write.send(WsMessage::Close(None)).await;
+                            return SessionExit::Dropped("closed by server".to_string());
+                        }
+                        Some(Ok(WsMessage::Ping(_))) | Some(Ok(WsMessage::Pong(_))) => {}
+                        Some(Ok(message)) => {
+                            let Some(parsed) = Self::parse_message(message)
+                                .map(|parsed| Self::type_depth(parsed, depth_levels))
+                            else {
+                                continue;
+                            };
+                            if let Some(gap) = sequences.observe(&parsed) {
+                                warn!(?gap, "ws sequence gap detected");
+                                if message_tx.send(gap).await.is_err() {
+                                    return SessionExit::Closed;
                                 }
                             }
-                            Some(Err(_)) | None => {
-                                break;
+                            if message_tx.send(Self::type_order(parsed)).await.is_err() {
+                                return SessionExit::Closed;
                             }
                         }
+                        Some(Err(err)) => {
+                            return SessionExit::Dropped(format!("read failed: {err}"));
+                        }
+                        None => {
+                            return SessionExit::Dropped("stream ended".to_string());
+                        }
                     }
                 }
             }
+        }
+    }
 
-            let mut guard = outbound_state_for_task.lock().await;
-            *guard = None;
-        });
-
-        Ok(())
+    async fn reconnect_with_replay(
+        target: &StreamTarget,
+        frames: &[Value],
+        stream_kind: Option<&'static str>,
+        policy: &BackoffPolicy,
+    ) -> Option<WsStream> {
+        let mut attempt = 1;
+        loop {
+            let delay = policy.delay_for(attempt);
+            warn!(
+                stream = stream_kind,
+                attempt,
+                ?delay,
+                "ws connection dropped; reconnecting"
+            );
+            tokio::time::sleep(delay).await;
+
+            let result = match target.connect().await {
+                Ok(mut ws_stream) => {
+                    let mut replayed = Ok(());
+                    for frame in frames {
+                        let frame = ensure_request_id(frame.clone(), stream_kind);
+                        replayed = ws_stream
+                            .send(WsMessage::Text(frame.to_string().into()))
+                            .await;
+                        if replayed.is_err() {
+                            break;
+                        }
+                    }
+                    replayed.map(|_| ws_stream).map_err(Box::new)
+                }
+                Err(err) => Err(err),
+            };
+
+            match result {
+                Ok(ws_stream) => {
+                    info!(
+                        stream = stream_kind,
+                        attempt,
+                        replayed = frames.len(),
+                        "ws reconnected and replayed subscriptions"
+                    );
+                    return Some(ws_stream);
+                }
+                Err(err) if policy.should_retry(attempt) => {
+                    warn!(stream = stream_kind, attempt, error = %err, "ws reconnect failed");
+                    attempt += 1;
+                }
+                Err(err) => {
+                    warn!(stream = stream_kind, attempt, error = %err, "ws reconnect gave up");
+                    return None;
+                }
+            }
+        }
     }
 
     async fn send_subscription(
@@ -327,6 +713,7 @@ impl StandxWebSocket {
 
         let stream_kind = *self.stream_kind.lock().await;
 
+        self.replay.lock().await.record(&message);
         let message = ensure_request_id(message, stream_kind);
 
         sender
@@ -344,6 +731,35 @@ impl StandxWebSocket {
         Ok(())
     }
 
+    /// Upgrade raw `DepthBook` frames to `Depth`, applying the subscribed level cap.
+    fn type_depth(message: WebSocketMessage, depth_levels: &DepthLevels) -> WebSocketMessage {
+        let WebSocketMessage::DepthBook { symbol, data } = message else {
+            return message;
+        };
+        let Some(mut update) = DepthUpdate::from_value(&symbol, &data) else {
+            return WebSocketMessage::DepthBook { symbol, data };
+        };
+        let levels = depth_levels
+            .lock()
+            .ok()
+            .and_then(|guard| guard.get(&update.symbol).copied());
+        if let Some(levels) = levels {
+            update.truncate(levels);
+        }
+        WebSocketMessage::Depth(update)
+    }
+
+    /// Upgrade raw `Order` frames to `OrderUpdate` when they match `OrderUpdateData`.
+    fn type_order(message: WebSocketMessage) -> WebSocketMessage {
+        let WebSocketMessage::Order { data } = message else {
+            return message;
+        };
+        match serde_json::from_value::<OrderUpdateData>(data.clone()) {
+            Ok(update) => WebSocketMessage::OrderUpdate(update),
+            Err(_) => WebSocketMessage::Order { data },
+        }
+    }
+
     fn parse_message(message: WsMessage) -> Option<WebSocketMessage> {
         let text: String = match message {
             WsMessage::Text(text) => text.to_string(),
@@ -468,10 +884,8 @@ fn describe_subscription(
 ) -> Option<(&'static str, &str, Option<&str>)> {
     let (action, payload) = if let Some(payload) = message.get("subscribe") {
         ("subscribe", payload)
-    } else if let Some(payload) = message.get("unsubscribe") {
-        ("unsubscribe", payload)
     } else {
-        return None;
+        ("unsubscribe", message.get("unsubscribe")?)
     };
 
     let channel = payload.get("channel")?.as_str()?;
@@ -542,7 +956,16 @@ fn log_message_sample_once(message: &WebSocketMessage) {
                 "ws message sample"
             );
         }
-        WebSocketMessage::Order { .. } => {
+        WebSocketMessage::Depth(update) => {
+            info!(
+                sample_index = count + 1,
+                sample_limit = MESSAGE_SAMPLE_LIMIT,
+                channel = "depth",
+                symbol = %update.symbol,
+                "ws message sample"
+            );
+        }
+        WebSocketMessage::Order { .. } | WebSocketMessage::OrderUpdate(_) => {
             info!(
                 sample_index = count + 1,
                 sample_limit = MESSAGE_SAMPLE_LIMIT,
@@ -566,6 +989,14 @@ fn log_message_sample_once(message: &WebSocketMessage) {
                 "ws message sample"
             );
         }
+        WebSocketMessage::Gap { .. } => {
+            info!(
+                sample_index = count + 1,
+                sample_limit = MESSAGE_SAMPLE_LIMIT,
+                channel = "gap",
+                "ws message sample"
+            );
+        }
         WebSocketMessage::Other => {
             info!(
                 sample_index = count + 1,
@@ -692,3 +1123,84 @@ fn looks_like_position(value: &Value) -> bool {
 
     value.get("symbol").is_some() && value.get("qty").is_some()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn order_frame(seq: u64) -> WebSocketMessage {
+        WebSocketMessage::Order {
+            data: json!({ "id": 1, "seq": seq }),
+        }
+    }
+
+    #[test]
+    fn order_frames_become_typed_updates() {
+        let frame = WebSocketMessage::Order {
+            data: json!({
+                "id": 42, "symbol": "BTC-USD", "side": "buy", "status": "filled",
+                "qty": "1", "fill_qty": "1", "price": "100", "order_type": "limit",
+                "cl_ord_id": "mm:bid:l1:1", "seq": 3
+            }),
+        };
+        match StandxWebSocket::type_order(frame) {
+            WebSocketMessage::OrderUpdate(update) => {
+                assert_eq!(update.id, 42);
+                assert_eq!(update.status, "filled");
+                assert_eq!(update.cl_ord_id.as_deref(), Some("mm:bid:l1:1"));
+            }
+            other => panic!("expected OrderUpdate, got {other:?}"),
+        }
+
+        // Frames missing required fields stay raw.
+        assert!(matches!(
+            StandxWebSocket::type_order(order_frame(1)),
+            WebSocketMessage::Order { .. }
+        ));
+    }
+
+    #[test]
+    fn sequence_tracker_reports_gaps_and_out_of_order_frames() {
+        let mut tracker = SequenceTracker::default();
+
+        assert!(tracker.observe(&order_frame(7)).is_none());
+        assert!(tracker.observe(&order_frame(8)).is_none());
+
+        let gap = tracker.observe(&order_frame(10));
+        assert!(matches!(
+            gap,
+            Some(WebSocketMessage::Gap { ref channel, expected: 9, got: 10 }) if channel == "order"
+        ));
+
+        // A late frame behind the high-water mark is also a gap and does not rewind it.
+        let late = tracker.observe(&order_frame(9));
+        assert!(matches!(
+            late,
+            Some(WebSocketMessage::Gap {
+                expected: 11,
+                got: 9,
+                ..
+            })
+        ));
+        assert!(tracker.observe(&order_frame(11)).is_none());
+    }
+
+    #[test]
+    fn sequence_tracker_keeps_channels_independent() {
+        let mut tracker = SequenceTracker::default();
+        let depth = |symbol: &str, seq: &str| WebSocketMessage::DepthBook {
+            symbol: symbol.to_string(),
+            data: json!({ "bids": [], "asks": [], "sequence": seq }),
+        };
+
+        assert!(tracker.observe(&depth("BTC-USD", "100")).is_none());
+        assert!(tracker.observe(&depth("ETH-USD", "5")).is_none());
+        assert!(tracker.observe(&order_frame(1)).is_none());
+        assert!(tracker.observe(&depth("BTC-USD", "101")).is_none());
+        assert!(tracker.observe(&depth("ETH-USD", "6")).is_none());
+
+        let unsequenced = WebSocketMessage::Balance { data: json!({}) };
+        assert!(tracker.observe(&unsequenced).is_none());
+    }
+}
@@ -3,9 +3,19 @@
 [OUTPUT]: Parsed WebSocketMessage structs
 [POS]:    WebSocket layer - message parsing and validation
 [UPDATE]: When adding new message types or changing format
+[UPDATE]: 2026-10-16 Add parsed DepthUpdate levels with optional sequence number
+[UPDATE]: 2026-10-16 Share sequence-number extraction for gap detection
+[UPDATE]: 2026-10-17 Carry cl_ord_id on OrderUpdateData
+[UPDATE]: 2026-10-17 Parse PriceData.spread into a typed Spread
 */
 
+use std::str::FromStr;
+
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::Spread;
 
 /// Market price data
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -16,7 +26,9 @@ pub struct PriceData {
     pub mark_price: String,
     pub mid_price: String,
     pub quote: String,
-    pub spread: Vec<String>,
+    /// `[bid, ask]` on the wire; see [`Spread`] for the lenient parsing rules
+    #[serde(default)]
+    pub spread: Spread,
     pub symbol: String,
     pub time: String,
 }
@@ -29,6 +41,88 @@ pub struct DepthBookData {
     pub symbol: String,
 }
 
+/// Parsed depth book update with `(price, qty)` levels, best price first
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct DepthUpdate {
+    pub symbol: String,
+    pub bids: Vec<(Decimal, Decimal)>,
+    pub asks: Vec<(Decimal, Decimal)>,
+    /// Feed sequence number, when the frame carries one
+    pub sequence: Option<u64>,
+}
+
+impl DepthUpdate {
+    /// Parse a raw `depth_book` payload; `None` if the levels are malformed.
+    pub fn from_value(symbol: &str, data: &Value) -> Option<Self> {
+        let mut bids = parse_levels(data.get("bids")?)?;
+        let mut asks = parse_levels(data.get("asks")?)?;
+        bids.sort_by_key(|level| std::cmp::Reverse(level.0));
+        asks.sort_by_key(|level| level.0);
+
+        let symbol = data
+            .get("symbol")
+            .and_then(Value::as_str)
+            .unwrap_or(symbol)
+            .to_string();
+        let sequence = sequence_number(data);
+
+        Some(Self {
+            symbol,
+            bids,
+            asks,
+            sequence,
+        })
+    }
+
+    /// Keep at most `levels` entries per side.
+    pub fn truncate(&mut self, levels: usize) {
+        self.bids.truncate(levels);
+        self.asks.truncate(levels);
+    }
+
+    pub fn best_bid(&self) -> Option<(Decimal, Decimal)> {
+        self.bids.first().copied()
+    }
+
+    pub fn best_ask(&self) -> Option<(Decimal, Decimal)> {
+        self.asks.first().copied()
+    }
+}
+
+/// Feed sequence number carried by a payload under `seq` or `sequence`.
+pub(crate) fn sequence_number(data: &Value) -> Option<u64> {
+    ["seq", "sequence"]
+        .iter()
+        .find_map(|key| data.get(*key))
+        .and_then(|value| {
+            value
+                .as_u64()
+                .or_else(|| value.as_str().and_then(|raw| raw.parse().ok()))
+        })
+}
+
+fn parse_levels(value: &Value) -> Option<Vec<(Decimal, Decimal)>> {
+    value
+        .as_array()?
+        .iter()
+        .map(|level| {
+            let level = level.as_array()?;
+            Some((
+                parse_decimal(level.first()?)?,
+                parse_decimal(level.get(1)?)?,
+            ))
+        })
+        .collect()
+}
+
+fn parse_decimal(value: &Value) -> Option<Decimal> {
+    match value {
+        Value::String(raw) => Decimal::from_str(raw).ok(),
+        Value::Number(number) => Decimal::from_str(&number.to_string()).ok(),
+        _ => None,
+    }
+}
+
 /// Order update data
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct OrderUpdateData {
@@ -40,4 +134,7 @@ pub struct OrderUpdateData {
     pub fill_qty: String,
     pub price: String,
     pub order_type: String,
+    /// Client order id, when the frame carries one
+    #[serde(default)]
+    pub cl_ord_id: Option<String>,
 }
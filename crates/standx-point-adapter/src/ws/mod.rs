@@ -9,4 +9,4 @@ pub mod client;
 pub mod message;
 
 pub use client::{StandxWebSocket, WebSocketMessage};
-pub use message::{DepthBookData, OrderUpdateData, PriceData};
+pub use message::{DepthBookData, DepthUpdate, OrderUpdateData, PriceData};
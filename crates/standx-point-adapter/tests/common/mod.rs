@@ -22,6 +22,7 @@ pub fn generate_test_keypair() -> ([u8; 32], [u8; 32]) {
 }
 
 /// Mock JWT token for testing
+#[allow(dead_code)]
 pub fn mock_jwt_token() -> String {
     "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9.test.signature".to_string()
 }
@@ -0,0 +1,54 @@
+/*
+[INPUT]:  Process environment overrides
+[OUTPUT]: Test results for environment-driven client configuration
+[POS]:    Integration tests - environment variables (own binary, so env
+          changes cannot leak into other suites)
+[UPDATE]: When client environment variables change
+[UPDATE]: 2026-10-17 Cover STANDX_AUTH_BASE_URL / STANDX_TRADING_BASE_URL overrides
+*/
+
+mod common;
+
+use common::setup_mock_server;
+use standx_point_adapter::http::{AUTH_BASE_URL, AUTH_BASE_URL_ENV, TRADING_BASE_URL_ENV};
+use standx_point_adapter::{StandxClient, StandxError};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+fn set_env(var: &str, value: &str) {
+    // SAFETY: this binary has a single test, so nothing reads the
+    // environment concurrently.
+    unsafe { std::env::set_var(var, value) };
+}
+
+#[tokio::test]
+async fn test_base_url_env_overrides_default_urls() {
+    let server = setup_mock_server().await;
+    Mock::given(method("GET"))
+        .and(path("/api/query_symbol_info"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    set_env(AUTH_BASE_URL_ENV, " ");
+    set_env(TRADING_BASE_URL_ENV, &server.uri());
+    let client = StandxClient::new().expect("client from env");
+    assert_eq!(client.auth_base_url().as_str(), format!("{AUTH_BASE_URL}/"));
+    assert_eq!(
+        client.trading_base_url().as_str(),
+        format!("{}/", server.uri())
+    );
+    let infos = client.query_symbol_info("BTC-USD").await.unwrap();
+    assert!(infos.is_empty());
+
+    for invalid in ["not a url", "ftp://staging.example.com"] {
+        set_env(AUTH_BASE_URL_ENV, invalid);
+        let err = StandxClient::new().unwrap_err();
+        let err = err.downcast_ref::<StandxError>().expect("config error");
+        assert!(
+            matches!(err, StandxError::Config(message) if message.contains(AUTH_BASE_URL_ENV)),
+            "{err}"
+        );
+    }
+}
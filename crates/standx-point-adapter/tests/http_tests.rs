@@ -8,12 +8,22 @@
 mod common;
 
 use common::{generate_test_keypair, mock_jwt_token, setup_mock_server};
-use standx_point_adapter::{Chain, ClientConfig, Credentials, StandxClient, StandxError};
+use standx_point_adapter::{
+    Chain, ClientConfig, ClockSync, Credentials, OrderSubmission, ProxyConfig, RateLimitConfig,
+    RateLimiter, RequestSigner, StandxClient, StandxError,
+};
 use tokio_test::assert_ok;
-use wiremock::matchers::{header, method, path, query_param};
+use wiremock::matchers::{
+    body_json, body_partial_json, header, header_regex, method, path, query_param,
+    query_param_is_missing,
+};
 use wiremock::{Match, Mock, Request, ResponseTemplate};
 
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::{GzEncoder, ZlibEncoder};
+use futures_util::TryStreamExt as _;
 use rust_decimal::Decimal;
 use standx_point_adapter::http::signature::{
     HEADER_REQUEST_ID, HEADER_REQUEST_SIGNATURE, HEADER_REQUEST_TIMESTAMP, HEADER_REQUEST_VERSION,
@@ -21,6 +31,7 @@ use standx_point_adapter::http::signature::{
 use standx_point_adapter::{
     Ed25519Signer, NewOrderRequest, OrderStatus, OrderType, Side, TimeInForce,
 };
+use std::io::{Read as _, Write as _};
 use std::str;
 
 #[test]
@@ -54,6 +65,18 @@ fn test_client_credentials_roundtrip() {
     assert_eq!(stored.chain, credentials.chain);
 }
 
+#[test]
+fn test_self_test_signature_requires_signer_and_round_trips() {
+    let mut client = assert_ok!(StandxClient::new());
+    assert!(matches!(
+        client.self_test_signature(),
+        Err(StandxError::Config(_))
+    ));
+
+    client.set_request_signer(Ed25519Signer::from_secret_key(&[9u8; 32]));
+    assert_ok!(client.self_test_signature());
+}
+
 #[test]
 fn test_error_retryable() {
     let timeout_err = StandxError::Timeout { duration: 30 };
@@ -128,7 +151,11 @@ impl Match for ValidBodySignatureMatcher {
             None => return false,
         };
 
-        let payload = match str::from_utf8(&request.body) {
+        let body = match decoded_body(request) {
+            Some(body) => body,
+            None => return false,
+        };
+        let payload = match str::from_utf8(&body) {
             Ok(s) => s,
             Err(_) => return false,
         };
@@ -144,6 +171,22 @@ impl Match for ValidBodySignatureMatcher {
     }
 }
 
+/// Request body with any gzip content-encoding removed.
+fn decoded_body(request: &Request) -> Option<Vec<u8>> {
+    let gzipped = request
+        .headers
+        .get("content-encoding")
+        .is_some_and(|value| value.as_bytes() == b"gzip");
+    if !gzipped {
+        return Some(request.body.clone());
+    }
+    let mut decoded = Vec::new();
+    GzDecoder::new(request.body.as_slice())
+        .read_to_end(&mut decoded)
+        .ok()?;
+    Some(decoded)
+}
+
 #[tokio::test]
 async fn test_http_user_endpoints_send_bearer_jwt() {
     let server = setup_mock_server().await;
@@ -236,6 +279,81 @@ async fn test_http_user_endpoints_send_bearer_jwt() {
     assert_eq!(balance.balance, Decimal::ZERO);
 }
 
+#[tokio::test]
+async fn test_query_trades_parses_fills_and_treats_404_as_empty() {
+    let server = setup_mock_server().await;
+    let base_url = server.uri();
+
+    let jwt = mock_jwt_token();
+
+    Mock::given(method("GET"))
+        .and(path("/api/query_trades"))
+        .and(query_param("symbol", "BTC-USD"))
+        .and(query_param("start_time", "1000"))
+        .and(query_param("end_time", "2000"))
+        .and(query_param("limit", "50"))
+        .and(header("authorization", format!("Bearer {jwt}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "page_size": 50,
+            "result": [{
+                "created_at": "2026-01-01T00:00:00Z",
+                "fee_asset": "DUSD",
+                "fee_qty": "0.01",
+                "id": 7,
+                "order_id": 42,
+                "pnl": "-0.5",
+                "price": "100.5",
+                "qty": "2",
+                "side": "buy",
+                "symbol": "BTC-USD",
+                "updated_at": "2026-01-01T00:00:00Z",
+                "user": "user",
+                "value": "201"
+            }],
+            "total": 1,
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/query_trades"))
+        .and(query_param("symbol", "ETH-USD"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "message": "not found"
+        })))
+        .mount(&server)
+        .await;
+
+    let mut client = assert_ok!(StandxClient::with_config_and_base_urls(
+        ClientConfig::default(),
+        &base_url,
+        &base_url
+    ));
+    client.set_credentials(Credentials {
+        jwt_token: jwt,
+        wallet_address: "0x1234567890abcdef".to_string(),
+        chain: Chain::Bsc,
+    });
+
+    let trades = assert_ok!(
+        client
+            .query_trades(Some("BTC-USD"), Some(1000), Some(2000), Some(50))
+            .await
+    );
+    assert_eq!(trades.total, 1);
+    assert_eq!(trades.result.len(), 1);
+    let fill = &trades.result[0];
+    assert_eq!(fill.order_id, 42);
+    assert_eq!(fill.side, Side::Buy);
+    assert_eq!(fill.price, Decimal::new(1005, 1));
+    assert_eq!(fill.fee_qty, Decimal::new(1, 2));
+
+    let empty = assert_ok!(client.query_trades(Some("ETH-USD"), None, None, None).await);
+    assert!(empty.result.is_empty());
+    assert_eq!(empty.total, 0);
+}
+
 #[tokio::test]
 async fn test_query_open_orders_defaults_missing_total() {
     let server = setup_mock_server().await;
@@ -317,6 +435,21 @@ async fn test_http_trading_endpoints_send_body_signature_headers() {
         .mount(&server)
         .await;
 
+    Mock::given(method("POST"))
+        .and(path("/api/change_margin_mode"))
+        .and(header("authorization", format!("Bearer {jwt}")))
+        .and(body_json(serde_json::json!({
+            "symbol": "BTC-USD",
+            "margin_mode": "isolated"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "code": 0,
+            "message": "ok",
+            "request_id": "req-4"
+        })))
+        .mount(&server)
+        .await;
+
     let mut client = assert_ok!(StandxClient::with_config_and_base_urls(
         ClientConfig::default(),
         &base_url,
@@ -361,4 +494,914 @@ async fn test_http_trading_endpoints_send_body_signature_headers() {
 
     let change = assert_ok!(client.change_leverage("BTC-USD", 10).await);
     assert_eq!(change.code, 0);
+
+    let leverage = assert_ok!(client.set_leverage("BTC-USD", 5).await);
+    assert_eq!(leverage.request_id, "req-3");
+
+    let margin = assert_ok!(
+        client
+            .set_margin_mode("BTC-USD", standx_point_adapter::MarginMode::Isolated)
+            .await
+    );
+    assert_eq!(margin.request_id, "req-4");
+}
+
+#[tokio::test]
+async fn test_iter_orders_follows_last_id_across_pages() {
+    let server = setup_mock_server().await;
+    let base_url = server.uri();
+    let jwt = mock_jwt_token();
+
+    let order = |id: i64| {
+        serde_json::json!({
+            "cl_ord_id": format!("cl-{id}"),
+            "closed_block": 0,
+            "created_at": "0",
+            "created_block": 0,
+            "fill_avg_price": "0",
+            "fill_qty": "0",
+            "id": id,
+            "leverage": "1",
+            "liq_id": 0,
+            "margin": "0",
+            "order_type": "limit",
+            "position_id": 0,
+            "price": "100",
+            "qty": "1",
+            "reduce_only": false,
+            "remark": "",
+            "side": "buy",
+            "source": "test",
+            "status": "open",
+            "symbol": "BTC-USD",
+            "time_in_force": "gtc",
+            "updated_at": "0",
+            "user": "user",
+        })
+    };
+    let page = |ids: &[i64]| {
+        ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "page_size": ids.len(),
+            "result": ids.iter().map(|id| order(*id)).collect::<Vec<_>>(),
+            "total": 5,
+        }))
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/api/query_orders"))
+        .and(query_param("status", "open"))
+        .and(query_param("limit", "2"))
+        .and(query_param_is_missing("last_id"))
+        .respond_with(page(&[1, 2]))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/query_orders"))
+        .and(query_param("last_id", "2"))
+        .respond_with(page(&[3, 4]))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/query_orders"))
+        .and(query_param("last_id", "4"))
+        .respond_with(page(&[5]))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = assert_ok!(StandxClient::with_config_and_base_urls(
+        ClientConfig::default(),
+        &base_url,
+        &base_url
+    ));
+    client.set_credentials(Credentials {
+        jwt_token: jwt,
+        wallet_address: "0x1234567890abcdef".to_string(),
+        chain: Chain::Bsc,
+    });
+
+    let pages: Vec<Vec<i64>> = assert_ok!(
+        client
+            .iter_orders(Some("BTC-USD"), Some(OrderStatus::Open), 2)
+            .map_ok(|orders| orders.iter().map(|order| order.id).collect())
+            .try_collect()
+            .await
+    );
+    assert_eq!(pages, vec![vec![1, 2], vec![3, 4], vec![5]]);
+}
+
+#[tokio::test]
+async fn test_cancel_all_orders_bulk_cancels_open_order_ids() {
+    let server = setup_mock_server().await;
+    let base_url = server.uri();
+
+    let jwt = mock_jwt_token();
+    let secret_key = [7u8; 32];
+    let signer = Ed25519Signer::from_secret_key(&secret_key);
+
+    let open_order = |id: i64| {
+        serde_json::json!({
+            "avail_locked": "0",
+            "cl_ord_id": format!("cl-{id}"),
+            "closed_block": 0,
+            "created_at": "0",
+            "created_block": 0,
+            "fill_avg_price": "0",
+            "fill_qty": "0",
+            "id": id,
+            "leverage": "1",
+            "liq_id": 0,
+            "margin": "0",
+            "order_type": "limit",
+            "position_id": 0,
+            "price": "100",
+            "qty": "1",
+            "reduce_only": false,
+            "remark": "",
+            "side": "buy",
+            "source": "test",
+            "status": "open",
+            "symbol": "BTC-USD",
+            "time_in_force": "gtc",
+            "updated_at": "0",
+            "user": "user",
+        })
+    };
+
+    Mock::given(method("GET"))
+        .and(path("/api/query_open_orders"))
+        .and(query_param("symbol", "BTC-USD"))
+        .and(header("authorization", format!("Bearer {jwt}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "page_size": 2,
+            "result": [open_order(11), open_order(12)],
+            "total": 2,
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/api/cancel_orders"))
+        .and(header("authorization", format!("Bearer {jwt}")))
+        .and(ValidBodySignatureMatcher { secret_key })
+        .and(body_json(serde_json::json!({ "order_id_list": [11, 12] })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = assert_ok!(StandxClient::with_config_and_base_urls(
+        ClientConfig::default(),
+        &base_url,
+        &base_url
+    ));
+    client.set_credentials_and_signer(
+        Credentials {
+            jwt_token: jwt,
+            wallet_address: "0x1234567890abcdef".to_string(),
+            chain: Chain::Bsc,
+        },
+        signer,
+    );
+
+    let response = assert_ok!(client.cancel_all_orders(Some("BTC-USD")).await);
+    assert!(response.0.is_empty());
+}
+
+#[tokio::test]
+async fn test_cancel_all_orders_pages_listing_and_cancels_in_chunks() {
+    let server = setup_mock_server().await;
+    let base_url = server.uri();
+
+    let jwt = mock_jwt_token();
+    let secret_key = [7u8; 32];
+    let signer = Ed25519Signer::from_secret_key(&secret_key);
+
+    let open_order = |id: i64| {
+        serde_json::json!({
+            "avail_locked": "0",
+            "cl_ord_id": format!("cl-{id}"),
+            "closed_block": 0,
+            "created_at": "0",
+            "created_block": 0,
+            "fill_avg_price": "0",
+            "fill_qty": "0",
+            "id": id,
+            "leverage": "1",
+            "liq_id": 0,
+            "margin": "0",
+            "order_type": "limit",
+            "position_id": 0,
+            "price": "100",
+            "qty": "1",
+            "reduce_only": false,
+            "remark": "",
+            "side": "buy",
+            "source": "test",
+            "status": "open",
+            "symbol": "BTC-USD",
+            "time_in_force": "gtc",
+            "updated_at": "0",
+            "user": "user",
+        })
+    };
+    let ids: Vec<i64> = (1..=150).collect();
+
+    // query_open_orders returns one 50-order page of a 150-order listing.
+    Mock::given(method("GET"))
+        .and(path("/api/query_open_orders"))
+        .and(query_param("symbol", "BTC-USD"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "page_size": 50,
+            "result": ids[..50].iter().map(|id| open_order(*id)).collect::<Vec<_>>(),
+            "total": 150,
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/query_orders"))
+        .and(query_param("status", "open"))
+        .and(query_param_is_missing("last_id"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "page_size": 150,
+            "result": ids.iter().map(|id| open_order(*id)).collect::<Vec<_>>(),
+            "total": 150,
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    for chunk in [&ids[..100], &ids[100..]] {
+        Mock::given(method("POST"))
+            .and(path("/api/cancel_orders"))
+            .and(ValidBodySignatureMatcher { secret_key })
+            .and(body_json(serde_json::json!({ "order_id_list": chunk })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+
+    let mut client = assert_ok!(StandxClient::with_config_and_base_urls(
+        ClientConfig::default(),
+        &base_url,
+        &base_url
+    ));
+    client.set_credentials_and_signer(
+        Credentials {
+            jwt_token: jwt,
+            wallet_address: "0x1234567890abcdef".to_string(),
+            chain: Chain::Bsc,
+        },
+        signer,
+    );
+
+    assert_ok!(client.cancel_all_orders(Some("BTC-USD")).await);
+}
+
+#[tokio::test]
+async fn test_amend_order_sends_signed_price_and_qty() {
+    let server = setup_mock_server().await;
+    let base_url = server.uri();
+
+    let jwt = mock_jwt_token();
+    let secret_key = [7u8; 32];
+    let signer = Ed25519Signer::from_secret_key(&secret_key);
+
+    Mock::given(method("POST"))
+        .and(path("/api/amend_order"))
+        .and(header("authorization", format!("Bearer {jwt}")))
+        .and(ValidBodySignatureMatcher { secret_key })
+        .and(body_json(serde_json::json!({
+            "cl_ord_id": "cl-1",
+            "price": "101.5",
+            "qty": "2",
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "code": 0,
+            "message": "ok",
+            "request_id": "req-amend"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = assert_ok!(StandxClient::with_config_and_base_urls(
+        ClientConfig::default(),
+        &base_url,
+        &base_url
+    ));
+    client.set_credentials_and_signer(
+        Credentials {
+            jwt_token: jwt,
+            wallet_address: "0x1234567890abcdef".to_string(),
+            chain: Chain::Bsc,
+        },
+        signer,
+    );
+
+    let response = assert_ok!(
+        client
+            .amend_order(standx_point_adapter::AmendOrderRequest {
+                cl_ord_id: Some("cl-1".to_string()),
+                price: Some(Decimal::new(1015, 1)),
+                qty: Some(Decimal::from(2)),
+                ..Default::default()
+            })
+            .await
+    );
+    assert_eq!(response.code, 0);
+    assert_eq!(response.request_id, "req-amend");
+}
+
+#[tokio::test]
+async fn test_shared_order_limiter_serializes_order_actions() {
+    let server = setup_mock_server().await;
+    let base_url = server.uri();
+
+    let jwt = mock_jwt_token();
+    let secret_key = [7u8; 32];
+
+    Mock::given(method("POST"))
+        .and(path("/api/new_order"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_delay(std::time::Duration::from_millis(200))
+                .set_body_json(serde_json::json!({
+                    "code": 0,
+                    "message": "ok",
+                    "request_id": "req-1"
+                })),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let limiter = std::sync::Arc::new(tokio::sync::Semaphore::new(1));
+    let build_client = || {
+        let mut client =
+            StandxClient::with_config_and_base_urls(ClientConfig::default(), &base_url, &base_url)
+                .expect("client");
+        client.set_credentials_and_signer(
+            Credentials {
+                jwt_token: jwt.clone(),
+                wallet_address: "0x1234567890abcdef".to_string(),
+                chain: Chain::Bsc,
+            },
+            Ed25519Signer::from_secret_key(&secret_key),
+        );
+        client.set_order_limiter(limiter.clone());
+        client
+    };
+    let first = build_client();
+    let second = build_client();
+
+    let order = || NewOrderRequest {
+        symbol: "BTC-USD".to_string(),
+        side: Side::Buy,
+        order_type: OrderType::Limit,
+        qty: Decimal::from(1),
+        time_in_force: TimeInForce::PostOnly,
+        reduce_only: false,
+        price: Some(Decimal::from(10)),
+        cl_ord_id: None,
+        margin_mode: None,
+        leverage: None,
+        tp_price: None,
+        sl_price: None,
+    };
+
+    let started = std::time::Instant::now();
+    let (a, b) = tokio::join!(first.new_order(order()), second.new_order(order()));
+    assert_ok!(a);
+    assert_ok!(b);
+    assert!(started.elapsed() >= std::time::Duration::from_millis(400));
+}
+
+#[tokio::test]
+async fn test_shared_rate_limiter_paces_requests_across_clients() {
+    let server = setup_mock_server().await;
+    let base_url = server.uri();
+
+    Mock::given(method("GET"))
+        .and(path("/api/query_symbol_info"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(4)
+        .mount(&server)
+        .await;
+
+    let limiter = std::sync::Arc::new(RateLimiter::new(RateLimitConfig {
+        requests_per_second: 10,
+        burst: 2,
+    }));
+    let build_client = || {
+        let mut client =
+            StandxClient::with_config_and_base_urls(ClientConfig::default(), &base_url, &base_url)
+                .expect("client");
+        client.set_rate_limiter(limiter.clone());
+        client
+    };
+    let first = build_client();
+    let second = build_client();
+
+    // Burst of two, then the shared bucket refills one token per 100ms.
+    let started = std::time::Instant::now();
+    let (a, b, c, d) = tokio::join!(
+        first.query_symbol_info("BTC-USD"),
+        second.query_symbol_info("BTC-USD"),
+        first.query_symbol_info("BTC-USD"),
+        second.query_symbol_info("BTC-USD"),
+    );
+    assert_ok!(a);
+    assert_ok!(b);
+    assert_ok!(c);
+    assert_ok!(d);
+    assert!(started.elapsed() >= std::time::Duration::from_millis(200));
+}
+
+#[tokio::test]
+async fn test_proxy_config_routes_requests_through_proxy() {
+    let proxy = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/query_symbol_info"))
+        .and(header("proxy-authorization", "Basic dXNlcjpwYXNz"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+        .expect(1)
+        .mount(&proxy)
+        .await;
+
+    let config = ClientConfig {
+        proxy: Some(ProxyConfig {
+            url: proxy.uri(),
+            username: Some("user".to_string()),
+            password: Some("pass".to_string()),
+        }),
+        ..ClientConfig::default()
+    };
+    // The target host never resolves; only the proxy can answer.
+    let client = assert_ok!(StandxClient::with_config_and_base_urls(
+        config,
+        "http://standx.invalid",
+        "http://standx.invalid"
+    ));
+    let infos = assert_ok!(client.query_symbol_info("BTC-USD").await);
+    assert!(infos.is_empty());
+}
+
+#[test]
+fn test_proxy_config_rejects_invalid_urls() {
+    for url in ["not a url", "ftp://proxy.local:21"] {
+        let config = ClientConfig {
+            proxy: Some(ProxyConfig::new(url)),
+            ..ClientConfig::default()
+        };
+        let err = StandxClient::with_config(config).unwrap_err().to_string();
+        assert!(err.contains("proxy"), "{url}: {err}");
+    }
+}
+
+#[test]
+fn test_pinned_cert_must_be_certificate_or_spki_hash() {
+    let config = ClientConfig {
+        pinned_cert: Some(b"not a certificate".to_vec()),
+        ..ClientConfig::default()
+    };
+    let err = StandxClient::with_config(config).unwrap_err().to_string();
+    assert!(err.contains("pinned_cert"), "{err}");
+
+    let config = ClientConfig {
+        pinned_cert: Some(vec![0x42; 32]),
+        ..ClientConfig::default()
+    };
+    assert_ok!(StandxClient::with_config(config));
+}
+
+#[tokio::test]
+async fn test_compression_signs_uncompressed_payload_and_round_trips() {
+    let server = setup_mock_server().await;
+    let base_url = server.uri();
+
+    let jwt = mock_jwt_token();
+    let secret_key = [9u8; 32];
+    let signer = Ed25519Signer::from_secret_key(&secret_key);
+
+    let response_body = serde_json::json!({
+        "code": 0,
+        "message": "ok",
+        "request_id": "req-gzip"
+    });
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder
+        .write_all(response_body.to_string().as_bytes())
+        .unwrap();
+    let compressed_response = encoder.finish().unwrap();
+
+    Mock::given(method("POST"))
+        .and(path("/api/cancel_order"))
+        .and(header("content-encoding", "gzip"))
+        .and(header_regex("accept-encoding", "gzip"))
+        .and(ValidBodySignatureMatcher { secret_key })
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-encoding", "gzip")
+                .set_body_raw(compressed_response, "application/json"),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let config = ClientConfig {
+        compression: true,
+        ..ClientConfig::default()
+    };
+    let mut client = assert_ok!(StandxClient::with_config_and_base_urls(
+        config, &base_url, &base_url
+    ));
+    client.set_credentials_and_signer(
+        Credentials {
+            jwt_token: jwt,
+            wallet_address: "0x1234567890abcdef".to_string(),
+            chain: Chain::Bsc,
+        },
+        signer,
+    );
+
+    let req = standx_point_adapter::CancelOrderRequest {
+        order_id: Some(42),
+        cl_ord_id: None,
+    };
+    let expected_payload = serde_json::to_string(&req).unwrap();
+    let cancel = assert_ok!(client.cancel_order(req).await);
+    assert_eq!(cancel.code, 0);
+    assert_eq!(cancel.request_id, "req-gzip");
+
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_ne!(requests[0].body, expected_payload.as_bytes());
+    assert_eq!(
+        decoded_body(&requests[0]).unwrap(),
+        expected_payload.as_bytes()
+    );
+}
+
+#[tokio::test]
+async fn test_compressed_responses_are_negotiated_and_decoded() {
+    let server = setup_mock_server().await;
+    let base_url = server.uri();
+    let jwt = mock_jwt_token();
+
+    let page = serde_json::json!({ "page_size": 2, "result": [], "total": 7 }).to_string();
+    let mut gzip = GzEncoder::new(Vec::new(), Compression::default());
+    gzip.write_all(page.as_bytes()).unwrap();
+    let mut deflate = ZlibEncoder::new(Vec::new(), Compression::default());
+    deflate.write_all(page.as_bytes()).unwrap();
+
+    for (symbol, encoding, body) in [
+        ("BTC-USD", "gzip", gzip.finish().unwrap()),
+        ("ETH-USD", "deflate", deflate.finish().unwrap()),
+    ] {
+        Mock::given(method("GET"))
+            .and(path("/api/query_orders"))
+            .and(query_param("symbol", symbol))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .insert_header("content-encoding", encoding)
+                    .set_body_raw(body, "application/json"),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path("/api/query_orders"))
+        .and(query_param("symbol", "SOL-USD"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(page.clone()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let credentials = Credentials {
+        jwt_token: jwt,
+        wallet_address: "0x1234567890abcdef".to_string(),
+        chain: Chain::Bsc,
+    };
+    let mut client = assert_ok!(StandxClient::with_config_and_base_urls(
+        ClientConfig::default(),
+        &base_url,
+        &base_url
+    ));
+    client.set_credentials(credentials.clone());
+    for symbol in ["BTC-USD", "ETH-USD"] {
+        let orders = assert_ok!(client.query_orders(Some(symbol), None, None).await);
+        assert_eq!(orders.total, 7, "{symbol}");
+    }
+
+    let mut raw_client = assert_ok!(StandxClient::with_config_and_base_urls(
+        ClientConfig {
+            decompress_responses: false,
+            ..ClientConfig::default()
+        },
+        &base_url,
+        &base_url
+    ));
+    raw_client.set_credentials(credentials);
+    assert_ok!(raw_client.query_orders(Some("SOL-USD"), None, None).await);
+
+    let requests = server.received_requests().await.unwrap();
+    let accept_encoding = |request: &Request| {
+        request
+            .headers
+            .get("accept-encoding")
+            .map(|value| value.to_str().unwrap().to_string())
+    };
+    for request in &requests[..2] {
+        let accepted = accept_encoding(request).expect("accept-encoding");
+        assert!(
+            accepted.contains("gzip") && accepted.contains("deflate"),
+            "{accepted}"
+        );
+    }
+    assert_eq!(accept_encoding(&requests[2]), None);
+}
+
+fn signed_client_with_config(
+    config: ClientConfig,
+    base_url: &str,
+    secret_key: [u8; 32],
+) -> StandxClient {
+    let mut client = assert_ok!(StandxClient::with_config_and_base_urls(
+        config, base_url, base_url
+    ));
+    client.set_credentials_and_signer(
+        Credentials {
+            jwt_token: mock_jwt_token(),
+            wallet_address: "0x1234567890abcdef".to_string(),
+            chain: Chain::Bsc,
+        },
+        Ed25519Signer::from_secret_key(&secret_key),
+    );
+    client
+}
+
+fn signed_timestamps(requests: &[Request]) -> Vec<u64> {
+    requests
+        .iter()
+        .filter_map(|request| request.headers.get(HEADER_REQUEST_TIMESTAMP))
+        .map(|value| value.to_str().unwrap().parse().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_signed_timestamp_applies_clock_offset() {
+    let server = setup_mock_server().await;
+    let secret_key = [9u8; 32];
+
+    Mock::given(method("POST"))
+        .and(path("/api/cancel_order"))
+        .and(ValidBodySignatureMatcher { secret_key })
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "code": 0,
+            "message": "ok",
+            "request_id": "req-1"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let client = signed_client_with_config(ClientConfig::default(), &server.uri(), secret_key);
+    // Local clock one hour ahead of the server.
+    let offset_millis: i64 = -3_600_000;
+    client.set_clock_offset_millis(offset_millis);
+
+    let before = RequestSigner::timestamp_millis();
+    let cancel = standx_point_adapter::CancelOrderRequest {
+        order_id: Some(1),
+        cl_ord_id: None,
+    };
+    assert_ok!(client.cancel_order(cancel).await);
+    let after = RequestSigner::timestamp_millis();
+
+    let requests = server.received_requests().await.unwrap();
+    let [timestamp] = signed_timestamps(&requests)[..] else {
+        panic!("expected one signed request");
+    };
+    let corrected = before - 3_600_000..=after - 3_600_000;
+    assert!(corrected.contains(&timestamp), "timestamp {timestamp}");
+}
+
+#[tokio::test]
+async fn test_order_is_signed_after_waiting_for_the_order_permit() {
+    let server = setup_mock_server().await;
+    let secret_key = [11u8; 32];
+
+    Mock::given(method("POST"))
+        .and(path("/api/cancel_order"))
+        .and(ValidBodySignatureMatcher { secret_key })
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "code": 0,
+            "message": "ok",
+            "request_id": "req-1"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let mut client = signed_client_with_config(ClientConfig::default(), &server.uri(), secret_key);
+    let limiter = std::sync::Arc::new(tokio::sync::Semaphore::new(1));
+    client.set_order_limiter(limiter.clone());
+
+    // Another order action holds the only permit while this one queues.
+    let held = limiter.clone().acquire_owned().await.unwrap();
+    let cancel = tokio::spawn(async move {
+        client
+            .cancel_order(standx_point_adapter::CancelOrderRequest {
+                order_id: Some(1),
+                cl_ord_id: None,
+            })
+            .await
+    });
+    tokio::time::sleep(std::time::Duration::from_millis(300)).await;
+    let released = RequestSigner::timestamp_millis();
+    drop(held);
+    assert_ok!(cancel.await.unwrap());
+
+    let requests = server.received_requests().await.unwrap();
+    let [timestamp] = signed_timestamps(&requests)[..] else {
+        panic!("expected one signed request");
+    };
+    assert!(
+        timestamp >= released,
+        "signed at {timestamp}, permit released at {released}"
+    );
+}
+
+#[tokio::test]
+async fn test_time_sync_interval_resyncs_offset_before_signing() {
+    let server = setup_mock_server().await;
+    let secret_key = [9u8; 32];
+    let local_millis = || 1_700_000_000_000;
+    let server_millis: u64 = 1_700_000_060_000;
+
+    Mock::given(method("GET"))
+        .and(path("/api/query_server_time"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!(server_millis)))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/cancel_order"))
+        .and(ValidBodySignatureMatcher { secret_key })
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "code": 0,
+            "message": "ok",
+            "request_id": "req-1"
+        })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let config = ClientConfig {
+        time_sync_interval: Some(std::time::Duration::from_secs(3600)),
+        ..ClientConfig::default()
+    };
+    let mut client = signed_client_with_config(config, &server.uri(), secret_key);
+    client.set_clock_sync(std::sync::Arc::new(ClockSync::with_local_clock(
+        local_millis,
+    )));
+
+    for order_id in [1, 2] {
+        let cancel = standx_point_adapter::CancelOrderRequest {
+            order_id: Some(order_id),
+            cl_ord_id: None,
+        };
+        assert_ok!(client.cancel_order(cancel).await);
+    }
+
+    // One sync within the interval; both orders signed with server time.
+    assert_eq!(client.clock_offset_millis(), 60_000);
+    let requests = server.received_requests().await.unwrap();
+    assert_eq!(signed_timestamps(&requests), vec![server_millis; 2]);
+}
+
+#[tokio::test]
+async fn test_new_order_checked_looks_up_cl_ord_id_after_timeout() {
+    let server = setup_mock_server().await;
+    let secret_key = [9u8; 32];
+    let slow_ok = ResponseTemplate::new(200)
+        .set_delay(std::time::Duration::from_millis(500))
+        .set_body_json(serde_json::json!({
+            "code": 0,
+            "message": "ok",
+            "request_id": "req-slow"
+        }));
+
+    // quote-1 lands although its response times out.
+    Mock::given(method("POST"))
+        .and(path("/api/new_order"))
+        .and(body_partial_json(
+            serde_json::json!({ "cl_ord_id": "quote-1" }),
+        ))
+        .respond_with(slow_ok.clone())
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/query_order"))
+        .and(query_param("cl_ord_id", "quote-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "avail_locked": "0",
+            "cl_ord_id": "quote-1",
+            "closed_block": 0,
+            "created_at": "0",
+            "created_block": 0,
+            "fill_avg_price": "0",
+            "fill_qty": "0",
+            "id": 21,
+            "leverage": "1",
+            "liq_id": 0,
+            "margin": "0",
+            "order_type": "limit",
+            "position_id": 0,
+            "price": "10",
+            "qty": "1",
+            "reduce_only": false,
+            "remark": "",
+            "side": "buy",
+            "source": "test",
+            "status": "open",
+            "symbol": "BTC-USD",
+            "time_in_force": "post_only",
+            "updated_at": "0",
+            "user": "user",
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // quote-2 times out without landing, so it is resent with the same id.
+    Mock::given(method("POST"))
+        .and(path("/api/new_order"))
+        .and(body_partial_json(
+            serde_json::json!({ "cl_ord_id": "quote-2" }),
+        ))
+        .respond_with(slow_ok)
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/api/new_order"))
+        .and(body_partial_json(
+            serde_json::json!({ "cl_ord_id": "quote-2" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "code": 0,
+            "message": "ok",
+            "request_id": "req-2"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/query_order"))
+        .and(query_param("cl_ord_id", "quote-2"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "message": "order not found"
+        })))
+        .mount(&server)
+        .await;
+
+    let config = ClientConfig {
+        timeout: std::time::Duration::from_millis(200),
+        ..ClientConfig::default()
+    };
+    let client = signed_client_with_config(config, &server.uri(), secret_key);
+    let order = |cl_ord_id: &str| NewOrderRequest {
+        symbol: "BTC-USD".to_string(),
+        side: Side::Buy,
+        order_type: OrderType::Limit,
+        qty: Decimal::from(1),
+        time_in_force: TimeInForce::PostOnly,
+        reduce_only: false,
+        price: Some(Decimal::from(10)),
+        cl_ord_id: Some(cl_ord_id.to_string()),
+        margin_mode: None,
+        leverage: None,
+        tp_price: None,
+        sl_price: None,
+    };
+
+    match assert_ok!(client.new_order_checked(order("quote-1")).await) {
+        OrderSubmission::Landed(order) => assert_eq!(order.id, 21),
+        other => panic!("expected landed order, got {other:?}"),
+    }
+    match assert_ok!(client.new_order_checked(order("quote-2")).await) {
+        OrderSubmission::Accepted(resp) => assert_eq!(resp.request_id, "req-2"),
+        other => panic!("expected accepted order, got {other:?}"),
+    }
 }
@@ -3,9 +3,19 @@
 [OUTPUT]: Test results for WebSocket client
 [POS]:    Integration tests - WebSocket
 [UPDATE]: When WebSocket client changes
+[UPDATE]: 2026-10-16 Cover subscription replay after a dropped connection
+[UPDATE]: 2026-10-16 Cover heartbeat liveness and stale-connection detection
+[UPDATE]: 2026-10-16 Cover typed depth updates with level caps and sequence numbers
 */
 
-use standx_point_adapter::StandxWebSocket;
+use std::time::Duration;
+
+use futures_util::{SinkExt, StreamExt};
+use rust_decimal::Decimal;
+use serde_json::{Value, json};
+use standx_point_adapter::{BackoffPolicy, StandxWebSocket, WebSocketMessage};
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
 
 #[test]
 fn test_websocket_creation() {
@@ -25,3 +35,199 @@ fn test_websocket_receiver_take_once() {
     assert!(ws.take_receiver().is_some());
     assert!(ws.take_receiver().is_none());
 }
+
+async fn next_json(
+    socket: &mut tokio_tungstenite::WebSocketStream<tokio::net::TcpStream>,
+) -> Value {
+    loop {
+        let frame = tokio::time::timeout(Duration::from_secs(5), socket.next())
+            .await
+            .expect("timed out waiting for frame")
+            .expect("socket closed")
+            .expect("socket error");
+        if let Message::Text(text) = frame {
+            return serde_json::from_str(&text).expect("json frame");
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_reconnect_replays_active_subscriptions() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+
+    let ws = StandxWebSocket::new().with_reconnect(BackoffPolicy {
+        initial_delay: Duration::from_millis(10),
+        max_delay: Duration::from_millis(50),
+        max_attempts: 3,
+    });
+
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut first = tokio_tungstenite::accept_async(stream).await.unwrap();
+        let mut initial = Vec::new();
+        for _ in 0..5 {
+            initial.push(next_json(&mut first).await);
+        }
+        // Simulate the exchange dropping the connection.
+        drop(first);
+
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut second = tokio_tungstenite::accept_async(stream).await.unwrap();
+        let mut replayed = Vec::new();
+        for _ in 0..3 {
+            replayed.push(next_json(&mut second).await);
+        }
+        (initial, replayed)
+    });
+
+    ws.connect_market_stream_url(&url).await.unwrap();
+    ws.authenticate("jwt-token", Some(&["position"]))
+        .await
+        .unwrap();
+    ws.subscribe_price("BTC-USD").await.unwrap();
+    ws.subscribe_price("ETH-USD").await.unwrap();
+    ws.unsubscribe_price("ETH-USD").await.unwrap();
+    ws.subscribe_depth("BTC-USD", Some(5)).await.unwrap();
+
+    let (initial, replayed) = tokio::time::timeout(Duration::from_secs(10), server)
+        .await
+        .expect("server timed out")
+        .unwrap();
+
+    assert_eq!(initial[0]["auth"]["token"], "jwt-token");
+    assert_eq!(initial[3]["unsubscribe"]["symbol"], "ETH-USD");
+
+    assert_eq!(replayed[0]["auth"]["token"], "jwt-token");
+    assert_eq!(replayed[1]["subscribe"]["channel"], "price");
+    assert_eq!(replayed[1]["subscribe"]["symbol"], "BTC-USD");
+    assert_eq!(replayed[2]["subscribe"]["channel"], "depth_book");
+    assert_eq!(replayed[2]["subscribe"]["symbol"], "BTC-USD");
+}
+
+#[tokio::test]
+async fn test_heartbeat_closes_receiver_on_stale_connection() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+        // Half-open: keep the socket but never read, so pings go unanswered.
+        tokio::time::sleep(Duration::from_secs(10)).await;
+        drop(socket);
+    });
+
+    let mut ws = StandxWebSocket::new()
+        .with_heartbeat(Duration::from_millis(50), Duration::from_millis(200));
+    let mut rx = ws.take_receiver().unwrap();
+    ws.connect_market_stream_url(&url).await.unwrap();
+
+    let ended = tokio::time::timeout(Duration::from_secs(5), rx.recv())
+        .await
+        .expect("stale connection was not detected");
+    assert!(ended.is_none());
+    let reason = ws.disconnect_reason().await.expect("has disconnect reason");
+    assert!(reason.contains("heartbeat timeout"), "{reason}");
+
+    server.abort();
+}
+
+#[tokio::test]
+async fn test_heartbeat_keeps_responsive_connection_alive() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+        // Reading lets tungstenite answer pings with pongs.
+        while let Some(Ok(_)) = socket.next().await {}
+    });
+
+    let mut ws = StandxWebSocket::new()
+        .with_heartbeat(Duration::from_millis(50), Duration::from_millis(200));
+    let mut rx = ws.take_receiver().unwrap();
+    ws.connect_market_stream_url(&url).await.unwrap();
+
+    let outcome = tokio::time::timeout(Duration::from_millis(800), rx.recv()).await;
+    assert!(outcome.is_err(), "responsive connection was closed");
+    assert!(ws.disconnect_reason().await.is_none());
+
+    server.abort();
+}
+
+#[tokio::test]
+async fn test_depth_subscription_yields_typed_levels() {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("ws://{}", listener.local_addr().unwrap());
+
+    let server = tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+        let subscribe = next_json(&mut socket).await;
+        assert_eq!(subscribe["subscribe"]["channel"], "depth_book");
+
+        let frames = [
+            json!({
+                "channel": "depth_book",
+                "symbol": "BTC-USD",
+                "seq": 42,
+                "data": {
+                    "symbol": "BTC-USD",
+                    "seq": 42,
+                    "bids": [["99.5", "2"], ["99.9", "1"], ["99.0", "3"]],
+                    "asks": [["100.5", "2"], ["100.1", "1"], ["101.0", "3"]],
+                },
+            }),
+            json!({
+                "channel": "depth_book",
+                "symbol": "BTC-USD",
+                "data": { "bids": [["not-a-price", "1"]], "asks": [] },
+            }),
+        ];
+        for frame in frames {
+            socket
+                .send(Message::Text(frame.to_string().into()))
+                .await
+                .unwrap();
+        }
+        while let Some(Ok(_)) = socket.next().await {}
+    });
+
+    let mut ws = StandxWebSocket::new();
+    let mut rx = ws.take_receiver().unwrap();
+    ws.connect_market_stream_url(&url).await.unwrap();
+    ws.subscribe_depth("BTC-USD", Some(2)).await.unwrap();
+
+    let recv = async {
+        tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timed out waiting for depth")
+            .expect("receiver closed")
+    };
+
+    let WebSocketMessage::Depth(update) = recv.await else {
+        panic!("expected typed depth update");
+    };
+    let d = |raw: &str| raw.parse::<Decimal>().unwrap();
+    assert_eq!(update.symbol, "BTC-USD");
+    assert_eq!(update.sequence, Some(42));
+    assert_eq!(update.bids, vec![(d("99.9"), d("1")), (d("99.5"), d("2"))]);
+    assert_eq!(
+        update.asks,
+        vec![(d("100.1"), d("1")), (d("100.5"), d("2"))]
+    );
+    assert_eq!(update.best_bid(), Some((d("99.9"), d("1"))));
+    assert_eq!(update.best_ask(), Some((d("100.1"), d("1"))));
+
+    let recv = async {
+        tokio::time::timeout(Duration::from_secs(5), rx.recv())
+            .await
+            .expect("timed out waiting for raw depth")
+            .expect("receiver closed")
+    };
+    assert!(matches!(recv.await, WebSocketMessage::DepthBook { .. }));
+
+    server.abort();
+}
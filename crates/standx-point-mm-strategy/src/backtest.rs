@@ -0,0 +1,571 @@
+/*
+[INPUT]:  Recorded SymbolPrice updates (CSV, JSON, or JSONL) and strategy settings
+[OUTPUT]: BacktestReport with fills, realized PnL, and uptime ratio
+[POS]:    Offline layer - historical replay harness for tuning quote bands
+[UPDATE]: When changing the simulated fill model or replay input formats
+[UPDATE]: 2026-10-17 Add Backtester replaying prices through a simulated exchange
+*/
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow};
+use chrono::DateTime;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use standx_point_adapter::{
+    AmendOrderRequest, CancelOrderRequest, CancelOrderResponse, NewOrderRequest, NewOrderResponse,
+    Order, OrderStatus, OrderType, Side, SymbolPrice, TimeInForce,
+};
+use tokio::sync::{Mutex, mpsc, watch};
+
+use crate::order_state::OrderTracker;
+use crate::risk::PositionLedger;
+use crate::strategy::{
+    MarketMakingStrategy, OrderExecutor, OrderReconcileRequest, QUOTE_REFRESH_INTERVAL,
+    QuotingBands, RiskLevel, StrategyMode,
+};
+
+/// Outcome of replaying a price series.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BacktestReport {
+    pub updates: usize,
+    pub fills: u64,
+    pub filled_qty: Decimal,
+    pub realized_pnl_usd: Decimal,
+    pub final_position_qty: Decimal,
+    pub uptime_ratio: Decimal,
+}
+
+/// Replays recorded prices into a `MarketMakingStrategy` against a simulated
+/// exchange.
+///
+/// Resting quotes fill in full at their limit price once mark trades through
+/// them (bids at or above mark, asks at or below). Post-only orders that
+/// would cross are cancelled, IOC orders fill at their limit when marketable
+/// and are otherwise cancelled, market orders fill at mark. Fees, queue
+/// position, and partial fills are not modelled.
+pub struct Backtester {
+    strategy: MarketMakingStrategy,
+    exchange: SimExchange,
+    price_tx: watch::Sender<SymbolPrice>,
+    position_tx: watch::Sender<Decimal>,
+    order_tracker: Arc<Mutex<OrderTracker>>,
+    reconcile_rx: mpsc::UnboundedReceiver<OrderReconcileRequest>,
+}
+
+impl Backtester {
+    /// Strategy set up the way a task would be for `risk_level`, flat at start.
+    pub fn new(symbol: &str, budget_usd: Decimal, risk_level: RiskLevel) -> Self {
+        let (price_tx, price_rx) = watch::channel(empty_price(symbol));
+        let (position_tx, position_rx) = watch::channel(Decimal::ZERO);
+        let (reconcile_tx, reconcile_rx) = mpsc::unbounded_channel();
+        let order_tracker = Arc::new(Mutex::new(OrderTracker::new()));
+        let strategy = MarketMakingStrategy::new_with_params(
+            symbol.to_string(),
+            budget_usd,
+            risk_level,
+            None,
+            None,
+            price_rx,
+            position_rx,
+            order_tracker.clone(),
+            reconcile_tx,
+            StrategyMode::aggressive_for_risk(risk_level),
+            MarketMakingStrategy::tier_count_for_risk(risk_level),
+            Decimal::ZERO,
+        );
+        Self {
+            strategy,
+            exchange: SimExchange::default(),
+            price_tx,
+            position_tx,
+            order_tracker,
+            reconcile_rx,
+        }
+    }
+
+    /// Override the quote bps bands under test.
+    pub fn with_quoting(mut self, quoting: QuotingBands) -> Self {
+        self.strategy.set_quoting(quoting);
+        self
+    }
+
+    /// Access the strategy to apply further settings before replaying.
+    pub fn strategy_mut(&mut self) -> &mut MarketMakingStrategy {
+        &mut self.strategy
+    }
+
+    /// Feed `updates` in order, stepping simulated time by their `time` field.
+    ///
+    /// Between updates the strategy also gets its periodic refresh, as the
+    /// live run loop would.
+    pub async fn run(&mut self, updates: &[SymbolPrice]) -> Result<BacktestReport> {
+        let start = tokio::time::Instant::now();
+        let mut first_millis = None;
+        let mut now = start;
+        let mut next_refresh = start;
+
+        for update in updates {
+            let millis = parse_time_millis(&update.time)?;
+            let offset = millis - *first_millis.get_or_insert(millis);
+            // Out-of-order stamps never move the clock backwards.
+            now = now.max(start + Duration::from_millis(offset.max(0) as u64));
+
+            while next_refresh < now {
+                self.strategy
+                    .refresh_from_latest(&self.exchange, next_refresh)
+                    .await?;
+                self.sync_tracker().await?;
+                next_refresh += QUOTE_REFRESH_INTERVAL;
+            }
+
+            self.price_tx.send_replace(update.clone());
+            if self.exchange.match_price(update.mark_price) {
+                self.position_tx.send_replace(self.exchange.position_qty());
+                self.sync_tracker().await?;
+                self.strategy
+                    .on_position_change(&self.exchange, now)
+                    .await?;
+            } else {
+                self.strategy.on_price_change(&self.exchange, now).await?;
+            }
+            self.sync_tracker().await?;
+        }
+
+        let state = self.exchange.lock();
+        Ok(BacktestReport {
+            updates: updates.len(),
+            fills: state.fills,
+            filled_qty: state.filled_qty,
+            realized_pnl_usd: state.realized_pnl_usd,
+            final_position_qty: state.ledger.position_qty(),
+            uptime_ratio: self.strategy.uptime_snapshot_at(now).uptime_ratio,
+        })
+    }
+
+    /// Deliver exchange order states to the tracker, as reconciliation does live.
+    async fn sync_tracker(&mut self) -> Result<()> {
+        while self.reconcile_rx.try_recv().is_ok() {}
+        let orders = self.exchange.drain_snapshot();
+        self.order_tracker
+            .lock()
+            .await
+            .reconcile_with_exchange(&orders, std::time::Instant::now())
+            .map_err(|err| anyhow!("order tracker sync failed: {err}"))?;
+        Ok(())
+    }
+}
+
+/// Load price updates from `.csv`, `.json` (array), or `.jsonl` files.
+///
+/// CSV needs a header with at least `time` and `mark_price`; `symbol`,
+/// `index_price`, `mid_price`, and `last_price` are optional. Fields must not
+/// contain commas. `time` is RFC 3339 or unix milliseconds.
+pub fn load_price_updates(path: &Path) -> Result<Vec<SymbolPrice>> {
+    let raw = std::fs::read_to_string(path)
+        .with_context(|| format!("read price updates {}", path.display()))?;
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(str::to_ascii_lowercase);
+    match extension.as_deref() {
+        Some("csv") => parse_price_csv(&raw),
+        Some("json") => serde_json::from_str(&raw)
+            .with_context(|| format!("parse price updates {}", path.display())),
+        Some("jsonl") => raw
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| {
+                serde_json::from_str(line)
+                    .with_context(|| format!("parse {} line {}", path.display(), idx + 1))
+            })
+            .collect(),
+        _ => Err(anyhow!(
+            "unsupported price update file {} (expected .csv, .json, or .jsonl)",
+            path.display()
+        )),
+    }
+}
+
+fn parse_price_csv(raw: &str) -> Result<Vec<SymbolPrice>> {
+    let mut lines = raw.lines().filter(|line| !line.trim().is_empty());
+    let header: Vec<&str> = lines
+        .next()
+        .ok_or_else(|| anyhow!("price csv is empty"))?
+        .split(',')
+        .map(str::trim)
+        .collect();
+    let column = |name: &str| header.iter().position(|field| *field == name);
+    let time_col = column("time").ok_or_else(|| anyhow!("price csv missing time column"))?;
+    let mark_col =
+        column("mark_price").ok_or_else(|| anyhow!("price csv missing mark_price column"))?;
+    let (symbol_col, index_col, mid_col, last_col) = (
+        column("symbol"),
+        column("index_price"),
+        column("mid_price"),
+        column("last_price"),
+    );
+
+    lines
+        .enumerate()
+        .map(|(idx, line)| {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            let field = |col: Option<usize>| {
+                col.and_then(|col| fields.get(col).copied())
+                    .filter(|value| !value.is_empty())
+            };
+            let decimal = |col: Option<usize>| {
+                field(col)
+                    .map(|value| {
+                        value.parse::<Decimal>().with_context(|| {
+                            format!("price csv row {}: invalid decimal {value}", idx + 2)
+                        })
+                    })
+                    .transpose()
+            };
+            let mark_price = decimal(Some(mark_col))?
+                .ok_or_else(|| anyhow!("price csv row {}: missing mark_price", idx + 2))?;
+            let mut price = empty_price(field(symbol_col).unwrap_or_default());
+            price.time = field(Some(time_col))
+                .ok_or_else(|| anyhow!("price csv row {}: missing time", idx + 2))?
+                .to_string();
+            price.mark_price = mark_price;
+            price.index_price = decimal(index_col)?.unwrap_or(mark_price);
+            price.mid_price = decimal(mid_col)?;
+            price.last_price = decimal(last_col)?;
+            Ok(price)
+        })
+        .collect()
+}
+
+fn parse_time_millis(raw: &str) -> Result<i64> {
+    let raw = raw.trim();
+    if let Ok(millis) = raw.parse::<i64>() {
+        return Ok(millis);
+    }
+    DateTime::parse_from_rfc3339(raw)
+        .map(|time| time.timestamp_millis())
+        .map_err(|_| anyhow!("price update time must be RFC 3339 or unix millis: {raw}"))
+}
+
+fn empty_price(symbol: &str) -> SymbolPrice {
+    SymbolPrice {
+        base: String::new(),
+        index_price: Decimal::ZERO,
+        last_price: None,
+        mark_price: Decimal::ZERO,
+        mid_price: None,
+        quote: String::new(),
+        spread_ask: None,
+        spread_bid: None,
+        symbol: symbol.to_string(),
+        time: String::new(),
+    }
+}
+
+/// In-memory matching against the latest mark price.
+#[derive(Default)]
+struct SimExchange {
+    state: StdMutex<SimState>,
+}
+
+#[derive(Default)]
+struct SimState {
+    mark_price: Decimal,
+    next_order_id: i64,
+    open: HashMap<String, Order>,
+    /// Orders closed since the last tracker sync
+    closed: Vec<Order>,
+    ledger: PositionLedger,
+    fills: u64,
+    filled_qty: Decimal,
+    realized_pnl_usd: Decimal,
+}
+
+impl SimExchange {
+    fn lock(&self) -> std::sync::MutexGuard<'_, SimState> {
+        self.state.lock().unwrap_or_else(|err| err.into_inner())
+    }
+
+    fn position_qty(&self) -> Decimal {
+        self.lock().ledger.position_qty()
+    }
+
+    /// Fill resting orders that `mark_price` trades through; true if any did.
+    fn match_price(&self, mark_price: Decimal) -> bool {
+        let mut state = self.lock();
+        state.mark_price = mark_price;
+        let crossed: Vec<String> = state
+            .open
+            .values()
+            .filter(|order| {
+                order
+                    .price
+                    .is_some_and(|price| crosses(order.side, price, mark_price))
+            })
+            .map(|order| order.cl_ord_id.clone())
+            .collect();
+        for cl_ord_id in &crossed {
+            if let Some(order) = state.open.remove(cl_ord_id) {
+                let price = order.price.unwrap_or(mark_price);
+                state.fill(order, price);
+            }
+        }
+        !crossed.is_empty()
+    }
+
+    /// Open orders plus everything closed since the last call.
+    fn drain_snapshot(&self) -> Vec<Order> {
+        let mut state = self.lock();
+        let mut orders: Vec<Order> = std::mem::take(&mut state.closed);
+        orders.extend(state.open.values().cloned());
+        orders
+    }
+}
+
+impl SimState {
+    fn fill(&mut self, mut order: Order, price: Decimal) {
+        let signed_qty = match order.side {
+            Side::Buy => order.qty,
+            Side::Sell => -order.qty,
+        };
+        self.realized_pnl_usd += self.ledger.apply(signed_qty, price);
+        self.fills += 1;
+        self.filled_qty += order.qty;
+        order.fill_qty = order.qty;
+        order.fill_avg_price = price;
+        order.status = OrderStatus::Filled;
+        self.closed.push(order);
+    }
+
+    fn close(&mut self, mut order: Order, status: OrderStatus) {
+        order.status = status;
+        self.closed.push(order);
+    }
+}
+
+fn crosses(side: Side, price: Decimal, mark_price: Decimal) -> bool {
+    match side {
+        Side::Buy => mark_price <= price,
+        Side::Sell => mark_price >= price,
+    }
+}
+
+fn ok_response() -> NewOrderResponse {
+    NewOrderResponse {
+        code: 0,
+        message: "ok".to_string(),
+        request_id: String::new(),
+    }
+}
+
+impl OrderExecutor for SimExchange {
+    fn new_order(
+        &self,
+        req: NewOrderRequest,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = standx_point_adapter::Result<NewOrderResponse>>
+                + Send
+                + '_,
+        >,
+    > {
+        Box::pin(async move {
+            let mut state = self.lock();
+            state.next_order_id += 1;
+            let mark_price = state.mark_price;
+            let mut qty = req.qty;
+            if req.reduce_only {
+                let held = state.ledger.position_qty();
+                let reducible = match req.side {
+                    Side::Buy if held < Decimal::ZERO => held.abs(),
+                    Side::Sell if held > Decimal::ZERO => held,
+                    _ => Decimal::ZERO,
+                };
+                qty = qty.min(reducible);
+            }
+            let order = Order {
+                avail_locked: Decimal::ZERO,
+                cl_ord_id: req.cl_ord_id.clone().unwrap_or_default(),
+                closed_block: 0,
+                created_at: String::new(),
+                created_block: 0,
+                fill_avg_price: Decimal::ZERO,
+                fill_qty: Decimal::ZERO,
+                id: state.next_order_id,
+                leverage: Decimal::ONE,
+                liq_id: 0,
+                margin: Decimal::ZERO,
+                order_type: req.order_type,
+                payload: None,
+                tp_price: req.tp_price,
+                sl_price: req.sl_price,
+                position_id: 0,
+                price: req.price,
+                qty,
+                reduce_only: req.reduce_only,
+                remark: String::new(),
+                side: req.side,
+                source: "backtest".to_string(),
+                status: OrderStatus::Open,
+                symbol: req.symbol.clone(),
+                time_in_force: req.time_in_force,
+                updated_at: String::new(),
+                user: String::new(),
+            };
+
+            if qty <= Decimal::ZERO {
+                state.close(order, OrderStatus::Cancelled);
+                return Ok(ok_response());
+            }
+            let marketable = match req.price {
+                Some(price) => crosses(req.side, price, mark_price),
+                None => true,
+            };
+            match (req.order_type, req.time_in_force, marketable) {
+                (OrderType::Market, _, _) => state.fill(order, mark_price),
+                (_, TimeInForce::PostOnly, true) => state.close(order, OrderStatus::Cancelled),
+                (_, TimeInForce::Ioc, true) => {
+                    let price = req.price.unwrap_or(mark_price);
+                    state.fill(order, price);
+                }
+                (_, TimeInForce::Ioc, false) => state.close(order, OrderStatus::Cancelled),
+                (_, _, true) => state.fill(order, mark_price),
+                (_, _, false) => {
+                    state.open.insert(order.cl_ord_id.clone(), order);
+                }
+            }
+            Ok(ok_response())
+        })
+    }
+
+    fn cancel_order(
+        &self,
+        req: CancelOrderRequest,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = standx_point_adapter::Result<CancelOrderResponse>>
+                + Send
+                + '_,
+        >,
+    > {
+        Box::pin(async move {
+            let mut state = self.lock();
+            if let Some(order) = req
+                .cl_ord_id
+                .as_deref()
+                .and_then(|cl_ord_id| state.open.remove(cl_ord_id))
+            {
+                state.close(order, OrderStatus::Cancelled);
+            }
+            Ok(CancelOrderResponse {
+                code: 0,
+                message: "ok".to_string(),
+                request_id: String::new(),
+            })
+        })
+    }
+
+    fn amend_order(
+        &self,
+        req: AmendOrderRequest,
+    ) -> std::pin::Pin<
+        Box<
+            dyn std::future::Future<Output = standx_point_adapter::Result<NewOrderResponse>>
+                + Send
+                + '_,
+        >,
+    > {
+        Box::pin(async move {
+            let mut state = self.lock();
+            if let Some(order) = req
+                .cl_ord_id
+                .as_deref()
+                .and_then(|cl_ord_id| state.open.get_mut(cl_ord_id))
+            {
+                if let Some(price) = req.price {
+                    order.price = Some(price);
+                }
+                if let Some(qty) = req.qty {
+                    order.qty = qty;
+                }
+            }
+            Ok(ok_response())
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dec(value: &str) -> Decimal {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn price_csv_reads_named_columns() {
+        let updates = parse_price_csv(
+            "time,symbol,mark_price,mid_price\n\
+             2026-01-01T00:00:00Z,BTC-USD,100,\n\
+             1767225601000,BTC-USD,100.5,100.4\n",
+        )
+        .unwrap();
+        assert_eq!(updates.len(), 2);
+        assert_eq!(updates[0].mark_price, dec("100"));
+        assert_eq!(updates[0].index_price, dec("100"));
+        assert_eq!(updates[0].mid_price, None);
+        assert_eq!(updates[1].mid_price, Some(dec("100.4")));
+        assert_eq!(
+            parse_time_millis(&updates[1].time).unwrap()
+                - parse_time_millis(&updates[0].time).unwrap(),
+            1_000
+        );
+
+        assert!(parse_price_csv("time,symbol\n0,BTC-USD\n").is_err());
+    }
+
+    // Paused so real time spent between construction and `run` under a
+    // loaded test runner does not count against uptime.
+    #[tokio::test(start_paused = true)]
+    async fn backtest_fills_when_mark_trades_through_quotes() {
+        let series = |marks: &[&str]| {
+            marks
+                .iter()
+                .enumerate()
+                .map(|(idx, mark)| {
+                    let mut price = empty_price("BTC-USD");
+                    price.time = (idx as i64 * 1_000).to_string();
+                    price.mark_price = dec(mark);
+                    price.index_price = dec(mark);
+                    price
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut backtester = Backtester::new("BTC-USD", dec("1000"), RiskLevel::XHigh);
+        let flat = backtester
+            .run(&series(&["100", "100", "100", "100"]))
+            .await
+            .unwrap();
+        assert_eq!(flat.fills, 0);
+        assert_eq!(flat.uptime_ratio, Decimal::ONE);
+
+        // Dip through the bid, then rally through the ask re-quoted around 99:
+        // both fill at their limits, so the round trip sells below the entry.
+        let mut backtester = Backtester::new("BTC-USD", dec("1000"), RiskLevel::XHigh);
+        let report = backtester
+            .run(&series(&["100", "99", "99", "101", "101"]))
+            .await
+            .unwrap();
+        assert_eq!(report.fills, 2, "{report:?}");
+        assert!(report.realized_pnl_usd < Decimal::ZERO, "{report:?}");
+        assert!(report.realized_pnl_usd > dec("-10"), "{report:?}");
+        assert!(report.uptime_ratio < Decimal::ONE, "{report:?}");
+        assert_eq!(report.updates, 5);
+    }
+}
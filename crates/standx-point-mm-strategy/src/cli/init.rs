@@ -1,9 +1,10 @@
 /*
 [INPUT]:  Interactive user input via CLI
-[OUTPUT]: Generated YAML configuration file
+[OUTPUT]: Generated YAML, TOML, or JSON configuration file
 [POS]:    CLI initialization layer
 [UPDATE]: When StrategyConfig schema changes
 [UPDATE]: 2026-02-08 Collect wallet private key for auth
+[UPDATE]: 2026-10-16 Write the config format matching the output extension
 */
 
 use anyhow::{Context, Result};
@@ -11,7 +12,9 @@ use console::style;
 use dialoguer::{Input, Select, theme::ColorfulTheme};
 use std::path::PathBuf;
 
-use standx_point_mm_strategy::config::{AccountConfig, RiskConfig, StrategyConfig, TaskConfig};
+use standx_point_mm_strategy::config::{
+    AccountConfig, ConfigFormat, RiskConfig, StrategyConfig, TaskConfig,
+};
 
 pub fn run_init(output: PathBuf) -> Result<()> {
     println!(
@@ -78,24 +81,39 @@ pub fn run_init(output: PathBuf) -> Result<()> {
             jwt_token: None,
             signing_key: None,
             chain,
+            keystore_path: None,
+            mnemonic: None,
+            mnemonic_index: None,
+            wallet_address: None,
+            max_concurrent_order_actions: None,
+            rate_limit: None,
         }],
         tasks: vec![TaskConfig {
             id,
             symbol,
+            symbols: Vec::new(),
+            quoting: None,
+            leverage: None,
+            margin_mode: None,
             account_id,
             risk: RiskConfig {
                 level: risk_level,
                 budget_usd,
-                guard_close_enabled: None,
-                tp_bps: None,
-                sl_bps: None,
+                ..Default::default()
             },
         }],
+        max_total_budget_usd: None,
+        allow_duplicate_symbols: false,
+        max_concurrent_startups: None,
+        environment: None,
     };
 
-    let yaml = serde_yaml::to_string(&config).context("failed to serialize config to YAML")?;
+    let format = ConfigFormat::from_path(&output);
+    let content = config
+        .to_string_as(format)
+        .with_context(|| format!("failed to serialize config as {format:?}"))?;
 
-    std::fs::write(&output, yaml)
+    std::fs::write(&output, content)
         .context(format!("failed to write config to {}", output.display()))?;
 
     println!("\n{}", style("SUCCESS!").bold().green());
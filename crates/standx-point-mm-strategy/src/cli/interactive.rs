@@ -4,6 +4,7 @@
 [POS]:    CLI interactive flow
 [UPDATE]: 2026-02-06 Add interactive CLI task/account management
 [UPDATE]: 2026-02-08 Build config using wallet private key auth
+[UPDATE]: 2026-10-16 Retry transient authenticate failures
 */
 
 use anyhow::{Context, Result, anyhow};
@@ -17,7 +18,7 @@ use crate::state::storage::{Account, Storage, Task};
 use base64::Engine as _;
 use base64::engine::general_purpose::STANDARD;
 use standx_point_adapter::auth::{EvmWalletSigner, SolanaWalletSigner};
-use standx_point_adapter::{AuthManager, Chain, StandxClient, WalletSigner};
+use standx_point_adapter::{AuthManager, BackoffPolicy, Chain, StandxClient, WalletSigner};
 use standx_point_mm_strategy::config::{AccountConfig, RiskConfig, StrategyConfig, TaskConfig};
 
 pub async fn run_interactive() -> Result<Option<StrategyConfig>> {
@@ -537,13 +538,17 @@ pub(crate) async fn build_strategy_config(
         let task_config = TaskConfig {
             id: task.id.clone(),
             symbol: task.symbol.clone(),
+            symbols: Vec::new(),
+            quoting: None,
+            leverage: None,
+            margin_mode: None,
             account_id: account.id.clone(),
             risk: RiskConfig {
                 level: task.risk_level.clone(),
                 budget_usd: task.budget_usd.clone(),
-                guard_close_enabled: None,
                 tp_bps: task.tp_bps.clone(),
                 sl_bps: task.sl_bps.clone(),
+                ..Default::default()
             },
         };
         configs.push(task_config);
@@ -556,11 +561,21 @@ pub(crate) async fn build_strategy_config(
             jwt_token: non_empty(&account.jwt_token),
             signing_key: non_empty(&account.signing_key),
             chain: account.chain.unwrap_or(Chain::Bsc),
+            keystore_path: None,
+            mnemonic: None,
+            mnemonic_index: None,
+            wallet_address: None,
+            max_concurrent_order_actions: None,
+            rate_limit: None,
         })
         .collect();
     Ok(StrategyConfig {
         accounts,
         tasks: configs,
+        max_total_budget_usd: None,
+        allow_duplicate_symbols: false,
+        max_concurrent_startups: None,
+        environment: None,
     })
 }
 
@@ -573,7 +588,7 @@ async fn authenticate_account(private_key: &str, chain: Chain) -> Result<(String
                 .map_err(|err| anyhow!("invalid EVM private key: {err}"))?;
             let address = wallet.address().to_string();
             let login = auth
-                .authenticate(&wallet, 7 * 24 * 60 * 60)
+                .authenticate_with_retry(&wallet, 7 * 24 * 60 * 60, &BackoffPolicy::default())
                 .await
                 .map_err(|err| anyhow!("authenticate failed: {err}"))?;
             (address, login)
@@ -583,7 +598,7 @@ async fn authenticate_account(private_key: &str, chain: Chain) -> Result<(String
                 .map_err(|err| anyhow!("invalid Solana private key: {err}"))?;
             let address = wallet.address().to_string();
             let login = auth
-                .authenticate(&wallet, 7 * 24 * 60 * 60)
+                .authenticate_with_retry(&wallet, 7 * 24 * 60 * 60, &BackoffPolicy::default())
                 .await
                 .map_err(|err| anyhow!("authenticate failed: {err}"))?;
             (address, login)
@@ -3,7 +3,11 @@
 [OUTPUT]: CLI init and interactive helpers
 [POS]:    CLI module root
 [UPDATE]: 2026-02-06 Add interactive CLI support
+[UPDATE]: 2026-10-17 Add validate subcommand module
+[UPDATE]: 2026-10-17 Add status subcommand module
 */
 
 pub mod init;
 pub mod interactive;
+pub mod status;
+pub mod validate;
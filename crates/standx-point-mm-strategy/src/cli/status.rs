@@ -0,0 +1,163 @@
+/*
+[INPUT]:  Stored accounts (Storage) and optional account filter
+[OUTPUT]: Balance, open positions, and open orders per account (text or JSON)
+[POS]:    CLI status subcommand for scripts and health checks
+[UPDATE]: 2026-10-17 Add status subcommand querying live account state
+*/
+
+use anyhow::{Result, anyhow};
+use serde::Serialize;
+use standx_point_adapter::{Balance, JwtClaims, Order, Position, StandxError};
+
+use crate::state::storage::{Account, Storage};
+use crate::tui::build_live_client;
+
+#[derive(Debug, Default, Serialize)]
+struct AccountStatus {
+    id: String,
+    name: String,
+    /// Credentials are expired or were rejected
+    auth_failed: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    errors: Vec<String>,
+    balance: Option<Balance>,
+    positions: Vec<Position>,
+    open_orders: Vec<Order>,
+}
+
+/// Print live state for every stored account (or just `account_id`).
+///
+/// Fails when any account's credentials are expired or unauthorized, or any
+/// query fails, so cron jobs can alert on the exit code.
+pub async fn run_status(account_id: Option<&str>, json: bool) -> Result<()> {
+    let storage = Storage::new().await?;
+    let mut accounts = storage.list_accounts().await?;
+    if let Some(id) = account_id {
+        accounts.retain(|account| account.id == id);
+        if accounts.is_empty() {
+            return Err(anyhow!("account not found: {id}"));
+        }
+    }
+
+    let mut statuses = Vec::with_capacity(accounts.len());
+    for account in &accounts {
+        statuses.push(query_account(account).await);
+    }
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&serde_json::json!({ "accounts": statuses }))?
+        );
+    } else {
+        for status in &statuses {
+            print_status(status);
+        }
+    }
+
+    let auth_failures = statuses.iter().filter(|s| s.auth_failed).count();
+    let failures = statuses.iter().filter(|s| !s.errors.is_empty()).count();
+    if auth_failures > 0 {
+        return Err(anyhow!(
+            "{auth_failures} account(s) have expired or unauthorized credentials"
+        ));
+    }
+    if failures > 0 {
+        return Err(anyhow!("status queries failed for {failures} account(s)"));
+    }
+    Ok(())
+}
+
+async fn query_account(account: &Account) -> AccountStatus {
+    let mut status = AccountStatus {
+        id: account.id.clone(),
+        name: account.name.clone(),
+        ..Default::default()
+    };
+    if let Ok(claims) = JwtClaims::decode(&account.jwt_token)
+        && claims.is_expired(chrono::Utc::now())
+    {
+        status.auth_failed = true;
+        status
+            .errors
+            .push(format!("jwt_token expired at {:?}", claims.expires_at()));
+        return status;
+    }
+    let client = match build_live_client(account) {
+        Ok(client) => client,
+        Err(err) => {
+            status.errors.push(format!("{err:#}"));
+            return status;
+        }
+    };
+
+    let mut record = |what: &str, err: StandxError| {
+        status.auth_failed |= err.is_auth_error();
+        status.errors.push(format!("{what}: {err}"));
+    };
+    let balance = client.query_balance().await;
+    let positions = client.query_positions(None).await;
+    let open_orders = match client.query_open_orders(None).await {
+        Err(err) if err.is_not_found() => Ok(Vec::new()),
+        result => result.map(|page| page.result),
+    };
+    let balance = balance.map_err(|err| record("balance", err)).ok();
+    let positions = positions.map_err(|err| record("positions", err)).ok();
+    let open_orders = open_orders.map_err(|err| record("open_orders", err)).ok();
+
+    status.balance = balance;
+    status.positions = positions
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|position| !position.qty.is_zero())
+        .collect();
+    status.open_orders = open_orders.unwrap_or_default();
+    status
+}
+
+fn print_status(status: &AccountStatus) {
+    println!("account {} ({})", status.id, status.name);
+    if let Some(balance) = &status.balance {
+        println!(
+            "  balance: equity {} available {} upnl {}",
+            balance.equity.round_dp(2),
+            balance.cross_available.round_dp(2),
+            balance.upnl.round_dp(2)
+        );
+    }
+    if status.positions.is_empty() {
+        println!("  positions: none");
+    }
+    for position in &status.positions {
+        println!(
+            "  position: {} qty {} entry {} mark {} upnl {}",
+            position.symbol,
+            position.qty,
+            position.entry_price,
+            position.mark_price,
+            position.upnl.round_dp(2)
+        );
+    }
+    println!("  open orders: {}", status.open_orders.len());
+    for order in &status.open_orders {
+        let price = order
+            .price
+            .map_or_else(|| "market".to_string(), |price| price.to_string());
+        println!(
+            "    {} {:?} {:?} {} @ {}{}",
+            order.symbol,
+            order.side,
+            order.order_type,
+            order.qty,
+            price,
+            if order.reduce_only {
+                " reduce-only"
+            } else {
+                ""
+            }
+        );
+    }
+    for error in &status.errors {
+        println!("  error: {error}");
+    }
+}
@@ -0,0 +1,211 @@
+/*
+[INPUT]:  Strategy config file
+[OUTPUT]: Per-task pass/fail table; error when any check fails
+[POS]:    CLI validate subcommand (no orders, no WebSocket)
+[UPDATE]: 2026-10-17 Add validate subcommand with key, symbol, and budget checks
+*/
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{Result, anyhow};
+use rust_decimal::Decimal;
+use standx_point_adapter::{Chain, ClientConfig, JwtClaims, StandxClient};
+use standx_point_mm_strategy::StrategyConfig;
+use standx_point_mm_strategy::config::AccountConfig;
+use standx_point_mm_strategy::task::static_wallet_address;
+
+/// Run every check and print one row per task.
+///
+/// Only public REST endpoints are called: symbols are looked up with
+/// `query_symbol_info`, and nothing is authenticated or subscribed.
+pub async fn run_validate(path: &Path) -> Result<()> {
+    let mut config = crate::load_config(path)?;
+    let config_error = crate::validate_strategy_config(&config).err();
+    config.sort_tasks();
+
+    let accounts: BTreeMap<&str, Result<(), String>> = config
+        .accounts
+        .iter()
+        .map(|account| (account.id.as_str(), check_account(account)))
+        .collect();
+    let symbols = check_symbols(&config).await;
+
+    let mut failures = usize::from(config_error.is_some());
+    println!("{:<20} {:<24} {:<6} DETAILS", "TASK", "SYMBOLS", "RESULT");
+    for task in &config.tasks {
+        let mut problems = Vec::new();
+        match accounts.get(task.account_id.as_str()) {
+            Some(Ok(())) => {}
+            Some(Err(err)) => problems.push(format!("account {}: {err}", task.account_id)),
+            None => problems.push(format!("account {} not found", task.account_id)),
+        }
+        for symbol in task.symbol_list() {
+            if let Some(Err(err)) = symbols.get(symbol) {
+                problems.push(format!("symbol {symbol}: {err}"));
+            }
+        }
+        if let Err(err) = check_budget(&task.risk.budget_usd) {
+            problems.push(format!("budget_usd: {err}"));
+        }
+
+        let (result, details) = if problems.is_empty() {
+            ("PASS", "ok".to_string())
+        } else {
+            failures += 1;
+            ("FAIL", problems.join("; "))
+        };
+        println!(
+            "{:<20} {:<24} {:<6} {details}",
+            task.id,
+            task.symbol_list().join(","),
+            result
+        );
+    }
+    if let Some(err) = &config_error {
+        println!("config: FAIL {err:#}");
+    }
+
+    if failures > 0 {
+        return Err(anyhow!("validation failed ({failures} problem(s))"));
+    }
+    println!("config: PASS ({} task(s))", config.tasks.len());
+    Ok(())
+}
+
+/// The configured key must derive the address the account claims to be:
+/// the JWT's address claim when a token is set, else a `0x` account id.
+fn check_account(account: &AccountConfig) -> Result<(), String> {
+    let has_key = account
+        .private_key
+        .as_deref()
+        .is_some_and(|key| !key.trim().is_empty())
+        || account
+            .mnemonic
+            .as_deref()
+            .is_some_and(|phrase| !phrase.trim().is_empty());
+    if !has_key {
+        // Static jwt+signing_key or keystore (passphrase needed): nothing to derive.
+        return Ok(());
+    }
+    let derived = static_wallet_address(account, "")
+        .ok_or_else(|| "private_key/mnemonic does not derive a wallet".to_string())?;
+
+    let jwt_address = account
+        .jwt_token
+        .as_deref()
+        .and_then(|token| JwtClaims::decode(token.trim()).ok())
+        .and_then(|claims| claims.wallet_address().map(str::to_string));
+    let expected = jwt_address.or_else(|| {
+        (account.chain == Chain::Bsc && is_evm_address(&account.id)).then(|| account.id.clone())
+    });
+    match expected {
+        Some(expected) if !addresses_match(&expected, &derived, account.chain) => {
+            Err(format!("key derives {derived}, expected {expected}"))
+        }
+        _ => Ok(()),
+    }
+}
+
+fn is_evm_address(raw: &str) -> bool {
+    raw.strip_prefix("0x")
+        .is_some_and(|hex| hex.len() == 40 && hex.chars().all(|ch| ch.is_ascii_hexdigit()))
+}
+
+/// EVM addresses compare case-insensitively (checksum casing varies).
+fn addresses_match(expected: &str, derived: &str, chain: Chain) -> bool {
+    match chain {
+        Chain::Bsc => expected.eq_ignore_ascii_case(derived),
+        Chain::Solana => expected == derived,
+    }
+}
+
+fn check_budget(raw: &str) -> Result<Decimal, String> {
+    let budget =
+        Decimal::from_str(raw.trim()).map_err(|err| format!("{raw:?} is not a decimal: {err}"))?;
+    if budget <= Decimal::ZERO {
+        return Err(format!("{raw} must be > 0"));
+    }
+    Ok(budget)
+}
+
+async fn check_symbols(config: &StrategyConfig) -> BTreeMap<String, Result<(), String>> {
+    let symbols: BTreeSet<String> = config
+        .tasks
+        .iter()
+        .flat_map(|task| task.symbol_list())
+        .map(str::to_string)
+        .collect();
+    let client = config
+        .environment
+        .clone()
+        .unwrap_or_default()
+        .base_urls()
+        .map_err(|err| err.to_string())
+        .and_then(|(auth, trading)| {
+            StandxClient::with_config_and_base_urls(
+                ClientConfig::default(),
+                auth.as_str(),
+                trading.as_str(),
+            )
+            .map_err(|err| format!("create StandxClient failed: {err}"))
+        });
+
+    let mut results = BTreeMap::new();
+    for symbol in symbols {
+        let result = match &client {
+            Ok(client) => match client.query_symbol_info(&symbol).await {
+                Ok(infos) if infos.iter().any(|info| info.symbol == symbol) => Ok(()),
+                Ok(_) => Err("not listed".to_string()),
+                Err(err) if err.is_not_found() => Err("not listed".to_string()),
+                Err(err) => Err(format!("query_symbol_info failed: {err}")),
+            },
+            Err(err) => Err(err.clone()),
+        };
+        results.insert(symbol, result);
+    }
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(id: &str, private_key: &str) -> AccountConfig {
+        AccountConfig {
+            id: id.to_string(),
+            private_key: Some(private_key.to_string()),
+            jwt_token: None,
+            signing_key: None,
+            chain: Chain::Bsc,
+            keystore_path: None,
+            mnemonic: None,
+            mnemonic_index: None,
+            wallet_address: None,
+            max_concurrent_order_actions: None,
+            rate_limit: None,
+        }
+    }
+
+    #[test]
+    fn account_key_must_derive_its_address_id() {
+        // Well-known Hardhat account #0.
+        let key = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        let address = "0xf39fd6e51aad88f6f4ce6ab8827279cfffb92266";
+        assert_eq!(check_account(&account(address, key)), Ok(()));
+        assert_eq!(check_account(&account("main", key)), Ok(()));
+
+        let other = "0x70997970c51812dc3a010c7d01b50e0d17dc79c8";
+        let err = check_account(&account(other, key)).unwrap_err();
+        assert!(err.contains("expected"), "{err}");
+        assert!(check_account(&account("main", "not-a-key")).is_err());
+    }
+
+    #[test]
+    fn budget_must_be_positive_decimal() {
+        assert_eq!(check_budget(" 1000.5 "), Ok(Decimal::new(10005, 1)));
+        assert!(check_budget("0").is_err());
+        assert!(check_budget("10k").is_err());
+    }
+}
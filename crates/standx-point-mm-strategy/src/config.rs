@@ -1,13 +1,59 @@
 /*
-[INPUT]:  YAML configuration file
+[INPUT]:  YAML, TOML, or JSON configuration file
 [OUTPUT]: Parsed strategy configuration
 [POS]:    Configuration layer - task setup
 [UPDATE]: When adding new configuration options
 [UPDATE]: 2026-02-08 Accept wallet private key auth configuration
+[UPDATE]: 2026-10-16 Add guard_flatten_after_failures escalation threshold
+[UPDATE]: 2026-10-16 Add per-account max_concurrent_order_actions
+[UPDATE]: 2026-10-16 Add keystore_path as a private_key alternative
+[UPDATE]: 2026-10-16 Add mnemonic + mnemonic_index account credentials
+[UPDATE]: 2026-10-16 Add risk.replace_order quote replacement ordering
+[UPDATE]: 2026-10-16 Add risk.min_equity_usd capital-preservation stop
+[UPDATE]: 2026-10-16 Add canonical task ordering by task id
+[UPDATE]: 2026-10-16 Add max_total_budget_usd cap across task budgets
+[UPDATE]: 2026-10-16 Reject duplicate (account_id, symbol) tasks unless allow_duplicate_symbols
+[UPDATE]: 2026-10-16 Detect TOML/JSON/YAML config format from the file extension
+[UPDATE]: 2026-10-16 Add multi-symbol tasks that fan out with a split budget
+[UPDATE]: 2026-10-16 Add per-task quoting bps overrides
+[UPDATE]: 2026-10-16 Add quoting.inventory_skew_bps
+[UPDATE]: 2026-10-16 Add risk.daily_loss_limit_usd
+[UPDATE]: 2026-10-16 Add quoting.funding_skew_bps
+[UPDATE]: 2026-10-17 Add max_concurrent_startups task startup limit
+[UPDATE]: 2026-10-17 Add per-account REST rate_limit
+[UPDATE]: 2026-10-17 Accept risk.replace_order: amend
+[UPDATE]: 2026-10-17 Add per-task leverage and margin_mode applied at startup
+[UPDATE]: 2026-10-17 Add environment selecting mainnet, testnet, or custom endpoints
+[UPDATE]: 2026-10-17 Add quoting.size_jitter_bps order-size randomization
+[UPDATE]: 2026-10-17 Add quoting.min_replace_dwell_ms replacement throttle
+[UPDATE]: 2026-10-17 Add risk.taker_sweep reduce-only IOC inventory reduction
+[UPDATE]: 2026-10-17 Add quoting.reference_price (ticker or book_mid)
+[UPDATE]: 2026-10-17 Add quoting.fee_floor_multiple spread floor
+[UPDATE]: 2026-10-17 Add risk.min_uptime_ratio / risk.uptime_window_secs
+[UPDATE]: 2026-10-17 Add accounts[].wallet_address expected signer identity
+[UPDATE]: 2026-10-17 Add risk.max_price_age_secs stale price guard
+[UPDATE]: 2026-10-17 Accept any number of weighted quoting.tiers
+[UPDATE]: 2026-10-17 Add quoting.tier_weights size weights for L1..L5
+[UPDATE]: 2026-10-17 Drop quoting.tier_weights in favour of quoting.tiers[].weight
+[UPDATE]: 2026-10-17 Build every quoting.tiers override as a ladder; unweighted tiers take preset weights
 */
 
+use anyhow::anyhow;
+use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
-use standx_point_adapter::Chain;
+use standx_point_adapter::{Chain, MarginMode, RateLimitConfig, StandxEnvironment};
+
+use crate::strategy::{MinUptime, QuoteReference, QuotingBands, TakerSweep, TierBand};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Default rolling window for `risk.min_uptime_ratio`.
+const DEFAULT_UPTIME_WINDOW_SECS: u64 = 3600;
+/// Default for `risk.max_price_age_secs`.
+const DEFAULT_MAX_PRICE_AGE_SECS: u64 = 15;
 
 /// Top-level configuration for the market making bot
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -17,6 +63,18 @@ pub struct StrategyConfig {
     pub accounts: Vec<AccountConfig>,
     /// List of trading tasks to run
     pub tasks: Vec<TaskConfig>,
+    /// Upper bound in USD for the sum of all task budgets (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_total_budget_usd: Option<String>,
+    /// Allow several tasks to quote the same symbol on one account (default: false)
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub allow_duplicate_symbols: bool,
+    /// Max tasks running their startup sequence at once (default: 4, 0 disables)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_startups: Option<usize>,
+    /// StandX deployment for REST and WebSocket endpoints (default: mainnet)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<StandxEnvironment>,
 }
 
 /// Account credentials configuration
@@ -27,15 +85,35 @@ pub struct AccountConfig {
     /// Wallet private key used for authentication
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub private_key: Option<String>,
+    /// Encrypted V3 keystore used instead of private_key (bsc only);
+    /// the passphrase is read from STANDX_MM_KEYSTORE_PASSPHRASE
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keystore_path: Option<PathBuf>,
+    /// BIP-39 mnemonic used instead of private_key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mnemonic: Option<String>,
+    /// Account index on the chain's standard derivation path (default: 0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mnemonic_index: Option<u32>,
     /// JWT token for authentication (legacy/manual override)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub jwt_token: Option<String>,
     /// Ed25519 private key for body signing (base64 encoded, legacy/manual override)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub signing_key: Option<String>,
+    /// Expected wallet address; startup fails if the credentials resolve to
+    /// a different one, and it stands in when no address can be derived
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wallet_address: Option<String>,
     /// Chain used for authentication
     #[serde(default = "default_chain")]
     pub chain: Chain,
+    /// Max concurrent order actions across tasks sharing this account (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_order_actions: Option<usize>,
+    /// REST request budget shared by all tasks on this account (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimitConfig>,
 }
 
 /// Configuration for a single trading task
@@ -43,13 +121,311 @@ pub struct AccountConfig {
 pub struct TaskConfig {
     /// Task identifier
     pub id: String,
-    /// Trading symbol (e.g., "BTC-USD")
+    /// Trading symbol (e.g., "BTC-USD"); deprecated in favor of `symbols`
+    #[serde(default, skip_serializing_if = "String::is_empty")]
     pub symbol: String,
+    /// Symbols quoted under this task, sharing `risk.budget_usd` evenly
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub symbols: Vec<String>,
     /// Account identifier
     pub account_id: String,
     /// Risk parameters
     #[serde(default)]
     pub risk: RiskConfig,
+    /// Quote bps overrides; risk-level defaults apply when absent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quoting: Option<QuotingConfig>,
+    /// Leverage set on each symbol at startup; must not exceed the symbol's
+    /// max_leverage (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub leverage: Option<u32>,
+    /// Margin mode set on each symbol at startup: "cross" or "isolated" (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub margin_mode: Option<MarginMode>,
+}
+
+/// Per-task overrides of the quoting bps ladder
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct QuotingConfig {
+    /// Ladder bands, increasing and non-overlapping: five unweighted bands
+    /// replace L1..L5, or any number of weighted bands replace the ladder
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tiers: Vec<TierBandConfig>,
+    /// Aggressive-mode band quotes are clamped to (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_bps: Option<BpsBand>,
+    /// Band used while backing off after a fill (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub survival_bps: Option<BpsBand>,
+    /// Bps to shift quotes per unit of inventory relative to budget_usd:
+    /// long widens the bid and tightens the ask, short the reverse
+    /// (default: 0, off)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inventory_skew_bps: Option<String>,
+    /// Bps to shift quotes at full-strength funding, growing toward each
+    /// settlement: positive funding widens the bid and tightens the ask,
+    /// negative the reverse (default: 0, off)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub funding_skew_bps: Option<String>,
+    /// Bps bound for randomly perturbing each quote's size, below 10000
+    /// (default: 0, off)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_jitter_bps: Option<String>,
+    /// Milliseconds a quote rests before a price move may replace it;
+    /// replacements also need at least one price tick of movement
+    /// (default: 0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_replace_dwell_ms: Option<u64>,
+    /// Price quotes are built around: `ticker` (mid, then last, then mark)
+    /// or `book_mid` (best bid/ask from a polled order book snapshot,
+    /// falling back to `ticker` while the book is empty or one-sided)
+    /// (default: ticker)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reference_price: Option<String>,
+    /// Taker fees the bid-ask spread must cover: each quote sits at least
+    /// `fee_floor_multiple * taker_fee / 2` from the reference price, raising
+    /// tighter tiers (default: 2; 0 disables)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_floor_multiple: Option<String>,
+}
+
+/// Distance band from the reference price, in bps
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BpsBand {
+    pub min_bps: String,
+    pub max_bps: String,
+}
+
+impl BpsBand {
+    fn parse(&self, field: &str) -> anyhow::Result<(Decimal, Decimal)> {
+        parse_bps_band(field, &self.min_bps, &self.max_bps)
+    }
+}
+
+/// One ladder tier: a bps band plus an optional relative size weight
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TierBandConfig {
+    pub min_bps: String,
+    pub max_bps: String,
+    /// Relative share of the per-side budget; set on every tier or none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<String>,
+}
+
+fn parse_bps_band(field: &str, min_bps: &str, max_bps: &str) -> anyhow::Result<(Decimal, Decimal)> {
+    let parse = |raw: &str| {
+        Decimal::from_str(raw.trim())
+            .ok()
+            .filter(|value| *value > Decimal::ZERO)
+            .ok_or_else(|| anyhow!("quoting.{field} bps must be a positive decimal: {raw}"))
+    };
+    let (min, max) = (parse(min_bps)?, parse(max_bps)?);
+    if min >= max {
+        return Err(anyhow!(
+            "quoting.{field} min_bps {min} must be below max_bps {max}"
+        ));
+    }
+    Ok((min, max))
+}
+
+impl QuotingConfig {
+    /// Parse and validate into strategy bands.
+    pub fn bands(&self) -> anyhow::Result<QuotingBands> {
+        Ok(QuotingBands {
+            ladder: self.tier_ladder()?,
+            target_bps: self
+                .target_bps
+                .as_ref()
+                .map(|band| band.parse("target_bps"))
+                .transpose()?,
+            survival_bps: self
+                .survival_bps
+                .as_ref()
+                .map(|band| band.parse("survival_bps"))
+                .transpose()?,
+            inventory_skew_bps: parse_skew_bps(
+                "inventory_skew_bps",
+                self.inventory_skew_bps.as_deref(),
+            )?,
+            funding_skew_bps: parse_skew_bps("funding_skew_bps", self.funding_skew_bps.as_deref())?,
+            size_jitter_bps: self.size_jitter_bps()?,
+            min_replace_dwell: self.min_replace_dwell_ms.map(Duration::from_millis),
+            reference_price: self
+                .reference_price
+                .as_deref()
+                .map(|raw| {
+                    QuoteReference::from_str(raw).map_err(|()| {
+                        anyhow!("quoting.reference_price must be ticker or book_mid: {raw}")
+                    })
+                })
+                .transpose()?,
+            fee_floor_multiple: parse_skew_bps(
+                "fee_floor_multiple",
+                self.fee_floor_multiple.as_deref(),
+            )?,
+        })
+    }
+
+    /// Ladder from `quoting.tiers`; unweighted tiers take the preset weights
+    /// for their count, or split the budget evenly when no preset matches.
+    fn tier_ladder(&self) -> anyhow::Result<Option<Vec<TierBand>>> {
+        if self.tiers.is_empty() {
+            return Ok(None);
+        }
+        let mut bands: Vec<(Decimal, Decimal)> = Vec::with_capacity(self.tiers.len());
+        for (idx, tier) in self.tiers.iter().enumerate() {
+            let (min, max) =
+                parse_bps_band(&format!("tiers[{idx}]"), &tier.min_bps, &tier.max_bps)?;
+            if let Some(&(_, prev_max)) = bands.last()
+                && min < prev_max
+            {
+                return Err(anyhow!(
+                    "quoting.tiers[{idx}] min_bps {min} overlaps the previous tier (max_bps {prev_max})"
+                ));
+            }
+            bands.push((min, max));
+        }
+
+        let weighted = self
+            .tiers
+            .iter()
+            .filter(|tier| tier.weight.is_some())
+            .count();
+        if weighted != 0 && weighted != self.tiers.len() {
+            return Err(anyhow!(
+                "quoting.tiers must set weight on every tier or on none"
+            ));
+        }
+
+        let presets = TierBand::presets(u8::try_from(bands.len()).unwrap_or(u8::MAX));
+        let mut ladder = Vec::with_capacity(bands.len());
+        for (idx, (tier, (min_bps, max_bps))) in self.tiers.iter().zip(bands).enumerate() {
+            let weight = match tier.weight.as_deref() {
+                Some(raw) => parse_weight(&format!("tiers[{idx}] weight"), raw)?,
+                None if presets.len() == self.tiers.len() => presets[idx].weight,
+                None => Decimal::ONE,
+            };
+            ladder.push(TierBand {
+                min_bps,
+                max_bps,
+                weight,
+            });
+        }
+        Ok(Some(ladder))
+    }
+
+    fn size_jitter_bps(&self) -> anyhow::Result<Option<Decimal>> {
+        let jitter_bps = parse_skew_bps("size_jitter_bps", self.size_jitter_bps.as_deref())?;
+        if let Some(bps) = jitter_bps
+            && bps >= Decimal::from(10_000)
+        {
+            return Err(anyhow!(
+                "quoting.size_jitter_bps must be below 10000, got {bps}"
+            ));
+        }
+        Ok(jitter_bps)
+    }
+}
+
+fn parse_weight(field: &str, raw: &str) -> anyhow::Result<Decimal> {
+    Decimal::from_str(raw.trim())
+        .ok()
+        .filter(|value| *value > Decimal::ZERO)
+        .ok_or_else(|| anyhow!("quoting.{field} must be a positive decimal: {raw}"))
+}
+
+fn parse_skew_bps(field: &str, raw: Option<&str>) -> anyhow::Result<Option<Decimal>> {
+    raw.map(|raw| {
+        Decimal::from_str(raw.trim())
+            .ok()
+            .filter(|value| *value >= Decimal::ZERO)
+            .ok_or_else(|| anyhow!("quoting.{field} must be a non-negative decimal: {raw}"))
+    })
+    .transpose()
+}
+
+impl TaskConfig {
+    /// Symbols this task quotes: `symbols`, or the single `symbol` when unset.
+    pub fn symbol_list(&self) -> Vec<&str> {
+        if self.symbols.is_empty() {
+            std::iter::once(self.symbol.trim())
+                .filter(|symbol| !symbol.is_empty())
+                .collect()
+        } else {
+            self.symbols.iter().map(|symbol| symbol.trim()).collect()
+        }
+    }
+
+    /// Reject tasks with no symbol, blank or repeated entries in `symbols`,
+    /// or both `symbol` and `symbols` set.
+    pub fn check_symbols(&self) -> anyhow::Result<()> {
+        if !self.symbols.is_empty() && !self.symbol.trim().is_empty() {
+            return Err(anyhow!(
+                "task {} sets both symbol and symbols; use symbols only",
+                self.id
+            ));
+        }
+        let symbols = self.symbol_list();
+        if symbols.is_empty() {
+            return Err(anyhow!("task {} must list at least one symbol", self.id));
+        }
+        let mut seen = std::collections::HashSet::new();
+        for symbol in symbols {
+            if symbol.is_empty() {
+                return Err(anyhow!("task {} has an empty symbol", self.id));
+            }
+            if !seen.insert(symbol) {
+                return Err(anyhow!("task {} lists {symbol} more than once", self.id));
+            }
+        }
+        Ok(())
+    }
+
+    /// Split into one single-symbol task per symbol.
+    ///
+    /// A single-symbol task keeps its id and budget. Otherwise each child is
+    /// named `<id>-<symbol>` and gets an equal share of `risk.budget_usd`,
+    /// truncated to cents so the shares never exceed the total.
+    pub fn fan_out(&self) -> anyhow::Result<Vec<TaskConfig>> {
+        self.check_symbols()?;
+        let symbols = self.symbol_list();
+        let single = |id: String, symbol: &str, budget_usd: String| TaskConfig {
+            id,
+            symbol: symbol.to_string(),
+            symbols: Vec::new(),
+            account_id: self.account_id.clone(),
+            risk: RiskConfig {
+                budget_usd,
+                ..self.risk.clone()
+            },
+            quoting: self.quoting.clone(),
+            leverage: self.leverage,
+            margin_mode: self.margin_mode,
+        };
+        if let [symbol] = symbols.as_slice() {
+            return Ok(vec![single(
+                self.id.clone(),
+                symbol,
+                self.risk.budget_usd.clone(),
+            )]);
+        }
+
+        let budget = Decimal::from_str(self.risk.budget_usd.trim()).map_err(|_| {
+            anyhow!(
+                "task {} budget_usd must be a decimal: {}",
+                self.id,
+                self.risk.budget_usd
+            )
+        })?;
+        let share = (budget / Decimal::from(symbols.len()))
+            .round_dp_with_strategy(2, RoundingStrategy::ToZero)
+            .normalize()
+            .to_string();
+        Ok(symbols
+            .into_iter()
+            .map(|symbol| single(format!("{}-{symbol}", self.id), symbol, share.clone()))
+            .collect())
+    }
 }
 
 /// Risk management configuration
@@ -64,6 +440,10 @@ pub struct RiskConfig {
     /// Enable position guard close orders (default: false)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub guard_close_enabled: Option<bool>,
+    /// Consecutive guard placement failures before market-closing the position
+    /// (default: unset, off; 0 also disables)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guard_flatten_after_failures: Option<u32>,
     /// Take-profit distance in bps (optional)
     #[serde(
         default,
@@ -78,6 +458,33 @@ pub struct RiskConfig {
         alias = "stop_loss_bps"
     )]
     pub sl_bps: Option<String>,
+    /// Quote replacement ordering: "cancel_first" (default), "place_first", or "amend"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replace_order: Option<String>,
+    /// Stop the task when account equity drops below this USD value (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_equity_usd: Option<String>,
+    /// Halt quoting until the next UTC day once realized losses since
+    /// midnight reach this USD value (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub daily_loss_limit_usd: Option<String>,
+    /// Actively trim inventory with reduce-only IOC orders (optional)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub taker_sweep: Option<TakerSweepConfig>,
+    /// Warn when the rolling uptime ratio drops below this value, in (0, 1]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_uptime_ratio: Option<String>,
+    /// Rolling window for `min_uptime_ratio` in seconds (default: 3600)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uptime_window_secs: Option<u64>,
+    /// Pull quotes once the latest price, by arrival or exchange timestamp,
+    /// is this many seconds old (default: 15, 0 disables)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        alias = "max_price_staleness_secs"
+    )]
+    pub max_price_age_secs: Option<u64>,
 }
 
 impl Default for RiskConfig {
@@ -86,9 +493,101 @@ impl Default for RiskConfig {
             level: default_risk_level(),
             budget_usd: default_budget_usd(),
             guard_close_enabled: None,
+            guard_flatten_after_failures: None,
             tp_bps: None,
             sl_bps: None,
+            replace_order: None,
+            min_equity_usd: None,
+            daily_loss_limit_usd: None,
+            taker_sweep: None,
+            min_uptime_ratio: None,
+            uptime_window_secs: None,
+            max_price_age_secs: None,
+        }
+    }
+}
+
+impl RiskConfig {
+    /// Parse `min_uptime_ratio` and its window, if a floor is set.
+    pub fn min_uptime(&self) -> anyhow::Result<Option<MinUptime>> {
+        let Some(raw) = self
+            .min_uptime_ratio
+            .as_deref()
+            .filter(|raw| !raw.trim().is_empty())
+        else {
+            return Ok(None);
+        };
+        let ratio = Decimal::from_str(raw.trim())
+            .ok()
+            .filter(|ratio| *ratio > Decimal::ZERO && *ratio <= Decimal::ONE)
+            .ok_or_else(|| anyhow!("risk.min_uptime_ratio must be in (0, 1]: {raw}"))?;
+        let window_secs = self
+            .uptime_window_secs
+            .unwrap_or(DEFAULT_UPTIME_WINDOW_SECS);
+        if window_secs == 0 {
+            return Err(anyhow!("risk.uptime_window_secs must be at least 1"));
+        }
+        Ok(Some(MinUptime {
+            ratio,
+            window: Duration::from_secs(window_secs),
+        }))
+    }
+
+    /// Price feed silence after which quotes are pulled, or `None` if disabled.
+    pub fn max_price_age(&self) -> Option<Duration> {
+        match self
+            .max_price_age_secs
+            .unwrap_or(DEFAULT_MAX_PRICE_AGE_SECS)
+        {
+            0 => None,
+            secs => Some(Duration::from_secs(secs)),
+        }
+    }
+}
+
+/// Reduce-only IOC sweep for inventory left above a threshold too long
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TakerSweepConfig {
+    /// Inventory notional in USD above which the sweep arms
+    pub threshold_usd: String,
+    /// Seconds inventory must stay above the threshold (default: 60)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after_secs: Option<u64>,
+    /// Farthest the IOC limit price may sit from mark, in bps (default: 10)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_slippage_bps: Option<String>,
+    /// Most notional in USD swept per interval
+    pub max_usd_per_interval: String,
+    /// Interval length in seconds (default: 60)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interval_secs: Option<u64>,
+}
+
+impl TakerSweepConfig {
+    /// Parse and validate into strategy settings.
+    pub fn sweep(&self) -> anyhow::Result<TakerSweep> {
+        let positive = |field: &str, raw: &str| {
+            Decimal::from_str(raw.trim())
+                .ok()
+                .filter(|value| *value > Decimal::ZERO)
+                .ok_or_else(|| {
+                    anyhow!("risk.taker_sweep.{field} must be a positive decimal: {raw}")
+                })
+        };
+        let interval_secs = self.interval_secs.unwrap_or(60);
+        if interval_secs == 0 {
+            return Err(anyhow!("risk.taker_sweep.interval_secs must be at least 1"));
         }
+        Ok(TakerSweep {
+            threshold_usd: positive("threshold_usd", &self.threshold_usd)?,
+            after: Duration::from_secs(self.after_secs.unwrap_or(60)),
+            max_slippage_bps: positive(
+                "max_slippage_bps",
+                self.max_slippage_bps.as_deref().unwrap_or("10"),
+            )?,
+            max_usd_per_interval: positive("max_usd_per_interval", &self.max_usd_per_interval)?,
+            interval: Duration::from_secs(interval_secs),
+        })
     }
 }
 
@@ -104,11 +603,518 @@ fn default_chain() -> Chain {
     Chain::Bsc
 }
 
+/// On-disk configuration format, chosen by file extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Yaml,
+    Toml,
+    Json,
+}
+
+impl ConfigFormat {
+    /// `.toml` and `.json` select those formats; anything else is YAML.
+    pub fn from_path(path: impl AsRef<Path>) -> Self {
+        let extension = path
+            .as_ref()
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("toml") => Self::Toml,
+            Some("json") => Self::Json,
+            _ => Self::Yaml,
+        }
+    }
+}
+
 impl StrategyConfig {
-    /// Load configuration from YAML file
+    /// Load configuration from a YAML, TOML, or JSON file
     pub fn from_file(path: &str) -> anyhow::Result<Self> {
         let content = std::fs::read_to_string(path)?;
-        let config: Self = serde_yaml::from_str(&content)?;
+        Self::from_str_as(&content, ConfigFormat::from_path(path))
+    }
+
+    /// Parse configuration text in the given format
+    pub fn from_str_as(content: &str, format: ConfigFormat) -> anyhow::Result<Self> {
+        let config = match format {
+            ConfigFormat::Yaml => serde_yaml::from_str(content)?,
+            ConfigFormat::Toml => toml::from_str(content)?,
+            ConfigFormat::Json => serde_json::from_str(content)?,
+        };
         Ok(config)
     }
+
+    /// Serialize configuration in the given format
+    pub fn to_string_as(&self, format: ConfigFormat) -> anyhow::Result<String> {
+        let content = match format {
+            ConfigFormat::Yaml => serde_yaml::to_string(self)?,
+            ConfigFormat::Toml => toml::to_string_pretty(self)?,
+            ConfigFormat::Json => serde_json::to_string_pretty(self)?,
+        };
+        Ok(content)
+    }
+
+    /// Reject configs whose task budgets add up to more than `max_total_budget_usd`.
+    pub fn check_total_budget(&self) -> anyhow::Result<()> {
+        let Some(raw) = self.max_total_budget_usd.as_deref() else {
+            return Ok(());
+        };
+        let cap = Decimal::from_str(raw.trim())
+            .map_err(|_| anyhow!("max_total_budget_usd must be a decimal: {raw}"))?;
+
+        let mut total = Decimal::ZERO;
+        for task in &self.tasks {
+            total += Decimal::from_str(task.risk.budget_usd.trim()).map_err(|_| {
+                anyhow!(
+                    "task {} risk.budget_usd must be a decimal: {}",
+                    task.id,
+                    task.risk.budget_usd
+                )
+            })?;
+        }
+        if total > cap {
+            return Err(anyhow!(
+                "total task budget {total} USD exceeds max_total_budget_usd {cap} USD"
+            ));
+        }
+        Ok(())
+    }
+
+    /// Reject two tasks quoting the same symbol on the same account.
+    ///
+    /// Such tasks cancel each other's quotes and share one position; layered
+    /// setups must opt in with `allow_duplicate_symbols`.
+    pub fn check_duplicate_symbols(&self) -> anyhow::Result<()> {
+        if self.allow_duplicate_symbols {
+            return Ok(());
+        }
+        let mut seen: HashMap<(&str, &str), &str> = HashMap::new();
+        for task in &self.tasks {
+            for symbol in task.symbol_list() {
+                let key = (task.account_id.as_str(), symbol);
+                if let Some(first) = seen.insert(key, task.id.as_str()) {
+                    return Err(anyhow!(
+                        "tasks {first} and {} both trade {symbol} on account {}; \
+                         set allow_duplicate_symbols: true to permit this",
+                        task.id,
+                        task.account_id
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Expand multi-symbol tasks (see [`TaskConfig::fan_out`]) in canonical order.
+    pub fn fan_out_tasks(&self) -> anyhow::Result<Vec<TaskConfig>> {
+        let mut tasks = Vec::with_capacity(self.tasks.len());
+        for task in &self.tasks {
+            tasks.extend(task.fan_out()?);
+        }
+        tasks.sort_by(|a, b| task_order(&a.id, &b.id));
+        Ok(tasks)
+    }
+
+    /// Sort tasks into the canonical order (see [`task_order`]).
+    pub fn sort_tasks(&mut self) {
+        self.tasks.sort_by(|a, b| task_order(&a.id, &b.id));
+    }
+}
+
+/// Canonical ordering of tasks: ascending by task id.
+///
+/// Spawn order, startup logs, and the TUI task list all use this so the same
+/// configuration always lists and starts tasks in the same sequence.
+pub fn task_order(a: &str, b: &str) -> Ordering {
+    a.cmp(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn task(id: &str) -> TaskConfig {
+        TaskConfig {
+            id: id.to_string(),
+            symbol: "BTC-USD".to_string(),
+            symbols: Vec::new(),
+            account_id: "a1".to_string(),
+            risk: RiskConfig::default(),
+            quoting: None,
+            leverage: None,
+            margin_mode: None,
+        }
+    }
+
+    fn budget_task(id: &str, budget_usd: &str) -> TaskConfig {
+        TaskConfig {
+            risk: RiskConfig {
+                budget_usd: budget_usd.to_string(),
+                ..Default::default()
+            },
+            ..task(id)
+        }
+    }
+
+    #[test]
+    fn sort_tasks_orders_by_task_id() {
+        let mut config = StrategyConfig {
+            accounts: Vec::new(),
+            tasks: vec![task("task-eth"), task("task-btc"), task("task-sol")],
+            max_total_budget_usd: None,
+            allow_duplicate_symbols: false,
+            max_concurrent_startups: None,
+            environment: None,
+        };
+
+        config.sort_tasks();
+
+        let ids: Vec<&str> = config.tasks.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, ["task-btc", "task-eth", "task-sol"]);
+    }
+
+    #[test]
+    fn check_total_budget_rejects_over_allocation() {
+        let mut config = StrategyConfig {
+            accounts: Vec::new(),
+            tasks: vec![
+                budget_task("task-btc", "60000"),
+                budget_task("task-eth", "50000.5"),
+            ],
+            max_total_budget_usd: Some("110000".to_string()),
+            allow_duplicate_symbols: false,
+            max_concurrent_startups: None,
+            environment: None,
+        };
+
+        let err = config.check_total_budget().unwrap_err();
+        assert!(
+            err.to_string().contains("110000.5"),
+            "unexpected error: {err}"
+        );
+
+        config.max_total_budget_usd = Some("110000.5".to_string());
+        config.check_total_budget().unwrap();
+
+        config.max_total_budget_usd = None;
+        config.tasks.push(budget_task("task-sol", "1000000"));
+        config.check_total_budget().unwrap();
+    }
+
+    #[test]
+    fn check_duplicate_symbols_requires_opt_in() {
+        let mut config = StrategyConfig {
+            accounts: Vec::new(),
+            tasks: vec![task("task-btc-a"), task("task-btc-b")],
+            max_total_budget_usd: None,
+            allow_duplicate_symbols: false,
+            max_concurrent_startups: None,
+            environment: None,
+        };
+
+        let err = config.check_duplicate_symbols().unwrap_err();
+        assert!(err.to_string().contains("task-btc-a and task-btc-b"));
+
+        config.allow_duplicate_symbols = true;
+        config.check_duplicate_symbols().unwrap();
+
+        config.allow_duplicate_symbols = false;
+        config.tasks[1].account_id = "a2".to_string();
+        config.check_duplicate_symbols().unwrap();
+    }
+
+    #[test]
+    fn config_round_trips_through_every_format() {
+        let config = StrategyConfig {
+            accounts: vec![AccountConfig {
+                id: "a1".to_string(),
+                private_key: Some("0xabc".to_string()),
+                keystore_path: None,
+                mnemonic: None,
+                mnemonic_index: Some(2),
+                wallet_address: None,
+                jwt_token: None,
+                signing_key: None,
+                chain: Chain::Solana,
+                max_concurrent_order_actions: Some(4),
+                rate_limit: Some(RateLimitConfig {
+                    requests_per_second: 10,
+                    burst: 20,
+                }),
+            }],
+            tasks: vec![TaskConfig {
+                risk: RiskConfig {
+                    guard_close_enabled: Some(true),
+                    tp_bps: Some("30".to_string()),
+                    ..Default::default()
+                },
+                ..task("task-btc")
+            }],
+            max_total_budget_usd: Some("100000".to_string()),
+            allow_duplicate_symbols: true,
+            max_concurrent_startups: Some(2),
+            environment: Some(StandxEnvironment::Testnet),
+        };
+        let expected = serde_json::to_value(&config).unwrap();
+
+        for format in [ConfigFormat::Yaml, ConfigFormat::Toml, ConfigFormat::Json] {
+            let text = config.to_string_as(format).unwrap();
+            let parsed = StrategyConfig::from_str_as(&text, format).unwrap();
+            assert_eq!(
+                serde_json::to_value(&parsed).unwrap(),
+                expected,
+                "{format:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn config_format_follows_extension() {
+        assert_eq!(ConfigFormat::from_path("mm.toml"), ConfigFormat::Toml);
+        assert_eq!(ConfigFormat::from_path("mm.JSON"), ConfigFormat::Json);
+        assert_eq!(ConfigFormat::from_path("mm.yml"), ConfigFormat::Yaml);
+        assert_eq!(ConfigFormat::from_path("mm.yaml"), ConfigFormat::Yaml);
+        assert_eq!(ConfigFormat::from_path("mm"), ConfigFormat::Yaml);
+    }
+
+    #[test]
+    fn multi_symbol_task_fans_out_with_split_budget() {
+        let config = StrategyConfig {
+            accounts: Vec::new(),
+            tasks: vec![
+                TaskConfig {
+                    symbol: String::new(),
+                    symbols: vec!["EUR-USD".into(), "GBP-USD".into(), "AUD-USD".into()],
+                    ..budget_task("fx", "1000")
+                },
+                task("task-btc"),
+            ],
+            max_total_budget_usd: None,
+            allow_duplicate_symbols: false,
+            max_concurrent_startups: None,
+            environment: None,
+        };
+
+        let tasks = config.fan_out_tasks().unwrap();
+        let summary: Vec<(&str, &str, &str)> = tasks
+            .iter()
+            .map(|t| (t.id.as_str(), t.symbol.as_str(), t.risk.budget_usd.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("fx-AUD-USD", "AUD-USD", "333.33"),
+                ("fx-EUR-USD", "EUR-USD", "333.33"),
+                ("fx-GBP-USD", "GBP-USD", "333.33"),
+                ("task-btc", "BTC-USD", "50000"),
+            ]
+        );
+        assert!(tasks.iter().all(|t| t.symbols.is_empty()));
+    }
+
+    #[test]
+    fn check_symbols_rejects_empty_and_repeated_lists() {
+        let mut multi = TaskConfig {
+            symbol: String::new(),
+            symbols: vec!["EUR-USD".into(), "EUR-USD".into()],
+            ..task("fx")
+        };
+        let err = multi.check_symbols().unwrap_err();
+        assert!(err.to_string().contains("EUR-USD more than once"), "{err}");
+
+        multi.symbols.clear();
+        let err = multi.check_symbols().unwrap_err();
+        assert!(err.to_string().contains("at least one symbol"), "{err}");
+
+        multi.symbols.push("EUR-USD".into());
+        multi.check_symbols().unwrap();
+        multi.symbol = "BTC-USD".into();
+        assert!(multi.check_symbols().is_err());
+    }
+
+    #[test]
+    fn taker_sweep_defaults_and_rejects_bad_values() {
+        let mut config = TakerSweepConfig {
+            threshold_usd: "500".to_string(),
+            after_secs: None,
+            max_slippage_bps: None,
+            max_usd_per_interval: "100".to_string(),
+            interval_secs: Some(30),
+        };
+        let sweep = config.sweep().unwrap();
+        assert_eq!(sweep.threshold_usd, Decimal::from(500));
+        assert_eq!(sweep.after, Duration::from_secs(60));
+        assert_eq!(sweep.max_slippage_bps, Decimal::from(10));
+        assert_eq!(sweep.interval, Duration::from_secs(30));
+
+        config.interval_secs = Some(0);
+        assert!(config.sweep().is_err());
+
+        config.interval_secs = None;
+        config.max_usd_per_interval = "0".to_string();
+        let err = config.sweep().unwrap_err();
+        assert!(err.to_string().contains("max_usd_per_interval"), "{err}");
+    }
+
+    #[test]
+    fn max_price_age_defaults_on_and_zero_disables() {
+        let mut risk = RiskConfig::default();
+        assert_eq!(risk.max_price_age(), Some(Duration::from_secs(15)));
+        risk.max_price_age_secs = Some(5);
+        assert_eq!(risk.max_price_age(), Some(Duration::from_secs(5)));
+        risk.max_price_age_secs = Some(0);
+        assert_eq!(risk.max_price_age(), None);
+    }
+
+    #[test]
+    fn min_uptime_defaults_window_and_rejects_out_of_range_ratio() {
+        let mut risk = RiskConfig::default();
+        assert_eq!(risk.min_uptime().unwrap(), None);
+
+        risk.min_uptime_ratio = Some("0.9".to_string());
+        let min_uptime = risk.min_uptime().unwrap().unwrap();
+        assert_eq!(min_uptime.ratio, Decimal::new(9, 1));
+        assert_eq!(min_uptime.window, Duration::from_secs(3600));
+
+        risk.uptime_window_secs = Some(0);
+        assert!(risk.min_uptime().is_err());
+
+        risk.uptime_window_secs = Some(600);
+        risk.min_uptime_ratio = Some("1.5".to_string());
+        let err = risk.min_uptime().unwrap_err();
+        assert!(err.to_string().contains("min_uptime_ratio"), "{err}");
+    }
+
+    #[test]
+    fn quoting_bands_require_increasing_positive_tiers() {
+        let band = |min: &str, max: &str| BpsBand {
+            min_bps: min.to_string(),
+            max_bps: max.to_string(),
+        };
+        let tier = |min: &str, max: &str| TierBandConfig {
+            min_bps: min.to_string(),
+            max_bps: max.to_string(),
+            weight: None,
+        };
+        let mut quoting = QuotingConfig {
+            tiers: vec![
+                tier("3", "6"),
+                tier("6", "9"),
+                tier("9", "12"),
+                tier("12", "18"),
+                tier("18", "25"),
+            ],
+            target_bps: Some(band("3", "25")),
+            survival_bps: None,
+            inventory_skew_bps: Some("4".to_string()),
+            funding_skew_bps: Some("2.5".to_string()),
+            size_jitter_bps: Some("50".to_string()),
+            min_replace_dwell_ms: Some(1500),
+            reference_price: Some("Book_Mid".to_string()),
+            fee_floor_multiple: Some("3".to_string()),
+        };
+        let bands = quoting.bands().unwrap();
+        let ladder = bands.ladder.as_deref().unwrap();
+        assert_eq!(ladder.len(), 5);
+        assert_eq!(
+            ladder[4],
+            TierBand {
+                min_bps: Decimal::from(18),
+                max_bps: Decimal::from(25),
+                weight: TierBand::presets(5)[4].weight,
+            }
+        );
+        assert_eq!(
+            bands.target_bps,
+            Some((Decimal::from(3), Decimal::from(25)))
+        );
+        assert_eq!(bands.survival_bps, None);
+        assert_eq!(bands.inventory_skew_bps, Some(Decimal::from(4)));
+        assert_eq!(bands.funding_skew_bps, Some(Decimal::new(25, 1)));
+        assert_eq!(bands.size_jitter_bps, Some(Decimal::from(50)));
+        assert_eq!(bands.min_replace_dwell, Some(Duration::from_millis(1500)));
+        assert_eq!(bands.reference_price, Some(QuoteReference::BookMid));
+        assert_eq!(bands.fee_floor_multiple, Some(Decimal::from(3)));
+
+        quoting.tiers[2] = tier("8", "12");
+        let err = quoting.bands().unwrap_err();
+        assert!(err.to_string().contains("tiers[2]"), "{err}");
+
+        quoting.tiers[2] = tier("9", "12");
+        quoting.survival_bps = Some(band("0", "5"));
+        assert!(quoting.bands().is_err());
+
+        quoting.survival_bps = Some(band("5", "5"));
+        assert!(quoting.bands().is_err());
+
+        quoting.survival_bps = None;
+        quoting.inventory_skew_bps = Some("-1".to_string());
+        assert!(quoting.bands().is_err());
+
+        quoting.inventory_skew_bps = None;
+        quoting.funding_skew_bps = Some("-0.5".to_string());
+        let err = quoting.bands().unwrap_err();
+        assert!(err.to_string().contains("funding_skew_bps"), "{err}");
+
+        quoting.funding_skew_bps = None;
+        quoting.size_jitter_bps = Some("10000".to_string());
+        let err = quoting.bands().unwrap_err();
+        assert!(err.to_string().contains("size_jitter_bps"), "{err}");
+
+        quoting.size_jitter_bps = None;
+        quoting.reference_price = Some("mark".to_string());
+        let err = quoting.bands().unwrap_err();
+        assert!(err.to_string().contains("reference_price"), "{err}");
+
+        quoting.reference_price = None;
+        quoting.fee_floor_multiple = Some("-1".to_string());
+        let err = quoting.bands().unwrap_err();
+        assert!(err.to_string().contains("fee_floor_multiple"), "{err}");
+
+        quoting.fee_floor_multiple = None;
+        quoting.tiers.pop();
+        let ladder = quoting.bands().unwrap().ladder.unwrap();
+        assert_eq!(ladder.len(), 4);
+        assert!(ladder.iter().all(|tier| tier.weight == Decimal::ONE));
+
+        quoting.tiers.truncate(3);
+        let ladder = quoting.bands().unwrap().ladder.unwrap();
+        let weights: Vec<Decimal> = ladder.iter().map(|tier| tier.weight).collect();
+        let presets: Vec<Decimal> = TierBand::presets(3)
+            .iter()
+            .map(|tier| tier.weight)
+            .collect();
+        assert_eq!(weights, presets);
+    }
+
+    #[test]
+    fn weighted_quoting_tiers_build_a_ladder_of_any_depth() {
+        let tier = |min: u32, weight: &str| TierBandConfig {
+            min_bps: min.to_string(),
+            max_bps: (min + 4).to_string(),
+            weight: Some(weight.to_string()),
+        };
+        let mut quoting = QuotingConfig {
+            tiers: (0..8).map(|idx| tier(5 + idx * 4, "1.5")).collect(),
+            ..QuotingConfig::default()
+        };
+        let ladder = quoting.bands().unwrap().ladder.unwrap();
+        assert_eq!(ladder.len(), 8);
+        assert_eq!(
+            ladder[7],
+            TierBand {
+                min_bps: Decimal::from(33),
+                max_bps: Decimal::from(37),
+                weight: Decimal::new(15, 1),
+            }
+        );
+
+        quoting.tiers[3].weight = Some("0".to_string());
+        let err = quoting.bands().unwrap_err();
+        assert!(err.to_string().contains("tiers[3] weight"), "{err}");
+
+        quoting.tiers[3].weight = None;
+        let err = quoting.bands().unwrap_err();
+        assert!(err.to_string().contains("every tier"), "{err}");
+    }
 }
@@ -0,0 +1,394 @@
+/*
+[INPUT]:  Runtime events emitted by tasks, the strategy, and the position guard
+[OUTPUT]: Broadcast fan-out of RuntimeEvent to any number of observers
+[POS]:    Runtime layer - observability event bus
+[UPDATE]: When adding event kinds or changing subscriber semantics
+[UPDATE]: 2026-10-16 Add RuntimeEvent bus with lag-tolerant subscriptions
+[UPDATE]: 2026-10-16 Add JSONL event recorder for forensic replay
+[UPDATE]: 2026-10-16 Carry an estimated fee on Fill events
+[UPDATE]: 2026-10-17 Tag PriceStale with its task, add PriceRecovered, and log alerts from the bus
+[UPDATE]: 2026-10-17 Fill carries the trade's executed price and charged fee
+*/
+
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tokio::fs::{self, OpenOptions};
+use tokio::io::{AsyncWriteExt, BufWriter};
+use tokio::sync::broadcast;
+use tokio::sync::broadcast::error::{RecvError, TryRecvError};
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+/// Default number of events buffered per subscriber before it starts lagging.
+pub const DEFAULT_EVENT_CAPACITY: usize = 1024;
+
+/// What the position guard did to protect an open position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GuardActionKind {
+    Place,
+    Cancel,
+    Flatten,
+}
+
+/// Observable runtime occurrence, tagged with the task it belongs to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum RuntimeEvent {
+    /// Quote accepted by the exchange.
+    OrderPlaced {
+        task_id: String,
+        symbol: String,
+        cl_ord_id: String,
+        side: String,
+        price: Decimal,
+        qty: Decimal,
+    },
+    /// Cancel request accepted by the exchange.
+    OrderCancelled {
+        task_id: String,
+        symbol: String,
+        cl_ord_id: String,
+    },
+    /// One executed trade of a task's order, from the trades endpoint.
+    Fill {
+        task_id: String,
+        symbol: String,
+        order_id: i64,
+        side: String,
+        /// Executed price, also for market orders.
+        price: Decimal,
+        fill_qty: Decimal,
+        /// Fee the exchange charged for the trade.
+        #[serde(default)]
+        fee: Decimal,
+    },
+    /// Task lifecycle transition (`Starting`, `Running`, `Stopping`, ...).
+    StateChanged {
+        task_id: String,
+        state: String,
+    },
+    GuardAction {
+        task_id: String,
+        symbol: String,
+        action: GuardActionKind,
+        qty: Decimal,
+    },
+    /// No fresh price for `symbol` within `age_ms`; quotes are pulled.
+    PriceStale {
+        task_id: String,
+        symbol: String,
+        age_ms: u64,
+    },
+    /// Fresh prices are back after a [`PriceStale`](Self::PriceStale).
+    PriceRecovered {
+        task_id: String,
+        symbol: String,
+    },
+    TaskFailed {
+        task_id: String,
+        error: String,
+    },
+}
+
+/// Cloneable handle producers emit on; emitting never blocks.
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    tx: broadcast::Sender<RuntimeEvent>,
+}
+
+impl EventBus {
+    pub fn new(capacity: usize) -> Self {
+        let (tx, _) = broadcast::channel(capacity.max(1));
+        Self { tx }
+    }
+
+    /// Publish to all current subscribers; dropped when nobody listens.
+    pub fn emit(&self, event: RuntimeEvent) {
+        let _ = self.tx.send(event);
+    }
+
+    pub fn subscribe(&self) -> EventSubscription {
+        EventSubscription {
+            rx: self.tx.subscribe(),
+            dropped: 0,
+        }
+    }
+}
+
+impl Default for EventBus {
+    fn default() -> Self {
+        Self::new(DEFAULT_EVENT_CAPACITY)
+    }
+}
+
+/// Receiving side of the bus that skips past events lost to lag.
+#[derive(Debug)]
+pub struct EventSubscription {
+    rx: broadcast::Receiver<RuntimeEvent>,
+    dropped: u64,
+}
+
+impl EventSubscription {
+    /// Next event, or `None` once every bus handle is gone.
+    ///
+    /// A subscriber that falls more than the bus capacity behind loses the
+    /// oldest events; the loss is counted in [`dropped`](Self::dropped) and
+    /// delivery resumes with the oldest retained event.
+    pub async fn recv(&mut self) -> Option<RuntimeEvent> {
+        loop {
+            match self.rx.recv().await {
+                Ok(event) => return Some(event),
+                Err(RecvError::Lagged(skipped)) => {
+                    self.dropped += skipped;
+                    tracing::warn!(skipped, "runtime event subscriber lagged");
+                }
+                Err(RecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// Next already-buffered event without waiting; lag is counted as in
+    /// [`recv`](Self::recv).
+    pub fn try_recv(&mut self) -> Option<RuntimeEvent> {
+        loop {
+            match self.rx.try_recv() {
+                Ok(event) => return Some(event),
+                Err(TryRecvError::Lagged(skipped)) => {
+                    self.dropped += skipped;
+                    tracing::warn!(skipped, "runtime event subscriber lagged");
+                }
+                Err(TryRecvError::Empty | TryRecvError::Closed) => return None,
+            }
+        }
+    }
+
+    /// Events this subscriber has missed because it lagged.
+    pub fn dropped(&self) -> u64 {
+        self.dropped
+    }
+}
+
+/// Log the events an operator should be alerted to: stale and recovered
+/// price feeds, guard flattens, and task failures.
+///
+/// Runs until every bus handle is gone or `shutdown` fires.
+pub fn spawn_event_alerts(
+    mut subscription: EventSubscription,
+    shutdown: CancellationToken,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            let event = tokio::select! {
+                event = subscription.recv() => event,
+                _ = shutdown.cancelled() => None,
+            };
+            let Some(event) = event else { break };
+            alert(&event);
+        }
+    })
+}
+
+fn alert(event: &RuntimeEvent) {
+    match event {
+        RuntimeEvent::PriceStale {
+            task_id,
+            symbol,
+            age_ms,
+        } => tracing::warn!(task_id, symbol, age_ms, "alert: price feed stale"),
+        RuntimeEvent::PriceRecovered { task_id, symbol } => {
+            tracing::info!(task_id, symbol, "alert cleared: price feed recovered")
+        }
+        RuntimeEvent::GuardAction {
+            task_id,
+            symbol,
+            action: GuardActionKind::Flatten,
+            qty,
+        } => tracing::warn!(task_id, symbol, %qty, "alert: position guard flattened"),
+        RuntimeEvent::TaskFailed { task_id, error } => {
+            tracing::error!(task_id, error, "alert: task failed")
+        }
+        _ => {}
+    }
+}
+
+/// One line of the event log.
+///
+/// The event's own fields are flattened next to `ts`, so a line reads
+/// `{"ts":"2026-10-16T08:00:00.123Z","event":"order_placed","task_id":...}`.
+/// Decimals are written as strings to keep full precision.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordedEvent {
+    /// Time the recorder received the event (UTC, RFC 3339).
+    pub ts: DateTime<Utc>,
+    #[serde(flatten)]
+    pub event: RuntimeEvent,
+}
+
+/// Append every event from `subscription` to the JSONL file at `path`.
+///
+/// Writes happen on a dedicated task through a buffered writer that is
+/// flushed whenever the subscription runs dry, so emitters never wait on
+/// disk. After `shutdown` fires, already-buffered events are drained and
+/// flushed before the task returns.
+pub fn spawn_event_recorder(
+    mut subscription: EventSubscription,
+    path: PathBuf,
+    shutdown: CancellationToken,
+) -> JoinHandle<Result<()>> {
+    tokio::spawn(async move {
+        if let Some(parent) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("create event log directory {}", parent.display()))?;
+        }
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .await
+            .with_context(|| format!("open event log {}", path.display()))?;
+        let mut writer = BufWriter::new(file);
+
+        loop {
+            let event = tokio::select! {
+                event = subscription.recv() => event,
+                _ = shutdown.cancelled() => None,
+            };
+            let Some(event) = event else { break };
+            write_event(&mut writer, event).await?;
+            while let Some(event) = subscription.try_recv() {
+                write_event(&mut writer, event).await?;
+            }
+            writer.flush().await.context("flush event log")?;
+        }
+
+        while let Some(event) = subscription.try_recv() {
+            write_event(&mut writer, event).await?;
+        }
+        writer.flush().await.context("flush event log")?;
+        if subscription.dropped() > 0 {
+            tracing::warn!(
+                dropped = subscription.dropped(),
+                path = %path.display(),
+                "event log is missing events lost to subscriber lag"
+            );
+        }
+        Ok(())
+    })
+}
+
+async fn write_event<W: AsyncWriteExt + Unpin>(writer: &mut W, event: RuntimeEvent) -> Result<()> {
+    let record = RecordedEvent {
+        ts: Utc::now(),
+        event,
+    };
+    let mut line = serde_json::to_vec(&record).context("serialize runtime event")?;
+    line.push(b'\n');
+    writer.write_all(&line).await.context("write event log")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(task_id: &str, state: &str) -> RuntimeEvent {
+        RuntimeEvent::StateChanged {
+            task_id: task_id.to_string(),
+            state: state.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn events_reach_every_subscriber() {
+        let bus = EventBus::default();
+        let mut first = bus.subscribe();
+        let mut second = bus.subscribe();
+
+        bus.emit(state("t1", "Running"));
+        bus.emit(RuntimeEvent::TaskFailed {
+            task_id: "t1".to_string(),
+            error: "boom".to_string(),
+        });
+
+        for sub in [&mut first, &mut second] {
+            assert_eq!(sub.recv().await, Some(state("t1", "Running")));
+            assert!(matches!(
+                sub.recv().await,
+                Some(RuntimeEvent::TaskFailed { ref error, .. }) if error == "boom"
+            ));
+        }
+
+        drop(bus);
+        assert_eq!(first.recv().await, None);
+    }
+
+    #[tokio::test]
+    async fn lagging_subscriber_skips_to_retained_events() {
+        let bus = EventBus::new(2);
+        let mut slow = bus.subscribe();
+        let mut fast = bus.subscribe();
+
+        for idx in 0..5 {
+            bus.emit(state("t1", &idx.to_string()));
+            assert_eq!(fast.recv().await, Some(state("t1", &idx.to_string())));
+        }
+
+        assert_eq!(slow.recv().await, Some(state("t1", "3")));
+        assert_eq!(slow.dropped(), 3);
+        assert_eq!(slow.recv().await, Some(state("t1", "4")));
+        assert_eq!(fast.dropped(), 0);
+    }
+
+    #[tokio::test]
+    async fn recorder_writes_events_in_order() {
+        let path = std::env::temp_dir()
+            .join(format!("standx-events-{}", uuid::Uuid::new_v4()))
+            .join("events.jsonl");
+        let bus = EventBus::default();
+        let shutdown = CancellationToken::new();
+        let recorder = spawn_event_recorder(bus.subscribe(), path.clone(), shutdown.clone());
+
+        let events = vec![
+            state("t1", "Starting"),
+            RuntimeEvent::OrderPlaced {
+                task_id: "t1".to_string(),
+                symbol: "BTC-USD".to_string(),
+                cl_ord_id: "c1".to_string(),
+                side: "buy".to_string(),
+                price: Decimal::new(650001, 1),
+                qty: Decimal::new(1, 3),
+            },
+            RuntimeEvent::GuardAction {
+                task_id: "t1".to_string(),
+                symbol: "BTC-USD".to_string(),
+                action: GuardActionKind::Flatten,
+                qty: Decimal::new(1, 3),
+            },
+            state("t1", "Stopped"),
+        ];
+        for event in &events {
+            bus.emit(event.clone());
+        }
+        shutdown.cancel();
+        recorder.await.unwrap().unwrap();
+
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert!(lines[1].contains(r#""event":"order_placed""#));
+        assert!(lines[1].contains(r#""price":"65000.1""#));
+        let recorded: Vec<RecordedEvent> = lines
+            .iter()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert!(recorded.windows(2).all(|pair| pair[0].ts <= pair[1].ts));
+        let replayed: Vec<RuntimeEvent> = recorded.into_iter().map(|r| r.event).collect();
+        assert_eq!(replayed, events);
+
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
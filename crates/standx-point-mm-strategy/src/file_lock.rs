@@ -0,0 +1,122 @@
+/*
+[INPUT]:  Path of a data file shared between processes (TUI, CLI runner)
+[OUTPUT]: Advisory exclusive lock held on a sibling `.lock` file until dropped
+[POS]:    Runtime layer - cross-process guard for read-modify-write of JSON state
+[UPDATE]: 2026-10-17 Add advisory file locking with contention timeout
+*/
+
+use std::fs::{File, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use anyhow::{Context, Result, anyhow};
+use fs2::FileExt;
+use tokio::time::Instant;
+
+/// How long writers wait for another process before giving up.
+pub const DEFAULT_LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+const LOCK_RETRY_INTERVAL: Duration = Duration::from_millis(50);
+
+/// Exclusive advisory lock guarding one data file.
+///
+/// The lock lives on `<file>.lock` rather than the data file itself, since
+/// atomic writes replace the data file's inode on every save. Uses `flock`
+/// on Unix and `LockFileEx` on Windows; released on drop.
+#[derive(Debug)]
+pub struct FileLock {
+    file: File,
+    path: PathBuf,
+}
+
+impl FileLock {
+    /// Lock `target`, polling until `timeout` elapses.
+    ///
+    /// Fails with an error naming the lock file when another process keeps
+    /// holding it, so callers can report contention instead of clobbering.
+    pub async fn acquire(target: &Path, timeout: Duration) -> Result<Self> {
+        let path = lock_path(target);
+        if let Some(parent) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .with_context(|| format!("create directory {}", parent.display()))?;
+        }
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&path)
+            .with_context(|| format!("open lock file {}", path.display()))?;
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            match file.try_lock_exclusive() {
+                Ok(()) => return Ok(Self { file, path }),
+                Err(err) if is_contended(&err) => {
+                    if Instant::now() >= deadline {
+                        return Err(anyhow!(
+                            "timed out after {timeout:?} waiting for {}; another standx-mm \
+                             process is writing the same data directory",
+                            path.display()
+                        ));
+                    }
+                    tokio::time::sleep(LOCK_RETRY_INTERVAL).await;
+                }
+                Err(err) => {
+                    return Err(err).with_context(|| format!("lock {}", path.display()));
+                }
+            }
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        if let Err(err) = FileExt::unlock(&self.file) {
+            tracing::warn!(path = %self.path.display(), "release file lock failed: {err}");
+        }
+    }
+}
+
+fn lock_path(target: &Path) -> PathBuf {
+    let mut name = target.file_name().unwrap_or_default().to_os_string();
+    name.push(".lock");
+    target.with_file_name(name)
+}
+
+fn is_contended(err: &io::Error) -> bool {
+    err.kind() == io::ErrorKind::WouldBlock
+        || err.raw_os_error() == fs2::lock_contended_error().raw_os_error()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn second_holder_times_out_until_first_releases() {
+        let dir = std::env::temp_dir().join(format!("standx-lock-{}", uuid::Uuid::new_v4()));
+        let target = dir.join("symbols.json");
+
+        let first = FileLock::acquire(&target, DEFAULT_LOCK_TIMEOUT)
+            .await
+            .expect("first lock");
+        assert_eq!(first.path(), dir.join("symbols.json.lock"));
+
+        let err = FileLock::acquire(&target, Duration::from_millis(120))
+            .await
+            .expect_err("contended lock should time out");
+        assert!(format!("{err:#}").contains("symbols.json.lock"), "{err:#}");
+
+        drop(first);
+        FileLock::acquire(&target, Duration::from_millis(120))
+            .await
+            .expect("lock after release");
+        let _ = std::fs::remove_dir_all(dir);
+    }
+}
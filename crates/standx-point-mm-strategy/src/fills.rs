@@ -0,0 +1,317 @@
+/*
+[INPUT]:  RuntimeEvent::Fill stream, TaskConfig + TaskMetricsSnapshot maps
+[OUTPUT]: Per-task fill history CSVs and atomic metrics snapshot CSV exports
+[POS]:    Runtime layer - durable fill/PnL history for offline analysis
+[UPDATE]: When changing CSV columns or realized PnL attribution
+[UPDATE]: 2026-10-16 Add per-task fill CSV writer and metrics snapshot export
+*/
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, SecondsFormat, Utc};
+use rust_decimal::Decimal;
+use tokio::fs::{self, OpenOptions};
+use tokio::io::AsyncWriteExt;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+use crate::config::TaskConfig;
+use crate::events::{EventSubscription, RuntimeEvent};
+use crate::metrics::TaskMetricsSnapshot;
+use crate::risk::PositionLedger;
+
+pub const FILL_CSV_HEADER: &str = "timestamp,side,price,qty,fee,realized_pnl";
+pub const METRICS_CSV_HEADER: &str =
+    "task_id,symbol,open_orders,position_qty,uptime_ratio,fills,realized_pnl_usd,halt_reason";
+
+/// One row of a task's fill history.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FillRecord {
+    pub timestamp: DateTime<Utc>,
+    pub side: String,
+    pub price: Decimal,
+    pub qty: Decimal,
+    pub fee: Decimal,
+    /// Gross PnL this fill realized against the task's average entry price.
+    pub realized_pnl: Decimal,
+}
+
+impl FillRecord {
+    pub fn to_csv_row(&self) -> String {
+        format!(
+            "{},{},{},{},{},{}",
+            self.timestamp.to_rfc3339_opts(SecondsFormat::Millis, true),
+            csv_field(&self.side),
+            self.price.normalize(),
+            self.qty.normalize(),
+            self.fee.normalize(),
+            self.realized_pnl.normalize(),
+        )
+    }
+}
+
+/// File a task's fills are appended to.
+pub fn fill_csv_path(dir: &Path, task_id: &str) -> PathBuf {
+    let name: String = task_id
+        .chars()
+        .map(|ch| {
+            if ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.') {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    dir.join(format!("fills-{name}.csv"))
+}
+
+/// Append every `Fill` event to `<dir>/fills-<task_id>.csv`.
+///
+/// Realized PnL is attributed per task with an average-cost ledger that
+/// starts flat when the writer starts, so fills that reduce a position
+/// opened before then realize against their own price. Each row is written
+/// with a single append so a crash never leaves a partial line. After
+/// `shutdown` fires, already-buffered fills are written before returning.
+pub fn spawn_fill_history_writer(
+    mut subscription: EventSubscription,
+    dir: PathBuf,
+    shutdown: CancellationToken,
+) -> JoinHandle<Result<()>> {
+    tokio::spawn(async move {
+        fs::create_dir_all(&dir)
+            .await
+            .with_context(|| format!("create fill history directory {}", dir.display()))?;
+        let mut ledgers: HashMap<String, PositionLedger> = HashMap::new();
+
+        loop {
+            let event = tokio::select! {
+                event = subscription.recv() => event,
+                _ = shutdown.cancelled() => None,
+            };
+            let Some(event) = event else { break };
+            record_fill(&dir, &mut ledgers, event).await?;
+        }
+        while let Some(event) = subscription.try_recv() {
+            record_fill(&dir, &mut ledgers, event).await?;
+        }
+        Ok(())
+    })
+}
+
+async fn record_fill(
+    dir: &Path,
+    ledgers: &mut HashMap<String, PositionLedger>,
+    event: RuntimeEvent,
+) -> Result<()> {
+    let RuntimeEvent::Fill {
+        task_id,
+        side,
+        price,
+        fill_qty,
+        fee,
+        ..
+    } = event
+    else {
+        return Ok(());
+    };
+
+    let signed_qty = if side.eq_ignore_ascii_case("sell") {
+        -fill_qty
+    } else {
+        fill_qty
+    };
+    let realized_pnl = ledgers
+        .entry(task_id.clone())
+        .or_default()
+        .apply(signed_qty, price);
+    let record = FillRecord {
+        timestamp: Utc::now(),
+        side,
+        price,
+        qty: fill_qty,
+        fee,
+        realized_pnl,
+    };
+
+    let path = fill_csv_path(dir, &task_id);
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .await
+        .with_context(|| format!("open fill history {}", path.display()))?;
+    let mut chunk = String::new();
+    if file.metadata().await.map(|meta| meta.len()).unwrap_or(0) == 0 {
+        chunk.push_str(FILL_CSV_HEADER);
+        chunk.push('\n');
+    }
+    chunk.push_str(&record.to_csv_row());
+    chunk.push('\n');
+    file.write_all(chunk.as_bytes())
+        .await
+        .with_context(|| format!("write fill history {}", path.display()))?;
+    // tokio completes file writes in the background; wait for this one.
+    file.flush()
+        .await
+        .with_context(|| format!("flush fill history {}", path.display()))
+}
+
+/// Render one CSV row per task, in task-id order.
+pub fn render_metrics_csv(
+    configs: &HashMap<String, TaskConfig>,
+    metrics: &HashMap<String, TaskMetricsSnapshot>,
+) -> String {
+    let mut task_ids: Vec<&String> = metrics.keys().collect();
+    task_ids.sort_unstable();
+
+    let mut out = String::from(METRICS_CSV_HEADER);
+    out.push('\n');
+    for task_id in task_ids {
+        let snapshot = &metrics[task_id];
+        let symbol = configs
+            .get(task_id)
+            .map_or("", |config| config.symbol.as_str());
+        out.push_str(&format!(
+            "{},{},{},{},{},{},{},{}\n",
+            csv_field(task_id),
+            csv_field(symbol),
+            snapshot.open_orders,
+            snapshot.position_qty.normalize(),
+            snapshot
+                .uptime_ratio
+                .map(|ratio| ratio.normalize().to_string())
+                .unwrap_or_default(),
+            snapshot.fills,
+            snapshot.realized_pnl_usd.normalize(),
+            csv_field(snapshot.halt_reason.as_deref().unwrap_or_default()),
+        ));
+    }
+    out
+}
+
+/// Replace `path` with `contents` via a temp file and rename, so readers
+/// never see a partially written file.
+pub async fn write_atomic(path: &Path, contents: &str) -> Result<()> {
+    if let Some(parent) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .await
+            .with_context(|| format!("create directory {}", parent.display()))?;
+    }
+    let temp_path = path.with_extension("tmp");
+    fs::write(&temp_path, contents)
+        .await
+        .with_context(|| format!("write {}", temp_path.display()))?;
+    fs::rename(&temp_path, path)
+        .await
+        .with_context(|| format!("rename {} -> {}", temp_path.display(), path.display()))
+}
+
+/// Quote a CSV field when it contains a delimiter, quote, or line break.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventBus;
+
+    fn fill(task_id: &str, side: &str, price: &str, qty: &str) -> RuntimeEvent {
+        RuntimeEvent::Fill {
+            task_id: task_id.to_string(),
+            symbol: "BTC-USD".to_string(),
+            order_id: 1,
+            side: side.to_string(),
+            price: price.parse().unwrap(),
+            fill_qty: qty.parse().unwrap(),
+            fee: "0.01".parse().unwrap(),
+        }
+    }
+
+    #[tokio::test]
+    async fn fill_writer_appends_rows_with_realized_pnl_per_task() {
+        let dir = std::env::temp_dir().join(format!("standx-fills-{}", uuid::Uuid::new_v4()));
+        let bus = EventBus::default();
+        let shutdown = CancellationToken::new();
+        let writer = spawn_fill_history_writer(bus.subscribe(), dir.clone(), shutdown.clone());
+
+        bus.emit(fill("t1", "buy", "100", "2"));
+        bus.emit(fill("t2", "sell", "50", "1"));
+        bus.emit(fill("t1", "sell", "110", "1.5"));
+        bus.emit(RuntimeEvent::StateChanged {
+            task_id: "t1".to_string(),
+            state: "Running".to_string(),
+        });
+        shutdown.cancel();
+        writer.await.unwrap().unwrap();
+
+        let t1 = std::fs::read_to_string(fill_csv_path(&dir, "t1")).unwrap();
+        let rows: Vec<Vec<&str>> = t1.lines().map(|line| line.split(',').collect()).collect();
+        assert_eq!(rows.len(), 3);
+        assert_eq!(rows[0].join(","), FILL_CSV_HEADER);
+        assert_eq!(rows[1][1..], ["buy", "100", "2", "0.01", "0"]);
+        assert_eq!(rows[2][1..], ["sell", "110", "1.5", "0.01", "15"]);
+
+        let t2 = std::fs::read_to_string(fill_csv_path(&dir, "t2")).unwrap();
+        assert_eq!(t2.lines().count(), 2);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn metrics_csv_quotes_free_text() {
+        let configs = HashMap::from([(
+            "t1".to_string(),
+            TaskConfig {
+                id: "t1".to_string(),
+                symbol: "BTC-USD".to_string(),
+                symbols: Vec::new(),
+                quoting: None,
+                leverage: None,
+                margin_mode: None,
+                account_id: "a1".to_string(),
+                risk: crate::config::RiskConfig::default(),
+            },
+        )]);
+        let metrics = HashMap::from([(
+            "t1".to_string(),
+            TaskMetricsSnapshot {
+                open_orders: 2,
+                position_qty: "0.50".parse().unwrap(),
+                last_heartbeat: None,
+                last_price: None,
+                last_update: None,
+                halt_reason: Some("daily loss 12, limit \"10\"".to_string()),
+                fills: 7,
+                uptime_ratio: None,
+                uptime_ratio_1h: None,
+                realized_pnl_usd: "-12".parse().unwrap(),
+                session_realized_pnl_usd: "-12".parse().unwrap(),
+                funding_rate: None,
+                leverage: None,
+                margin_mode: None,
+                last_order_latency: None,
+                request_latency: Default::default(),
+                deployed_usd: None,
+                utilization_ratio: None,
+                uptime_below_min: false,
+                price_stale: false,
+            },
+        )]);
+
+        let csv = render_metrics_csv(&configs, &metrics);
+
+        assert_eq!(
+            csv,
+            format!(
+                "{METRICS_CSV_HEADER}\nt1,BTC-USD,2,0.5,,7,-12,\"daily loss 12, limit \"\"10\"\"\"\n"
+            )
+        );
+    }
+}
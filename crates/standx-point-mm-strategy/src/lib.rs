@@ -3,17 +3,31 @@
 [OUTPUT]: Module declarations and public re-exports
 [POS]:    Crate root - library entry point
 [UPDATE]: When adding new modules or public exports
+[UPDATE]: 2026-10-16 Add events module (RuntimeEvent bus)
+[UPDATE]: 2026-10-16 Add fills module (fill history CSV, metrics CSV export)
+[UPDATE]: 2026-10-17 Re-export MarketMakingStrategy and OrderExecutor for custom backends
+[UPDATE]: 2026-10-17 Add backtest module (historical price replay)
+[UPDATE]: 2026-10-17 Add file_lock module (cross-process advisory locks)
 */
 
+pub mod backtest;
 pub mod config;
+pub mod events;
+pub mod file_lock;
+pub mod fills;
 pub mod market_data;
 pub mod metrics;
 pub mod order_state;
 pub mod risk;
+pub mod server;
+pub mod startup;
 pub mod strategy;
 pub mod task;
 
 // Re-export main types for convenience
+pub use backtest::{BacktestReport, Backtester};
 pub use config::StrategyConfig;
+pub use events::{EventBus, RuntimeEvent};
 pub use market_data::MarketDataHub;
+pub use strategy::{MarketMakingStrategy, OrderExecutor};
 pub use task::TaskManager;
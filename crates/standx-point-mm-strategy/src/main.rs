@@ -1,23 +1,52 @@
 /*
-[INPUT]:  CLI arguments, YAML configuration file, OS shutdown signals
+[INPUT]:  CLI arguments, YAML/TOML/JSON configuration file, OS shutdown and kill-switch signals
 [OUTPUT]: Running market making tasks with graceful shutdown
 [POS]:    Binary entry point
 [UPDATE]: When changing CLI flags, startup flow, or shutdown handling
 [UPDATE]: 2026-02-05 Configure tracing to log to daily files only
 [UPDATE]: 2026-02-08 Remove TUI runtime and keep CLI-only entry
 [UPDATE]: 2026-02-08 Add environment-variable startup path
+[UPDATE]: 2026-10-16 Add --symbol-cache-dir / STANDX_MM_SYMBOL_CACHE_DIR
+[UPDATE]: 2026-10-16 Accept keystore_path as an account credential
+[UPDATE]: 2026-10-16 Accept mnemonic accounts and STANDX_MM_MNEMONIC
+[UPDATE]: 2026-10-16 Log a consolidated startup banner
+[UPDATE]: 2026-10-16 Validate risk.replace_order
+[UPDATE]: 2026-10-16 Sort tasks into canonical order before logging and spawn
+[UPDATE]: 2026-10-16 Enforce max_total_budget_usd during config validation
+[UPDATE]: 2026-10-16 Reject duplicate (account_id, symbol) tasks during validation
+[UPDATE]: 2026-10-16 Add --event-log JSONL runtime event recorder
+[UPDATE]: 2026-10-16 Validate multi-symbol task lists
+[UPDATE]: 2026-10-16 Validate per-task quoting bps overrides
+[UPDATE]: 2026-10-16 Flatten all tasks and exit on SIGUSR1 kill switch
+[UPDATE]: 2026-10-16 Add --metrics-addr / STANDX_MM_METRICS_ADDR Prometheus endpoint
+[UPDATE]: 2026-10-16 Append per-task fill history CSVs under logs/fills
+[UPDATE]: 2026-10-16 Add --max-task-restarts to auto-restart failed tasks
+[UPDATE]: 2026-10-17 Report env-overridable REST base URLs in the startup banner
+[UPDATE]: 2026-10-17 Validate and report the configured StandxEnvironment endpoints
+[UPDATE]: 2026-10-17 Validate risk.taker_sweep settings
+[UPDATE]: 2026-10-17 Add export/import subcommands for stored accounts and tasks
+[UPDATE]: 2026-10-17 Add validate subcommand for CI config checks
+[UPDATE]: 2026-10-17 Add status subcommand for live account health checks
+[UPDATE]: 2026-10-17 Add --log-format json for structured file and stdout logs
+[UPDATE]: 2026-10-17 Add --health-addr / STANDX_MM_HEALTH_ADDR liveness/readiness probes
+[UPDATE]: 2026-10-17 Drain tasks on shutdown signal, logging tasks remaining
+[UPDATE]: 2026-10-17 Validate risk.min_uptime_ratio
+[UPDATE]: 2026-10-17 Log per-task startup outcomes from SpawnReport
+[UPDATE]: 2026-10-17 Validate accounts[].wallet_address and record the env-derived address
+[UPDATE]: 2026-10-17 Log runtime alerts from the event bus in both run modes
 */
 
 use anyhow::{Context, Result, anyhow};
 use clap::Parser;
 use std::env;
 use std::fs;
+use std::net::SocketAddr;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use tokio_util::sync::CancellationToken;
-use tracing::{info, warn};
+use tracing::{error, info, warn};
 use tracing_appender::rolling;
 use tracing_subscriber::EnvFilter;
 use tracing_subscriber::prelude::*;
@@ -27,10 +56,22 @@ mod state;
 mod tui;
 
 use standx_point_adapter::Chain;
+use standx_point_adapter::auth::mnemonic::{evm_derivation_path, solana_derivation_path};
 use standx_point_adapter::auth::{EvmWalletSigner, SolanaWalletSigner};
 use standx_point_adapter::http::StandxClient;
+use standx_point_mm_strategy::events::{spawn_event_alerts, spawn_event_recorder};
+use standx_point_mm_strategy::fills::spawn_fill_history_writer;
+use standx_point_mm_strategy::server::{bind_listener, spawn_health_server, spawn_metrics_server};
+use standx_point_mm_strategy::startup::StartupBanner;
+use standx_point_mm_strategy::strategy::ReplaceOrder;
+use standx_point_mm_strategy::task::{
+    DEFAULT_DRAIN_TIMEOUT, RestartPolicy, spawn_restart_supervisor,
+};
 use standx_point_mm_strategy::{MarketDataHub, StrategyConfig, TaskManager};
 
+/// Subdirectory of the log directory holding per-task fill CSVs.
+const FILL_HISTORY_DIR: &str = "fills";
+
 #[derive(Parser, Debug)]
 #[command(
     name = "standx-point-mm-strategy",
@@ -46,10 +87,56 @@ struct Cli {
     env: bool,
     #[arg(short, long, value_name = "LEVEL", default_value = "info")]
     log_level: String,
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = LogFormat::Pretty,
+        help = "Log line format for file and stdout output"
+    )]
+    log_format: LogFormat,
     #[arg(long)]
     dry_run: bool,
     #[arg(long, help = "Start TUI mode")]
     tui: bool,
+    #[arg(
+        long,
+        value_name = "DIR",
+        help = "Symbol-info cache directory (env: STANDX_MM_SYMBOL_CACHE_DIR)"
+    )]
+    symbol_cache_dir: Option<PathBuf>,
+    #[arg(
+        long,
+        value_name = "PATH",
+        help = "Append every runtime event to a JSONL file"
+    )]
+    event_log: Option<PathBuf>,
+    #[arg(
+        long,
+        value_name = "ADDR",
+        help = "Serve Prometheus metrics on ADDR/metrics (env: STANDX_MM_METRICS_ADDR)"
+    )]
+    metrics_addr: Option<SocketAddr>,
+    #[arg(
+        long,
+        value_name = "ADDR",
+        help = "Serve /healthz and /readyz probes on ADDR (env: STANDX_MM_HEALTH_ADDR)"
+    )]
+    health_addr: Option<SocketAddr>,
+    #[arg(
+        long,
+        value_name = "N",
+        help = "Restart failed tasks with exponential backoff, giving up after N attempts"
+    )]
+    max_task_restarts: Option<u32>,
+}
+
+/// Tracing output format.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum LogFormat {
+    /// Human-readable lines
+    Pretty,
+    /// One JSON object per line, event fields flattened to the top level
+    Json,
 }
 
 #[derive(clap::Subcommand, Debug)]
@@ -59,28 +146,129 @@ enum Commands {
         output: PathBuf,
     },
     Migrate,
+    /// Check a config (keys, symbols, budgets) without starting tasks
+    Validate {
+        config: PathBuf,
+    },
+    /// Print balance, positions, and open orders for stored accounts
+    Status {
+        #[arg(long)]
+        account: Option<String>,
+        #[arg(long, help = "Emit machine-readable JSON")]
+        json: bool,
+    },
+    /// Write stored accounts and tasks to a JSON bundle
+    Export {
+        #[arg(short, long)]
+        output: PathBuf,
+        #[arg(long, help = "Blank private keys, JWTs and signing keys")]
+        redact: bool,
+    },
+    /// Add or overwrite stored accounts and tasks from a JSON bundle
+    Import {
+        #[arg(short, long)]
+        input: PathBuf,
+        #[arg(long, value_enum, default_value = "skip")]
+        merge: state::storage::MergeStrategy,
+    },
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let args = Cli::parse();
     if let Some(Commands::Init { output }) = args.command {
-        init_tracing(&args.log_level, true)?;
+        init_tracing(&args.log_level, args.log_format, true)?;
         return cli::init::run_init(output);
     }
 
     if let Some(Commands::Migrate) = args.command {
-        init_tracing(&args.log_level, true)?;
+        init_tracing(&args.log_level, args.log_format, true)?;
         return run_migrations().await;
     }
 
+    if let Some(Commands::Validate { config }) = &args.command {
+        init_tracing(&args.log_level, args.log_format, true)?;
+        return cli::validate::run_validate(config).await;
+    }
+
+    if let Some(Commands::Status { account, json }) = &args.command {
+        // Keep stdout clean for --json consumers; logs still go to file.
+        init_tracing(&args.log_level, args.log_format, !json)?;
+        return cli::status::run_status(account.as_deref(), *json).await;
+    }
+
+    if let Some(Commands::Export { output, redact }) = &args.command {
+        init_tracing(&args.log_level, args.log_format, true)?;
+        let storage = state::storage::Storage::new().await?;
+        let bundle = storage.export(output, *redact).await?;
+        info!(
+            path = %output.display(),
+            accounts = bundle.accounts.len(),
+            tasks = bundle.tasks.len(),
+            redacted = redact,
+            "storage exported"
+        );
+        return Ok(());
+    }
+
+    if let Some(Commands::Import { input, merge }) = &args.command {
+        init_tracing(&args.log_level, args.log_format, true)?;
+        let storage = state::storage::Storage::new().await?;
+        let summary = storage.import(input, *merge).await?;
+        info!(
+            path = %input.display(),
+            accounts_added = summary.accounts_added,
+            accounts_updated = summary.accounts_updated,
+            tasks_added = summary.tasks_added,
+            tasks_updated = summary.tasks_updated,
+            skipped = summary.skipped,
+            "storage imported"
+        );
+        return Ok(());
+    }
+
+    let symbol_cache_dir = args
+        .symbol_cache_dir
+        .or_else(|| env::var_os("STANDX_MM_SYMBOL_CACHE_DIR").map(PathBuf::from));
+
+    let metrics_addr = addr_arg_or_env(args.metrics_addr, "STANDX_MM_METRICS_ADDR")?;
+    let health_addr = addr_arg_or_env(args.health_addr, "STANDX_MM_HEALTH_ADDR")?;
+
     if args.tui {
-        init_tracing(&args.log_level, false)?;
-        run_tui_mode().await
+        init_tracing(&args.log_level, args.log_format, false)?;
+        run_tui_mode(symbol_cache_dir, metrics_addr, health_addr).await
     } else {
-        init_tracing(&args.log_level, true)?;
-        run_cli_mode(args.config, args.env, args.dry_run).await
+        init_tracing(&args.log_level, args.log_format, true)?;
+        run_cli_mode(
+            args.config,
+            args.env,
+            args.dry_run,
+            &args.log_level,
+            symbol_cache_dir,
+            args.event_log,
+            metrics_addr,
+            health_addr,
+            args.max_task_restarts,
+        )
+        .await
+    }
+}
+
+/// Prefer the CLI flag; otherwise parse a non-empty `var` as a socket address.
+fn addr_arg_or_env(arg: Option<SocketAddr>, var: &str) -> Result<Option<SocketAddr>> {
+    if arg.is_some() {
+        return Ok(arg);
     }
+    env::var(var)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+        .map(|value| {
+            value
+                .trim()
+                .parse::<SocketAddr>()
+                .with_context(|| format!("invalid {var}: {value}"))
+        })
+        .transpose()
 }
 
 async fn run_migrations() -> Result<()> {
@@ -98,7 +286,18 @@ async fn run_migrations() -> Result<()> {
     Ok(())
 }
 
-async fn run_cli_mode(config_path: Option<PathBuf>, env_mode: bool, dry_run: bool) -> Result<()> {
+#[allow(clippy::too_many_arguments)]
+async fn run_cli_mode(
+    config_path: Option<PathBuf>,
+    env_mode: bool,
+    dry_run: bool,
+    log_level: &str,
+    symbol_cache_dir: Option<PathBuf>,
+    event_log: Option<PathBuf>,
+    metrics_addr: Option<SocketAddr>,
+    health_addr: Option<SocketAddr>,
+    max_task_restarts: Option<u32>,
+) -> Result<()> {
     if let Some(path) = &config_path {
         info!(
             config_path = %path.display(),
@@ -109,7 +308,12 @@ async fn run_cli_mode(config_path: Option<PathBuf>, env_mode: bool, dry_run: boo
         info!(dry_run = dry_run, "starting standx-mm-strategy (CLI mode)");
     }
 
-    let config = match config_path {
+    let mode = match (&config_path, env_mode) {
+        (Some(_), _) => "config",
+        (None, true) => "env",
+        (None, false) => "interactive",
+    };
+    let mut config = match config_path {
         Some(path) => {
             let config = load_config(&path)?;
             info!(task_count = config.tasks.len(), "configuration loaded");
@@ -137,6 +341,20 @@ async fn run_cli_mode(config_path: Option<PathBuf>, env_mode: bool, dry_run: boo
     };
 
     validate_strategy_config(&config)?;
+    config.sort_tasks();
+    let (auth_base_url, trading_base_url) =
+        config.environment.clone().unwrap_or_default().base_urls()?;
+    let mut banner = StartupBanner::new(
+        mode,
+        &config,
+        log_level,
+        dry_run,
+        auth_base_url.as_str(),
+        trading_base_url.as_str(),
+    );
+    banner.metrics_addr = metrics_addr;
+    banner.health_addr = health_addr;
+    info!("startup: {banner}");
     log_strategy_config(&config);
 
     if dry_run {
@@ -146,25 +364,135 @@ async fn run_cli_mode(config_path: Option<PathBuf>, env_mode: bool, dry_run: boo
 
     let market_data_hub = Arc::new(Mutex::new(MarketDataHub::new()));
     let mut task_manager = TaskManager::with_market_data_hub(market_data_hub.clone());
+    if let Some(dir) = symbol_cache_dir {
+        task_manager.set_symbol_cache_dir(dir);
+    }
+    let max_task_restarts = max_task_restarts.filter(|attempts| *attempts > 0);
+    if let Some(max_attempts) = max_task_restarts {
+        info!(max_attempts, "auto-restart enabled for failed tasks");
+        task_manager = task_manager.with_auto_restart(RestartPolicy {
+            max_attempts,
+            ..RestartPolicy::default()
+        });
+    }
 
     let shutdown = task_manager.shutdown_token();
     setup_signal_handlers(shutdown.clone());
+    let kill_switch = CancellationToken::new();
+    setup_kill_switch_handler(kill_switch.clone());
+
+    let metrics_listener = match metrics_addr {
+        Some(addr) => Some(bind_listener("metrics", addr).await?),
+        None => None,
+    };
+    let health_listener = match health_addr {
+        Some(addr) => Some(bind_listener("health", addr).await?),
+        None => None,
+    };
+
+    let recorder_shutdown = CancellationToken::new();
+    let fill_writer = spawn_fill_history_writer(
+        task_manager.subscribe_events(),
+        log_dir()?.join(FILL_HISTORY_DIR),
+        recorder_shutdown.clone(),
+    );
+    let alerts = spawn_event_alerts(task_manager.subscribe_events(), recorder_shutdown.clone());
+    let recorder = event_log.map(|path| {
+        info!(path = %path.display(), "recording runtime events");
+        spawn_event_recorder(
+            task_manager.subscribe_events(),
+            path,
+            recorder_shutdown.clone(),
+        )
+    });
 
     info!("spawning tasks");
-    task_manager
+    let report = task_manager
         .spawn_from_config(config)
         .await
         .context("spawn tasks from config")?;
-    info!("tasks started");
-
-    shutdown.cancelled().await;
-    info!("shutdown signal received");
+    for (task_id, error) in &report.failed {
+        warn!(task_id = %task_id, error = %error, "task failed to start");
+    }
+    info!(
+        started = report.started.len(),
+        failed = report.failed.len(),
+        "tasks started"
+    );
 
-    task_manager
-        .shutdown_and_wait()
-        .await
-        .context("shutdown tasks")?;
+    let task_manager = Arc::new(Mutex::new(task_manager));
+    let metrics_shutdown = CancellationToken::new();
+    let metrics_server = metrics_listener.map(|listener| {
+        spawn_metrics_server(listener, task_manager.clone(), metrics_shutdown.clone())
+    });
+    let health_server = match health_listener {
+        Some(listener) => {
+            let connection_state = market_data_hub.lock().await.subscribe_connection_state();
+            Some(spawn_health_server(
+                listener,
+                task_manager.clone(),
+                connection_state,
+                metrics_shutdown.clone(),
+            ))
+        }
+        None => None,
+    };
+    let supervisor =
+        max_task_restarts.map(|_| spawn_restart_supervisor(task_manager.clone(), shutdown.clone()));
+
+    tokio::select! {
+        _ = shutdown.cancelled() => {
+            info!("shutdown signal received; draining tasks");
+            task_manager
+                .lock()
+                .await
+                .drain(DEFAULT_DRAIN_TIMEOUT, |remaining| {
+                    info!(remaining, "draining tasks");
+                })
+                .await
+                .context("drain tasks")?;
+        }
+        _ = kill_switch.cancelled() => {
+            warn!("kill switch triggered; flattening all tasks");
+            task_manager
+                .lock()
+                .await
+                .emergency_flatten()
+                .await
+                .context("emergency flatten")?;
+        }
+    }
     info!("tasks shutdown complete");
+    if let Some(supervisor) = supervisor {
+        supervisor.abort();
+    }
+
+    metrics_shutdown.cancel();
+    if let Some(server) = metrics_server
+        && let Err(err) = server.await
+    {
+        warn!(error = %err, "metrics server task panicked");
+    }
+    if let Some(server) = health_server
+        && let Err(err) = server.await
+    {
+        warn!(error = %err, "health server task panicked");
+    }
+
+    recorder_shutdown.cancel();
+    let _ = alerts.await;
+    match fill_writer.await {
+        Ok(Ok(())) => info!("fill history flushed"),
+        Ok(Err(err)) => warn!(error = %err, "fill history writer failed"),
+        Err(err) => warn!(error = %err, "fill history writer panicked"),
+    }
+    if let Some(recorder) = recorder {
+        match recorder.await {
+            Ok(Ok(())) => info!("event log flushed"),
+            Ok(Err(err)) => warn!(error = %err, "event recorder failed"),
+            Err(err) => warn!(error = %err, "event recorder task panicked"),
+        }
+    }
 
     let hub = market_data_hub.lock().await;
     hub.shutdown();
@@ -173,23 +501,44 @@ async fn run_cli_mode(config_path: Option<PathBuf>, env_mode: bool, dry_run: boo
     Ok(())
 }
 
-fn init_tracing(log_level: &str, enable_stdout: bool) -> Result<()> {
-    let filter = EnvFilter::try_new(log_level).context("invalid log level")?;
-    let log_dir = std::env::current_dir()
+/// Directory for rolling logs and fill history, relative to the working directory.
+fn log_dir() -> Result<PathBuf> {
+    Ok(std::env::current_dir()
         .context("resolve current directory")?
-        .join("logs");
+        .join("logs"))
+}
+
+fn init_tracing(log_level: &str, format: LogFormat, enable_stdout: bool) -> Result<()> {
+    let filter = EnvFilter::try_new(log_level).context("invalid log level")?;
+    let log_dir = log_dir()?;
     fs::create_dir_all(&log_dir)
         .with_context(|| format!("create log directory {}", log_dir.display()))?;
     let file_appender = rolling::daily(&log_dir, "standx-point-mm-strategy.log");
-    let file_layer = tracing_subscriber::fmt::layer()
-        .with_writer(file_appender)
-        .with_ansi(false)
-        .with_filter(filter.clone());
+    let file_layer = match format {
+        LogFormat::Pretty => tracing_subscriber::fmt::layer()
+            .with_writer(file_appender)
+            .with_ansi(false)
+            .boxed(),
+        LogFormat::Json => tracing_subscriber::fmt::layer()
+            .json()
+            .flatten_event(true)
+            .with_writer(file_appender)
+            .boxed(),
+    }
+    .with_filter(filter.clone());
     let stdout_layer = enable_stdout.then(|| {
-        tracing_subscriber::fmt::layer()
-            .with_writer(std::io::stdout)
-            .with_ansi(true)
-            .with_filter(filter.clone())
+        match format {
+            LogFormat::Pretty => tracing_subscriber::fmt::layer()
+                .with_writer(std::io::stdout)
+                .with_ansi(true)
+                .boxed(),
+            LogFormat::Json => tracing_subscriber::fmt::layer()
+                .json()
+                .flatten_event(true)
+                .with_writer(std::io::stdout)
+                .boxed(),
+        }
+        .with_filter(filter.clone())
     });
     tracing_subscriber::registry()
         .with(file_layer)
@@ -200,14 +549,61 @@ fn init_tracing(log_level: &str, enable_stdout: bool) -> Result<()> {
     Ok(())
 }
 
-async fn run_tui_mode() -> Result<()> {
+async fn run_tui_mode(
+    symbol_cache_dir: Option<PathBuf>,
+    metrics_addr: Option<SocketAddr>,
+    health_addr: Option<SocketAddr>,
+) -> Result<()> {
     let market_data_hub = Arc::new(Mutex::new(MarketDataHub::new()));
-    let task_manager = Arc::new(Mutex::new(TaskManager::with_market_data_hub(
-        market_data_hub.clone(),
-    )));
+    let mut task_manager = TaskManager::with_market_data_hub(market_data_hub.clone());
+    if let Some(dir) = symbol_cache_dir {
+        task_manager.set_symbol_cache_dir(dir);
+    }
+    let task_manager = Arc::new(Mutex::new(task_manager));
+
+    let metrics_shutdown = CancellationToken::new();
+    let metrics_server = match metrics_addr {
+        Some(addr) => Some(spawn_metrics_server(
+            bind_listener("metrics", addr).await?,
+            task_manager.clone(),
+            metrics_shutdown.clone(),
+        )),
+        None => None,
+    };
+    let health_server = match health_addr {
+        Some(addr) => Some(spawn_health_server(
+            bind_listener("health", addr).await?,
+            task_manager.clone(),
+            market_data_hub.lock().await.subscribe_connection_state(),
+            metrics_shutdown.clone(),
+        )),
+        None => None,
+    };
 
     let shutdown = { task_manager.lock().await.shutdown_token() };
     setup_signal_handlers(shutdown.clone());
+    let fill_shutdown = CancellationToken::new();
+    let fill_writer = spawn_fill_history_writer(
+        task_manager.lock().await.subscribe_events(),
+        log_dir()?.join(FILL_HISTORY_DIR),
+        fill_shutdown.clone(),
+    );
+    let alerts = spawn_event_alerts(
+        task_manager.lock().await.subscribe_events(),
+        fill_shutdown.clone(),
+    );
+    let kill_switch = CancellationToken::new();
+    setup_kill_switch_handler(kill_switch.clone());
+    {
+        let task_manager = task_manager.clone();
+        tokio::spawn(async move {
+            kill_switch.cancelled().await;
+            warn!("kill switch triggered; flattening all tasks");
+            if let Err(err) = task_manager.lock().await.emergency_flatten().await {
+                error!(error = %format!("{err:#}"), "emergency flatten failed");
+            }
+        });
+    }
 
     tui::run_tui(
         task_manager.clone(),
@@ -221,6 +617,18 @@ async fn run_tui_mode() -> Result<()> {
         .shutdown_and_wait()
         .await
         .context("shutdown tasks")?;
+    metrics_shutdown.cancel();
+    if let Some(server) = metrics_server {
+        let _ = server.await;
+    }
+    if let Some(server) = health_server {
+        let _ = server.await;
+    }
+    fill_shutdown.cancel();
+    let _ = alerts.await;
+    if let Ok(Err(err)) = fill_writer.await {
+        warn!(error = %err, "fill history writer failed");
+    }
     let hub = market_data_hub.lock().await;
     hub.shutdown();
     Ok(())
@@ -238,6 +646,9 @@ fn validate_strategy_config(config: &StrategyConfig) -> Result<()> {
     if config.tasks.is_empty() {
         return Err(anyhow!("strategy config must contain at least one task"));
     }
+    if let Some(environment) = &config.environment {
+        environment.validate()?;
+    }
 
     let mut seen_accounts = std::collections::HashSet::new();
     let mut account_ids = std::collections::HashSet::new();
@@ -250,14 +661,29 @@ fn validate_strategy_config(config: &StrategyConfig) -> Result<()> {
         let signing_key = account.signing_key.as_deref().unwrap_or("").trim();
 
         let has_private_key = !private_key.is_empty();
+        let has_keystore = account.keystore_path.is_some();
+        let has_mnemonic = account
+            .mnemonic
+            .as_deref()
+            .is_some_and(|phrase| !phrase.trim().is_empty());
         let has_jwt = !jwt_token.is_empty();
         let has_signing = !signing_key.is_empty();
 
-        if !has_private_key && (!has_jwt || !has_signing) {
+        if !has_private_key && !has_keystore && !has_mnemonic && (!has_jwt || !has_signing) {
             return Err(anyhow!(
-                "account must provide private_key or jwt_token+signing_key"
+                "account must provide private_key, keystore_path, mnemonic or jwt_token+signing_key"
             ));
         }
+        if account
+            .wallet_address
+            .as_deref()
+            .is_some_and(|address| address.trim().is_empty())
+        {
+            return Err(anyhow!("account wallet_address cannot be empty when set"));
+        }
+        if has_keystore && account.chain != Chain::Bsc {
+            return Err(anyhow!("account keystore_path is only supported on bsc"));
+        }
         if has_jwt && !has_signing {
             return Err(anyhow!(
                 "account signing_key cannot be empty when jwt_token is set"
@@ -279,9 +705,7 @@ fn validate_strategy_config(config: &StrategyConfig) -> Result<()> {
         if task.id.trim().is_empty() {
             return Err(anyhow!("task id cannot be empty"));
         }
-        if task.symbol.trim().is_empty() {
-            return Err(anyhow!("task symbol cannot be empty"));
-        }
+        task.check_symbols()?;
         if task.account_id.trim().is_empty() {
             return Err(anyhow!("task account_id cannot be empty"));
         }
@@ -294,15 +718,49 @@ fn validate_strategy_config(config: &StrategyConfig) -> Result<()> {
         if task.risk.budget_usd.trim().is_empty() {
             return Err(anyhow!("task risk.budget_usd cannot be empty"));
         }
+        if let Some(raw) = task.risk.replace_order.as_deref()
+            && raw.parse::<ReplaceOrder>().is_err()
+        {
+            return Err(anyhow!(
+                "task risk.replace_order must be cancel_first, place_first, or amend: {raw}"
+            ));
+        }
+        if task.leverage == Some(0) {
+            return Err(anyhow!("task leverage must be at least 1"));
+        }
+        if let Some(quoting) = &task.quoting {
+            quoting
+                .bands()
+                .with_context(|| format!("task {} quoting", task.id))?;
+        }
+        if let Some(sweep) = &task.risk.taker_sweep {
+            sweep
+                .sweep()
+                .with_context(|| format!("task {} risk.taker_sweep", task.id))?;
+        }
+        task.risk
+            .min_uptime()
+            .with_context(|| format!("task {} risk.min_uptime_ratio", task.id))?;
         if !seen_ids.insert(task.id.clone()) {
             return Err(anyhow!("duplicate task id in config: {}", task.id));
         }
     }
+    config.check_duplicate_symbols()?;
+    config.check_total_budget()?;
     Ok(())
 }
 
 fn load_env_config() -> Result<Option<StrategyConfig>> {
     let private_key = env::var("STANDX_MM_PRIVATE_KEY").ok();
+    let mnemonic = env::var("STANDX_MM_MNEMONIC").ok();
+    let mnemonic_index = env::var("STANDX_MM_MNEMONIC_INDEX")
+        .ok()
+        .map(|raw| {
+            raw.trim()
+                .parse::<u32>()
+                .map_err(|_| anyhow!("invalid STANDX_MM_MNEMONIC_INDEX: {raw}"))
+        })
+        .transpose()?;
     let symbol = env::var("STANDX_MM_SYMBOL").ok();
     let risk_level = env::var("STANDX_MM_RISK_LEVEL").ok();
     let budget_usd = env::var("STANDX_MM_BUDGET_USD").ok();
@@ -311,6 +769,7 @@ fn load_env_config() -> Result<Option<StrategyConfig>> {
     let sl_bps = env::var("STANDX_MM_SL_BPS").ok();
 
     let any_set = private_key.is_some()
+        || mnemonic.is_some()
         || symbol.is_some()
         || risk_level.is_some()
         || budget_usd.is_some()
@@ -321,29 +780,48 @@ fn load_env_config() -> Result<Option<StrategyConfig>> {
         return Ok(None);
     }
 
-    let private_key = private_key.ok_or_else(|| anyhow!("STANDX_MM_PRIVATE_KEY is required"))?;
     let symbol = symbol.ok_or_else(|| anyhow!("STANDX_MM_SYMBOL is required"))?;
     let risk_level = risk_level.ok_or_else(|| anyhow!("STANDX_MM_RISK_LEVEL is required"))?;
     let budget_usd = budget_usd.ok_or_else(|| anyhow!("STANDX_MM_BUDGET_USD is required"))?;
 
     let chain = parse_chain(env::var("STANDX_MM_CHAIN").ok())?;
-    let wallet_address = derive_wallet_address(&private_key, chain)?;
+    let wallet_address = match (&private_key, &mnemonic) {
+        (Some(private_key), _) => derive_wallet_address(private_key, chain)?,
+        (None, Some(phrase)) => {
+            derive_mnemonic_address(phrase, mnemonic_index.unwrap_or(0), chain)?
+        }
+        (None, None) => {
+            return Err(anyhow!(
+                "STANDX_MM_PRIVATE_KEY or STANDX_MM_MNEMONIC is required"
+            ));
+        }
+    };
 
-    let account_id = env::var("STANDX_MM_ACCOUNT_ID").unwrap_or(wallet_address);
+    let account_id = env::var("STANDX_MM_ACCOUNT_ID").unwrap_or_else(|_| wallet_address.clone());
     let task_id = env::var("STANDX_MM_TASK_ID")
         .unwrap_or_else(|_| format!("task-{}", slugify_symbol(&symbol)));
 
     let config = StrategyConfig {
         accounts: vec![standx_point_mm_strategy::config::AccountConfig {
             id: account_id.clone(),
-            private_key: Some(private_key),
+            private_key,
             jwt_token: None,
             signing_key: None,
             chain,
+            keystore_path: None,
+            mnemonic,
+            mnemonic_index,
+            wallet_address: Some(wallet_address),
+            max_concurrent_order_actions: None,
+            rate_limit: None,
         }],
         tasks: vec![standx_point_mm_strategy::config::TaskConfig {
             id: task_id,
             symbol,
+            symbols: Vec::new(),
+            quoting: None,
+            leverage: None,
+            margin_mode: None,
             account_id,
             risk: standx_point_mm_strategy::config::RiskConfig {
                 level: risk_level,
@@ -351,8 +829,13 @@ fn load_env_config() -> Result<Option<StrategyConfig>> {
                 guard_close_enabled,
                 tp_bps,
                 sl_bps,
+                ..Default::default()
             },
         }],
+        max_total_budget_usd: None,
+        allow_duplicate_symbols: false,
+        max_concurrent_startups: None,
+        environment: None,
     };
 
     Ok(Some(config))
@@ -401,6 +884,21 @@ fn derive_wallet_address(private_key: &str, chain: Chain) -> Result<String> {
     }
 }
 
+fn derive_mnemonic_address(phrase: &str, index: u32, chain: Chain) -> Result<String> {
+    match chain {
+        Chain::Bsc => {
+            let wallet = EvmWalletSigner::from_mnemonic(phrase, &evm_derivation_path(index))
+                .map_err(|err| anyhow!("invalid STANDX_MM_MNEMONIC: {err}"))?;
+            Ok(standx_point_adapter::auth::WalletSigner::address(&wallet).to_string())
+        }
+        Chain::Solana => {
+            let wallet = SolanaWalletSigner::from_mnemonic(phrase, &solana_derivation_path(index))
+                .map_err(|err| anyhow!("invalid STANDX_MM_MNEMONIC: {err}"))?;
+            Ok(standx_point_adapter::auth::WalletSigner::address(&wallet).to_string())
+        }
+    }
+}
+
 fn slugify_symbol(symbol: &str) -> String {
     let mut slug = String::with_capacity(symbol.len());
     for ch in symbol.chars() {
@@ -418,14 +916,10 @@ fn slugify_symbol(symbol: &str) -> String {
 }
 
 fn log_strategy_config(config: &StrategyConfig) {
-    info!(
-        task_count = config.tasks.len(),
-        "strategy configuration confirmed"
-    );
     for task in &config.tasks {
         info!(
             task_id = %task.id,
-            symbol = %task.symbol,
+            symbols = %task.symbol_list().join(","),
             account_id = %task.account_id,
             risk_level = %task.risk.level,
             budget_usd = %task.risk.budget_usd,
@@ -466,3 +960,27 @@ fn setup_signal_handlers(shutdown: CancellationToken) {
         });
     }
 }
+
+/// SIGUSR1 is the out-of-band kill switch: flatten every task, then exit.
+fn setup_kill_switch_handler(kill_switch: CancellationToken) {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{SignalKind, signal};
+
+        tokio::spawn(async move {
+            match signal(SignalKind::user_defined1()) {
+                Ok(mut stream) => {
+                    stream.recv().await;
+                    warn!("received SIGUSR1");
+                    kill_switch.cancel();
+                }
+                Err(err) => {
+                    warn!(error = %err, "failed to install SIGUSR1 handler");
+                }
+            }
+        });
+    }
+
+    #[cfg(not(unix))]
+    drop(kill_switch);
+}
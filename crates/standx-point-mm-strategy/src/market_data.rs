@@ -1,23 +1,57 @@
 /*
 [INPUT]:  StandX market WebSocket stream + per-symbol subscriptions.
-[OUTPUT]: Latest-per-symbol price snapshots via `watch` + connection state notifications.
+[OUTPUT]: Latest-per-symbol price snapshots via `watch`, per-account position events via
+          `broadcast`, + connection state notifications.
 [POS]:    Data layer - shared market data distribution (no trading logic).
 [UPDATE]: When changing subscription channels, reconnection backoff, or shutdown semantics.
+[UPDATE]: 2026-10-16 Let the adapter socket reconnect and replay symbol subscriptions itself
+[UPDATE]: 2026-10-16 Enable WS heartbeat so half-open connections are detected
+[UPDATE]: 2026-10-17 Take the market stream URL from the configured StandxEnvironment
+[UPDATE]: 2026-10-17 Take bid/ask from the typed PriceData spread
+[UPDATE]: 2026-10-17 Share one reconnecting position stream per account across tasks
+[UPDATE]: 2026-10-17 Expose is_connected and per-symbol last_tick_age
+[UPDATE]: 2026-10-17 Drop price frames with a non-positive mark price
+[UPDATE]: 2026-10-17 Share the account stream reconnect backoff with the order stream
 */
 
+//! Fan-out model: the hub keeps one unauthenticated market stream for every
+//! tracked symbol and hands each task a `watch` receiver per symbol, so N
+//! tasks quoting one symbol read one subscription. Position updates need an
+//! authenticated socket, so the hub keeps one per account and hands each task
+//! on that account a `broadcast` receiver. Both connections reconnect on
+//! their own; subscribers only see prices resume or a
+//! [`PositionFeedEvent::Reconnected`] telling them to resync from REST.
+
 use std::collections::{HashMap, HashSet};
 use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Duration;
 
 use rust_decimal::Decimal;
-use tokio::sync::{mpsc, watch};
+use tokio::sync::{broadcast, mpsc, watch};
+use tokio::time::Instant;
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, warn};
 
-use standx_point_adapter::{PriceData, StandxWebSocket, SymbolPrice, WebSocketMessage};
+use standx_point_adapter::environment::MARKET_STREAM_URL;
+use standx_point_adapter::{
+    BackoffPolicy, PriceData, StandxEnvironment, StandxWebSocket, SymbolPrice, WebSocketMessage,
+};
 
-const DEFAULT_WS_URL: &str = "wss://perps.standx.com/ws-stream/v1";
 const DEFAULT_MAX_RETRIES: u32 = 10;
+/// Ping cadence for strategy WebSockets.
+pub(crate) const WS_HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+/// Silence after which a strategy WebSocket is treated as dead.
+pub(crate) const WS_HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(45);
+/// Delays between account (position and order) stream reconnects; attempts
+/// never run out.
+pub(crate) const ACCOUNT_STREAM_RECONNECT_BACKOFF: BackoffPolicy = BackoffPolicy {
+    initial_delay: Duration::from_millis(500),
+    max_delay: Duration::from_secs(30),
+    max_attempts: u32::MAX,
+};
+/// Position events buffered per account before slow subscribers lag.
+const POSITION_FEED_CAPACITY: usize = 256;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ConnectionState {
@@ -26,6 +60,20 @@ pub enum ConnectionState {
     Paused,
 }
 
+/// Event on an account's shared position stream.
+#[derive(Debug, Clone)]
+pub enum PositionFeedEvent {
+    /// Raw `position` channel payload; subscribers filter by symbol
+    Positions(serde_json::Value),
+    /// The stream is down; updates are lost until it reconnects
+    Disconnected { reason: String },
+    /// The stream is back after an outage; resync from REST
+    Reconnected,
+}
+
+/// Arrival time of the latest price per symbol, shared with the worker.
+type LastTicks = Arc<std::sync::Mutex<HashMap<String, Instant>>>;
+
 #[derive(Debug)]
 enum HubCommand {
     TrackSymbol {
@@ -43,6 +91,8 @@ pub struct MarketDataHub {
     ws_url: String,
     symbols: Vec<String>,
     price_txs: HashMap<String, watch::Sender<SymbolPrice>>,
+    last_ticks: LastTicks,
+    position_feeds: HashMap<String, broadcast::Sender<PositionFeedEvent>>,
     connection_state: watch::Sender<ConnectionState>,
     shutdown: CancellationToken,
     cmd_tx: mpsc::UnboundedSender<HubCommand>,
@@ -60,7 +110,7 @@ impl MarketDataHub {
     }
 
     #[cfg(test)]
-    fn new_for_test() -> Self {
+    pub(crate) fn new_for_test() -> Self {
         Self::new_internal(false)
     }
 
@@ -70,9 +120,11 @@ impl MarketDataHub {
         let (cmd_tx, cmd_rx) = mpsc::unbounded_channel();
 
         Self {
-            ws_url: DEFAULT_WS_URL.to_string(),
+            ws_url: MARKET_STREAM_URL.to_string(),
             symbols: Vec::new(),
             price_txs: HashMap::new(),
+            last_ticks: LastTicks::default(),
+            position_feeds: HashMap::new(),
             connection_state,
             shutdown: CancellationToken::new(),
             cmd_tx,
@@ -82,6 +134,17 @@ impl MarketDataHub {
         }
     }
 
+    /// Point the hub at another market stream (e.g. testnet).
+    ///
+    /// Only takes effect before the worker starts on the first subscription.
+    pub fn set_ws_url(&mut self, ws_url: impl Into<String>) {
+        if self.worker_handle.is_some() {
+            warn!("MarketDataHub worker already running; ignoring ws_url change");
+            return;
+        }
+        self.ws_url = ws_url.into();
+    }
+
     /// Subscribe to connection state changes.
     pub fn subscribe_connection_state(&self) -> watch::Receiver<ConnectionState> {
         self.connection_state.subscribe()
@@ -115,11 +178,62 @@ impl MarketDataHub {
         rx
     }
 
+    /// Subscribe to the authenticated position stream of `account_id`.
+    ///
+    /// Tasks on one account share a single connection, opened with the first
+    /// live subscriber's `account_jwt` and `environment` and closed once
+    /// every receiver is dropped.
+    pub fn subscribe_positions(
+        &mut self,
+        account_id: &str,
+        account_jwt: &str,
+        environment: &StandxEnvironment,
+    ) -> broadcast::Receiver<PositionFeedEvent> {
+        if let Some(existing) = self.position_feeds.get(account_id)
+            && existing.receiver_count() > 0
+        {
+            return existing.subscribe();
+        }
+
+        let (tx, rx) = broadcast::channel(POSITION_FEED_CAPACITY);
+        self.position_feeds
+            .insert(account_id.to_string(), tx.clone());
+        if tokio::runtime::Handle::try_current().is_err() {
+            warn!(%account_id, "MarketDataHub created without Tokio runtime; position feed not started");
+            return rx;
+        }
+
+        let feed = PositionFeedWorker {
+            account_id: account_id.to_string(),
+            account_jwt: account_jwt.to_string(),
+            environment: environment.clone(),
+            tx,
+            shutdown: self.shutdown.clone(),
+        };
+        tokio::spawn(feed.run());
+        rx
+    }
+
     /// Get the current price for a symbol if available.
     pub fn get_price(&self, symbol: &str) -> Option<SymbolPrice> {
         self.price_txs.get(symbol).map(|tx| tx.borrow().clone())
     }
 
+    /// Whether the market stream is currently connected.
+    pub fn is_connected(&self) -> bool {
+        *self.connection_state.borrow() == ConnectionState::Connected
+    }
+
+    /// Time since the last price for `symbol` arrived, or `None` before the
+    /// first one.
+    pub fn last_tick_age(&self, symbol: &str) -> Option<Duration> {
+        let last_ticks = self
+            .last_ticks
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        last_ticks.get(symbol).map(|at| at.elapsed())
+    }
+
     /// Trigger a graceful shutdown of the internal worker.
     pub fn shutdown(&self) {
         self.shutdown.cancel();
@@ -142,11 +256,13 @@ impl MarketDataHub {
         }
 
         let ws_url = self.ws_url.clone();
+        let last_ticks = self.last_ticks.clone();
         let connection_state = self.connection_state.clone();
         let shutdown = self.shutdown.clone();
 
         self.worker_handle = Some(tokio::spawn(async move {
-            let worker = MarketDataHubWorker::new(ws_url, cmd_rx, connection_state, shutdown);
+            let worker =
+                MarketDataHubWorker::new(ws_url, cmd_rx, last_ticks, connection_state, shutdown);
             worker.run().await;
         }));
     }
@@ -170,6 +286,7 @@ struct MarketDataHubWorker {
     tracked_symbols: HashSet<String>,
     price_sampled_symbols: HashSet<String>,
     price_txs: HashMap<String, watch::Sender<SymbolPrice>>,
+    last_ticks: LastTicks,
     cmd_rx: mpsc::UnboundedReceiver<HubCommand>,
     connection_state: watch::Sender<ConnectionState>,
     shutdown: CancellationToken,
@@ -180,6 +297,7 @@ impl MarketDataHubWorker {
     fn new(
         ws_url: String,
         cmd_rx: mpsc::UnboundedReceiver<HubCommand>,
+        last_ticks: LastTicks,
         connection_state: watch::Sender<ConnectionState>,
         shutdown: CancellationToken,
     ) -> Self {
@@ -188,6 +306,7 @@ impl MarketDataHubWorker {
             tracked_symbols: HashSet::new(),
             price_sampled_symbols: HashSet::new(),
             price_txs: HashMap::new(),
+            last_ticks,
             cmd_rx,
             connection_state,
             shutdown,
@@ -297,10 +416,18 @@ impl MarketDataHubWorker {
     async fn connect_once(
         &self,
     ) -> Result<(StandxWebSocket, mpsc::Receiver<WebSocketMessage>), String> {
-        let mut ws = StandxWebSocket::new();
+        // Drops inside a session are healed by the socket itself, which
+        // replays tracked symbol subscriptions so the watch channels keep
+        // receiving prices.
+        let mut ws = StandxWebSocket::new()
+            .with_reconnect(BackoffPolicy {
+                max_attempts: self.max_retries,
+                ..BackoffPolicy::default()
+            })
+            .with_heartbeat(WS_HEARTBEAT_INTERVAL, WS_HEARTBEAT_TIMEOUT);
 
         info!(ws_url = %self.ws_url, "Connecting to StandX market WebSocket");
-        ws.connect_market_stream()
+        ws.connect_market_stream_url(&self.ws_url)
             .await
             .map_err(|err| err.to_string())?;
         self.subscribe_tracked_symbols(&ws).await?;
@@ -368,7 +495,7 @@ impl MarketDataHubWorker {
         ws.subscribe_price(symbol)
             .await
             .map_err(|err| err.to_string())?;
-        ws.subscribe_depth(symbol)
+        ws.subscribe_depth(symbol, None)
             .await
             .map_err(|err| err.to_string())?;
         Ok(())
@@ -380,7 +507,9 @@ impl MarketDataHubWorker {
                 match serde_json::from_value::<PriceData>(data) {
                     Ok(price_data) => {
                         let Some(price) = symbol_price_from_price_data(price_data) else {
-                            debug!(%symbol, "Failed to parse decimals from price payload");
+                            // Dropped frames leave the last good price aging,
+                            // so the strategy's staleness guard takes over.
+                            warn!(%symbol, "dropping price update with malformed or non-positive mark/index price");
                             return;
                         };
 
@@ -397,6 +526,10 @@ impl MarketDataHubWorker {
                         }
 
                         if let Some(tx) = self.price_txs.get(&symbol) {
+                            self.last_ticks
+                                .lock()
+                                .unwrap_or_else(|err| err.into_inner())
+                                .insert(symbol.clone(), Instant::now());
                             let _ = tx.send(price);
                         } else {
                             debug!(%symbol, "Received price for untracked symbol");
@@ -407,12 +540,104 @@ impl MarketDataHubWorker {
                     }
                 }
             }
-            WebSocketMessage::DepthBook { .. } => {}
+            WebSocketMessage::DepthBook { .. } | WebSocketMessage::Depth(_) => {}
             _ => {}
         }
     }
 }
 
+/// One authenticated `position` stream shared by an account's tasks.
+#[derive(Debug)]
+struct PositionFeedWorker {
+    account_id: String,
+    account_jwt: String,
+    environment: StandxEnvironment,
+    tx: broadcast::Sender<PositionFeedEvent>,
+    shutdown: CancellationToken,
+}
+
+impl PositionFeedWorker {
+    /// Connect, forward, and reconnect until shutdown or the last receiver
+    /// goes away.
+    async fn run(self) {
+        let account_id = self.account_id.as_str();
+        let mut attempt = 0u32;
+        let mut resumed = false;
+        loop {
+            let connected = tokio::select! {
+                _ = self.shutdown.cancelled() => return,
+                _ = self.tx.closed() => return,
+                connected = self.connect() => connected,
+            };
+            match connected {
+                Ok((ws, mut rx)) => {
+                    if resumed {
+                        info!(%account_id, attempt, "position feed reconnected");
+                        let _ = self.tx.send(PositionFeedEvent::Reconnected);
+                    }
+                    attempt = 0;
+                    let reason = loop {
+                        tokio::select! {
+                            _ = self.shutdown.cancelled() => return,
+                            _ = self.tx.closed() => return,
+                            msg = rx.recv() => match msg {
+                                Some(WebSocketMessage::Position { data }) => {
+                                    let _ = self.tx.send(PositionFeedEvent::Positions(data));
+                                }
+                                Some(_) => {}
+                                None => {
+                                    break ws
+                                        .disconnect_reason()
+                                        .await
+                                        .unwrap_or_else(|| "unknown".to_string());
+                                }
+                            },
+                        }
+                    };
+                    warn!(%account_id, %reason, "position feed ended; reconnecting");
+                    let _ = self.tx.send(PositionFeedEvent::Disconnected { reason });
+                }
+                Err(err) => {
+                    warn!(%account_id, attempt = attempt + 1, "position feed connect failed: {err}");
+                    if attempt == 0 {
+                        let _ = self
+                            .tx
+                            .send(PositionFeedEvent::Disconnected { reason: err });
+                    }
+                }
+            }
+
+            resumed = true;
+            attempt = attempt.saturating_add(1);
+            let delay = ACCOUNT_STREAM_RECONNECT_BACKOFF.delay_for(attempt);
+            tokio::select! {
+                _ = self.shutdown.cancelled() => return,
+                _ = self.tx.closed() => return,
+                _ = tokio::time::sleep(delay) => {}
+            }
+        }
+    }
+
+    async fn connect(&self) -> Result<(StandxWebSocket, mpsc::Receiver<WebSocketMessage>), String> {
+        let mut ws = StandxWebSocket::new()
+            .with_environment(&self.environment)
+            .with_heartbeat(WS_HEARTBEAT_INTERVAL, WS_HEARTBEAT_TIMEOUT);
+        ws.connect_market_stream()
+            .await
+            .map_err(|err| format!("connect failed: {err}"))?;
+        ws.authenticate(&self.account_jwt, Some(&["position"]))
+            .await
+            .map_err(|err| format!("authenticate failed: {err}"))?;
+        ws.subscribe_positions()
+            .await
+            .map_err(|err| format!("subscribe failed: {err}"))?;
+        let rx = ws
+            .take_receiver()
+            .ok_or_else(|| "position feed receiver already taken".to_string())?;
+        Ok((ws, rx))
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum StreamExit {
     Disconnected,
@@ -450,14 +675,11 @@ fn symbol_price_from_price_data(data: PriceData) -> Option<SymbolPrice> {
     };
 
     let index_price = parse_decimal_str(&data.index_price)?;
-    let mark_price = parse_decimal_str(&data.mark_price)?;
+    let mark_price = parse_decimal_str(&data.mark_price).filter(|mark| *mark > Decimal::ZERO)?;
 
     let last_price = parse_decimal_str(&data.last_price);
     let mid_price = parse_decimal_str(&data.mid_price);
 
-    let spread_bid = data.spread.first().and_then(|s| parse_decimal_str(s));
-    let spread_ask = data.spread.get(1).and_then(|s| parse_decimal_str(s));
-
     Some(SymbolPrice {
         base: data.base,
         index_price,
@@ -465,8 +687,8 @@ fn symbol_price_from_price_data(data: PriceData) -> Option<SymbolPrice> {
         mark_price,
         mid_price,
         quote: data.quote,
-        spread_ask,
-        spread_bid,
+        spread_ask: data.spread.ask,
+        spread_bid: data.spread.bid,
         symbol: data.symbol,
         time: data.time,
     })
@@ -490,6 +712,40 @@ mod tests {
         assert_eq!(backoff_duration(10), Duration::from_secs(30));
     }
 
+    #[test]
+    fn price_frames_with_malformed_or_zero_mark_are_dropped() {
+        let frame = |mark: &str| {
+            serde_json::from_value::<PriceData>(serde_json::json!({
+                "base": "BTC",
+                "index_price": "100",
+                "last_price": "",
+                "mark_price": mark,
+                "mid_price": "",
+                "quote": "USD",
+                "symbol": "BTC-USD",
+                "time": "0",
+            }))
+            .unwrap()
+        };
+        assert_eq!(
+            symbol_price_from_price_data(frame("100.5")).map(|price| price.mark_price),
+            Some(Decimal::from_str("100.5").unwrap())
+        );
+        for mark in ["", "abc", "1.2.3", "0", "-1"] {
+            assert_eq!(symbol_price_from_price_data(frame(mark)), None, "{mark:?}");
+        }
+    }
+
+    #[test]
+    fn market_data_ws_url_defaults_to_mainnet_and_can_be_overridden() {
+        let mut hub = MarketDataHub::new_for_test();
+        assert_eq!(hub.ws_url, MARKET_STREAM_URL);
+
+        let testnet = standx_point_adapter::StandxEnvironment::Testnet;
+        hub.set_ws_url(testnet.market_stream_url());
+        assert_eq!(hub.ws_url, testnet.market_stream_url());
+    }
+
     #[tokio::test]
     async fn market_data_watch_broadcasts_latest_price() {
         let mut hub = MarketDataHub::new_for_test();
@@ -554,6 +810,116 @@ mod tests {
         assert_eq!(&*rx2.borrow(), &next);
     }
 
+    #[tokio::test]
+    async fn two_tasks_on_one_symbol_share_price_and_position_streams() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        use futures_util::{SinkExt, StreamExt};
+        use tokio::net::TcpListener;
+        use tokio_tungstenite::tungstenite::Message;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let ws_url = format!("ws://{}", listener.local_addr().unwrap());
+        let accepted = Arc::new(AtomicUsize::new(0));
+        let server_accepted = accepted.clone();
+        let ws_server = tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                server_accepted.fetch_add(1, Ordering::SeqCst);
+                tokio::spawn(async move {
+                    let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+                    let _auth = ws.next().await;
+                    let _subscribe = ws.next().await;
+                    let update = serde_json::json!({
+                        "channel": "position",
+                        "data": { "symbol": "BTC-USD", "qty": "1" },
+                    });
+                    ws.send(Message::Text(update.to_string().into()))
+                        .await
+                        .unwrap();
+                    while let Some(Ok(_)) = ws.next().await {}
+                });
+            }
+        });
+
+        let environment = StandxEnvironment::Custom {
+            auth_base_url: "http://127.0.0.1:1".to_string(),
+            trading_base_url: "http://127.0.0.1:1".to_string(),
+            market_stream_url: ws_url.clone(),
+            order_stream_url: ws_url,
+        };
+        let mut hub = MarketDataHub::new_for_test();
+        let _price_a = hub.subscribe_price("BTC-USD");
+        let _price_b = hub.subscribe_price("BTC-USD");
+        let mut feed_a = hub.subscribe_positions("account-1", "jwt", &environment);
+        let mut feed_b = hub.subscribe_positions("account-1", "jwt", &environment);
+        assert_eq!(hub.symbols, ["BTC-USD"]);
+        assert_eq!(hub.price_txs.len(), 1);
+
+        for feed in [&mut feed_a, &mut feed_b] {
+            let event = tokio::time::timeout(Duration::from_secs(5), feed.recv())
+                .await
+                .expect("position update before timeout")
+                .expect("feed open");
+            let PositionFeedEvent::Positions(data) = event else {
+                panic!("expected a position payload, got {event:?}");
+            };
+            assert_eq!(data["qty"], "1");
+        }
+        assert_eq!(accepted.load(Ordering::SeqCst), 1);
+
+        // Once every task is gone the next subscriber opens a fresh stream.
+        drop((feed_a, feed_b));
+        let mut feed_c = hub.subscribe_positions("account-1", "jwt", &environment);
+        tokio::time::timeout(Duration::from_secs(5), feed_c.recv())
+            .await
+            .expect("position update before timeout")
+            .expect("feed open");
+        assert_eq!(accepted.load(Ordering::SeqCst), 2);
+        ws_server.abort();
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn last_tick_age_tracks_price_arrivals() {
+        let mut hub = MarketDataHub::new_for_test();
+        let _rx = hub.subscribe_price("BTC-USD");
+        assert!(!hub.is_connected());
+        assert_eq!(hub.last_tick_age("BTC-USD"), None);
+
+        let cmd_rx = hub.cmd_rx.take().expect("worker not started");
+        let mut worker = MarketDataHubWorker::new(
+            hub.ws_url.clone(),
+            cmd_rx,
+            hub.last_ticks.clone(),
+            hub.connection_state.clone(),
+            hub.shutdown.clone(),
+        );
+        let price_tx = hub.price_txs["BTC-USD"].clone();
+        worker.track_symbol("BTC-USD".to_string(), price_tx);
+        worker.handle_ws_message(WebSocketMessage::Price {
+            symbol: "BTC-USD".to_string(),
+            data: serde_json::json!({
+                "base": "BTC",
+                "index_price": "100",
+                "last_price": "100",
+                "mark_price": "100",
+                "mid_price": "100",
+                "quote": "USD",
+                "spread": ["99.9", "100.1"],
+                "symbol": "BTC-USD",
+                "time": "2026-10-17T00:00:00Z",
+            }),
+        });
+        hub.connection_state
+            .send_replace(ConnectionState::Connected);
+
+        tokio::time::advance(Duration::from_secs(30)).await;
+        assert!(hub.is_connected());
+        assert_eq!(hub.last_tick_age("BTC-USD"), Some(Duration::from_secs(30)));
+        assert_eq!(hub.last_tick_age("ETH-USD"), None);
+    }
+
     #[tokio::test]
     async fn market_data_connection_state_broadcasts_updates() {
         let hub = MarketDataHub::new_for_test();
@@ -3,10 +3,31 @@
 [OUTPUT]: Snapshot-friendly task metrics for UI display
 [POS]:    Shared runtime metrics between task loops and UI
 [UPDATE]: When adding/removing task-level runtime signals
+[UPDATE]: 2026-10-16 Record why a task halted itself
+[UPDATE]: 2026-10-16 Clear the halt reason when quoting resumes
+[UPDATE]: 2026-10-16 Track fills, uptime ratio, and realized PnL for the metrics endpoint
+[UPDATE]: 2026-10-16 Track the latest funding rate
+[UPDATE]: 2026-10-17 Track leverage and margin mode confirmed at startup
+[UPDATE]: 2026-10-17 Snapshot REST request latency from the task's LatencyTracker
+[UPDATE]: 2026-10-17 Track deployed USD and budget utilization
+[UPDATE]: 2026-10-17 Flag tasks whose rolling uptime is below risk.min_uptime_ratio
+[UPDATE]: 2026-10-17 Track the rolling 1h uptime ratio next to the cumulative one
+[UPDATE]: 2026-10-17 Track session realized PnL for the TUI task list
+[UPDATE]: 2026-10-17 Flag stale price feeds from PriceStale/PriceRecovered bus events
 */
 
 use rust_decimal::Decimal;
-use std::time::Instant;
+use standx_point_adapter::{LatencyStats, LatencyTracker, MarginMode};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+
+use crate::events::{EventSubscription, RuntimeEvent};
+
+/// Request path whose latest round-trip is reported as the order latency.
+pub const NEW_ORDER_ENDPOINT: &str = "/api/new_order";
 
 #[derive(Debug, Clone)]
 pub struct TaskMetricsSnapshot {
@@ -15,6 +36,33 @@ pub struct TaskMetricsSnapshot {
     pub last_heartbeat: Option<Instant>,
     pub last_price: Option<Decimal>,
     pub last_update: Option<Instant>,
+    pub halt_reason: Option<String>,
+    pub fills: u64,
+    pub uptime_ratio: Option<Decimal>,
+    /// Uptime ratio over the last hour.
+    pub uptime_ratio_1h: Option<Decimal>,
+    /// Realized PnL for the current UTC day, in USD.
+    pub realized_pnl_usd: Decimal,
+    /// Realized PnL since the task started, in USD.
+    pub session_realized_pnl_usd: Decimal,
+    /// Latest funding rate per interval for the task's symbol.
+    pub funding_rate: Option<Decimal>,
+    /// Leverage the server confirmed at startup, if the task set one.
+    pub leverage: Option<u32>,
+    /// Margin mode the server confirmed at startup, if the task set one.
+    pub margin_mode: Option<MarginMode>,
+    /// Round-trip time of the most recent `new_order` request.
+    pub last_order_latency: Option<Duration>,
+    /// Recent REST round-trip stats keyed by endpoint path.
+    pub request_latency: BTreeMap<String, LatencyStats>,
+    /// Resting quote notional plus position value at mark, in USD.
+    pub deployed_usd: Option<Decimal>,
+    /// `deployed_usd / budget_usd`; `None` without a positive budget.
+    pub utilization_ratio: Option<Decimal>,
+    /// Rolling uptime is below `risk.min_uptime_ratio`.
+    pub uptime_below_min: bool,
+    /// The price feed is stale and quotes are pulled.
+    pub price_stale: bool,
 }
 
 #[derive(Debug, Default)]
@@ -24,16 +72,60 @@ pub struct TaskMetrics {
     last_heartbeat: Option<Instant>,
     last_price: Option<Decimal>,
     last_update: Option<Instant>,
+    halt_reason: Option<String>,
+    fills: u64,
+    uptime_ratio: Option<Decimal>,
+    uptime_ratio_1h: Option<Decimal>,
+    realized_pnl_usd: Decimal,
+    session_realized_pnl_usd: Decimal,
+    funding_rate: Option<Decimal>,
+    leverage: Option<u32>,
+    margin_mode: Option<MarginMode>,
+    latency: Option<Arc<LatencyTracker>>,
+    deployed_usd: Option<Decimal>,
+    utilization_ratio: Option<Decimal>,
+    uptime_below_min: bool,
+    price_stale: bool,
 }
 
 impl TaskMetrics {
+    /// Metrics that read request latency from the task client's tracker.
+    pub fn with_latency_tracker(latency: Arc<LatencyTracker>) -> Self {
+        Self {
+            latency: Some(latency),
+            ..Self::default()
+        }
+    }
+
     pub fn snapshot(&self) -> TaskMetricsSnapshot {
+        let request_latency = self
+            .latency
+            .as_ref()
+            .map(|latency| latency.snapshot())
+            .unwrap_or_default();
         TaskMetricsSnapshot {
             open_orders: self.open_orders,
             position_qty: self.position_qty,
             last_heartbeat: self.last_heartbeat,
             last_price: self.last_price,
             last_update: self.last_update,
+            halt_reason: self.halt_reason.clone(),
+            fills: self.fills,
+            uptime_ratio: self.uptime_ratio,
+            uptime_ratio_1h: self.uptime_ratio_1h,
+            realized_pnl_usd: self.realized_pnl_usd,
+            session_realized_pnl_usd: self.session_realized_pnl_usd,
+            funding_rate: self.funding_rate,
+            leverage: self.leverage,
+            margin_mode: self.margin_mode,
+            last_order_latency: request_latency
+                .get(NEW_ORDER_ENDPOINT)
+                .map(|stats| stats.last),
+            request_latency,
+            deployed_usd: self.deployed_usd,
+            utilization_ratio: self.utilization_ratio,
+            uptime_below_min: self.uptime_below_min,
+            price_stale: self.price_stale,
         }
     }
 
@@ -56,4 +148,119 @@ impl TaskMetrics {
         self.last_price = Some(price);
         self.last_update = Some(Instant::now());
     }
+
+    pub fn record_halt(&mut self, reason: impl Into<String>) {
+        self.halt_reason = Some(reason.into());
+        self.last_update = Some(Instant::now());
+    }
+
+    pub fn clear_halt(&mut self) {
+        self.halt_reason = None;
+        self.last_update = Some(Instant::now());
+    }
+
+    pub fn record_fill(&mut self) {
+        self.fills += 1;
+        self.last_update = Some(Instant::now());
+    }
+
+    pub fn record_uptime_below_min(&mut self, below: bool) {
+        self.uptime_below_min = below;
+        self.last_update = Some(Instant::now());
+    }
+
+    pub fn record_price_stale(&mut self, stale: bool) {
+        self.price_stale = stale;
+        self.last_update = Some(Instant::now());
+    }
+
+    pub fn record_uptime_ratio(&mut self, uptime_ratio: Decimal, uptime_ratio_1h: Decimal) {
+        self.uptime_ratio = Some(uptime_ratio);
+        self.uptime_ratio_1h = Some(uptime_ratio_1h);
+        self.last_update = Some(Instant::now());
+    }
+
+    pub fn record_realized_pnl(&mut self, realized_pnl_usd: Decimal, session_pnl_usd: Decimal) {
+        self.realized_pnl_usd = realized_pnl_usd;
+        self.session_realized_pnl_usd = session_pnl_usd;
+        self.last_update = Some(Instant::now());
+    }
+
+    pub fn record_funding_rate(&mut self, funding_rate: Decimal) {
+        self.funding_rate = Some(funding_rate);
+        self.last_update = Some(Instant::now());
+    }
+
+    pub fn record_leverage(&mut self, leverage: u32) {
+        self.leverage = Some(leverage);
+        self.last_update = Some(Instant::now());
+    }
+
+    pub fn record_margin_mode(&mut self, margin_mode: MarginMode) {
+        self.margin_mode = Some(margin_mode);
+        self.last_update = Some(Instant::now());
+    }
+
+    /// Record how much of `budget_usd` is committed to quotes and position.
+    pub fn record_deployment(&mut self, deployed_usd: Decimal, budget_usd: Decimal) {
+        self.deployed_usd = Some(deployed_usd);
+        self.utilization_ratio = (budget_usd > Decimal::ZERO).then(|| deployed_usd / budget_usd);
+        self.last_update = Some(Instant::now());
+    }
+}
+
+/// Apply `task_id`'s events from the bus to `metrics` until every bus handle
+/// is gone; callers abort the handle when the task goes away.
+pub fn spawn_event_metrics(
+    mut subscription: EventSubscription,
+    task_id: String,
+    metrics: Arc<Mutex<TaskMetrics>>,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        while let Some(event) = subscription.recv().await {
+            match event {
+                RuntimeEvent::PriceStale { task_id: id, .. } if id == task_id => {
+                    metrics.lock().await.record_price_stale(true);
+                }
+                RuntimeEvent::PriceRecovered { task_id: id, .. } if id == task_id => {
+                    metrics.lock().await.record_price_stale(false);
+                }
+                _ => {}
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::EventBus;
+
+    #[tokio::test]
+    async fn event_metrics_track_stale_price_for_their_task() {
+        let bus = EventBus::default();
+        let metrics = Arc::new(Mutex::new(TaskMetrics::default()));
+        let handle = spawn_event_metrics(bus.subscribe(), "t1".to_string(), metrics.clone());
+
+        let stale = |task_id: &str| RuntimeEvent::PriceStale {
+            task_id: task_id.to_string(),
+            symbol: "BTC-USD".to_string(),
+            age_ms: 20_000,
+        };
+        bus.emit(stale("t2"));
+        bus.emit(stale("t1"));
+        drop(bus);
+        handle.await.unwrap();
+        assert!(metrics.lock().await.snapshot().price_stale);
+
+        let bus = EventBus::default();
+        let handle = spawn_event_metrics(bus.subscribe(), "t1".to_string(), metrics.clone());
+        bus.emit(RuntimeEvent::PriceRecovered {
+            task_id: "t1".to_string(),
+            symbol: "BTC-USD".to_string(),
+        });
+        drop(bus);
+        handle.await.unwrap();
+        assert!(!metrics.lock().await.snapshot().price_stale);
+    }
 }
@@ -3,6 +3,10 @@
 [OUTPUT]: Tracked order states, timeout results, and reconciliation summary.
 [POS]:    State layer - order lifecycle tracking and correlation.
 [UPDATE]: When order state transitions or external order schemas change.
+[UPDATE]: 2026-10-16 Add OrderRole classification from cl_ord_id prefixes
+[UPDATE]: 2026-10-17 Record in-place amended quantities
+[UPDATE]: 2026-10-17 Signal fills and cancels from WS updates to subscribers
+[UPDATE]: 2026-10-17 Classify sweep: client ids as OrderRole::Sweep
 */
 
 use std::collections::{HashMap, HashSet};
@@ -14,6 +18,7 @@ use rust_decimal::Decimal;
 use standx_point_adapter::types::enums::OrderStatus;
 use standx_point_adapter::types::models::Order;
 use standx_point_adapter::ws::message::OrderUpdateData;
+use tokio::sync::watch;
 
 /// Order state machine for tracking order lifecycle.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -49,6 +54,54 @@ impl OrderState {
     }
 }
 
+/// Order role derived from the client order id scheme.
+///
+/// Quotes use `mm:{symbol}:{side}:{tier}:{uuid}`, position guards use
+/// `pg:{symbol}:{side}:{uuid}`, taker inventory sweeps use
+/// `sweep:{symbol}:{side}:{uuid}`, and the `ex:` prefix is reserved for exits.
+/// Anything else (including orders without a client id) is treated as manual.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrderRole {
+    Quote,
+    Guard,
+    Exit,
+    Sweep,
+    Manual,
+}
+
+impl OrderRole {
+    /// Classify an order from its client order id.
+    pub fn from_cl_ord_id(cl_ord_id: Option<&str>) -> Self {
+        let Some(cl_ord_id) = cl_ord_id else {
+            return OrderRole::Manual;
+        };
+
+        match cl_ord_id.split_once(':').map(|(prefix, _)| prefix) {
+            Some("mm") => OrderRole::Quote,
+            Some("pg") => OrderRole::Guard,
+            Some("ex") => OrderRole::Exit,
+            Some("sweep") => OrderRole::Sweep,
+            _ => OrderRole::Manual,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderRole::Quote => "quote",
+            OrderRole::Guard => "guard",
+            OrderRole::Exit => "exit",
+            OrderRole::Sweep => "sweep",
+            OrderRole::Manual => "manual",
+        }
+    }
+}
+
+impl fmt::Display for OrderRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Tracked order metadata keyed by client order id.
 #[derive(Debug, Clone)]
 pub struct TrackedOrder {
@@ -156,6 +209,8 @@ pub struct OrderTracker {
     orders: HashMap<String, TrackedOrder>,
     order_id_index: HashMap<i64, String>,
     timeout: Duration,
+    /// Bumped whenever a WS update fills, cancels, or rejects an order
+    changes: watch::Sender<u64>,
 }
 
 impl OrderTracker {
@@ -170,6 +225,7 @@ impl OrderTracker {
             orders: HashMap::new(),
             order_id_index: HashMap::new(),
             timeout,
+            changes: watch::channel(0).0,
         }
     }
 
@@ -330,6 +386,32 @@ impl OrderTracker {
         }
     }
 
+    /// Record a new total quantity after an in-place amend of an unfilled order.
+    pub fn record_amended_qty(
+        &mut self,
+        cl_ord_id: &str,
+        qty: Decimal,
+    ) -> Result<(), OrderTrackerError> {
+        let tracked =
+            self.orders
+                .get_mut(cl_ord_id)
+                .ok_or_else(|| OrderTrackerError::UnknownClOrdId {
+                    cl_ord_id: cl_ord_id.to_string(),
+                })?;
+
+        match &tracked.state {
+            OrderState::Sent { .. } | OrderState::Acknowledged { .. } => {
+                tracked.total_qty = qty;
+                Ok(())
+            }
+            _ => Err(OrderTrackerError::InvalidTransition {
+                cl_ord_id: cl_ord_id.to_string(),
+                from: state_name(&tracked.state),
+                to: "Amended",
+            }),
+        }
+    }
+
     /// Mark an order as failed with a reason.
     pub fn mark_failed(
         &mut self,
@@ -361,6 +443,12 @@ impl OrderTracker {
         Ok(())
     }
 
+    /// Wakes whenever a WS update moves an order past acknowledgement
+    /// (partial fill, fill, cancel, or reject).
+    pub fn subscribe_changes(&self) -> watch::Receiver<u64> {
+        self.changes.subscribe()
+    }
+
     /// Handle a WebSocket order update and update local state.
     pub fn handle_ws_update(
         &mut self,
@@ -428,6 +516,9 @@ impl OrderTracker {
         };
 
         tracked.state = next_state.clone();
+        if !matches!(next_state, OrderState::Acknowledged { .. }) {
+            self.changes.send_modify(|version| *version += 1);
+        }
         Ok(next_state)
     }
 
@@ -671,6 +762,32 @@ mod tests {
         }
     }
 
+    #[test]
+    fn order_role_classifies_guard_and_quote_ids() {
+        assert_eq!(
+            OrderRole::from_cl_ord_id(Some("mm:BTC-USD:bid:l1:0b7c")),
+            OrderRole::Quote
+        );
+        assert_eq!(
+            OrderRole::from_cl_ord_id(Some("pg:BTC-USD:sell:0b7c")),
+            OrderRole::Guard
+        );
+        assert_eq!(
+            OrderRole::from_cl_ord_id(Some("ex:BTC-USD:sell:0b7c")),
+            OrderRole::Exit
+        );
+        assert_eq!(
+            OrderRole::from_cl_ord_id(Some("sweep:BTC-USD:sell:0b7c")),
+            OrderRole::Sweep
+        );
+        assert_eq!(
+            OrderRole::from_cl_ord_id(Some("mmx:BTC-USD")),
+            OrderRole::Manual
+        );
+        assert_eq!(OrderRole::from_cl_ord_id(None), OrderRole::Manual);
+        assert_eq!(OrderRole::Guard.to_string(), "guard");
+    }
+
     #[test]
     fn rejects_duplicate_cl_ord_id() {
         let now = Instant::now();
@@ -728,6 +845,7 @@ mod tests {
             fill_qty: "4".to_string(),
             price: "1".to_string(),
             order_type: "limit".to_string(),
+            cl_ord_id: None,
         };
 
         tracker
@@ -746,6 +864,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn ws_fill_update_transitions_state_and_signals_subscribers() {
+        let now = Instant::now();
+        let mut tracker = OrderTracker::new();
+        let mut changes = tracker.subscribe_changes();
+
+        tracker
+            .register_pending("mm:BTC-USD:bid:l1:1".to_string(), decimal("2"), now)
+            .expect("register pending");
+        tracker
+            .mark_sent("mm:BTC-USD:bid:l1:1", now)
+            .expect("mark sent");
+        tracker
+            .acknowledge("mm:BTC-USD:bid:l1:1", 7, now)
+            .expect("acknowledge");
+
+        let mut update = OrderUpdateData {
+            id: 7,
+            symbol: "BTC-USD".to_string(),
+            side: "buy".to_string(),
+            status: "open".to_string(),
+            qty: "2".to_string(),
+            fill_qty: "0".to_string(),
+            price: "100".to_string(),
+            order_type: "limit".to_string(),
+            cl_ord_id: Some("mm:BTC-USD:bid:l1:1".to_string()),
+        };
+        tracker.handle_ws_update(&update, now).expect("ack update");
+        assert!(!changes.has_changed().unwrap());
+
+        update.status = "filled".to_string();
+        update.fill_qty = "2".to_string();
+        let state = tracker
+            .handle_ws_update(&update, now + Duration::from_millis(5))
+            .expect("fill update");
+        assert!(matches!(state, OrderState::Filled { .. }));
+        assert!(matches!(
+            tracker.state("mm:BTC-USD:bid:l1:1"),
+            Some(OrderState::Filled { .. })
+        ));
+        assert!(changes.has_changed().unwrap());
+        assert_eq!(*changes.borrow_and_update(), 1);
+    }
+
     #[test]
     fn reconcile_updates_and_marks_missing_orders() {
         let now = Instant::now();
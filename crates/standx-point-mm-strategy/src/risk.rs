@@ -3,11 +3,17 @@
 [OUTPUT]: RiskState (Safe/Caution/Halt) with guard reasons.
 [POS]:    Risk layer - safety guards and trading throttles.
 [UPDATE]: When guard logic or risk thresholds change.
+[UPDATE]: 2026-10-16 Add daily realized-loss circuit breaker (UTC day).
+[UPDATE]: 2026-10-16 Extract PositionLedger average-cost PnL for reuse by the fill log.
+[UPDATE]: 2026-10-17 Keep session realized PnL alongside the daily figure.
+[UPDATE]: 2026-10-17 Book PnL from fill prices net of fees; sync_position only re-anchors size.
+[UPDATE]: 2026-10-17 Replace sync_position with book_position_drift, booking drift at mark.
 */
 
 use std::collections::VecDeque;
 use std::time::{Duration, Instant};
 
+use chrono::{DateTime, NaiveDate, Utc};
 use rust_decimal::Decimal;
 use standx_point_adapter::types::models::{DepthBook, DepthLevel, Position};
 
@@ -29,6 +35,77 @@ pub struct RiskManager {
     max_spread_bps: Decimal,
     price_history: VecDeque<(Instant, Decimal)>,
     fills_history: VecDeque<Instant>,
+    daily_loss_limit_usd: Option<Decimal>,
+    daily_pnl: DailyPnl,
+    session_realized_usd: Decimal,
+}
+
+/// Realized PnL for the current UTC day, kept with average-cost accounting.
+#[derive(Debug, Clone, Default)]
+struct DailyPnl {
+    day: Option<NaiveDate>,
+    realized_usd: Decimal,
+    ledger: PositionLedger,
+    halted: bool,
+}
+
+/// Average-cost position that realizes PnL on the part of a fill that
+/// reduces it; a fill that flips the side reopens at the fill price.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct PositionLedger {
+    position_qty: Decimal,
+    avg_entry_price: Decimal,
+}
+
+impl PositionLedger {
+    pub fn new(position_qty: Decimal, avg_entry_price: Decimal) -> Self {
+        Self {
+            position_qty,
+            avg_entry_price: if position_qty.is_zero() {
+                Decimal::ZERO
+            } else {
+                avg_entry_price
+            },
+        }
+    }
+
+    pub fn position_qty(&self) -> Decimal {
+        self.position_qty
+    }
+
+    pub fn avg_entry_price(&self) -> Decimal {
+        self.avg_entry_price
+    }
+
+    /// Apply a fill (`signed_qty` > 0 buys) and return the PnL it realized.
+    pub fn apply(&mut self, signed_qty: Decimal, price: Decimal) -> Decimal {
+        if signed_qty.is_zero() || price <= Decimal::ZERO {
+            return Decimal::ZERO;
+        }
+
+        let held = self.position_qty;
+        let next = held + signed_qty;
+        let mut realized = Decimal::ZERO;
+        if held.is_zero() || held.is_sign_positive() == signed_qty.is_sign_positive() {
+            self.avg_entry_price =
+                (self.avg_entry_price * held.abs() + price * signed_qty.abs()) / next.abs();
+        } else {
+            let closed = decimal_min(signed_qty.abs(), held.abs());
+            let direction = if held.is_sign_positive() {
+                Decimal::ONE
+            } else {
+                -Decimal::ONE
+            };
+            realized = (price - self.avg_entry_price) * closed * direction;
+            if next.is_zero() {
+                self.avg_entry_price = Decimal::ZERO;
+            } else if next.is_sign_positive() != held.is_sign_positive() {
+                self.avg_entry_price = price;
+            }
+        }
+        self.position_qty = next;
+        realized
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -65,6 +142,103 @@ impl RiskManager {
             max_spread_bps,
             price_history: VecDeque::new(),
             fills_history: VecDeque::new(),
+            daily_loss_limit_usd: None,
+            daily_pnl: DailyPnl::default(),
+            session_realized_usd: Decimal::ZERO,
+        }
+    }
+
+    /// Halt once realized losses since UTC midnight reach `limit_usd`.
+    pub fn set_daily_loss_limit(&mut self, limit_usd: Option<Decimal>) {
+        self.daily_loss_limit_usd = limit_usd.filter(|limit| *limit > Decimal::ZERO);
+    }
+
+    /// Start PnL accounting from an existing position.
+    pub fn seed_position(&mut self, qty: Decimal, avg_entry_price: Decimal) {
+        self.daily_pnl.ledger = PositionLedger::new(qty, avg_entry_price);
+    }
+
+    /// Record a fill (`signed_qty` > 0 buys) at its own price and realize PnL
+    /// on the part that reduces the tracked position, net of `fee`.
+    pub fn record_trade(
+        &mut self,
+        now: DateTime<Utc>,
+        signed_qty: Decimal,
+        price: Decimal,
+        fee: Decimal,
+    ) {
+        self.roll_daily_pnl(now);
+        let pnl = &mut self.daily_pnl;
+        let realized = pnl.ledger.apply(signed_qty, price) - fee;
+        pnl.realized_usd += realized;
+        self.session_realized_usd += realized;
+
+        if let Some(limit) = self.daily_loss_limit_usd
+            && -pnl.realized_usd >= limit
+        {
+            pnl.halted = true;
+        }
+    }
+
+    /// Book a change to the tracked position that no recorded fill explains
+    /// (missed fills, outside trades) as a fee-free trade at `mark_price`, so
+    /// the drift moves realized PnL instead of vanishing. Ignored until a
+    /// positive mark is available.
+    pub fn book_position_drift(
+        &mut self,
+        now: DateTime<Utc>,
+        actual_qty: Decimal,
+        mark_price: Decimal,
+    ) {
+        let drift = actual_qty - self.daily_pnl.ledger.position_qty();
+        if drift.is_zero() || mark_price <= Decimal::ZERO {
+            return;
+        }
+        self.record_trade(now, drift, mark_price, Decimal::ZERO);
+    }
+
+    /// Position the PnL ledger currently tracks.
+    pub fn tracked_position_qty(&self) -> Decimal {
+        self.daily_pnl.ledger.position_qty()
+    }
+
+    /// Realized PnL since the last UTC midnight (or manual resume).
+    pub fn realized_pnl_today(&self) -> Decimal {
+        self.daily_pnl.realized_usd
+    }
+
+    /// Realized PnL since this manager was created; never rolls over.
+    pub fn realized_pnl_session(&self) -> Decimal {
+        self.session_realized_usd
+    }
+
+    /// Reason quoting is halted by the daily loss limit, if it is.
+    ///
+    /// The halt clears itself at the next UTC midnight.
+    pub fn daily_loss_halt(&mut self, now: DateTime<Utc>) -> Option<String> {
+        self.roll_daily_pnl(now);
+        if !self.daily_pnl.halted {
+            return None;
+        }
+        Some(format!(
+            "daily realized loss {} reached daily_loss_limit_usd {}",
+            -self.daily_pnl.realized_usd,
+            self.daily_loss_limit_usd.unwrap_or_default()
+        ))
+    }
+
+    /// Manually lift a daily loss halt, starting a fresh loss allowance.
+    pub fn resume_daily_loss(&mut self) {
+        self.daily_pnl.halted = false;
+        self.daily_pnl.realized_usd = Decimal::ZERO;
+    }
+
+    fn roll_daily_pnl(&mut self, now: DateTime<Utc>) {
+        let today = now.date_naive();
+        if self.daily_pnl.day != Some(today) {
+            self.daily_pnl.day = Some(today);
+            self.daily_pnl.realized_usd = Decimal::ZERO;
+            self.daily_pnl.halted = false;
         }
     }
 
@@ -290,6 +464,10 @@ fn decimal_max(a: Decimal, b: Decimal) -> Decimal {
     if a >= b { a } else { b }
 }
 
+fn decimal_min(a: Decimal, b: Decimal) -> Decimal {
+    if a <= b { a } else { b }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -401,4 +579,75 @@ mod tests {
         let state = manager.assess(Instant::now(), None, None);
         assert_eq!(state, RiskState::Safe);
     }
+
+    fn utc(raw: &str) -> DateTime<Utc> {
+        raw.parse().expect("valid timestamp")
+    }
+
+    #[test]
+    fn daily_loss_limit_halts_until_utc_midnight() {
+        let mut manager = RiskManager::new();
+        manager.set_daily_loss_limit(Some(dec("50")));
+
+        let evening = utc("2026-10-16T23:00:00Z");
+        manager.record_trade(evening, dec("2"), dec("100"), Decimal::ZERO);
+        manager.record_trade(evening, dec("-1"), dec("80"), Decimal::ZERO);
+        assert_eq!(manager.realized_pnl_today(), dec("-20"));
+        assert_eq!(manager.daily_loss_halt(evening), None);
+
+        let late = utc("2026-10-16T23:59:59Z");
+        manager.record_trade(late, dec("-1"), dec("70"), Decimal::ZERO);
+        assert_eq!(manager.realized_pnl_today(), dec("-50"));
+        let reason = manager.daily_loss_halt(late).expect("halted");
+        assert!(reason.contains("daily_loss_limit_usd 50"), "{reason}");
+
+        let next_day = utc("2026-10-17T00:00:01Z");
+        assert_eq!(manager.daily_loss_halt(next_day), None);
+        assert_eq!(manager.realized_pnl_today(), Decimal::ZERO);
+        assert_eq!(manager.realized_pnl_session(), dec("-50"));
+    }
+
+    #[test]
+    fn daily_pnl_realizes_short_covers_and_flips() {
+        let mut manager = RiskManager::new();
+        manager.set_daily_loss_limit(Some(dec("10")));
+        let now = utc("2026-10-16T12:00:00Z");
+
+        manager.seed_position(dec("-1"), dec("100"));
+        manager.record_trade(now, dec("3"), dec("90"), dec("0.5"));
+        assert_eq!(manager.realized_pnl_today(), dec("9.5"));
+
+        // Now long 2 @ 90; the fee of a loss-making close counts too.
+        manager.record_trade(now, dec("-2"), dec("84"), dec("0.5"));
+        assert_eq!(manager.realized_pnl_today(), dec("-3"));
+        assert_eq!(manager.daily_loss_halt(now), None);
+
+        manager.record_trade(now, dec("1"), dec("100"), Decimal::ZERO);
+        manager.record_trade(now, dec("-1"), dec("93"), Decimal::ZERO);
+        assert!(manager.daily_loss_halt(now).is_some());
+
+        manager.resume_daily_loss();
+        assert_eq!(manager.daily_loss_halt(now), None);
+    }
+
+    #[test]
+    fn position_drift_is_booked_at_mark() {
+        let mut manager = RiskManager::new();
+        let now = utc("2026-10-16T12:00:00Z");
+        manager.record_trade(now, dec("2"), dec("90"), Decimal::ZERO);
+
+        // A close the fill stream missed realizes its loss at the mark.
+        manager.book_position_drift(now, Decimal::ZERO, dec("84"));
+        assert_eq!(manager.tracked_position_qty(), Decimal::ZERO);
+        assert_eq!(manager.realized_pnl_today(), dec("-12"));
+
+        // Without a usable mark the drift waits for the next attempt.
+        manager.book_position_drift(now, dec("-1"), Decimal::ZERO);
+        assert_eq!(manager.tracked_position_qty(), Decimal::ZERO);
+
+        // Drift that opens a position enters it at the mark.
+        manager.book_position_drift(now, dec("-1"), dec("80"));
+        manager.record_trade(now, dec("1"), dec("75"), Decimal::ZERO);
+        assert_eq!(manager.realized_pnl_session(), dec("-7"));
+    }
 }
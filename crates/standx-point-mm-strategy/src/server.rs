@@ -0,0 +1,586 @@
+/*
+[INPUT]:  Listener addresses for auxiliary endpoints (metrics/control/health)
+[OUTPUT]: Bound TCP listeners or a startup error naming the address; Prometheus /metrics server;
+          /healthz and /readyz probes
+[POS]:    Runtime layer - auxiliary listener setup before tasks spawn
+[UPDATE]: When adding auxiliary endpoints or changing bind semantics
+[UPDATE]: 2026-10-16 Add bind_listener with clear startup errors
+[UPDATE]: 2026-10-16 Serve per-task metrics in Prometheus text format
+[UPDATE]: 2026-10-16 Export the funding rate gauge
+[UPDATE]: 2026-10-17 Serve /healthz and /readyz liveness/readiness probes
+[UPDATE]: 2026-10-17 Export the last order round-trip latency gauge
+[UPDATE]: 2026-10-17 Export the rolling 1h uptime ratio gauge
+[UPDATE]: 2026-10-17 Export the stale price feed gauge
+*/
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write as _;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::{Context as _, Result, anyhow};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{Mutex, watch};
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+use crate::config::TaskConfig;
+use crate::market_data::ConnectionState;
+use crate::metrics::TaskMetricsSnapshot;
+use crate::task::{TaskManager, TaskRuntimeStatus};
+
+/// Largest request head accepted by the auxiliary servers.
+const MAX_REQUEST_BYTES: usize = 8 * 1024;
+/// Time a client gets to send its request head.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+const JSON_CONTENT_TYPE: &str = "application/json";
+
+/// Bind an auxiliary listener, failing with the listener name and address.
+///
+/// Call this before spawning tasks so a port conflict aborts startup instead
+/// of leaving the process half-started. Port 0 binds an OS-assigned port,
+/// which is logged so operators can find it.
+pub async fn bind_listener(name: &str, addr: SocketAddr) -> Result<TcpListener> {
+    let listener = TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("failed to bind {name} listener on {addr}"))?;
+
+    let local_addr = listener
+        .local_addr()
+        .with_context(|| format!("failed to read {name} listener address"))?;
+    tracing::info!(listener = %name, requested = %addr, bound = %local_addr, "listener bound");
+
+    Ok(listener)
+}
+
+/// Serve `GET /metrics` from `listener` until `shutdown` fires.
+///
+/// Every scrape reads a fresh [`TaskManager::task_metrics_snapshot`]; other
+/// paths get 404. Connections are handled one request each and closed.
+pub fn spawn_metrics_server(
+    listener: TcpListener,
+    task_manager: Arc<Mutex<TaskManager>>,
+    shutdown: CancellationToken,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            let stream = tokio::select! {
+                accepted = listener.accept() => accepted,
+                _ = shutdown.cancelled() => break,
+            };
+            match stream {
+                Ok((stream, peer)) => {
+                    let task_manager = task_manager.clone();
+                    tokio::spawn(async move {
+                        if let Err(err) = serve_metrics_request(stream, &task_manager).await {
+                            tracing::debug!(peer = %peer, "metrics request failed: {err:#}");
+                        }
+                    });
+                }
+                Err(err) => tracing::warn!(error = %err, "metrics listener accept failed"),
+            }
+        }
+    })
+}
+
+async fn serve_metrics_request(
+    mut stream: TcpStream,
+    task_manager: &Mutex<TaskManager>,
+) -> Result<()> {
+    let head = tokio::time::timeout(REQUEST_TIMEOUT, read_request_head(&mut stream))
+        .await
+        .context("timed out reading request")??;
+
+    let (status, content_type, body) = match request_line(&head) {
+        (Some("GET"), Some("/metrics")) => {
+            let (configs, metrics) = {
+                let manager = task_manager.lock().await;
+                (
+                    manager.task_config_snapshot(),
+                    manager.task_metrics_snapshot().await,
+                )
+            };
+            (
+                "200 OK",
+                PROMETHEUS_CONTENT_TYPE,
+                render_prometheus(&configs, &metrics),
+            )
+        }
+        (method, _) => fallback_response(method),
+    };
+    write_response(stream, status, content_type, &body).await
+}
+
+/// Serve `GET /healthz` and `GET /readyz` from `listener` until `shutdown` fires.
+///
+/// `/healthz` answers 200 whenever the process can accept connections.
+/// `/readyz` answers 200 only when at least one task is configured, every
+/// task reports [`TaskRuntimeStatus::Running`], and the market data
+/// WebSocket is connected; otherwise 503. Both carry a small JSON body with
+/// per-task status so probes and operators see why a pod is not ready.
+pub fn spawn_health_server(
+    listener: TcpListener,
+    task_manager: Arc<Mutex<TaskManager>>,
+    connection_state: watch::Receiver<ConnectionState>,
+    shutdown: CancellationToken,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        loop {
+            let stream = tokio::select! {
+                accepted = listener.accept() => accepted,
+                _ = shutdown.cancelled() => break,
+            };
+            match stream {
+                Ok((stream, peer)) => {
+                    let task_manager = task_manager.clone();
+                    let connection_state = connection_state.clone();
+                    tokio::spawn(async move {
+                        if let Err(err) =
+                            serve_health_request(stream, &task_manager, &connection_state).await
+                        {
+                            tracing::debug!(peer = %peer, "health request failed: {err:#}");
+                        }
+                    });
+                }
+                Err(err) => tracing::warn!(error = %err, "health listener accept failed"),
+            }
+        }
+    })
+}
+
+async fn serve_health_request(
+    mut stream: TcpStream,
+    task_manager: &Mutex<TaskManager>,
+    connection_state: &watch::Receiver<ConnectionState>,
+) -> Result<()> {
+    let head = tokio::time::timeout(REQUEST_TIMEOUT, read_request_head(&mut stream))
+        .await
+        .context("timed out reading request")??;
+
+    let (status, content_type, body) = match request_line(&head) {
+        (Some("GET"), Some("/healthz")) => (
+            "200 OK",
+            JSON_CONTENT_TYPE,
+            "{\"status\":\"ok\"}\n".to_string(),
+        ),
+        (Some("GET"), Some("/readyz")) => {
+            let statuses = task_manager.lock().await.runtime_status_snapshot();
+            let connection = connection_state.borrow().clone();
+            let (ready, body) = render_readiness(&statuses, &connection);
+            let status = if ready {
+                "200 OK"
+            } else {
+                "503 Service Unavailable"
+            };
+            (status, JSON_CONTENT_TYPE, body)
+        }
+        (method, _) => fallback_response(method),
+    };
+    write_response(stream, status, content_type, &body).await
+}
+
+/// Decide readiness and render the `/readyz` JSON body.
+///
+/// Tasks are keyed by id in sorted order, e.g.
+/// `{"ready":false,"ws":"disconnected","tasks":{"t1":"running"}}`.
+pub fn render_readiness(
+    statuses: &HashMap<String, TaskRuntimeStatus>,
+    connection: &ConnectionState,
+) -> (bool, String) {
+    let ws_connected = *connection == ConnectionState::Connected;
+    let ready = ws_connected
+        && !statuses.is_empty()
+        && statuses
+            .values()
+            .all(|status| *status == TaskRuntimeStatus::Running);
+
+    let tasks: BTreeMap<&str, &str> = statuses
+        .iter()
+        .map(|(task_id, status)| (task_id.as_str(), runtime_status_label(*status)))
+        .collect();
+    let ws = match connection {
+        ConnectionState::Connected => "connected",
+        ConnectionState::Disconnected { .. } => "disconnected",
+        ConnectionState::Paused => "reconnecting",
+    };
+    let body = serde_json::json!({ "ready": ready, "ws": ws, "tasks": tasks });
+    (ready, format!("{body}\n"))
+}
+
+fn runtime_status_label(status: TaskRuntimeStatus) -> &'static str {
+    match status {
+        TaskRuntimeStatus::Running => "running",
+        TaskRuntimeStatus::Paused => "paused",
+        TaskRuntimeStatus::Restarting => "restarting",
+        TaskRuntimeStatus::Failed => "failed",
+        TaskRuntimeStatus::Finished => "finished",
+    }
+}
+
+/// Method and query-less path from the request line.
+fn request_line(head: &str) -> (Option<&str>, Option<&str>) {
+    let mut parts = head.lines().next().unwrap_or_default().split_whitespace();
+    let (method, target) = (parts.next(), parts.next());
+    (
+        method,
+        target.map(|target| target.split('?').next().unwrap_or_default()),
+    )
+}
+
+fn fallback_response(method: Option<&str>) -> (&'static str, &'static str, String) {
+    match method {
+        Some("GET") => ("404 Not Found", "text/plain", "not found\n".to_string()),
+        _ => (
+            "405 Method Not Allowed",
+            "text/plain",
+            "method not allowed\n".to_string(),
+        ),
+    }
+}
+
+async fn write_response(
+    mut stream: TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &str,
+) -> Result<()> {
+    let response = format!(
+        "HTTP/1.1 {status}\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    stream
+        .write_all(response.as_bytes())
+        .await
+        .context("write response")?;
+    stream.shutdown().await.context("close connection")
+}
+
+async fn read_request_head(stream: &mut TcpStream) -> Result<String> {
+    let mut buf = Vec::with_capacity(1024);
+    let mut chunk = [0u8; 1024];
+    while !buf.windows(4).any(|window| window == b"\r\n\r\n") {
+        if buf.len() >= MAX_REQUEST_BYTES {
+            return Err(anyhow!("request head exceeds {MAX_REQUEST_BYTES} bytes"));
+        }
+        let read = stream.read(&mut chunk).await.context("read request")?;
+        if read == 0 {
+            break;
+        }
+        buf.extend_from_slice(&chunk[..read]);
+    }
+    Ok(String::from_utf8_lossy(&buf).into_owned())
+}
+
+/// Render task metrics in the Prometheus text exposition format.
+///
+/// Each series is labelled with `task_id` and `symbol`; tasks are emitted in
+/// task-id order so consecutive scrapes diff cleanly. The uptime ratios are
+/// omitted for a task until its first strategy heartbeat.
+pub fn render_prometheus(
+    configs: &HashMap<String, TaskConfig>,
+    metrics: &HashMap<String, TaskMetricsSnapshot>,
+) -> String {
+    let mut tasks: Vec<(&str, &str, &TaskMetricsSnapshot)> = metrics
+        .iter()
+        .map(|(task_id, snapshot)| {
+            let symbol = configs
+                .get(task_id)
+                .map_or("", |config| config.symbol.as_str());
+            (task_id.as_str(), symbol, snapshot)
+        })
+        .collect();
+    tasks.sort_unstable_by_key(|(task_id, _, _)| *task_id);
+
+    type Sample = fn(&TaskMetricsSnapshot) -> Option<String>;
+    let families: [(&str, &str, &str, Sample); 9] = [
+        (
+            "standx_mm_open_orders",
+            "gauge",
+            "Open orders tracked for the task.",
+            |m| Some(m.open_orders.to_string()),
+        ),
+        (
+            "standx_mm_position_qty",
+            "gauge",
+            "Signed position quantity (positive is long).",
+            |m| Some(m.position_qty.normalize().to_string()),
+        ),
+        (
+            "standx_mm_uptime_ratio",
+            "gauge",
+            "Share of time spent quoting both sides within the reward band.",
+            |m| m.uptime_ratio.map(|ratio| ratio.normalize().to_string()),
+        ),
+        (
+            "standx_mm_uptime_ratio_1h",
+            "gauge",
+            "Share of the last hour spent quoting both sides within the reward band.",
+            |m| m.uptime_ratio_1h.map(|ratio| ratio.normalize().to_string()),
+        ),
+        (
+            "standx_mm_fills_total",
+            "counter",
+            "Quote fills observed since the task started.",
+            |m| Some(m.fills.to_string()),
+        ),
+        (
+            "standx_mm_realized_pnl_usd",
+            "gauge",
+            "Realized PnL for the current UTC day in USD, excluding fees.",
+            |m| Some(m.realized_pnl_usd.normalize().to_string()),
+        ),
+        (
+            "standx_mm_price_stale",
+            "gauge",
+            "1 while the price feed is stale and quotes are pulled, else 0.",
+            |m| Some(u8::from(m.price_stale).to_string()),
+        ),
+        (
+            "standx_mm_funding_rate",
+            "gauge",
+            "Latest funding rate per interval for the symbol.",
+            |m| m.funding_rate.map(|rate| rate.normalize().to_string()),
+        ),
+        (
+            "standx_mm_last_order_latency_seconds",
+            "gauge",
+            "Round-trip time of the most recent new_order request.",
+            |m| {
+                m.last_order_latency
+                    .map(|latency| latency.as_secs_f64().to_string())
+            },
+        ),
+    ];
+
+    let mut out = String::new();
+    for (name, kind, help, sample) in families {
+        let _ = writeln!(out, "# HELP {name} {help}");
+        let _ = writeln!(out, "# TYPE {name} {kind}");
+        for (task_id, symbol, snapshot) in &tasks {
+            if let Some(value) = sample(snapshot) {
+                let _ = writeln!(
+                    out,
+                    "{name}{{task_id=\"{}\",symbol=\"{}\"}} {value}",
+                    escape_label(task_id),
+                    escape_label(symbol)
+                );
+            }
+        }
+    }
+    out
+}
+
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn bind_listener_reports_conflicting_address() {
+        let first = bind_listener("metrics", "127.0.0.1:0".parse().unwrap())
+            .await
+            .expect("bind os-assigned port");
+        let taken = first.local_addr().unwrap();
+        assert_ne!(taken.port(), 0);
+
+        let err = bind_listener("control", taken)
+            .await
+            .expect_err("second bind should fail");
+        let message = format!("{err:#}");
+        assert!(message.contains("control"));
+        assert!(message.contains(&taken.to_string()));
+    }
+
+    fn task_config(id: &str, symbol: &str) -> TaskConfig {
+        TaskConfig {
+            id: id.to_string(),
+            symbol: symbol.to_string(),
+            symbols: Vec::new(),
+            quoting: None,
+            leverage: None,
+            margin_mode: None,
+            account_id: "a1".to_string(),
+            risk: crate::config::RiskConfig::default(),
+        }
+    }
+
+    fn snapshot(open_orders: usize, position_qty: &str) -> TaskMetricsSnapshot {
+        TaskMetricsSnapshot {
+            open_orders,
+            position_qty: position_qty.parse().unwrap(),
+            last_heartbeat: None,
+            last_price: None,
+            last_update: None,
+            halt_reason: None,
+            fills: 3,
+            uptime_ratio: None,
+            uptime_ratio_1h: None,
+            realized_pnl_usd: "-1.50".parse().unwrap(),
+            session_realized_pnl_usd: "-1.50".parse().unwrap(),
+            funding_rate: None,
+            leverage: None,
+            margin_mode: None,
+            last_order_latency: None,
+            request_latency: Default::default(),
+            deployed_usd: None,
+            utilization_ratio: None,
+            uptime_below_min: false,
+            price_stale: false,
+        }
+    }
+
+    #[test]
+    fn prometheus_output_labels_series_by_task_and_symbol() {
+        let configs = HashMap::from([
+            ("t2".to_string(), task_config("t2", "ETH-USD")),
+            ("t1".to_string(), task_config("t1", "BTC-USD")),
+        ]);
+        let mut ready = snapshot(4, "0.010");
+        ready.uptime_ratio = Some("0.75".parse().unwrap());
+        ready.uptime_ratio_1h = Some("0.5".parse().unwrap());
+        ready.last_order_latency = Some(std::time::Duration::from_millis(42));
+        let mut stale = snapshot(0, "-2");
+        stale.price_stale = true;
+        let metrics = HashMap::from([("t2".to_string(), stale), ("t1".to_string(), ready)]);
+
+        let output = render_prometheus(&configs, &metrics);
+
+        assert!(output.contains("# TYPE standx_mm_fills_total counter\n"));
+        assert!(output.contains("standx_mm_open_orders{task_id=\"t1\",symbol=\"BTC-USD\"} 4\n"));
+        assert!(
+            output.contains("standx_mm_position_qty{task_id=\"t1\",symbol=\"BTC-USD\"} 0.01\n")
+        );
+        assert!(output.contains("standx_mm_position_qty{task_id=\"t2\",symbol=\"ETH-USD\"} -2\n"));
+        assert!(
+            output.contains("standx_mm_uptime_ratio{task_id=\"t1\",symbol=\"BTC-USD\"} 0.75\n")
+        );
+        assert!(!output.contains("standx_mm_uptime_ratio{task_id=\"t2\""));
+        assert!(
+            output.contains("standx_mm_uptime_ratio_1h{task_id=\"t1\",symbol=\"BTC-USD\"} 0.5\n")
+        );
+        assert!(output.contains(
+            "standx_mm_last_order_latency_seconds{task_id=\"t1\",symbol=\"BTC-USD\"} 0.042\n"
+        ));
+        assert!(!output.contains("standx_mm_last_order_latency_seconds{task_id=\"t2\""));
+        assert!(output.contains("standx_mm_price_stale{task_id=\"t1\",symbol=\"BTC-USD\"} 0\n"));
+        assert!(output.contains("standx_mm_price_stale{task_id=\"t2\",symbol=\"ETH-USD\"} 1\n"));
+        assert!(
+            output.contains("standx_mm_realized_pnl_usd{task_id=\"t2\",symbol=\"ETH-USD\"} -1.5\n")
+        );
+        let t1 = output.find("task_id=\"t1\"").unwrap();
+        let t2 = output.find("task_id=\"t2\"").unwrap();
+        assert!(t1 < t2);
+        assert_eq!(escape_label("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+
+    #[tokio::test]
+    async fn metrics_server_answers_scrapes_until_shutdown() {
+        let listener = bind_listener("metrics", "127.0.0.1:0".parse().unwrap())
+            .await
+            .unwrap();
+        let addr = listener.local_addr().unwrap();
+        let shutdown = CancellationToken::new();
+        let server = spawn_metrics_server(
+            listener,
+            Arc::new(Mutex::new(TaskManager::new())),
+            shutdown.clone(),
+        );
+
+        async fn get(addr: SocketAddr, path: &str) -> String {
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            let request = format!("GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n");
+            stream.write_all(request.as_bytes()).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        }
+
+        let scrape = get(addr, "/metrics").await;
+        assert!(scrape.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(scrape.contains(PROMETHEUS_CONTENT_TYPE));
+        assert!(scrape.contains("# TYPE standx_mm_open_orders gauge"));
+        assert!(get(addr, "/").await.starts_with("HTTP/1.1 404"));
+
+        shutdown.cancel();
+        server.await.unwrap();
+    }
+
+    #[test]
+    fn readiness_requires_running_tasks_and_connected_ws() {
+        let running = HashMap::from([
+            ("t2".to_string(), TaskRuntimeStatus::Running),
+            ("t1".to_string(), TaskRuntimeStatus::Running),
+        ]);
+        let (ready, body) = render_readiness(&running, &ConnectionState::Connected);
+        assert!(ready);
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&body).unwrap(),
+            serde_json::json!({
+                "ready": true,
+                "ws": "connected",
+                "tasks": { "t1": "running", "t2": "running" },
+            })
+        );
+
+        let (ready, _) =
+            render_readiness(&running, &ConnectionState::Disconnected { retry_count: 2 });
+        assert!(!ready);
+
+        let mut paused = running.clone();
+        paused.insert("t2".to_string(), TaskRuntimeStatus::Paused);
+        let (ready, body) = render_readiness(&paused, &ConnectionState::Connected);
+        assert!(!ready);
+        assert!(body.contains("\"t2\":\"paused\""));
+
+        let (ready, _) = render_readiness(&HashMap::new(), &ConnectionState::Connected);
+        assert!(!ready);
+    }
+
+    #[tokio::test]
+    async fn health_server_reports_liveness_and_readiness() {
+        let listener = bind_listener("health", "127.0.0.1:0".parse().unwrap())
+            .await
+            .unwrap();
+        let addr = listener.local_addr().unwrap();
+        let shutdown = CancellationToken::new();
+        let (_state_tx, state_rx) = watch::channel(ConnectionState::Connected);
+        let server = spawn_health_server(
+            listener,
+            Arc::new(Mutex::new(TaskManager::new())),
+            state_rx,
+            shutdown.clone(),
+        );
+
+        async fn get(addr: SocketAddr, path: &str) -> String {
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            let request = format!("GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n");
+            stream.write_all(request.as_bytes()).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        }
+
+        assert!(
+            get(addr, "/healthz")
+                .await
+                .starts_with("HTTP/1.1 200 OK\r\n")
+        );
+        let readyz = get(addr, "/readyz").await;
+        assert!(readyz.starts_with("HTTP/1.1 503"), "{readyz}");
+        assert!(readyz.contains(JSON_CONTENT_TYPE));
+        assert!(readyz.contains("\"ready\":false"));
+        assert!(get(addr, "/metrics").await.starts_with("HTTP/1.1 404"));
+
+        shutdown.cancel();
+        server.await.unwrap();
+    }
+}
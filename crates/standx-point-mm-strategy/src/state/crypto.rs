@@ -0,0 +1,166 @@
+/*
+[INPUT]:  Storage passphrase (STANDX_MM_STORAGE_PASSPHRASE) and credential strings
+[OUTPUT]: AES-256-GCM encrypted credential values and their decryption
+[POS]:    State layer - at-rest encryption for stored account credentials
+[UPDATE]: 2026-10-17 Add Argon2id + AES-GCM credential cipher
+*/
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use aes_gcm::aead::{Aead, KeyInit, Payload};
+use aes_gcm::{Aes256Gcm, Nonce};
+use anyhow::{Result, anyhow};
+use argon2::Argon2;
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
+use zeroize::Zeroizing;
+
+/// Env var holding the passphrase that encrypts stored credentials.
+pub const PASSPHRASE_ENV: &str = "STANDX_MM_STORAGE_PASSPHRASE";
+
+/// Marks an encrypted value: `enc:v1:<salt>:<nonce>:<ciphertext>`, base64 parts.
+const PREFIX: &str = "enc:v1:";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 12;
+
+/// Encrypts credential strings with a key derived from a passphrase.
+///
+/// The salt is stored with every value so a file can be decrypted on its own;
+/// all values written by one cipher share a salt, so the (deliberately slow)
+/// Argon2id derivation runs once per salt rather than once per field.
+pub struct CredentialCipher {
+    passphrase: Zeroizing<String>,
+    salt: [u8; SALT_LEN],
+    keys: Mutex<HashMap<[u8; SALT_LEN], Zeroizing<[u8; 32]>>>,
+}
+
+impl std::fmt::Debug for CredentialCipher {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("CredentialCipher").finish_non_exhaustive()
+    }
+}
+
+impl CredentialCipher {
+    /// Empty passphrases are rejected.
+    pub fn new(passphrase: &str) -> Result<Self> {
+        if passphrase.is_empty() {
+            return Err(anyhow!("{PASSPHRASE_ENV} must not be empty"));
+        }
+        Ok(Self {
+            passphrase: Zeroizing::new(passphrase.to_string()),
+            salt: rand::random(),
+            keys: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Cipher from `STANDX_MM_STORAGE_PASSPHRASE`, or `None` when unset.
+    pub fn from_env() -> Result<Option<Self>> {
+        match std::env::var(PASSPHRASE_ENV) {
+            Ok(passphrase) => Self::new(&passphrase).map(Some),
+            Err(std::env::VarError::NotPresent) => Ok(None),
+            Err(err) => Err(anyhow!("{PASSPHRASE_ENV}: {err}")),
+        }
+    }
+
+    pub fn is_encrypted(value: &str) -> bool {
+        value.starts_with(PREFIX)
+    }
+
+    /// Encrypt `plaintext`, binding it to `context` (e.g. `account/field`) so
+    /// ciphertexts cannot be swapped between fields. Empty values stay empty.
+    pub fn encrypt(&self, plaintext: &str, context: &str) -> Result<String> {
+        if plaintext.is_empty() {
+            return Ok(String::new());
+        }
+        let nonce: [u8; NONCE_LEN] = rand::random();
+        let key = self.key(&self.salt)?;
+        let ciphertext = Aes256Gcm::new_from_slice(key.as_ref())
+            .map_err(|err| anyhow!("init cipher failed: {err}"))?
+            .encrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: plaintext.as_bytes(),
+                    aad: context.as_bytes(),
+                },
+            )
+            .map_err(|_| anyhow!("encrypt {context} failed"))?;
+        Ok(format!(
+            "{PREFIX}{}:{}:{}",
+            STANDARD.encode(self.salt),
+            STANDARD.encode(nonce),
+            STANDARD.encode(ciphertext)
+        ))
+    }
+
+    /// Decrypt a value written by [`CredentialCipher::encrypt`]; plaintext
+    /// values are returned unchanged.
+    pub fn decrypt(&self, value: &str, context: &str) -> Result<String> {
+        let Some(encoded) = value.strip_prefix(PREFIX) else {
+            return Ok(value.to_string());
+        };
+        let mut parts = encoded.splitn(3, ':');
+        let mut next = |name: &str| {
+            parts
+                .next()
+                .and_then(|part| STANDARD.decode(part).ok())
+                .ok_or_else(|| anyhow!("{context}: malformed encrypted value ({name})"))
+        };
+        let salt: [u8; SALT_LEN] = next("salt")?
+            .try_into()
+            .map_err(|_| anyhow!("{context}: bad salt length"))?;
+        let nonce = next("nonce")?;
+        if nonce.len() != NONCE_LEN {
+            return Err(anyhow!("{context}: bad nonce length"));
+        }
+        let ciphertext = next("ciphertext")?;
+
+        let key = self.key(&salt)?;
+        let plaintext = Aes256Gcm::new_from_slice(key.as_ref())
+            .map_err(|err| anyhow!("init cipher failed: {err}"))?
+            .decrypt(
+                Nonce::from_slice(&nonce),
+                Payload {
+                    msg: &ciphertext,
+                    aad: context.as_bytes(),
+                },
+            )
+            .map_err(|_| anyhow!("{context}: decryption failed (wrong {PASSPHRASE_ENV}?)"))?;
+        String::from_utf8(plaintext).map_err(|_| anyhow!("{context}: decrypted value is not UTF-8"))
+    }
+
+    fn key(&self, salt: &[u8; SALT_LEN]) -> Result<Zeroizing<[u8; 32]>> {
+        let mut keys = self.keys.lock().unwrap_or_else(|err| err.into_inner());
+        if let Some(key) = keys.get(salt) {
+            return Ok(key.clone());
+        }
+        let mut key = Zeroizing::new([0u8; 32]);
+        Argon2::default()
+            .hash_password_into(self.passphrase.as_bytes(), salt, key.as_mut())
+            .map_err(|err| anyhow!("derive storage key failed: {err}"))?;
+        keys.insert(*salt, key.clone());
+        Ok(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_binds_context_and_passphrase() {
+        let cipher = CredentialCipher::new("correct horse").unwrap();
+        let sealed = cipher.encrypt("secret-key", "acct/private_key").unwrap();
+        assert!(CredentialCipher::is_encrypted(&sealed));
+        assert!(!sealed.contains("secret-key"));
+        assert_eq!(
+            cipher.decrypt(&sealed, "acct/private_key").unwrap(),
+            "secret-key"
+        );
+        assert!(cipher.decrypt(&sealed, "acct/jwt_token").is_err());
+        assert_eq!(cipher.decrypt("plain", "acct/jwt_token").unwrap(), "plain");
+
+        let other = CredentialCipher::new("wrong").unwrap();
+        assert!(other.decrypt(&sealed, "acct/private_key").is_err());
+    }
+}
@@ -2,12 +2,16 @@ use anyhow::{Result, anyhow};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use standx_point_adapter::Chain;
-use std::collections::HashMap;
+use standx_point_mm_strategy::config::task_order;
+use standx_point_mm_strategy::file_lock::{DEFAULT_LOCK_TIMEOUT, FileLock};
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use tokio::fs;
 use tokio::sync::Mutex;
 
+use super::crypto::CredentialCipher;
+
 /// Account data structure for persistence
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Account {
@@ -24,6 +28,31 @@ pub struct Account {
 }
 
 impl Account {
+    /// Apply `f` to each credential field with its `account_id/field` context.
+    fn map_credentials(&mut self, f: impl Fn(&str, &str) -> Result<String>) -> Result<()> {
+        let id = self.id.clone();
+        for (name, value) in [
+            ("private_key", &mut self.private_key),
+            ("jwt_token", &mut self.jwt_token),
+            ("signing_key", &mut self.signing_key),
+        ] {
+            *value = f(value, &format!("{id}/{name}"))?;
+        }
+        Ok(())
+    }
+
+    fn has_encrypted_credentials(&self) -> bool {
+        [&self.private_key, &self.jwt_token, &self.signing_key]
+            .iter()
+            .any(|value| CredentialCipher::is_encrypted(value))
+    }
+
+    fn has_plaintext_credentials(&self) -> bool {
+        [&self.private_key, &self.jwt_token, &self.signing_key]
+            .iter()
+            .any(|value| !value.is_empty() && !CredentialCipher::is_encrypted(value))
+    }
+
     pub fn new(
         id: String,
         name: String,
@@ -161,9 +190,45 @@ fn validate_optional_bps(raw: &Option<String>, field: &str) -> Result<()> {
     Ok(())
 }
 
+/// Schema version written by [`Storage::export`] and required by [`Storage::import`].
+pub const BUNDLE_VERSION: u32 = 1;
+
+/// Portable snapshot of all accounts and tasks.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StorageBundle {
+    pub version: u32,
+    pub exported_at: chrono::DateTime<chrono::Utc>,
+    /// Credentials were blanked on export; accounts must already exist to import
+    #[serde(default)]
+    pub redacted: bool,
+    pub accounts: Vec<Account>,
+    pub tasks: Vec<Task>,
+}
+
+/// How [`Storage::import`] treats records whose id already exists.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum MergeStrategy {
+    /// Only add new ids; existing records are left untouched
+    Skip,
+    /// Replace existing records with the bundle's copy
+    Overwrite,
+}
+
+/// Record counts from an import.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct ImportSummary {
+    pub accounts_added: usize,
+    pub accounts_updated: usize,
+    pub tasks_added: usize,
+    pub tasks_updated: usize,
+    pub skipped: usize,
+}
+
 /// Storage manager for accounts and tasks
 #[derive(Debug)]
 pub struct Storage {
+    /// Encrypts credentials on disk; `None` keeps them in plaintext
+    cipher: Option<CredentialCipher>,
     accounts_path: PathBuf,
     tasks_path: PathBuf,
     accounts: Mutex<HashMap<String, Account>>,
@@ -179,27 +244,80 @@ impl Storage {
             Self::migrate_legacy_files(&data_dir, &legacy_dir).await?;
         }
 
+        Self::open(&data_dir).await
+    }
+
+    /// Load `accounts.json` and `tasks.json` from an existing directory,
+    /// encrypting credentials when `STANDX_MM_STORAGE_PASSPHRASE` is set.
+    pub async fn open(data_dir: &Path) -> Result<Self> {
+        Self::open_with_cipher(data_dir, CredentialCipher::from_env()?).await
+    }
+
+    /// Like [`Storage::open`] with an explicit cipher instead of the env var.
+    ///
+    /// Plaintext credentials found on load are encrypted and written back
+    /// immediately when a cipher is given.
+    pub async fn open_with_cipher(
+        data_dir: &Path,
+        cipher: Option<CredentialCipher>,
+    ) -> Result<Self> {
         let accounts_path = data_dir.join("accounts.json");
         let tasks_path = data_dir.join("tasks.json");
 
-        let accounts = Self::load_accounts(&accounts_path).await?;
+        let (accounts, needs_encryption) =
+            Self::load_accounts(&accounts_path, cipher.as_ref()).await?;
         let tasks = Self::load_tasks(&tasks_path).await?;
 
-        Ok(Self {
+        let storage = Self {
+            cipher,
             accounts_path,
             tasks_path,
             accounts: Mutex::new(accounts),
             tasks: Mutex::new(tasks),
-        })
+        };
+        if needs_encryption {
+            let accounts = storage.accounts.lock().await;
+            storage.save_accounts(&accounts).await?;
+            tracing::info!(
+                accounts = accounts.len(),
+                "encrypted plaintext account credentials"
+            );
+        }
+        Ok(storage)
     }
 
-    async fn load_accounts(path: &Path) -> Result<HashMap<String, Account>> {
+    /// Returns the decrypted accounts and whether any were stored in plaintext
+    /// while a cipher is configured.
+    async fn load_accounts(
+        path: &Path,
+        cipher: Option<&CredentialCipher>,
+    ) -> Result<(HashMap<String, Account>, bool)> {
         if !path.exists() {
-            return Ok(HashMap::new());
+            return Ok((HashMap::new(), false));
         }
         let content = fs::read_to_string(path).await?;
-        let accounts: Vec<Account> = serde_json::from_str(&content)?;
-        Ok(accounts.into_iter().map(|a| (a.id.clone(), a)).collect())
+        let mut accounts: Vec<Account> = serde_json::from_str(&content)?;
+        let mut needs_encryption = false;
+        for account in &mut accounts {
+            match cipher {
+                Some(cipher) => {
+                    needs_encryption |= account.has_plaintext_credentials();
+                    account.map_credentials(|value, context| cipher.decrypt(value, context))?;
+                }
+                None if account.has_encrypted_credentials() => {
+                    return Err(anyhow!(
+                        "account '{}' has encrypted credentials; set {}",
+                        account.id,
+                        super::crypto::PASSPHRASE_ENV
+                    ));
+                }
+                None => {}
+            }
+        }
+        Ok((
+            accounts.into_iter().map(|a| (a.id.clone(), a)).collect(),
+            needs_encryption,
+        ))
     }
 
     async fn load_tasks(path: &Path) -> Result<HashMap<String, Task>> {
@@ -341,7 +459,7 @@ impl Storage {
     pub async fn list_tasks(&self) -> Result<Vec<Task>> {
         let tasks = self.tasks.lock().await;
         let mut list: Vec<_> = tasks.values().cloned().collect();
-        list.sort_by(|a, b| a.id.cmp(&b.id));
+        list.sort_by(|a, b| task_order(&a.id, &b.id));
         Ok(list)
     }
 
@@ -353,7 +471,7 @@ impl Storage {
             .filter(|t| t.account_id == account_id)
             .cloned()
             .collect();
-        list.sort_by(|a, b| a.id.cmp(&b.id));
+        list.sort_by(|a, b| task_order(&a.id, &b.id));
         Ok(list)
     }
 
@@ -362,32 +480,187 @@ impl Storage {
         self.tasks.lock().await.get(id).cloned()
     }
 
+    /// Write every account and task to a single JSON bundle.
+    ///
+    /// With `redact_credentials` the private key, JWT and signing key are
+    /// blanked so the bundle is safe to share; it can then only restore tasks
+    /// and account metadata onto a machine that already has the accounts.
+    /// Otherwise credentials stay encrypted when a storage passphrase is set,
+    /// and importing needs the same passphrase.
+    pub async fn export(&self, path: &Path, redact_credentials: bool) -> Result<StorageBundle> {
+        let mut accounts = self.list_accounts().await?;
+        for account in &mut accounts {
+            if redact_credentials {
+                account.private_key.clear();
+                account.jwt_token.clear();
+                account.signing_key.clear();
+            } else {
+                self.seal(account)?;
+            }
+        }
+        let bundle = StorageBundle {
+            version: BUNDLE_VERSION,
+            exported_at: chrono::Utc::now(),
+            redacted: redact_credentials,
+            accounts,
+            tasks: self.list_tasks().await?,
+        };
+        write_atomic(path, &serde_json::to_string_pretty(&bundle)?).await?;
+        Ok(bundle)
+    }
+
+    /// Add or overwrite accounts and tasks by id from a bundle written by
+    /// [`Storage::export`].
+    ///
+    /// The whole bundle is validated before anything is written, so a bad
+    /// record leaves storage unchanged.
+    pub async fn import(&self, path: &Path, merge: MergeStrategy) -> Result<ImportSummary> {
+        let content = fs::read_to_string(path).await?;
+        let mut bundle: StorageBundle = serde_json::from_str(&content)
+            .map_err(|err| anyhow!("invalid bundle {}: {err}", path.display()))?;
+        if bundle.version != BUNDLE_VERSION {
+            return Err(anyhow!(
+                "unsupported bundle version {} (expected {BUNDLE_VERSION})",
+                bundle.version
+            ));
+        }
+
+        for account in &mut bundle.accounts {
+            self.unseal(account)?;
+        }
+
+        let mut accounts = self.accounts.lock().await;
+        let mut tasks = self.tasks.lock().await;
+
+        let mut seen = HashSet::new();
+        for account in &bundle.accounts {
+            if !seen.insert(account.id.as_str()) {
+                return Err(anyhow!("duplicate account id '{}' in bundle", account.id));
+            }
+            if bundle.redacted {
+                if !accounts.contains_key(&account.id) {
+                    return Err(anyhow!(
+                        "account '{}' has redacted credentials and does not exist locally",
+                        account.id
+                    ));
+                }
+            } else {
+                account
+                    .validate()
+                    .map_err(|err| anyhow!("account '{}': {err}", account.id))?;
+            }
+        }
+        let mut seen = HashSet::new();
+        for task in &bundle.tasks {
+            if !seen.insert(task.id.as_str()) {
+                return Err(anyhow!("duplicate task id '{}' in bundle", task.id));
+            }
+            task.validate()
+                .map_err(|err| anyhow!("task '{}': {err}", task.id))?;
+            let known_account = accounts.contains_key(&task.account_id)
+                || bundle.accounts.iter().any(|a| a.id == task.account_id);
+            if !known_account {
+                return Err(anyhow!(
+                    "task '{}' references unknown account '{}'",
+                    task.id,
+                    task.account_id
+                ));
+            }
+        }
+
+        let mut summary = ImportSummary::default();
+        for mut account in bundle.accounts {
+            match accounts.get(&account.id) {
+                None => summary.accounts_added += 1,
+                Some(_) if merge == MergeStrategy::Skip => {
+                    summary.skipped += 1;
+                    continue;
+                }
+                Some(existing) => {
+                    if bundle.redacted {
+                        account.private_key = existing.private_key.clone();
+                        account.jwt_token = existing.jwt_token.clone();
+                        account.signing_key = existing.signing_key.clone();
+                    }
+                    summary.accounts_updated += 1;
+                }
+            }
+            accounts.insert(account.id.clone(), account);
+        }
+        for task in bundle.tasks {
+            if !tasks.contains_key(&task.id) {
+                summary.tasks_added += 1;
+            } else if merge == MergeStrategy::Skip {
+                summary.skipped += 1;
+                continue;
+            } else {
+                summary.tasks_updated += 1;
+            }
+            tasks.insert(task.id.clone(), task);
+        }
+
+        self.save_accounts(&accounts).await?;
+        self.save_tasks(&tasks).await?;
+        Ok(summary)
+    }
+
     // Private helper methods
     async fn save_accounts(&self, accounts: &HashMap<String, Account>) -> Result<()> {
-        let list: Vec<_> = accounts.values().cloned().collect();
-        let content = serde_json::to_string_pretty(&list)?;
+        let mut list: Vec<_> = accounts.values().cloned().collect();
+        for account in &mut list {
+            self.seal(account)?;
+        }
+        write_atomic(&self.accounts_path, &serde_json::to_string_pretty(&list)?).await
+    }
 
-        // Atomic write: write to temp file then rename
-        let temp_path = self.accounts_path.with_extension("tmp");
-        fs::write(&temp_path, content).await?;
-        fs::rename(&temp_path, &self.accounts_path).await?;
-        Ok(())
+    /// Encrypt credentials in place when a cipher is configured.
+    fn seal(&self, account: &mut Account) -> Result<()> {
+        match &self.cipher {
+            Some(cipher) => account.map_credentials(|value, context| {
+                if CredentialCipher::is_encrypted(value) {
+                    Ok(value.to_string())
+                } else {
+                    cipher.encrypt(value, context)
+                }
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Decrypt credentials in place; encrypted values need a cipher.
+    fn unseal(&self, account: &mut Account) -> Result<()> {
+        match &self.cipher {
+            Some(cipher) => {
+                account.map_credentials(|value, context| cipher.decrypt(value, context))
+            }
+            None if account.has_encrypted_credentials() => Err(anyhow!(
+                "account '{}' has encrypted credentials; set {}",
+                account.id,
+                super::crypto::PASSPHRASE_ENV
+            )),
+            None => Ok(()),
+        }
     }
 
     async fn save_tasks(&self, tasks: &HashMap<String, Task>) -> Result<()> {
         let list: Vec<_> = tasks.values().cloned().collect();
-        let content = serde_json::to_string_pretty(&list)?;
-
-        let temp_path = self.tasks_path.with_extension("tmp");
-        fs::write(&temp_path, content).await?;
-        fs::rename(&temp_path, &self.tasks_path).await?;
-        Ok(())
+        write_atomic(&self.tasks_path, &serde_json::to_string_pretty(&list)?).await
     }
 }
 
+/// Atomic write: write to temp file then rename, holding the file's lock so
+/// another process sharing the directory cannot interleave on the temp file
+async fn write_atomic(path: &Path, content: &str) -> Result<()> {
+    let _lock = FileLock::acquire(path, DEFAULT_LOCK_TIMEOUT).await?;
+    let temp_path = path.with_extension("tmp");
+    fs::write(&temp_path, content).await?;
+    fs::rename(&temp_path, path).await?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Task;
+    use super::{Account, CredentialCipher, MergeStrategy, Storage, Task};
 
     #[test]
     fn task_deserialize_legacy_without_tp_sl() {
@@ -437,4 +710,112 @@ mod tests {
 
         task.validate().expect("should accept valid bps");
     }
+
+    #[tokio::test]
+    async fn export_import_round_trips_and_respects_merge_strategy() {
+        let root = std::env::temp_dir().join(format!("standx-storage-{}", uuid::Uuid::new_v4()));
+        let (source_dir, target_dir) = (root.join("source"), root.join("target"));
+        tokio::fs::create_dir_all(&source_dir).await.unwrap();
+        tokio::fs::create_dir_all(&target_dir).await.unwrap();
+
+        let source = Storage::open_with_cipher(&source_dir, None).await.unwrap();
+        let account = |name: &str| {
+            Account::new(
+                "0xabc".to_string(),
+                name.to_string(),
+                "private".to_string(),
+                "jwt".to_string(),
+                "signing".to_string(),
+                None,
+            )
+        };
+        source.create_account(account("main")).await.unwrap();
+        source
+            .create_task(Task::new(
+                "task-1".to_string(),
+                "BTC-USD".to_string(),
+                "0xabc".to_string(),
+                "low".to_string(),
+                "1000".to_string(),
+            ))
+            .await
+            .unwrap();
+        let full = root.join("full.json");
+        let redacted = root.join("redacted.json");
+        source.export(&full, false).await.unwrap();
+        let bundle = source.export(&redacted, true).await.unwrap();
+        assert!(bundle.accounts[0].private_key.is_empty());
+
+        let target = Storage::open_with_cipher(&target_dir, None).await.unwrap();
+        let err = target
+            .import(&redacted, MergeStrategy::Overwrite)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("redacted"), "{err}");
+        assert!(target.list_accounts().await.unwrap().is_empty());
+
+        let summary = target.import(&full, MergeStrategy::Skip).await.unwrap();
+        assert_eq!((summary.accounts_added, summary.tasks_added), (1, 1));
+        let reloaded = Storage::open_with_cipher(&target_dir, None).await.unwrap();
+        assert_eq!(reloaded.list_tasks().await.unwrap()[0].id, "task-1");
+
+        // Redacted overwrite updates metadata but keeps local credentials.
+        source
+            .update_account("0xabc", |a| a.name = "renamed".to_string())
+            .await
+            .unwrap();
+        source.export(&redacted, true).await.unwrap();
+        let summary = target.import(&redacted, MergeStrategy::Skip).await.unwrap();
+        assert_eq!(summary.skipped, 2);
+        let summary = target
+            .import(&redacted, MergeStrategy::Overwrite)
+            .await
+            .unwrap();
+        assert_eq!((summary.accounts_updated, summary.tasks_updated), (1, 1));
+        let imported = target.get_account("0xabc").await.unwrap();
+        assert_eq!(imported.name, "renamed");
+        assert_eq!(imported.private_key, "private");
+
+        tokio::fs::remove_dir_all(&root).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn plaintext_credentials_are_encrypted_on_first_load() {
+        let dir = std::env::temp_dir().join(format!("standx-storage-{}", uuid::Uuid::new_v4()));
+        tokio::fs::create_dir_all(&dir).await.unwrap();
+        let plain = Storage::open_with_cipher(&dir, None).await.unwrap();
+        plain
+            .create_account(Account::new(
+                "0xabc".to_string(),
+                "main".to_string(),
+                "private".to_string(),
+                "jwt".to_string(),
+                "signing".to_string(),
+                None,
+            ))
+            .await
+            .unwrap();
+
+        let cipher = || Some(CredentialCipher::new("test-passphrase").unwrap());
+        let encrypted = Storage::open_with_cipher(&dir, cipher()).await.unwrap();
+        assert_eq!(
+            encrypted.get_account("0xabc").await.unwrap().private_key,
+            "private"
+        );
+        let on_disk = tokio::fs::read_to_string(dir.join("accounts.json"))
+            .await
+            .unwrap();
+        assert!(!on_disk.contains("\"private\""), "{on_disk}");
+        assert!(on_disk.contains("enc:v1:"));
+
+        let reopened = Storage::open_with_cipher(&dir, cipher()).await.unwrap();
+        assert_eq!(
+            reopened.get_account("0xabc").await.unwrap().signing_key,
+            "signing"
+        );
+        let err = Storage::open_with_cipher(&dir, None).await.unwrap_err();
+        assert!(err.to_string().contains("STANDX_MM_STORAGE_PASSPHRASE"));
+
+        tokio::fs::remove_dir_all(&dir).await.unwrap();
+    }
 }
@@ -12,15 +12,55 @@
 [UPDATE]: 2026-02-07 Budget reflects total bid+ask notional.
 [UPDATE]: 2026-02-09 Gate replace on cancel ack with reconcile fallback.
 [UPDATE]: 2026-03-06 Sync inventory from authoritative position updates.
+[UPDATE]: 2026-10-16 Make quote replacement ordering configurable (cancel_first/place_first).
+[UPDATE]: 2026-10-16 Add SequenceGap reconcile reason for order-stream gaps.
+[UPDATE]: 2026-10-16 Emit OrderPlaced/OrderCancelled runtime events.
+[UPDATE]: 2026-10-16 Allow per-task overrides of tier, target, and survival bps bands.
+[UPDATE]: 2026-10-16 Skew quote distances against inventory (inventory_skew_bps).
+[UPDATE]: 2026-10-16 Halt quoting for the UTC day once risk.daily_loss_limit_usd is hit.
+[UPDATE]: 2026-10-16 Publish fills, uptime ratio, and daily realized PnL to TaskMetrics.
+[UPDATE]: 2026-10-16 Bias quotes away from the funding-penalized side (funding_skew_bps).
+[UPDATE]: 2026-10-16 Cap quotes at SymbolInfo max_position_size and ladder depth at max_open_orders.
+[UPDATE]: 2026-10-16 Pause/resume quoting via a watch flag without tearing the task down.
+[UPDATE]: 2026-10-17 Add ReplaceOrder::Amend with cancel-first fallback when amend is unsupported.
+[UPDATE]: 2026-10-17 Submit ladder quotes through new_order_checked so timeouts never duplicate them.
+[UPDATE]: 2026-10-17 Detect unsupported amend via StandxError::is_not_found.
+[UPDATE]: 2026-10-17 Skip quoting while cross_available cannot margin the ladder.
+[UPDATE]: 2026-10-17 Randomize quote sizes within size_jitter_bps.
+[UPDATE]: 2026-10-17 Throttle price-driven replaces to a tick move and min_replace_dwell.
+[UPDATE]: 2026-10-17 Trim stale inventory with capped reduce-only IOC taker sweeps.
+[UPDATE]: 2026-10-17 Make OrderExecutor public and run the strategy against any executor.
+[UPDATE]: 2026-10-17 Optionally quote around the REST order book mid (reference_price: book_mid).
+[UPDATE]: 2026-10-17 Floor quote distances so the spread covers fee_floor_multiple taker fees.
+[UPDATE]: 2026-10-17 Refresh quotes as soon as the order stream reports a fill or cancel.
+[UPDATE]: 2026-10-17 Report deployed USD (resting notional + position value) to metrics.
+[UPDATE]: 2026-10-17 Warn and flag metrics when rolling uptime falls below risk.min_uptime_ratio.
+[UPDATE]: 2026-10-17 Keep a bounded ring of active intervals for a rolling 1h uptime ratio.
+[UPDATE]: 2026-10-17 Pull quotes while the price feed is older than max_price_age.
+[UPDATE]: 2026-10-17 Also treat a lagging exchange price timestamp as stale.
+[UPDATE]: 2026-10-17 Pull quotes with a warning on a non-positive mark/reference price.
+[UPDATE]: 2026-10-17 Replace the fixed L1..L5 tiers with a configurable Vec<TierBand> ladder.
+[UPDATE]: 2026-10-17 Reweight L1..L5 quote sizes via quoting.tier_weights.
+[UPDATE]: 2026-10-17 Publish session realized PnL to TaskMetrics.
+[UPDATE]: 2026-10-17 Drop QuotingBands::tier_weights; size weights come only from the ladder's TierBands.
+[UPDATE]: 2026-10-17 Drop the fixed QuotingBands::tiers array; every override is a TierBand ladder.
+[UPDATE]: 2026-10-17 Emit PriceStale when the feed goes stale and PriceRecovered once it is fresh again.
+[UPDATE]: 2026-10-17 Book realized PnL from own Fill events; the position feed only re-anchors ledger size.
+[UPDATE]: 2026-10-17 Publish realized PnL to TaskMetrics as each fill is booked.
+[UPDATE]: 2026-10-17 Book persistent ledger drift at the mark instead of re-anchoring it.
 */
 
-use std::collections::{HashMap, HashSet};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::future::Future;
 use std::pin::Pin;
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{Result, anyhow};
+use chrono::{DateTime, Utc};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::{Decimal, RoundingStrategy};
 use tokio::sync::{Mutex, mpsc, watch};
 use tokio_util::sync::CancellationToken;
@@ -28,16 +68,17 @@ use tracing::{debug, error, info, warn};
 use uuid::Uuid;
 
 use standx_point_adapter::{
-    CancelOrderRequest, CancelOrderResponse, NewOrderRequest, NewOrderResponse, OrderType, Side,
-    StandxClient, SymbolPrice, TimeInForce,
+    AmendOrderRequest, CancelOrderRequest, CancelOrderResponse, NewOrderRequest, NewOrderResponse,
+    OrderSubmission, OrderType, Side, StandxClient, SymbolPrice, TimeInForce,
 };
 
+use crate::events::{EventBus, EventSubscription, RuntimeEvent};
 use crate::metrics::TaskMetrics;
 use crate::order_state::{OrderState, OrderTracker};
 use crate::risk::{RiskManager, RiskState};
 
 const BPS_DENOMINATOR: i64 = 10_000;
-const QUOTE_REFRESH_INTERVAL: Duration = Duration::from_secs(5); // >=5s min resting
+pub(crate) const QUOTE_REFRESH_INTERVAL: Duration = Duration::from_secs(5); // >=5s min resting
 const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(10);
 
 const SURVIVAL_AFTER_FILL: Duration = Duration::from_secs(60);
@@ -45,6 +86,14 @@ const FILL_BACKOFF_DURATION: Duration = Duration::from_secs(600);
 
 // Non-L1 replace threshold (bps).
 const REPLACE_DRIFT_BPS: i64 = 1;
+// Closest a skewed quote may sit to the reference price (bps).
+const MIN_SKEWED_QUOTE_BPS: i64 = 1;
+// Taker fees the bid-ask spread must cover unless quoting overrides it.
+const DEFAULT_FEE_FLOOR_MULTIPLE: i64 = 2;
+// Funding rate per interval (1 bp) at which funding skew reaches full strength.
+const FUNDING_RATE_FULL_SKEW: Decimal = Decimal::from_parts(1, 0, 0, false, 4);
+// Funding settles hourly on the hour (UTC); skew ramps up toward each settlement.
+const FUNDING_INTERVAL_SECS: i64 = 3600;
 const L1_MIN_REST: Duration = Duration::from_secs(3);
 const CANCEL_ACK_TIMEOUT: Duration = Duration::from_secs(10);
 const CANCEL_RETRY_INTERVAL: Duration = Duration::from_secs(15);
@@ -72,6 +121,107 @@ impl std::str::FromStr for RiskLevel {
     }
 }
 
+/// Order of operations when a resting quote must be replaced.
+///
+/// A cancel is only final once the exchange acknowledges it, so outside of
+/// `Amend` every replace is a separate cancel + new order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ReplaceOrder {
+    /// Cancel, wait for the ack, then place: never two live orders per slot,
+    /// at the cost of a quoting gap while the cancel is in flight.
+    #[default]
+    CancelFirst,
+    /// Place the new quote, then cancel the old one: no liquidity gap, but
+    /// both orders can rest (and fill) until the cancel lands.
+    PlaceFirst,
+    /// Amend unfilled quotes in place, keeping one order per slot with no
+    /// gap. Partially filled quotes, failed amends, and environments without
+    /// the amend endpoint fall back to `CancelFirst`.
+    Amend,
+}
+
+impl std::str::FromStr for ReplaceOrder {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "cancel_first" => Ok(Self::CancelFirst),
+            "place_first" => Ok(Self::PlaceFirst),
+            "amend" => Ok(Self::Amend),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Price quote distances are measured from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum QuoteReference {
+    /// Ticker mid, then last, then mark price.
+    #[default]
+    Ticker,
+    /// `(best_bid + best_ask) / 2` from the polled order book, falling back
+    /// to `Ticker` while no two-sided book is available.
+    BookMid,
+}
+
+impl std::str::FromStr for QuoteReference {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "ticker" => Ok(Self::Ticker),
+            "book_mid" => Ok(Self::BookMid),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Per-task overrides of the quote bps ladder; `None` keeps the built-in bands.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct QuotingBands {
+    /// Full ladder of any depth, replacing the risk-level presets.
+    pub ladder: Option<Vec<TierBand>>,
+    /// Aggressive-mode band, replacing the risk-level default.
+    pub target_bps: Option<(Decimal, Decimal)>,
+    /// Band used while backing off after a fill.
+    pub survival_bps: Option<(Decimal, Decimal)>,
+    /// Bps shift per unit of inventory relative to the budget.
+    pub inventory_skew_bps: Option<Decimal>,
+    /// Bps shift at full-strength funding, just before settlement.
+    pub funding_skew_bps: Option<Decimal>,
+    /// Bps bound on the random perturbation of each quote's size.
+    pub size_jitter_bps: Option<Decimal>,
+    /// Minimum resting time before a price move may replace a quote.
+    pub min_replace_dwell: Option<Duration>,
+    /// Price the ladder is built around.
+    pub reference_price: Option<QuoteReference>,
+    /// Taker fees the bid-ask spread must cover; `0` disables the floor.
+    pub fee_floor_multiple: Option<Decimal>,
+}
+
+/// Reduce-only IOC sweep that trims inventory held above a threshold.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TakerSweep {
+    /// Inventory notional (USD) above which the sweep arms.
+    pub threshold_usd: Decimal,
+    /// How long inventory must stay above the threshold before sweeping.
+    pub after: Duration,
+    /// Farthest the IOC limit price may sit from mark.
+    pub max_slippage_bps: Decimal,
+    /// Most notional (USD) submitted per interval.
+    pub max_usd_per_interval: Decimal,
+    pub interval: Duration,
+}
+
+/// Floor on the rolling uptime ratio that quotes need for reward eligibility.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MinUptime {
+    /// Uptime ratio in `(0, 1]` the task should stay at or above.
+    pub ratio: Decimal,
+    /// Trailing window the ratio is measured over.
+    pub window: Duration,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum StrategyMode {
     Aggressive { target_bps: (Decimal, Decimal) },
@@ -113,41 +263,54 @@ impl StrategyMode {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-enum Tier {
-    L1,
-    L2,
-    L3,
-    L4,
-    L5,
+/// One rung of the quote ladder: a bps distance band from the reference
+/// price and the relative size it quotes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TierBand {
+    pub min_bps: Decimal,
+    pub max_bps: Decimal,
+    /// Share of the per-side budget relative to the other tiers' weights
+    pub weight: Decimal,
 }
 
-impl Tier {
-    fn min_max_bps(self) -> (Decimal, Decimal) {
-        match self {
-            Tier::L1 => (Decimal::from(5), Decimal::from(8)),
-            Tier::L2 => (Decimal::from(8), Decimal::from(10)),
-            Tier::L3 => (Decimal::from(10), Decimal::from(15)),
-            Tier::L4 => (Decimal::from(15), Decimal::from(20)),
-            Tier::L5 => (Decimal::from(20), Decimal::from(30)),
-        }
+/// Default `(min_bps, max_bps)` for L1..L5.
+const PRESET_BANDS: [(i64, i64); 5] = [(5, 8), (8, 10), (10, 15), (15, 20), (20, 30)];
+
+impl TierBand {
+    /// Default ladder for a risk level's tier count (1, 2, 3 or 5 tiers).
+    pub fn presets(tier_count: u8) -> Vec<TierBand> {
+        // Weights as (mantissa, scale) per tier.
+        let weights: &[(i64, u32)] = match normalize_tier_count(tier_count) {
+            1 => &[(1, 0)],
+            2 => &[(6, 1), (4, 1)],
+            3 => &[(4, 1), (35, 2), (25, 2)],
+            _ => &[(3, 1), (25, 2), (2, 1), (15, 2), (1, 1)],
+        };
+        PRESET_BANDS
+            .iter()
+            .zip(weights)
+            .map(|(&(min, max), &(weight, scale))| TierBand {
+                min_bps: Decimal::from(min),
+                max_bps: Decimal::from(max),
+                weight: Decimal::new(weight, scale),
+            })
+            .collect()
     }
+}
 
-    fn as_str(self) -> &'static str {
-        match self {
-            Tier::L1 => "l1",
-            Tier::L2 => "l2",
-            Tier::L3 => "l3",
-            Tier::L4 => "l4",
-            Tier::L5 => "l5",
-        }
-    }
+/// Position in the ladder; L1 (index 0) sits nearest the reference price.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct Tier(usize);
+
+impl Tier {
+    const L1: Tier = Tier(0);
 }
 
-const TIERS_L1: [Tier; 1] = [Tier::L1];
-const TIERS_L1_L2: [Tier; 2] = [Tier::L1, Tier::L2];
-const TIERS_L1_L2_L3: [Tier; 3] = [Tier::L1, Tier::L2, Tier::L3];
-const TIERS_ALL: [Tier; 5] = [Tier::L1, Tier::L2, Tier::L3, Tier::L4, Tier::L5];
+impl std::fmt::Display for Tier {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "l{}", self.0 + 1)
+    }
+}
 
 fn normalize_tier_count(tiers: u8) -> usize {
     match tiers {
@@ -167,6 +330,12 @@ enum QuoteSide {
 #[derive(Debug, Clone)]
 pub enum OrderReconcileReason {
     CancelTimeout,
+    ReplacedCancelFailed,
+    /// Order stream skipped a sequence number; not tied to one order, so
+    /// the request's `cl_ord_id` is empty.
+    SequenceGap,
+    /// Order stream reconnected after missing updates; `cl_ord_id` is empty.
+    StreamReconnected,
 }
 
 #[derive(Debug, Clone)]
@@ -220,30 +389,101 @@ struct LiveQuote {
     cancel_in_flight: Option<CancelInFlight>,
 }
 
+/// Quote superseded under `ReplaceOrder::PlaceFirst`, kept until its cancel is confirmed.
+#[derive(Debug, Clone)]
+struct RetiringQuote {
+    cl_ord_id: String,
+    cancel_sent_at: tokio::time::Instant,
+}
+
+/// Trailing window behind [`UptimeSnapshot::uptime_ratio_1h`].
+pub const ROLLING_UPTIME_WINDOW: Duration = Duration::from_secs(3600);
+/// Most active intervals kept for rolling ratios; the oldest drop first.
+const UPTIME_RING_CAPACITY: usize = 1024;
+
 #[derive(Debug, Clone)]
 pub struct UptimeSnapshot {
     pub active: bool,
     pub active_duration: Duration,
     pub total_duration: Duration,
+    /// Ratio over the task's whole life.
     pub uptime_ratio: Decimal,
+    /// Ratio over the last hour, or since start for a younger task.
+    pub uptime_ratio_1h: Decimal,
+}
+
+/// Span of time spent quoting, `[start, end)`.
+#[derive(Debug, Clone, Copy)]
+struct ActiveInterval {
+    start: tokio::time::Instant,
+    end: tokio::time::Instant,
 }
 
 #[derive(Debug, Clone)]
 pub struct UptimeTracker {
+    started_at: tokio::time::Instant,
     last_update: tokio::time::Instant,
     active: bool,
     active_duration: Duration,
     inactive_duration: Duration,
+    /// Longest trailing window a rolling ratio may be asked for
+    retain: Duration,
+    /// Closed active intervals overlapping the last `retain`, oldest first;
+    /// gaps between them were inactive
+    intervals: VecDeque<ActiveInterval>,
 }
 
 impl UptimeTracker {
     pub fn new(now: tokio::time::Instant) -> Self {
         Self {
+            started_at: now,
             last_update: now,
             active: false,
             active_duration: Duration::from_secs(0),
             inactive_duration: Duration::from_secs(0),
+            retain: ROLLING_UPTIME_WINDOW,
+            intervals: VecDeque::new(),
+        }
+    }
+
+    /// Keep enough history to answer [`Self::window_ratio`] over `window`.
+    pub fn retain_window(&mut self, window: Duration) {
+        self.retain = self.retain.max(window);
+    }
+
+    /// Whether a full `window` has elapsed since the tracker started.
+    pub fn covers(&self, now: tokio::time::Instant, window: Duration) -> bool {
+        now.saturating_duration_since(self.started_at) >= window
+    }
+
+    /// Uptime ratio over the trailing `window`, or since start when the
+    /// tracker is younger than that.
+    ///
+    /// Time older than the retained history (or dropped once the ring is
+    /// full) counts as inactive.
+    pub fn window_ratio(&self, now: tokio::time::Instant, window: Duration) -> Decimal {
+        let start = now
+            .checked_sub(window)
+            .map_or(self.started_at, |start| start.max(self.started_at));
+        let open = self.active.then_some(ActiveInterval {
+            start: self.last_update,
+            end: now,
+        });
+        let active = self
+            .intervals
+            .iter()
+            .chain(open.as_ref())
+            .map(|interval| {
+                interval
+                    .end
+                    .saturating_duration_since(interval.start.max(start))
+            })
+            .sum::<Duration>();
+        let span_ms = Decimal::from(now.saturating_duration_since(start).as_millis() as i64);
+        if span_ms.is_zero() {
+            return Decimal::ZERO;
         }
+        Decimal::from(active.as_millis() as i64) / span_ms
     }
 
     pub fn update(&mut self, now: tokio::time::Instant, active: bool) {
@@ -251,12 +491,30 @@ impl UptimeTracker {
 
         if self.active {
             self.active_duration = self.active_duration.saturating_add(delta);
+            match self.intervals.back_mut() {
+                Some(last) if last.end == self.last_update => last.end = now,
+                _ => self.intervals.push_back(ActiveInterval {
+                    start: self.last_update,
+                    end: now,
+                }),
+            }
         } else {
             self.inactive_duration = self.inactive_duration.saturating_add(delta);
         }
 
         self.active = active;
         self.last_update = now;
+
+        let cutoff = now.checked_sub(self.retain);
+        while self.intervals.len() > UPTIME_RING_CAPACITY
+            || self
+                .intervals
+                .front()
+                .zip(cutoff)
+                .is_some_and(|(first, cutoff)| first.end <= cutoff)
+        {
+            self.intervals.pop_front();
+        }
     }
 
     pub fn snapshot(&self, now: tokio::time::Instant) -> UptimeSnapshot {
@@ -288,20 +546,35 @@ impl UptimeTracker {
             active_duration,
             total_duration: total,
             uptime_ratio,
+            uptime_ratio_1h: self.window_ratio(now, ROLLING_UPTIME_WINDOW),
         }
     }
 }
 
-trait OrderExecutor: Send + Sync {
+/// Order submission backend the strategy quotes through.
+///
+/// `StandxClient` is the live implementation; custom backends (simulators,
+/// other venues, recording or risk wrappers) can be passed to
+/// [`MarketMakingStrategy::run`] instead.
+pub trait OrderExecutor: Send + Sync {
+    /// Submit an order; a response with non-zero `code` counts as rejected.
     fn new_order(
         &self,
         req: NewOrderRequest,
     ) -> Pin<Box<dyn Future<Output = standx_point_adapter::Result<NewOrderResponse>> + Send + '_>>;
 
+    /// Cancel an order by `cl_ord_id`.
     fn cancel_order(
         &self,
         req: CancelOrderRequest,
     ) -> Pin<Box<dyn Future<Output = standx_point_adapter::Result<CancelOrderResponse>> + Send + '_>>;
+
+    /// Amend price/qty in place; `StandxError::is_not_found` marks amend as
+    /// unsupported and the strategy falls back to cancel + place.
+    fn amend_order(
+        &self,
+        req: AmendOrderRequest,
+    ) -> Pin<Box<dyn Future<Output = standx_point_adapter::Result<NewOrderResponse>> + Send + '_>>;
 }
 
 impl OrderExecutor for StandxClient {
@@ -310,7 +583,18 @@ impl OrderExecutor for StandxClient {
         req: NewOrderRequest,
     ) -> Pin<Box<dyn Future<Output = standx_point_adapter::Result<NewOrderResponse>> + Send + '_>>
     {
-        Box::pin(async move { StandxClient::new_order(self, req).await })
+        Box::pin(async move {
+            // A quote that landed despite a timeout counts as placed; WS
+            // updates and reconciliation pick up its exchange state.
+            match StandxClient::new_order_checked(self, req).await? {
+                OrderSubmission::Accepted(resp) => Ok(resp),
+                OrderSubmission::Landed(order) => Ok(NewOrderResponse {
+                    code: 0,
+                    message: format!("order {} landed before timeout", order.id),
+                    request_id: String::new(),
+                }),
+            }
+        })
     }
 
     fn cancel_order(
@@ -320,6 +604,14 @@ impl OrderExecutor for StandxClient {
     {
         Box::pin(async move { StandxClient::cancel_order(self, req).await })
     }
+
+    fn amend_order(
+        &self,
+        req: AmendOrderRequest,
+    ) -> Pin<Box<dyn Future<Output = standx_point_adapter::Result<NewOrderResponse>> + Send + '_>>
+    {
+        Box::pin(async move { StandxClient::amend_order(self, req).await })
+    }
 }
 
 /// Market making strategy implementation.
@@ -328,7 +620,7 @@ pub struct MarketMakingStrategy {
     symbol: String,
     base_qty: Decimal,
     budget_usd: Decimal,
-    tier_count: usize,
+    tiers: Vec<TierBand>,
     risk_level: RiskLevel,
     tp_bps: Option<Decimal>,
     sl_bps: Option<Decimal>,
@@ -336,24 +628,73 @@ pub struct MarketMakingStrategy {
     qty_tick_decimals: Option<u32>,
     min_order_qty: Option<Decimal>,
     max_order_qty: Option<Decimal>,
+    max_position_size: Option<Decimal>,
+    max_open_orders: Option<usize>,
+    position_capped_sides: HashSet<QuoteSide>,
     price_rx: watch::Receiver<SymbolPrice>,
     position_rx: watch::Receiver<Decimal>,
     order_tracker: Arc<Mutex<OrderTracker>>,
     risk_manager: RiskManager,
     uptime_tracker: UptimeTracker,
     mode: StrategyMode,
+    survival_mode: StrategyMode,
 
     preferred_mode: StrategyMode,
     survival_until: Option<tokio::time::Instant>,
     bid_backoff_until: Option<tokio::time::Instant>,
     ask_backoff_until: Option<tokio::time::Instant>,
     live_quotes: HashMap<QuoteSlot, LiveQuote>,
+    retiring_quotes: Vec<RetiringQuote>,
+    replace_order: ReplaceOrder,
+    /// Cleared once the exchange reports the amend endpoint missing.
+    amend_supported: bool,
     handled_fills: HashSet<String>,
     inventory_qty: Decimal,
     max_non_usd_value: Decimal,
+    inventory_skew_bps: Decimal,
+    funding_skew_bps: Decimal,
+    size_jitter_bps: Decimal,
+    size_rng: StdRng,
+    min_replace_dwell: Duration,
+    taker_sweep: Option<TakerSweep>,
+    min_uptime: Option<MinUptime>,
+    /// Whether the rolling uptime was below `min_uptime` at the last check
+    uptime_below_min: bool,
+    /// Pull quotes once the price feed has been silent this long
+    max_price_age: Option<Duration>,
+    /// When `price_rx` last delivered a snapshot
+    price_updated_at: tokio::time::Instant,
+    price_stale: bool,
+    /// Whether `price_rx` has delivered anything beyond its initial value
+    price_received: bool,
+    /// Whether the last refresh saw a non-positive mark or reference price
+    price_invalid: bool,
+    /// When inventory last crossed above the sweep threshold
+    sweep_armed_at: Option<tokio::time::Instant>,
+    /// Current sweep interval start and notional submitted in it
+    sweep_window: Option<(tokio::time::Instant, Decimal)>,
+    funding_rate_rx: Option<watch::Receiver<Option<Decimal>>>,
+    quote_reference: QuoteReference,
+    book_mid_rx: Option<watch::Receiver<Option<Decimal>>>,
+    /// Symbol taker fee in bps; zero until symbol info is known
+    taker_fee_bps: Decimal,
+    fee_floor_multiple: Decimal,
+    /// Tiers whose band was already reported as raised to the fee floor
+    fee_floor_logged: HashSet<Tier>,
+    daily_loss_halted: bool,
+    available_rx: Option<watch::Receiver<Option<Decimal>>>,
+    /// Leverage used to turn ladder notional into required margin
+    margin_leverage: Decimal,
+    balance_halted: bool,
+    paused_rx: Option<watch::Receiver<bool>>,
     bootstrap_side: Option<QuoteSide>,
     order_reconcile_tx: mpsc::UnboundedSender<OrderReconcileRequest>,
     metrics: Option<Arc<Mutex<TaskMetrics>>>,
+    events: Option<(EventBus, String)>,
+    /// Own `Fill` events, booked into realized PnL at their fill prices.
+    fill_events: Option<EventSubscription>,
+    /// Position that disagreed with the PnL ledger on the last heartbeat.
+    ledger_drift_qty: Option<Decimal>,
 }
 
 impl MarketMakingStrategy {
@@ -373,7 +714,7 @@ impl MarketMakingStrategy {
             symbol: String::new(),
             base_qty: Decimal::ZERO,
             budget_usd: Decimal::ZERO,
-            tier_count: 5,
+            tiers: TierBand::presets(5),
             risk_level: RiskLevel::Low,
             tp_bps: None,
             sl_bps: None,
@@ -381,23 +722,59 @@ impl MarketMakingStrategy {
             qty_tick_decimals: None,
             min_order_qty: None,
             max_order_qty: None,
+            max_position_size: None,
+            max_open_orders: None,
+            position_capped_sides: HashSet::new(),
             price_rx: rx,
             position_rx,
             order_tracker: Arc::new(Mutex::new(OrderTracker::new())),
             risk_manager: RiskManager::new(),
             uptime_tracker: UptimeTracker::new(now),
             mode,
+            survival_mode: StrategyMode::survival_default(),
             preferred_mode: mode,
             survival_until: None,
             bid_backoff_until: None,
             ask_backoff_until: None,
             live_quotes: HashMap::new(),
+            retiring_quotes: Vec::new(),
+            replace_order: ReplaceOrder::default(),
+            amend_supported: true,
             handled_fills: HashSet::new(),
             inventory_qty: Decimal::ZERO,
             max_non_usd_value: Decimal::ZERO,
+            inventory_skew_bps: Decimal::ZERO,
+            funding_skew_bps: Decimal::ZERO,
+            size_jitter_bps: Decimal::ZERO,
+            size_rng: StdRng::from_entropy(),
+            min_replace_dwell: Duration::ZERO,
+            taker_sweep: None,
+            min_uptime: None,
+            uptime_below_min: false,
+            max_price_age: None,
+            price_updated_at: now,
+            price_stale: false,
+            price_received: false,
+            price_invalid: false,
+            sweep_armed_at: None,
+            sweep_window: None,
+            funding_rate_rx: None,
+            quote_reference: QuoteReference::default(),
+            book_mid_rx: None,
+            taker_fee_bps: Decimal::ZERO,
+            fee_floor_multiple: Decimal::from(DEFAULT_FEE_FLOOR_MULTIPLE),
+            fee_floor_logged: HashSet::new(),
+            daily_loss_halted: false,
+            available_rx: None,
+            margin_leverage: Decimal::ONE,
+            balance_halted: false,
+            paused_rx: None,
             bootstrap_side: None,
             order_reconcile_tx: reconcile_tx,
             metrics: None,
+            events: None,
+            fill_events: None,
+            ledger_drift_qty: None,
         }
     }
 
@@ -427,7 +804,7 @@ impl MarketMakingStrategy {
             symbol,
             base_qty: Decimal::ZERO,
             budget_usd,
-            tier_count: normalize_tier_count(tier_count),
+            tiers: TierBand::presets(tier_count),
             risk_level,
             tp_bps,
             sl_bps,
@@ -435,23 +812,59 @@ impl MarketMakingStrategy {
             qty_tick_decimals: None,
             min_order_qty: None,
             max_order_qty: None,
+            max_position_size: None,
+            max_open_orders: None,
+            position_capped_sides: HashSet::new(),
             price_rx,
             position_rx,
             order_tracker,
             risk_manager: RiskManager::new(),
             uptime_tracker: UptimeTracker::new(now),
             mode,
+            survival_mode: StrategyMode::survival_default(),
             preferred_mode: mode,
             survival_until: None,
             bid_backoff_until: None,
             ask_backoff_until: None,
             live_quotes: HashMap::new(),
+            retiring_quotes: Vec::new(),
+            replace_order: ReplaceOrder::default(),
+            amend_supported: true,
             handled_fills: HashSet::new(),
             inventory_qty: initial_position_qty,
             max_non_usd_value,
+            inventory_skew_bps: Decimal::ZERO,
+            funding_skew_bps: Decimal::ZERO,
+            size_jitter_bps: Decimal::ZERO,
+            size_rng: StdRng::from_entropy(),
+            min_replace_dwell: Duration::ZERO,
+            taker_sweep: None,
+            min_uptime: None,
+            uptime_below_min: false,
+            max_price_age: None,
+            price_updated_at: now,
+            price_stale: false,
+            price_received: false,
+            price_invalid: false,
+            sweep_armed_at: None,
+            sweep_window: None,
+            funding_rate_rx: None,
+            quote_reference: QuoteReference::default(),
+            book_mid_rx: None,
+            taker_fee_bps: Decimal::ZERO,
+            fee_floor_multiple: Decimal::from(DEFAULT_FEE_FLOOR_MULTIPLE),
+            fee_floor_logged: HashSet::new(),
+            daily_loss_halted: false,
+            available_rx: None,
+            margin_leverage: Decimal::ONE,
+            balance_halted: false,
+            paused_rx: None,
             bootstrap_side,
             order_reconcile_tx,
             metrics: None,
+            events: None,
+            fill_events: None,
+            ledger_drift_qty: None,
         }
     }
 
@@ -459,6 +872,210 @@ impl MarketMakingStrategy {
         self.metrics = Some(metrics);
     }
 
+    pub fn set_replace_order(&mut self, replace_order: ReplaceOrder) {
+        self.replace_order = replace_order;
+    }
+
+    /// Start PnL accounting from the current position, opened at `entry_price`.
+    pub fn set_entry_price(&mut self, entry_price: Decimal) {
+        self.risk_manager
+            .seed_position(self.inventory_qty, entry_price);
+    }
+
+    /// Stop quoting for the rest of the UTC day once realized losses reach
+    /// `limit_usd`.
+    pub fn set_daily_loss_limit(&mut self, limit_usd: Decimal) {
+        self.risk_manager.set_daily_loss_limit(Some(limit_usd));
+    }
+
+    /// Apply per-task bps overrides; bands left as `None` are unchanged.
+    pub fn set_quoting(&mut self, quoting: QuotingBands) {
+        if let Some(ladder) = quoting.ladder {
+            self.tiers = ladder;
+        }
+        if let Some(target_bps) = quoting.target_bps {
+            self.set_mode(StrategyMode::Aggressive { target_bps });
+        }
+        if let Some(target_bps) = quoting.survival_bps {
+            self.survival_mode = StrategyMode::Survival { target_bps };
+        }
+        if let Some(skew_bps) = quoting.inventory_skew_bps {
+            self.inventory_skew_bps = skew_bps;
+        }
+        if let Some(skew_bps) = quoting.funding_skew_bps {
+            self.funding_skew_bps = skew_bps;
+        }
+        if let Some(jitter_bps) = quoting.size_jitter_bps {
+            self.size_jitter_bps = jitter_bps;
+        }
+        if let Some(dwell) = quoting.min_replace_dwell {
+            self.min_replace_dwell = dwell;
+        }
+        if let Some(reference) = quoting.reference_price {
+            self.quote_reference = reference;
+        }
+        if let Some(multiple) = quoting.fee_floor_multiple {
+            self.fee_floor_multiple = multiple;
+        }
+    }
+
+    /// Keep both quotes at least `fee_floor_multiple * taker_fee / 2` from the
+    /// reference price, so the spread covers the round-trip fee.
+    pub fn set_taker_fee(&mut self, taker_fee_rate: Decimal) {
+        self.taker_fee_bps =
+            decimal_max(taker_fee_rate, Decimal::ZERO) * Decimal::from(BPS_DENOMINATOR);
+        self.fee_floor_logged.clear();
+    }
+
+    /// Whether quotes follow the order book mid published via [`Self::set_book_mid`].
+    pub fn uses_book_mid(&self) -> bool {
+        self.quote_reference == QuoteReference::BookMid
+    }
+
+    /// Trim inventory with reduce-only IOC orders per `sweep`.
+    pub fn set_taker_sweep(&mut self, sweep: TakerSweep) {
+        self.taker_sweep = Some(sweep);
+    }
+
+    /// Warn and flag metrics while rolling uptime stays below `min_uptime`.
+    pub fn set_min_uptime(&mut self, min_uptime: MinUptime) {
+        self.min_uptime = Some(min_uptime);
+        self.uptime_tracker.retain_window(min_uptime.window);
+    }
+
+    /// Compare the rolling uptime with `min_uptime`, logging on each crossing.
+    ///
+    /// Returns whether uptime is below the floor, or `None` when no floor is
+    /// set or a full window has not elapsed yet.
+    fn check_min_uptime(&mut self, now: tokio::time::Instant) -> Option<bool> {
+        let min_uptime = self.min_uptime?;
+        if !self.uptime_tracker.covers(now, min_uptime.window) {
+            return None;
+        }
+        let ratio = self.uptime_tracker.window_ratio(now, min_uptime.window);
+        let below = ratio < min_uptime.ratio;
+        if below != self.uptime_below_min {
+            self.uptime_below_min = below;
+            if below {
+                warn!(
+                    symbol = %self.symbol,
+                    uptime_ratio = %ratio.round_dp(4),
+                    min_uptime_ratio = %min_uptime.ratio,
+                    window_secs = min_uptime.window.as_secs(),
+                    "rolling uptime below min_uptime_ratio; quotes may miss reward eligibility"
+                );
+            } else {
+                info!(
+                    symbol = %self.symbol,
+                    uptime_ratio = %ratio.round_dp(4),
+                    min_uptime_ratio = %min_uptime.ratio,
+                    "rolling uptime back above min_uptime_ratio"
+                );
+            }
+        }
+        Some(below)
+    }
+
+    /// Cancel quotes and stop quoting while no price arrives for `max_age`.
+    pub fn set_max_price_age(&mut self, max_age: Duration) {
+        self.max_price_age = Some(max_age);
+    }
+
+    /// Age of the last price snapshot once it exceeds `max_price_age`.
+    ///
+    /// The snapshot is as old as the later of its local arrival and its
+    /// exchange `time`, so a feed replaying a frozen price also counts.
+    fn stale_price_age(&self, now: tokio::time::Instant) -> Option<(Duration, &'static str)> {
+        let max_age = self.max_price_age?;
+        let received = now.saturating_duration_since(self.price_updated_at);
+        let published = exchange_price_age(&self.price_rx.borrow().time, Utc::now());
+        match published {
+            Some(published) if published > received => {
+                (published > max_age).then_some((published, "exchange timestamp"))
+            }
+            _ => (received > max_age).then_some((received, "last update")),
+        }
+    }
+
+    /// Make size jitter reproducible.
+    #[cfg(test)]
+    fn seed_size_jitter(&mut self, seed: u64) {
+        self.size_rng = StdRng::seed_from_u64(seed);
+    }
+
+    /// Follow the predicted funding rate published on `funding_rate_rx`.
+    pub fn set_funding_rate(&mut self, funding_rate_rx: watch::Receiver<Option<Decimal>>) {
+        self.funding_rate_rx = Some(funding_rate_rx);
+    }
+
+    /// Follow the order book mid published on `book_mid_rx`; `None` values
+    /// fall back to the ticker reference.
+    pub fn set_book_mid(&mut self, book_mid_rx: watch::Receiver<Option<Decimal>>) {
+        self.book_mid_rx = Some(book_mid_rx);
+    }
+
+    /// Skip quoting while the `cross_available` balance published on
+    /// `available_rx` cannot margin the ladder at `leverage`.
+    pub fn set_available_balance(
+        &mut self,
+        available_rx: watch::Receiver<Option<Decimal>>,
+        leverage: Decimal,
+    ) {
+        self.available_rx = Some(available_rx);
+        self.margin_leverage = leverage.max(Decimal::ONE);
+    }
+
+    /// Why quoting must wait for funds, if the latest balance cannot margin
+    /// the full ladder.
+    ///
+    /// Margin held by our own resting quotes counts as available, so live
+    /// quotes never trip the check by themselves.
+    fn insufficient_balance_reason(&self) -> Option<String> {
+        let available = (*self.available_rx.as_ref()?.borrow())?;
+        let held = self
+            .live_quotes
+            .values()
+            .map(|quote| quote.price * quote.qty)
+            .sum::<Decimal>()
+            / self.margin_leverage;
+        let required = self.budget_usd / self.margin_leverage;
+        if available + held >= required {
+            return None;
+        }
+        Some(format!(
+            "insufficient balance: {} USD available, ladder needs {} USD margin",
+            (available + held).round_dp(2),
+            required.round_dp(2)
+        ))
+    }
+
+    /// USD the task has committed: resting quote notional plus the position
+    /// valued at `mark_price`.
+    fn deployed_usd(&self, mark_price: Decimal) -> Decimal {
+        let resting = self
+            .live_quotes
+            .values()
+            .map(|quote| quote.price * quote.qty)
+            .sum::<Decimal>();
+        resting + self.inventory_qty.abs() * mark_price.max(Decimal::ZERO)
+    }
+
+    /// Cancel all quotes and stop quoting while `paused_rx` reads `true`.
+    pub fn set_pause(&mut self, paused_rx: watch::Receiver<bool>) {
+        self.paused_rx = Some(paused_rx);
+    }
+
+    fn is_paused(&self) -> bool {
+        self.paused_rx.as_ref().is_some_and(|rx| *rx.borrow())
+    }
+
+    /// Publish order placements and cancels on `events`, tagged with `task_id`,
+    /// and book that task's `Fill` events into realized PnL.
+    pub fn set_events(&mut self, events: EventBus, task_id: impl Into<String>) {
+        self.fill_events = Some(events.subscribe());
+        self.events = Some((events, task_id.into()));
+    }
+
     pub(crate) fn tier_count_for_risk(risk_level: RiskLevel) -> u8 {
         match risk_level {
             RiskLevel::Low => 5,
@@ -475,7 +1092,11 @@ impl MarketMakingStrategy {
     }
 
     pub fn uptime_snapshot(&self) -> UptimeSnapshot {
-        self.uptime_tracker.snapshot(tokio::time::Instant::now())
+        self.uptime_snapshot_at(tokio::time::Instant::now())
+    }
+
+    pub(crate) fn uptime_snapshot_at(&self, now: tokio::time::Instant) -> UptimeSnapshot {
+        self.uptime_tracker.snapshot(now)
     }
 
     pub fn set_symbol_constraints(
@@ -491,15 +1112,40 @@ impl MarketMakingStrategy {
         self.max_order_qty = max_order_qty;
     }
 
-    pub fn symbol(&self) -> &str {
-        &self.symbol
+    /// Apply the symbol's exchange limits; non-positive values mean unlimited.
+    ///
+    /// `max_position_size` is in base-asset quantity: quotes are clamped so the
+    /// position plus resting same-side quotes never exceeds it. `max_open_orders`
+    /// trims the ladder to the deepest tier whose bid+ask pair still fits.
+    pub fn set_position_limits(
+        &mut self,
+        max_position_size: Option<Decimal>,
+        max_open_orders: Option<Decimal>,
+    ) {
+        self.max_position_size = max_position_size.filter(|size| *size > Decimal::ZERO);
+        self.max_open_orders = max_open_orders
+            .filter(|limit| *limit > Decimal::ZERO)
+            .and_then(|limit| limit.to_usize());
+
+        let configured_tiers = self.tiers.len();
+        let quoted_tiers = self.active_tier_count();
+        if quoted_tiers < configured_tiers {
+            warn!(
+                symbol = %self.symbol,
+                configured_tiers,
+                quoted_tiers,
+                max_open_orders = ?self.max_open_orders,
+                "ladder trimmed to fit max_open_orders"
+            );
+        }
     }
 
-    pub async fn run(&mut self, client: &StandxClient, shutdown: CancellationToken) -> Result<()> {
-        self.run_with_executor(client, shutdown).await
+    pub fn symbol(&self) -> &str {
+        &self.symbol
     }
 
-    async fn run_with_executor(
+    /// Quote through `executor` until `shutdown` fires.
+    pub async fn run(
         &mut self,
         executor: &dyn OrderExecutor,
         shutdown: CancellationToken,
@@ -512,10 +1158,14 @@ impl MarketMakingStrategy {
         info!(
             symbol = %self.symbol,
             risk_level = ?self.risk_level,
-            tier_count = self.tier_count,
+            tier_count = self.tiers.len(),
             "strategy run loop starting"
         );
 
+        // Fills and cancel acks from the order stream re-quote right away
+        // instead of waiting for the next refresh tick.
+        let mut order_changes = self.order_tracker.lock().await.subscribe_changes();
+
         // Try to quote immediately using the current snapshot.
         self.refresh_from_latest(executor, tokio::time::Instant::now())
             .await?;
@@ -538,35 +1188,54 @@ impl MarketMakingStrategy {
                         continue;
                     }
 
-                    let (mark_price, reference_price) = {
-                        let snapshot = self.price_rx.borrow();
-                        (snapshot.mark_price, self.quote_reference_price(&snapshot))
-                    };
-                    if let Some(metrics) = self.metrics.as_ref() {
-                        let mut metrics = metrics.lock().await;
-                        metrics.record_price(mark_price);
+                    self.on_price_change(executor, tokio::time::Instant::now()).await?;
+                }
+                changed = pause_changed(&mut self.paused_rx) => {
+                    if changed.is_err() {
+                        self.paused_rx = None;
+                        continue;
                     }
-                    if self.live_quotes.is_empty() {
-                        // Kick-start quoting when idle.
-                        self.refresh_from_latest(executor, tokio::time::Instant::now()).await?;
-                    } else if self.should_refresh_for_price(reference_price, tokio::time::Instant::now()) {
-                        // Re-quote immediately when reference price drift exceeds threshold.
-                        self.refresh_from_latest(executor, tokio::time::Instant::now()).await?;
+
+                    if self.is_paused() {
+                        info!(symbol = %self.symbol, "quoting paused; cancelling quotes");
+                    } else {
+                        info!(symbol = %self.symbol, "quoting resumed");
                     }
+                    self.refresh_from_latest(executor, tokio::time::Instant::now()).await?;
                 }
                 changed = self.position_rx.changed() => {
                     if changed.is_err() {
                         continue;
                     }
 
-                    self.sync_inventory_from_position();
+                    self.on_position_change(executor, tokio::time::Instant::now()).await?;
+                }
+                Ok(()) = order_changes.changed() => {
                     self.refresh_from_latest(executor, tokio::time::Instant::now()).await?;
                 }
+                event = next_event(&mut self.fill_events) => {
+                    match event {
+                        Some(event) => self.on_runtime_event(event).await,
+                        None => self.fill_events = None,
+                    }
+                }
                 _ = heartbeat.tick() => {
+                    self.reconcile_ledger_position();
                     let snapshot = self.uptime_snapshot();
+                    let uptime_below_min = self.check_min_uptime(tokio::time::Instant::now());
                     if let Some(metrics) = self.metrics.as_ref() {
+                        let deployed_usd = self.deployed_usd(self.price_rx.borrow().mark_price);
                         let mut metrics = metrics.lock().await;
                         metrics.record_heartbeat();
+                        metrics.record_deployment(deployed_usd, self.budget_usd);
+                        metrics.record_uptime_ratio(snapshot.uptime_ratio, snapshot.uptime_ratio_1h);
+                        if let Some(below) = uptime_below_min {
+                            metrics.record_uptime_below_min(below);
+                        }
+                        metrics.record_realized_pnl(
+                            self.risk_manager.realized_pnl_today(),
+                            self.risk_manager.realized_pnl_session(),
+                        );
                     }
                     debug!(
                         symbol = %self.symbol,
@@ -580,7 +1249,50 @@ impl MarketMakingStrategy {
         }
     }
 
-    async fn refresh_from_latest(
+    /// React to a new price snapshot on `price_rx`.
+    pub(crate) async fn on_price_change(
+        &mut self,
+        executor: &dyn OrderExecutor,
+        now: tokio::time::Instant,
+    ) -> Result<()> {
+        self.price_updated_at = now;
+        self.price_received = true;
+        let (mark_price, reference_price) = {
+            let snapshot = self.price_rx.borrow();
+            (snapshot.mark_price, self.quote_reference_price(&snapshot))
+        };
+        if let Some(metrics) = self.metrics.as_ref() {
+            let mut metrics = metrics.lock().await;
+            metrics.record_price(mark_price);
+            metrics.record_deployment(self.deployed_usd(mark_price), self.budget_usd);
+        }
+        if self.live_quotes.is_empty()
+            || self.price_stale
+            || self.price_invalid
+            || mark_price <= Decimal::ZERO
+            || reference_price <= Decimal::ZERO
+        {
+            // Kick-start quoting when idle or back from a bad feed, and pull
+            // quotes right away on a bad price.
+            self.refresh_from_latest(executor, now).await?;
+        } else if self.should_refresh_for_price(reference_price, now) {
+            // Re-quote immediately when reference price drift exceeds threshold.
+            self.refresh_from_latest(executor, now).await?;
+        }
+        Ok(())
+    }
+
+    /// React to a new authoritative position on `position_rx`.
+    pub(crate) async fn on_position_change(
+        &mut self,
+        executor: &dyn OrderExecutor,
+        now: tokio::time::Instant,
+    ) -> Result<()> {
+        self.sync_inventory_from_position();
+        self.refresh_from_latest(executor, now).await
+    }
+
+    pub(crate) async fn refresh_from_latest(
         &mut self,
         executor: &dyn OrderExecutor,
         now: tokio::time::Instant,
@@ -590,16 +1302,112 @@ impl MarketMakingStrategy {
         // Check fills before placing new quotes.
         self.handle_fills(now).await?;
 
+        if self.is_paused() {
+            self.cancel_all_quotes(executor, now).await;
+            self.uptime_tracker.update(now, false);
+            return Ok(());
+        }
+
+        // Quoting around an old mark is how a silent feed turns into
+        // adverse fills, so nothing rests while prices are stale.
+        if let Some((age, source)) = self.stale_price_age(now) {
+            if !self.price_stale {
+                let age_ms = age.as_millis() as u64;
+                warn!(
+                    symbol = %self.symbol,
+                    age_ms,
+                    source,
+                    "price feed stale; pulling quotes"
+                );
+                self.price_stale = true;
+                self.emit(|task_id| RuntimeEvent::PriceStale {
+                    task_id,
+                    symbol: self.symbol.clone(),
+                    age_ms,
+                });
+            }
+            self.cancel_all_quotes(executor, now).await;
+            self.uptime_tracker.update(now, false);
+            return Ok(());
+        }
+        if self.price_stale {
+            info!(symbol = %self.symbol, "price feed fresh again; resuming quotes");
+            self.price_stale = false;
+            self.emit(|task_id| RuntimeEvent::PriceRecovered {
+                task_id,
+                symbol: self.symbol.clone(),
+            });
+        }
+
         let (mark_price, reference_price) = {
             let snapshot = self.price_rx.borrow();
             (snapshot.mark_price, self.quote_reference_price(&snapshot))
         };
-        if reference_price <= Decimal::ZERO {
+        // Offsets computed from a zero or negative price are nonsense, so
+        // nothing rests until a positive price comes back.
+        if mark_price <= Decimal::ZERO || reference_price <= Decimal::ZERO {
+            if self.price_received && !self.price_invalid {
+                warn!(
+                    symbol = %self.symbol,
+                    %mark_price,
+                    %reference_price,
+                    "non-positive mark/reference price; pulling quotes"
+                );
+                self.price_invalid = true;
+            }
+            self.cancel_all_quotes(executor, now).await;
+            self.uptime_tracker.update(now, false);
+            return Ok(());
+        }
+        if self.price_invalid {
+            info!(symbol = %self.symbol, %mark_price, "valid price again; resuming quotes");
+            self.price_invalid = false;
+        }
+
+        // Reducing inventory stays allowed while quoting is halted.
+        self.sweep_inventory(executor, now, mark_price).await;
+
+        if let Some(reason) = self.risk_manager.daily_loss_halt(Utc::now()) {
+            if !self.daily_loss_halted {
+                warn!(symbol = %self.symbol, %reason, "daily loss limit reached; halting quotes");
+                self.daily_loss_halted = true;
+                if let Some(metrics) = self.metrics.as_ref() {
+                    metrics.lock().await.record_halt(reason);
+                }
+            }
+            self.cancel_all_quotes(executor, now).await;
+            self.uptime_tracker.update(now, false);
+            return Ok(());
+        }
+        if self.daily_loss_halted {
+            info!(symbol = %self.symbol, "daily loss halt lifted; resuming quotes");
+            self.daily_loss_halted = false;
+            if let Some(metrics) = self.metrics.as_ref() {
+                metrics.lock().await.clear_halt();
+            }
+        }
+
+        if let Some(reason) = self.insufficient_balance_reason() {
+            if !self.balance_halted {
+                warn!(symbol = %self.symbol, %reason, "skipping quotes until the account is funded");
+                self.balance_halted = true;
+                if let Some(metrics) = self.metrics.as_ref() {
+                    metrics.lock().await.record_halt(reason);
+                }
+            }
+            self.cancel_all_quotes(executor, now).await;
             self.uptime_tracker.update(now, false);
             return Ok(());
         }
+        if self.balance_halted {
+            info!(symbol = %self.symbol, "balance covers the ladder again; resuming quotes");
+            self.balance_halted = false;
+            if let Some(metrics) = self.metrics.as_ref() {
+                metrics.lock().await.clear_halt();
+            }
+        }
 
-        let risk_now = std::time::Instant::now();
+        let risk_now = now.into_std();
         self.risk_manager.record_price(risk_now, mark_price);
         let risk_state = self.risk_manager.assess(risk_now, None, None);
         match &risk_state {
@@ -621,17 +1429,179 @@ impl MarketMakingStrategy {
         self.refresh_quotes(executor, now, reference_price).await
     }
 
-    fn update_mode_for_timers(&mut self, now: tokio::time::Instant) {
-        if let Some(until) = self.survival_until
-            && now >= until
-        {
-            self.survival_until = None;
-            self.mode = self.preferred_mode;
+    /// Submit a reduce-only IOC within `max_slippage_bps` of mark once
+    /// inventory has stayed above the sweep threshold for `after`, taking at
+    /// most `max_usd_per_interval` per interval.
+    async fn sweep_inventory(
+        &mut self,
+        executor: &dyn OrderExecutor,
+        now: tokio::time::Instant,
+        mark_price: Decimal,
+    ) {
+        let Some(sweep) = self.taker_sweep else {
+            return;
+        };
+        let inventory = self.inventory_qty;
+        if mark_price <= Decimal::ZERO || inventory.abs() * mark_price <= sweep.threshold_usd {
+            self.sweep_armed_at = None;
+            return;
+        }
+        let armed_at = *self.sweep_armed_at.get_or_insert(now);
+        if now.saturating_duration_since(armed_at) < sweep.after {
+            return;
+        }
+
+        let (window_start, taken_usd) = match self.sweep_window {
+            Some((started, taken)) if now.saturating_duration_since(started) < sweep.interval => {
+                (started, taken)
+            }
+            _ => (now, Decimal::ZERO),
+        };
+        let room_usd = sweep.max_usd_per_interval - taken_usd;
+        if room_usd <= Decimal::ZERO {
+            return;
+        }
+        let qty = self.align_qty_for_order(decimal_min(inventory.abs(), room_usd / mark_price));
+        if qty <= Decimal::ZERO {
+            return;
+        }
+
+        // Taking liquidity: sell below mark, buy above it, rounded toward mark.
+        let (side, side_label, price) = if inventory > Decimal::ZERO {
+            let price = price_at_bps(mark_price, Side::Buy, sweep.max_slippage_bps);
+            let price = match self.price_tick_decimals {
+                Some(decimals) => {
+                    price.round_dp_with_strategy(decimals, RoundingStrategy::AwayFromZero)
+                }
+                None => price,
+            };
+            (Side::Sell, "sell", price)
+        } else {
+            let price = price_at_bps(mark_price, Side::Sell, sweep.max_slippage_bps);
+            (Side::Buy, "buy", self.align_price_for_order(price))
+        };
+
+        let req = NewOrderRequest {
+            symbol: self.symbol.clone(),
+            side,
+            order_type: OrderType::Limit,
+            qty,
+            time_in_force: TimeInForce::Ioc,
+            reduce_only: true,
+            price: Some(price),
+            cl_ord_id: Some(format!(
+                "sweep:{}:{side_label}:{}",
+                self.symbol,
+                Uuid::new_v4()
+            )),
+            margin_mode: None,
+            leverage: None,
+            tp_price: None,
+            sl_price: None,
+        };
+
+        match executor.new_order(req).await {
+            Ok(resp) if resp.code == 0 => {
+                self.sweep_window = Some((window_start, taken_usd + qty * mark_price));
+                info!(
+                    symbol = %self.symbol,
+                    side = %side_label,
+                    qty = %qty,
+                    price = %price,
+                    inventory = %inventory,
+                    "taker sweep submitted reduce-only ioc"
+                );
+            }
+            Ok(resp) => {
+                warn!(
+                    symbol = %self.symbol,
+                    code = resp.code,
+                    message = %resp.message,
+                    "taker sweep rejected"
+                );
+            }
+            Err(err) => {
+                warn!(symbol = %self.symbol, error = %err, "taker sweep failed");
+            }
+        }
+    }
+
+    fn update_mode_for_timers(&mut self, now: tokio::time::Instant) {
+        if let Some(until) = self.survival_until
+            && now >= until
+        {
+            self.survival_until = None;
+            self.mode = self.preferred_mode;
         }
 
         self.update_backoff_for_timers(now);
     }
 
+    /// Book one of this task's fills into realized PnL at its own price and
+    /// publish the new totals right away.
+    pub(crate) async fn on_runtime_event(&mut self, event: RuntimeEvent) {
+        let RuntimeEvent::Fill {
+            task_id,
+            side,
+            price,
+            fill_qty,
+            fee,
+            ..
+        } = event
+        else {
+            return;
+        };
+        if self
+            .events
+            .as_ref()
+            .is_none_or(|(_, own_task_id)| *own_task_id != task_id)
+        {
+            return;
+        }
+        let signed_qty = if side.eq_ignore_ascii_case("sell") {
+            -fill_qty
+        } else {
+            fill_qty
+        };
+        self.risk_manager
+            .record_trade(Utc::now(), signed_qty, price, fee);
+        if let Some(metrics) = self.metrics.as_ref() {
+            metrics.lock().await.record_realized_pnl(
+                self.risk_manager.realized_pnl_today(),
+                self.risk_manager.realized_pnl_session(),
+            );
+        }
+    }
+
+    /// Book PnL-ledger drift from the authoritative position at the mark once
+    /// the same mismatch is seen on two heartbeats in a row, so a position
+    /// update that races ahead of its fill event is not mistaken for drift.
+    fn reconcile_ledger_position(&mut self) {
+        let actual_position_qty = *self.position_rx.borrow();
+        if actual_position_qty == self.risk_manager.tracked_position_qty() {
+            self.ledger_drift_qty = None;
+            return;
+        }
+        if self.ledger_drift_qty != Some(actual_position_qty) {
+            self.ledger_drift_qty = Some(actual_position_qty);
+            return;
+        }
+        let mark_price = self.price_rx.borrow().mark_price;
+        if mark_price <= Decimal::ZERO {
+            return;
+        }
+        warn!(
+            symbol = %self.symbol,
+            tracked_qty = %self.risk_manager.tracked_position_qty(),
+            actual_position_qty = %actual_position_qty,
+            %mark_price,
+            "pnl ledger drifted from authoritative position; booking drift at mark"
+        );
+        self.risk_manager
+            .book_position_drift(Utc::now(), actual_position_qty, mark_price);
+        self.ledger_drift_qty = None;
+    }
+
     fn sync_inventory_from_position(&mut self) {
         let actual_position_qty = *self.position_rx.borrow();
         if actual_position_qty == self.inventory_qty {
@@ -676,7 +1646,10 @@ impl MarketMakingStrategy {
                 info!(symbol = %self.symbol, "bootstrap fill detected; switching to bilateral quoting");
             }
             self.handled_fills.insert(cl_ord_id);
-            self.risk_manager.record_fill(std::time::Instant::now());
+            self.risk_manager.record_fill(now.into_std());
+            if let Some(metrics) = self.metrics.as_ref() {
+                metrics.lock().await.record_fill();
+            }
             if let Some(quote) = self.live_quotes.get(&slot) {
                 let signed_qty = match slot.side {
                     QuoteSide::Bid => quote.qty,
@@ -711,6 +1684,8 @@ impl MarketMakingStrategy {
         now: tokio::time::Instant,
         reference_price: Decimal,
     ) -> Result<()> {
+        self.sweep_retiring_quotes(executor, now).await;
+
         self.base_qty = self.derived_base_qty(reference_price);
         if self.base_qty <= Decimal::ZERO {
             self.cancel_all_quotes(executor, now).await;
@@ -718,14 +1693,16 @@ impl MarketMakingStrategy {
             return Ok(());
         }
 
-        for tier in self.active_tiers() {
+        for index in 0..self.active_tier_count() {
             for side in [QuoteSide::Bid, QuoteSide::Ask] {
+                let slot = QuoteSlot {
+                    tier: Tier(index),
+                    side,
+                };
                 if !self.bootstrap_allows_side(side) {
-                    let slot = QuoteSlot { tier: *tier, side };
                     self.cancel_slot_if_present(executor, now, slot, None).await;
                     continue;
                 }
-                let slot = QuoteSlot { tier: *tier, side };
                 self.refresh_slot(executor, now, reference_price, slot)
                     .await?;
             }
@@ -743,7 +1720,7 @@ impl MarketMakingStrategy {
             .values()
             .filter(|quote| quote.cancel_in_flight.is_none())
             .count();
-        live == self.active_tiers().len() * 2
+        live == self.active_tier_count() * 2
     }
 
     async fn refresh_slot(
@@ -753,12 +1730,14 @@ impl MarketMakingStrategy {
         reference_price: Decimal,
         slot: QuoteSlot,
     ) -> Result<()> {
+        self.log_fee_floor_override(slot.tier);
         let target_bps = self.target_bps_for_tier(slot.tier);
-        let mut desired_price =
-            price_at_bps(reference_price, slot.side.to_order_side(), target_bps);
+        let quote_bps = self.skewed_bps(target_bps, slot.side, reference_price);
+        let mut desired_price = price_at_bps(reference_price, slot.side.to_order_side(), quote_bps);
         desired_price = self.align_price_for_order(desired_price);
         let desired_qty = self.desired_qty_for_slot(slot.tier, slot.side, target_bps, now);
         let capped_qty = self.cap_qty_for_inventory(slot.side, desired_qty, reference_price);
+        let capped_qty = self.cap_qty_for_position_limit(slot, capped_qty);
         let backoff_active = self.is_backoff_active(slot.side, now);
 
         if capped_qty <= Decimal::ZERO || desired_price <= Decimal::ZERO {
@@ -914,17 +1893,28 @@ impl MarketMakingStrategy {
                     false
                 };
 
-                if outside_band || drift_replace || wants_reduce {
-                    self.cancel_slot_if_present(
-                        executor,
-                        now,
-                        slot,
-                        Some(PendingQuote {
-                            price: desired_price,
-                            qty: effective_qty,
-                        }),
-                    )
-                    .await;
+                let price_replace = (outside_band || drift_replace)
+                    && !self.replace_throttled(still_price, desired_price, placed_at, now);
+
+                if price_replace || wants_reduce {
+                    let pending = PendingQuote {
+                        price: desired_price,
+                        qty: effective_qty,
+                    };
+                    if self.replace_order == ReplaceOrder::Amend
+                        && self.amend_slot(executor, now, slot, &pending).await
+                    {
+                        return Ok(());
+                    }
+                    // Size reductions after a fill always cancel first so
+                    // exposure never grows while shrinking the quote.
+                    if self.replace_order == ReplaceOrder::PlaceFirst && !wants_reduce {
+                        self.replace_place_first(executor, now, slot, pending, reference_price)
+                            .await?;
+                    } else {
+                        self.cancel_slot_if_present(executor, now, slot, Some(pending))
+                            .await;
+                    }
                 } else {
                     // Keep current quote; update qty bookkeeping when partially filled.
                     if effective_qty != still_qty
@@ -959,13 +1949,61 @@ impl MarketMakingStrategy {
         let _ = self.order_reconcile_tx.send(request);
     }
 
+    fn emit(&self, build: impl FnOnce(String) -> RuntimeEvent) {
+        if let Some((events, task_id)) = &self.events {
+            events.emit(build(task_id.clone()));
+        }
+    }
+
+    fn emit_cancelled(&self, cl_ord_id: &str) {
+        self.emit(|task_id| RuntimeEvent::OrderCancelled {
+            task_id,
+            symbol: self.symbol.clone(),
+            cl_ord_id: cl_ord_id.to_string(),
+        });
+    }
+
     fn target_bps_for_tier(&self, tier: Tier) -> Decimal {
         let (min, max) = self.quote_band_for_tier(tier);
         (min + max) / Decimal::from(2)
     }
 
+    /// Tier band after the fee floor: both bounds are raised to at least
+    /// [`Self::spread_floor_bps`].
     fn quote_band_for_tier(&self, tier: Tier) -> (Decimal, Decimal) {
-        let (tier_min, tier_max) = tier.min_max_bps();
+        let (min, max) = self.configured_band_for_tier(tier);
+        let floor = self.spread_floor_bps();
+        (decimal_max(min, floor), decimal_max(max, floor))
+    }
+
+    /// Narrowest distance either side may quote from the reference price.
+    fn spread_floor_bps(&self) -> Decimal {
+        self.taker_fee_bps * self.fee_floor_multiple / Decimal::TWO
+    }
+
+    fn log_fee_floor_override(&mut self, tier: Tier) {
+        let (configured_min, configured_max) = self.configured_band_for_tier(tier);
+        let floor = self.spread_floor_bps();
+        if configured_min >= floor || !self.fee_floor_logged.insert(tier) {
+            return;
+        }
+        tracing::info!(
+            symbol = %self.symbol,
+            tier = %tier,
+            configured_min_bps = %configured_min,
+            configured_max_bps = %configured_max,
+            floor_bps = %floor,
+            taker_fee_bps = %self.taker_fee_bps,
+            "fee floor raises tier band"
+        );
+    }
+
+    fn configured_band_for_tier(&self, tier: Tier) -> (Decimal, Decimal) {
+        let TierBand {
+            min_bps: tier_min,
+            max_bps: tier_max,
+            ..
+        } = self.tiers[tier.0];
         let (mode_min, mode_max) = self.mode.target_range();
 
         let min = decimal_max(tier_min, mode_min);
@@ -978,6 +2016,71 @@ impl MarketMakingStrategy {
         }
     }
 
+    /// Signed bps added to `side`'s quote distance to lean against inventory.
+    ///
+    /// Long inventory pushes the bid out and pulls the ask in (short does the
+    /// reverse), in proportion to inventory value over `max_non_usd_value`,
+    /// capped at one full `inventory_skew_bps`.
+    fn inventory_skew_for_side(&self, side: QuoteSide, reference_price: Decimal) -> Decimal {
+        if self.inventory_skew_bps <= Decimal::ZERO
+            || self.max_non_usd_value <= Decimal::ZERO
+            || reference_price <= Decimal::ZERO
+        {
+            return Decimal::ZERO;
+        }
+        let ratio = (self.inventory_qty * reference_price / self.max_non_usd_value)
+            .clamp(-Decimal::ONE, Decimal::ONE);
+        let offset = self.inventory_skew_bps * ratio;
+        match side {
+            QuoteSide::Bid => offset,
+            QuoteSide::Ask => -offset,
+        }
+    }
+
+    /// Signed bps added to `side`'s quote distance to avoid holding the side
+    /// that pays funding.
+    ///
+    /// Positive funding (longs pay) pushes the bid out and pulls the ask in;
+    /// negative funding does the reverse. Strength scales with the rate up to
+    /// `FUNDING_RATE_FULL_SKEW` and with progress through the funding
+    /// interval, reaching one full `funding_skew_bps` at settlement.
+    fn funding_skew_for_side(&self, side: QuoteSide, now: DateTime<Utc>) -> Decimal {
+        let Some(funding_rate) = self.funding_rate_rx.as_ref().and_then(|rx| *rx.borrow()) else {
+            return Decimal::ZERO;
+        };
+        if self.funding_skew_bps <= Decimal::ZERO || funding_rate.is_zero() {
+            return Decimal::ZERO;
+        }
+        let strength = (funding_rate / FUNDING_RATE_FULL_SKEW).clamp(-Decimal::ONE, Decimal::ONE);
+        let progress = Decimal::from(now.timestamp().rem_euclid(FUNDING_INTERVAL_SECS))
+            / Decimal::from(FUNDING_INTERVAL_SECS);
+        let offset = self.funding_skew_bps * strength * progress;
+        match side {
+            QuoteSide::Bid => offset,
+            QuoteSide::Ask => -offset,
+        }
+    }
+
+    fn skewed_bps(&self, bps: Decimal, side: QuoteSide, reference_price: Decimal) -> Decimal {
+        self.skewed_bps_at(bps, side, reference_price, Utc::now())
+    }
+
+    fn skewed_bps_at(
+        &self,
+        bps: Decimal,
+        side: QuoteSide,
+        reference_price: Decimal,
+        now: DateTime<Utc>,
+    ) -> Decimal {
+        let skew = self.inventory_skew_for_side(side, reference_price)
+            + self.funding_skew_for_side(side, now);
+        if skew.is_zero() {
+            return bps;
+        }
+        let floor = decimal_max(Decimal::from(MIN_SKEWED_QUOTE_BPS), self.spread_floor_bps());
+        decimal_max(bps + skew, floor)
+    }
+
     fn should_refresh_for_price(
         &self,
         reference_price: Decimal,
@@ -989,8 +2092,26 @@ impl MarketMakingStrategy {
 
         for (slot, quote) in self.live_quotes.iter() {
             let (band_min, band_max) = self.quote_band_for_tier(slot.tier);
+            let band_min = self.skewed_bps(band_min, slot.side, reference_price);
+            let band_max = self.skewed_bps(band_max, slot.side, reference_price);
             let current_bps =
                 bps_from_price(reference_price, slot.side.to_order_side(), quote.price);
+            let target_bps = self.skewed_bps(
+                self.target_bps_for_tier(slot.tier),
+                slot.side,
+                reference_price,
+            );
+            let desired_price =
+                price_at_bps(reference_price, slot.side.to_order_side(), target_bps);
+            if self.replace_throttled(
+                quote.price,
+                self.align_price_for_order(desired_price),
+                quote.placed_at,
+                now,
+            ) {
+                continue;
+            }
+
             if current_bps < band_min || current_bps > band_max {
                 return true;
             }
@@ -998,9 +2119,6 @@ impl MarketMakingStrategy {
             if slot.tier == Tier::L1 {
                 let age = now.saturating_duration_since(quote.placed_at);
                 if l1_drift_check_ready(age, current_bps) {
-                    let target_bps = self.target_bps_for_tier(slot.tier);
-                    let desired_price =
-                        price_at_bps(reference_price, slot.side.to_order_side(), target_bps);
                     let drift_threshold = self.replace_drift_threshold_bps(slot.tier);
                     if should_replace(quote.price, desired_price, drift_threshold) {
                         return true;
@@ -1012,7 +2130,31 @@ impl MarketMakingStrategy {
         false
     }
 
+    /// Hold a quote against price-driven replacement until the aligned target
+    /// moves by at least one price tick and it has rested `min_replace_dwell`.
+    fn replace_throttled(
+        &self,
+        current_price: Decimal,
+        desired_price: Decimal,
+        placed_at: tokio::time::Instant,
+        now: tokio::time::Instant,
+    ) -> bool {
+        let moved = (desired_price - current_price).abs();
+        let tick_moved = match self.price_tick_decimals {
+            Some(decimals) => moved >= Decimal::new(1, decimals),
+            None => !moved.is_zero(),
+        };
+        !tick_moved || now.saturating_duration_since(placed_at) < self.min_replace_dwell
+    }
+
     fn quote_reference_price(&self, snapshot: &SymbolPrice) -> Decimal {
+        if self.quote_reference == QuoteReference::BookMid
+            && let Some(book_mid) = self.book_mid_rx.as_ref().and_then(|rx| *rx.borrow())
+            && book_mid > Decimal::ZERO
+        {
+            return book_mid;
+        }
+
         if let Some(mid_price) = snapshot.mid_price
             && mid_price > Decimal::ZERO
         {
@@ -1072,6 +2214,70 @@ impl MarketMakingStrategy {
         decimal_min(desired_qty, allowed_qty)
     }
 
+    /// Clamp `qty` so the position plus every resting quote on `slot.side`
+    /// stays within `max_position_size`. A side whose position already sits
+    /// at the limit is suppressed until inventory comes back down.
+    fn cap_qty_for_position_limit(&mut self, slot: QuoteSlot, qty: Decimal) -> Decimal {
+        let Some(max_position_size) = self.max_position_size else {
+            return qty;
+        };
+        if qty <= Decimal::ZERO {
+            return qty;
+        }
+
+        let side_exposure = match slot.side {
+            QuoteSide::Bid => self.inventory_qty,
+            QuoteSide::Ask => -self.inventory_qty,
+        };
+        if side_exposure >= max_position_size {
+            if self.position_capped_sides.insert(slot.side) {
+                info!(
+                    symbol = %self.symbol,
+                    side = ?slot.side,
+                    inventory_qty = %self.inventory_qty,
+                    max_position_size = %max_position_size,
+                    "max position size reached; suppressing quotes on this side"
+                );
+            }
+            return Decimal::ZERO;
+        }
+        if self.position_capped_sides.remove(&slot.side) {
+            info!(
+                symbol = %self.symbol,
+                side = ?slot.side,
+                inventory_qty = %self.inventory_qty,
+                max_position_size = %max_position_size,
+                "inventory back below max position size; resuming quotes on this side"
+            );
+        }
+
+        let resting_qty = self
+            .live_quotes
+            .iter()
+            .filter(|(other, _)| other.side == slot.side && **other != slot)
+            .fold(Decimal::ZERO, |acc, (_, quote)| acc + quote.qty);
+        let room = decimal_max(
+            max_position_size - side_exposure - resting_qty,
+            Decimal::ZERO,
+        );
+        if qty <= room {
+            return qty;
+        }
+
+        debug!(
+            symbol = %self.symbol,
+            tier = ?slot.tier,
+            side = ?slot.side,
+            desired_qty = %qty,
+            clamped_qty = %room,
+            inventory_qty = %self.inventory_qty,
+            resting_qty = %resting_qty,
+            max_position_size = %max_position_size,
+            "quote qty clamped to max position size"
+        );
+        room
+    }
+
     fn desired_qty_for_slot(
         &self,
         tier: Tier,
@@ -1123,50 +2329,29 @@ impl MarketMakingStrategy {
     }
 
     fn total_weight(&self) -> Decimal {
-        self.active_tiers()
+        self.tiers[..self.active_tier_count()]
             .iter()
-            .fold(Decimal::ZERO, |acc, tier| acc + self.tier_weight(*tier))
+            .fold(Decimal::ZERO, |acc, tier| acc + tier.weight)
     }
 
     fn tier_weight(&self, tier: Tier) -> Decimal {
-        match self.tier_count {
-            1 => match tier {
-                Tier::L1 => Decimal::ONE,
-                _ => Decimal::ZERO,
-            },
-            2 => match tier {
-                Tier::L1 => Decimal::new(6, 1),
-                Tier::L2 => Decimal::new(4, 1),
-                _ => Decimal::ZERO,
-            },
-            3 => match tier {
-                Tier::L1 => Decimal::new(4, 1),
-                Tier::L2 => Decimal::new(35, 2),
-                Tier::L3 => Decimal::new(25, 2),
-                _ => Decimal::ZERO,
-            },
-            _ => match tier {
-                Tier::L1 => Decimal::new(3, 1),
-                Tier::L2 => Decimal::new(25, 2),
-                Tier::L3 => Decimal::new(2, 1),
-                Tier::L4 => Decimal::new(15, 2),
-                Tier::L5 => Decimal::new(1, 1),
-            },
-        }
-    }
-
-    fn active_tiers(&self) -> &'static [Tier] {
-        match self.tier_count {
-            1 => &TIERS_L1,
-            2 => &TIERS_L1_L2,
-            3 => &TIERS_L1_L2_L3,
-            _ => &TIERS_ALL,
+        self.tiers
+            .get(tier.0)
+            .map_or(Decimal::ZERO, |band| band.weight)
+    }
+
+    fn active_tier_count(&self) -> usize {
+        let tiers = self.tiers.len();
+        match self.max_open_orders {
+            // Each tier rests one bid and one ask; always keep L1.
+            Some(limit) => (limit / 2).clamp(1, tiers),
+            None => tiers,
         }
     }
 
     fn enter_survival(&mut self, now: tokio::time::Instant) {
         if !self.mode.is_survival() {
-            self.mode = StrategyMode::survival_default();
+            self.mode = self.survival_mode;
         }
         self.survival_until = Some(now + SURVIVAL_AFTER_FILL);
     }
@@ -1206,9 +2391,10 @@ impl MarketMakingStrategy {
     }
 
     fn replace_drift_threshold_bps(&self, tier: Tier) -> Decimal {
-        match tier {
-            Tier::L1 => Decimal::new(5, 1),
-            _ => Decimal::from(REPLACE_DRIFT_BPS),
+        if tier == Tier::L1 {
+            Decimal::new(5, 1)
+        } else {
+            Decimal::from(REPLACE_DRIFT_BPS)
         }
     }
 
@@ -1229,12 +2415,13 @@ impl MarketMakingStrategy {
         if qty <= Decimal::ZERO {
             return Ok(());
         }
+        let qty = self.jitter_qty(qty);
 
         let cl_ord_id = format!(
             "mm:{}:{}:{}:{}",
             self.symbol,
             slot.side.as_str(),
-            slot.tier.as_str(),
+            slot.tier,
             Uuid::new_v4()
         );
 
@@ -1272,12 +2459,20 @@ impl MarketMakingStrategy {
                 info!(
                     symbol = %self.symbol,
                     side = %slot.side.as_str(),
-                    tier = %slot.tier.as_str(),
+                    tier = %slot.tier,
                     reference_price = %reference_price,
                     %price,
                     %qty,
                     "placed PostOnly quote"
                 );
+                self.emit(|task_id| RuntimeEvent::OrderPlaced {
+                    task_id,
+                    symbol: self.symbol.clone(),
+                    cl_ord_id: cl_ord_id.clone(),
+                    side: slot.side.as_str().to_string(),
+                    price,
+                    qty,
+                });
 
                 self.live_quotes.insert(
                     slot,
@@ -1296,7 +2491,7 @@ impl MarketMakingStrategy {
                 error!(
                     symbol = %self.symbol,
                     side = %slot.side.as_str(),
-                    tier = %slot.tier.as_str(),
+                    tier = %slot.tier,
                     %price,
                     %qty,
                     code = resp.code,
@@ -1315,7 +2510,7 @@ impl MarketMakingStrategy {
                 error!(
                     symbol = %self.symbol,
                     side = %slot.side.as_str(),
-                    tier = %slot.tier.as_str(),
+                    tier = %slot.tier,
                     %price,
                     %qty,
                     error = %err,
@@ -1361,9 +2556,10 @@ impl MarketMakingStrategy {
             cl_ord_id: Some(cl_ord_id.clone()),
         };
 
-        match executor.cancel_order(req).await {
+        let accepted = match executor.cancel_order(req).await {
             Ok(resp) if resp.code == 0 => {
                 info!(symbol = %self.symbol, cl_ord_id = %cl_ord_id, "cancel requested");
+                true
             }
             Ok(resp) => {
                 warn!(
@@ -1373,11 +2569,13 @@ impl MarketMakingStrategy {
                     message = %resp.message,
                     "cancel_order returned non-zero code"
                 );
+                false
             }
             Err(err) => {
                 warn!(symbol = %self.symbol, cl_ord_id = %cl_ord_id, error = %err, "cancel_order http failed");
+                false
             }
-        }
+        };
 
         existing.cancel_in_flight = Some(CancelInFlight {
             sent_at: now,
@@ -1385,78 +2583,316 @@ impl MarketMakingStrategy {
             last_reconcile_at: None,
             pending,
         });
-    }
-
-    async fn cancel_all_quotes(&mut self, executor: &dyn OrderExecutor, now: tokio::time::Instant) {
-        let slots: Vec<QuoteSlot> = self.live_quotes.keys().copied().collect();
-        for slot in slots {
-            self.cancel_slot_if_present(executor, now, slot, None).await;
+        if accepted {
+            self.emit_cancelled(&cl_ord_id);
         }
     }
 
-    fn align_qty_for_order(&self, qty: Decimal) -> Decimal {
-        if qty <= Decimal::ZERO {
-            return qty;
-        }
-
-        let mut aligned = match self.qty_tick_decimals {
-            Some(decimals) => qty.round_dp_with_strategy(decimals, RoundingStrategy::ToZero),
-            None => qty,
+    async fn replace_place_first(
+        &mut self,
+        executor: &dyn OrderExecutor,
+        now: tokio::time::Instant,
+        slot: QuoteSlot,
+        pending: PendingQuote,
+        reference_price: Decimal,
+    ) -> Result<()> {
+        let Some(previous) = self.live_quotes.remove(&slot) else {
+            return Ok(());
         };
 
-        if let Some(max_qty) = self.max_order_qty
-            && aligned > max_qty
+        if let Err(err) = self
+            .place_slot(
+                executor,
+                now,
+                slot,
+                pending.price,
+                pending.qty,
+                reference_price,
+            )
+            .await
         {
-            aligned = match self.qty_tick_decimals {
-                Some(decimals) => {
-                    max_qty.round_dp_with_strategy(decimals, RoundingStrategy::ToZero)
-                }
-                None => max_qty,
-            };
+            self.live_quotes.insert(slot, previous);
+            return Err(err);
         }
 
-        if let Some(min_qty) = self.min_order_qty
-            && aligned < min_qty
-        {
-            return Decimal::ZERO;
+        if let std::collections::hash_map::Entry::Vacant(entry) = self.live_quotes.entry(slot) {
+            // Nothing placeable after tick alignment; keep the old quote resting.
+            entry.insert(previous);
+            return Ok(());
         }
 
-        aligned
+        self.cancel_retiring(executor, &previous.cl_ord_id).await;
+        self.retiring_quotes.push(RetiringQuote {
+            cl_ord_id: previous.cl_ord_id,
+            cancel_sent_at: now,
+        });
+        Ok(())
     }
 
-    fn align_price_for_order(&self, price: Decimal) -> Decimal {
-        if price <= Decimal::ZERO {
-            return price;
+    /// Amend the slot's quote in place; `false` means the caller should
+    /// fall back to cancel + new order.
+    async fn amend_slot(
+        &mut self,
+        executor: &dyn OrderExecutor,
+        now: tokio::time::Instant,
+        slot: QuoteSlot,
+        pending: &PendingQuote,
+    ) -> bool {
+        if !self.amend_supported {
+            return false;
         }
-
-        match self.price_tick_decimals {
-            Some(decimals) => price.round_dp_with_strategy(decimals, RoundingStrategy::ToZero),
-            None => price,
+        let Some(cl_ord_id) = self.live_quotes.get(&slot).map(|q| q.cl_ord_id.clone()) else {
+            return false;
+        };
+        let price = self.align_price_for_order(pending.price);
+        let qty = self.align_qty_for_order(pending.qty);
+        if price <= Decimal::ZERO || qty <= Decimal::ZERO {
+            return false;
         }
-    }
-
-    fn tp_sl_for_order(&self, side: Side, price: Decimal) -> (Option<Decimal>, Option<Decimal>) {
-        let tp_price = self.tp_bps.and_then(|bps| match side {
-            Side::Buy => self.apply_bps(price, bps, true),
-            Side::Sell => self.apply_bps(price, bps, false),
-        });
-
-        let sl_price = self.sl_bps.and_then(|bps| match side {
-            Side::Buy => self.apply_bps(price, bps, false),
-            Side::Sell => self.apply_bps(price, bps, true),
-        });
-
-        (tp_price, sl_price)
-    }
-
-    fn apply_bps(&self, price: Decimal, bps: Decimal, increase: bool) -> Option<Decimal> {
-        if price <= Decimal::ZERO || bps <= Decimal::ZERO {
-            return None;
+        let qty = self.jitter_qty(qty);
+        let unfilled = {
+            let tracker = self.order_tracker.lock().await;
+            tracker.get(&cl_ord_id).is_some_and(|tracked| {
+                tracked.filled_qty.is_zero()
+                    && matches!(
+                        tracked.state,
+                        OrderState::Sent { .. } | OrderState::Acknowledged { .. }
+                    )
+            })
+        };
+        if !unfilled {
+            return false;
         }
 
-        let ratio = bps / Decimal::from(BPS_DENOMINATOR);
-        let adjusted = if increase {
-            price * (Decimal::ONE + ratio)
+        let (tp_price, sl_price) = self.tp_sl_for_order(slot.side.to_order_side(), price);
+        let req = AmendOrderRequest {
+            order_id: None,
+            cl_ord_id: Some(cl_ord_id.clone()),
+            price: Some(price),
+            qty: Some(qty),
+            tp_price,
+            sl_price,
+        };
+
+        match executor.amend_order(req).await {
+            Ok(resp) if resp.code == 0 => {}
+            Ok(resp) => {
+                warn!(
+                    symbol = %self.symbol,
+                    cl_ord_id = %cl_ord_id,
+                    code = resp.code,
+                    message = %resp.message,
+                    "amend_order returned non-zero code; falling back to cancel"
+                );
+                return false;
+            }
+            Err(err) if err.is_not_found() => {
+                warn!(
+                    symbol = %self.symbol,
+                    "amend_order unsupported; replacing quotes with cancel + new order"
+                );
+                self.amend_supported = false;
+                return false;
+            }
+            Err(err) => {
+                warn!(symbol = %self.symbol, cl_ord_id = %cl_ord_id, error = %err, "amend_order http failed; falling back to cancel");
+                return false;
+            }
+        }
+
+        {
+            let mut tracker = self.order_tracker.lock().await;
+            if let Err(err) = tracker.record_amended_qty(&cl_ord_id, qty) {
+                warn!(symbol = %self.symbol, cl_ord_id = %cl_ord_id, error = %err, "order_tracker record_amended_qty failed");
+            }
+        }
+        info!(
+            symbol = %self.symbol,
+            side = %slot.side.as_str(),
+            tier = %slot.tier,
+            cl_ord_id = %cl_ord_id,
+            %price,
+            %qty,
+            "amended PostOnly quote"
+        );
+        if let Some(quote) = self.live_quotes.get_mut(&slot) {
+            quote.price = price;
+            quote.qty = qty;
+            quote.placed_at = now;
+        }
+        true
+    }
+
+    async fn cancel_retiring(&self, executor: &dyn OrderExecutor, cl_ord_id: &str) {
+        {
+            let mut tracker = self.order_tracker.lock().await;
+            let _ = tracker.mark_cancelling(cl_ord_id, std::time::Instant::now());
+        }
+
+        let req = CancelOrderRequest {
+            order_id: None,
+            cl_ord_id: Some(cl_ord_id.to_string()),
+        };
+
+        let accepted = match executor.cancel_order(req).await {
+            Ok(resp) if resp.code == 0 => {
+                info!(symbol = %self.symbol, cl_ord_id = %cl_ord_id, "replaced quote cancel requested");
+                self.emit_cancelled(cl_ord_id);
+                true
+            }
+            Ok(resp) => {
+                warn!(
+                    symbol = %self.symbol,
+                    cl_ord_id = %cl_ord_id,
+                    code = resp.code,
+                    message = %resp.message,
+                    "replaced quote cancel returned non-zero code"
+                );
+                false
+            }
+            Err(err) => {
+                warn!(symbol = %self.symbol, cl_ord_id = %cl_ord_id, error = %err, "replaced quote cancel http failed");
+                false
+            }
+        };
+
+        if !accepted {
+            self.request_reconcile(OrderReconcileRequest {
+                cl_ord_id: cl_ord_id.to_string(),
+                reason: OrderReconcileReason::ReplacedCancelFailed,
+            });
+        }
+    }
+
+    /// Drop retiring quotes once terminal; re-send cancels that stay unacknowledged.
+    async fn sweep_retiring_quotes(
+        &mut self,
+        executor: &dyn OrderExecutor,
+        now: tokio::time::Instant,
+    ) {
+        if self.retiring_quotes.is_empty() {
+            return;
+        }
+
+        let mut still_retiring = Vec::with_capacity(self.retiring_quotes.len());
+        for mut retiring in std::mem::take(&mut self.retiring_quotes) {
+            let state = {
+                let tracker = self.order_tracker.lock().await;
+                tracker.state(&retiring.cl_ord_id).cloned()
+            };
+            let terminal = matches!(
+                state,
+                None | Some(OrderState::Cancelled { .. })
+                    | Some(OrderState::Filled { .. })
+                    | Some(OrderState::Failed { .. })
+            );
+            if terminal {
+                continue;
+            }
+
+            if now.saturating_duration_since(retiring.cancel_sent_at) >= CANCEL_RETRY_INTERVAL {
+                self.cancel_retiring(executor, &retiring.cl_ord_id).await;
+                retiring.cancel_sent_at = now;
+            }
+            still_retiring.push(retiring);
+        }
+        self.retiring_quotes = still_retiring;
+    }
+
+    async fn cancel_all_quotes(&mut self, executor: &dyn OrderExecutor, now: tokio::time::Instant) {
+        let slots: Vec<QuoteSlot> = self.live_quotes.keys().copied().collect();
+        for slot in slots {
+            self.cancel_slot_if_present(executor, now, slot, None).await;
+        }
+    }
+
+    fn align_qty_for_order(&self, qty: Decimal) -> Decimal {
+        if qty <= Decimal::ZERO {
+            return qty;
+        }
+
+        let mut aligned = match self.qty_tick_decimals {
+            Some(decimals) => qty.round_dp_with_strategy(decimals, RoundingStrategy::ToZero),
+            None => qty,
+        };
+
+        if let Some(max_qty) = self.max_order_qty
+            && aligned > max_qty
+        {
+            aligned = match self.qty_tick_decimals {
+                Some(decimals) => {
+                    max_qty.round_dp_with_strategy(decimals, RoundingStrategy::ToZero)
+                }
+                None => max_qty,
+            };
+        }
+
+        if let Some(min_qty) = self.min_order_qty
+            && aligned < min_qty
+        {
+            return Decimal::ZERO;
+        }
+
+        aligned
+    }
+
+    /// Scale an aligned qty by a random factor within `size_jitter_bps`,
+    /// re-aligned and kept inside the order size limits.
+    fn jitter_qty(&mut self, qty: Decimal) -> Decimal {
+        // Sample in millionths so fractional bps bounds still jitter.
+        let bound_ppm = (self.size_jitter_bps * Decimal::from(100))
+            .trunc()
+            .to_i64()
+            .unwrap_or(0);
+        if bound_ppm <= 0 || qty <= Decimal::ZERO {
+            return qty;
+        }
+        let ppm = self.size_rng.gen_range(-bound_ppm..=bound_ppm);
+        let mut jittered = qty * (Decimal::ONE + Decimal::new(ppm, 6));
+        if let Some(min_qty) = self.min_order_qty {
+            jittered = jittered.max(min_qty);
+        }
+        let aligned = self.align_qty_for_order(jittered);
+        if aligned > Decimal::ZERO {
+            aligned
+        } else {
+            qty
+        }
+    }
+
+    fn align_price_for_order(&self, price: Decimal) -> Decimal {
+        if price <= Decimal::ZERO {
+            return price;
+        }
+
+        match self.price_tick_decimals {
+            Some(decimals) => price.round_dp_with_strategy(decimals, RoundingStrategy::ToZero),
+            None => price,
+        }
+    }
+
+    fn tp_sl_for_order(&self, side: Side, price: Decimal) -> (Option<Decimal>, Option<Decimal>) {
+        let tp_price = self.tp_bps.and_then(|bps| match side {
+            Side::Buy => self.apply_bps(price, bps, true),
+            Side::Sell => self.apply_bps(price, bps, false),
+        });
+
+        let sl_price = self.sl_bps.and_then(|bps| match side {
+            Side::Buy => self.apply_bps(price, bps, false),
+            Side::Sell => self.apply_bps(price, bps, true),
+        });
+
+        (tp_price, sl_price)
+    }
+
+    fn apply_bps(&self, price: Decimal, bps: Decimal, increase: bool) -> Option<Decimal> {
+        if price <= Decimal::ZERO || bps <= Decimal::ZERO {
+            return None;
+        }
+
+        let ratio = bps / Decimal::from(BPS_DENOMINATOR);
+        let adjusted = if increase {
+            price * (Decimal::ONE + ratio)
         } else {
             price * (Decimal::ONE - ratio)
         };
@@ -1475,6 +2911,35 @@ impl Default for MarketMakingStrategy {
     }
 }
 
+/// Resolves when the pause flag changes; never resolves without one.
+/// How far `time` (RFC 3339 or unix millis) lags `now`; `None` when the
+/// snapshot carries no usable timestamp.
+fn exchange_price_age(time: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let time = time.trim();
+    let published = match time.parse::<i64>() {
+        Ok(millis) if millis > 0 => DateTime::from_timestamp_millis(millis)?,
+        Ok(_) => return None,
+        Err(_) => DateTime::parse_from_rfc3339(time).ok()?.with_timezone(&Utc),
+    };
+    Some((now - published).to_std().unwrap_or_default())
+}
+
+async fn next_event(subscription: &mut Option<EventSubscription>) -> Option<RuntimeEvent> {
+    match subscription {
+        Some(subscription) => subscription.recv().await,
+        None => std::future::pending().await,
+    }
+}
+
+async fn pause_changed(
+    paused_rx: &mut Option<watch::Receiver<bool>>,
+) -> std::result::Result<(), watch::error::RecvError> {
+    match paused_rx {
+        Some(rx) => rx.changed().await,
+        None => std::future::pending().await,
+    }
+}
+
 fn initial_symbol_price(symbol: &str) -> SymbolPrice {
     SymbolPrice {
         base: String::new(),
@@ -1545,6 +3010,7 @@ mod tests {
     use super::*;
 
     use crate::risk::RiskManager;
+    use standx_point_adapter::StandxError;
     use standx_point_adapter::ws::message::OrderUpdateData;
     use std::str::FromStr;
     use tokio::sync::mpsc;
@@ -1596,6 +3062,9 @@ mod tests {
     struct MockExecutor {
         new_orders: tokio::sync::Mutex<Vec<NewOrderRequest>>,
         cancels: tokio::sync::Mutex<Vec<CancelOrderRequest>>,
+        actions: tokio::sync::Mutex<Vec<String>>,
+        /// Answer amends with a 404 like an environment without the endpoint.
+        amend_unsupported: bool,
     }
 
     impl MockExecutor {
@@ -1619,6 +3088,10 @@ mod tests {
         ) -> Pin<Box<dyn Future<Output = standx_point_adapter::Result<NewOrderResponse>> + Send + '_>>
         {
             Box::pin(async move {
+                self.actions.lock().await.push(format!(
+                    "place {}",
+                    req.cl_ord_id.as_deref().unwrap_or_default()
+                ));
                 self.new_orders.lock().await.push(req);
                 Ok(NewOrderResponse {
                     code: 0,
@@ -1635,6 +3108,10 @@ mod tests {
             Box<dyn Future<Output = standx_point_adapter::Result<CancelOrderResponse>> + Send + '_>,
         > {
             Box::pin(async move {
+                self.actions.lock().await.push(format!(
+                    "cancel {}",
+                    req.cl_ord_id.as_deref().unwrap_or_default()
+                ));
                 self.cancels.lock().await.push(req);
                 Ok(CancelOrderResponse {
                     code: 0,
@@ -1643,6 +3120,30 @@ mod tests {
                 })
             })
         }
+
+        fn amend_order(
+            &self,
+            req: AmendOrderRequest,
+        ) -> Pin<Box<dyn Future<Output = standx_point_adapter::Result<NewOrderResponse>> + Send + '_>>
+        {
+            Box::pin(async move {
+                self.actions.lock().await.push(format!(
+                    "amend {}",
+                    req.cl_ord_id.as_deref().unwrap_or_default()
+                ));
+                if self.amend_unsupported {
+                    return Err(StandxError::Api {
+                        code: 404,
+                        message: "not found".to_string(),
+                    });
+                }
+                Ok(NewOrderResponse {
+                    code: 0,
+                    message: "ok".to_string(),
+                    request_id: "req".to_string(),
+                })
+            })
+        }
     }
 
     #[test]
@@ -1687,10 +3188,10 @@ mod tests {
         );
 
         let l1 = strategy.target_bps_for_tier(Tier::L1);
-        let l2 = strategy.target_bps_for_tier(Tier::L2);
-        let l3 = strategy.target_bps_for_tier(Tier::L3);
-        let l4 = strategy.target_bps_for_tier(Tier::L4);
-        let l5 = strategy.target_bps_for_tier(Tier::L5);
+        let l2 = strategy.target_bps_for_tier(Tier(1));
+        let l3 = strategy.target_bps_for_tier(Tier(2));
+        let l4 = strategy.target_bps_for_tier(Tier(3));
+        let l5 = strategy.target_bps_for_tier(Tier(4));
 
         assert!(l1 >= dec("5") && l1 <= dec("8"));
         assert!(l2 >= dec("8") && l2 <= dec("10"));
@@ -1699,46 +3200,9 @@ mod tests {
         assert!(l5 >= dec("20") && l5 <= dec("30"));
     }
 
-    #[test]
-    fn strategy_aligns_qty_to_tick_and_bounds() {
-        let mut strategy = MarketMakingStrategy::new();
-        strategy.set_symbol_constraints(Some(2), Some(2), Some(dec("0.05")), Some(dec("1.00")));
-
-        let rounded = strategy.align_qty_for_order(dec("0.1234"));
-        assert_eq!(rounded, dec("0.12"));
-
-        let too_small = strategy.align_qty_for_order(dec("0.041"));
-        assert_eq!(too_small, Decimal::ZERO);
-
-        let too_large = strategy.align_qty_for_order(dec("1.239"));
-        assert_eq!(too_large, dec("1.00"));
-    }
-
-    #[test]
-    fn strategy_aligns_price_to_tick() {
-        let mut strategy = MarketMakingStrategy::new();
-        strategy.set_symbol_constraints(Some(2), None, None, None);
-
-        let rounded = strategy.align_price_for_order(dec("4956.560550"));
-        assert_eq!(rounded, dec("4956.56"));
-    }
-
-    #[tokio::test]
-    async fn strategy_places_post_only_bilateral_ladder() {
-        let (tx, rx) = watch::channel(SymbolPrice {
-            base: "BTC".to_string(),
-            index_price: dec("100"),
-            last_price: None,
-            mark_price: dec("100"),
-            mid_price: None,
-            quote: "USD".to_string(),
-            spread_ask: None,
-            spread_bid: None,
-            symbol: "BTC-USD".to_string(),
-            time: "0".to_string(),
-        });
-
-        let executor = MockExecutor::default();
+    fn skew_strategy(inventory_qty: &str) -> MarketMakingStrategy {
+        let (tx, rx) = watch::channel(initial_symbol_price("BTC-USD"));
+        drop(tx);
         let mut strategy = MarketMakingStrategy::new_with_params(
             "BTC-USD".to_string(),
             dec("1000"),
@@ -1746,49 +3210,1269 @@ mod tests {
             None,
             None,
             rx,
-            position_receiver(Decimal::ONE),
+            position_receiver(Decimal::ZERO),
             Arc::new(Mutex::new(OrderTracker::new())),
             reconcile_tx(),
             StrategyMode::aggressive_default(),
             5,
-            Decimal::ONE,
+            dec(inventory_qty),
         );
-
-        strategy
-            .refresh_from_latest(&executor, tokio::time::Instant::now())
-            .await
-            .unwrap();
-
-        // 5 tiers * 2 sides
-        assert_eq!(executor.new_order_count().await, 10);
-
-        let last = executor.last_new_order().await.expect("has order");
-        assert_eq!(last.order_type, OrderType::Limit);
-        assert_eq!(last.time_in_force, TimeInForce::PostOnly);
-        assert!(!last.reduce_only);
-        assert!(last.price.is_some());
-
-        // Bump mark price; drift should cause cancel+replace.
-        tx.send_modify(|price| {
-            price.mark_price = dec("101");
+        strategy.set_quoting(QuotingBands {
+            inventory_skew_bps: Some(dec("10")),
+            ..Default::default()
         });
-
         strategy
-            .refresh_from_latest(&executor, tokio::time::Instant::now())
-            .await
-            .unwrap();
+    }
 
-        // Drift is large; replace should happen (cancel + new orders).
-        assert!(executor.cancel_count().await > 0);
+    #[test]
+    fn inventory_skew_widens_bid_and_tightens_ask_when_long() {
+        // 5 @ 100 = half of the 1000 budget -> 5 bps skew.
+        let strategy = skew_strategy("5");
+        assert_eq!(
+            strategy.skewed_bps(dec("8"), QuoteSide::Bid, dec("100")),
+            dec("13")
+        );
+        assert_eq!(
+            strategy.skewed_bps(dec("8"), QuoteSide::Ask, dec("100")),
+            dec("3")
+        );
+
+        // Skew saturates at one full inventory_skew_bps and never crosses.
+        let strategy = skew_strategy("50");
+        assert_eq!(
+            strategy.skewed_bps(dec("8"), QuoteSide::Bid, dec("100")),
+            dec("18")
+        );
+        assert_eq!(
+            strategy.skewed_bps(dec("8"), QuoteSide::Ask, dec("100")),
+            dec("1")
+        );
+    }
+
+    #[test]
+    fn inventory_skew_widens_ask_and_tightens_bid_when_short() {
+        let strategy = skew_strategy("-2.5");
+        assert_eq!(
+            strategy.skewed_bps(dec("8"), QuoteSide::Bid, dec("100")),
+            dec("5.5")
+        );
+        assert_eq!(
+            strategy.skewed_bps(dec("8"), QuoteSide::Ask, dec("100")),
+            dec("10.5")
+        );
+
+        let flat = skew_strategy("0");
+        assert_eq!(
+            flat.skewed_bps(dec("8"), QuoteSide::Bid, dec("100")),
+            dec("8")
+        );
+        assert_eq!(
+            flat.skewed_bps(dec("8"), QuoteSide::Ask, dec("100")),
+            dec("8")
+        );
+    }
+
+    fn funding_strategy(funding_rate: &str) -> MarketMakingStrategy {
+        let mut strategy = skew_strategy("0");
+        strategy.set_quoting(QuotingBands {
+            funding_skew_bps: Some(dec("4")),
+            ..Default::default()
+        });
+        let (tx, rx) = watch::channel(Some(dec(funding_rate)));
+        drop(tx);
+        strategy.set_funding_rate(rx);
+        strategy
+    }
+
+    #[test]
+    fn positive_funding_tightens_ask_and_widens_bid() {
+        // Half-way through the interval at full-strength funding -> 2 bps.
+        let now: DateTime<Utc> = "2026-10-16T08:30:00Z".parse().unwrap();
+        let strategy = funding_strategy("0.0003");
+        assert_eq!(
+            strategy.skewed_bps_at(dec("8"), QuoteSide::Bid, dec("100"), now),
+            dec("10")
+        );
+        assert_eq!(
+            strategy.skewed_bps_at(dec("8"), QuoteSide::Ask, dec("100"), now),
+            dec("6")
+        );
+
+        // Right after settlement there is nothing to avoid yet.
+        let settled: DateTime<Utc> = "2026-10-16T09:00:00Z".parse().unwrap();
+        assert_eq!(
+            strategy.skewed_bps_at(dec("8"), QuoteSide::Ask, dec("100"), settled),
+            dec("8")
+        );
+    }
+
+    #[test]
+    fn negative_funding_tightens_bid_and_widens_ask() {
+        // Weak funding scales the skew: -0.5 bp/interval at 45 minutes -> 1.5 bps.
+        let now: DateTime<Utc> = "2026-10-16T08:45:00Z".parse().unwrap();
+        let strategy = funding_strategy("-0.00005");
+        assert_eq!(
+            strategy.skewed_bps_at(dec("8"), QuoteSide::Bid, dec("100"), now),
+            dec("6.5")
+        );
+        assert_eq!(
+            strategy.skewed_bps_at(dec("8"), QuoteSide::Ask, dec("100"), now),
+            dec("9.5")
+        );
+
+        let neutral = funding_strategy("0");
+        assert_eq!(
+            neutral.skewed_bps_at(dec("8"), QuoteSide::Bid, dec("100"), now),
+            dec("8")
+        );
+    }
+
+    #[test]
+    fn book_mid_reference_falls_back_to_ticker_without_two_sided_book() {
+        let mut snapshot = initial_symbol_price("BTC-USD");
+        snapshot.mark_price = dec("100");
+        snapshot.mid_price = Some(dec("100.2"));
+
+        let mut strategy = skew_strategy("0");
+        let (book_mid_tx, book_mid_rx) = watch::channel(Some(dec("100.5")));
+        strategy.set_book_mid(book_mid_rx);
+        // Published but not configured: the ticker mid still wins.
+        assert_eq!(strategy.quote_reference_price(&snapshot), dec("100.2"));
+
+        strategy.set_quoting(QuotingBands {
+            reference_price: Some(QuoteReference::BookMid),
+            ..Default::default()
+        });
+        assert!(strategy.uses_book_mid());
+        assert_eq!(strategy.quote_reference_price(&snapshot), dec("100.5"));
+
+        // Empty or one-sided book (or a failed poll) publishes None.
+        book_mid_tx.send_replace(None);
+        assert_eq!(strategy.quote_reference_price(&snapshot), dec("100.2"));
+        snapshot.mid_price = None;
+        assert_eq!(strategy.quote_reference_price(&snapshot), dec("100"));
+    }
+
+    #[test]
+    fn fee_floor_raises_tiers_inside_high_taker_fees() {
+        // 15 bps taker fee: the spread must cover 2 fees -> 15 bps per side.
+        let mut strategy = skew_strategy("0");
+        let (l1_min, l1_max) = strategy.quote_band_for_tier(Tier::L1);
+        assert!(l1_min < dec("15"));
+        strategy.set_taker_fee(dec("0.0015"));
+
+        let (min, max) = strategy.quote_band_for_tier(Tier::L1);
+        assert_eq!(min, dec("15"));
+        assert_eq!(max, decimal_max(l1_max, dec("15")));
+        assert!(strategy.target_bps_for_tier(Tier::L1) >= dec("15"));
+        strategy.log_fee_floor_override(Tier::L1);
+        assert!(strategy.fee_floor_logged.contains(&Tier::L1));
+
+        // Skew never pulls a side back inside the floor.
+        strategy.set_quoting(QuotingBands {
+            inventory_skew_bps: Some(dec("10")),
+            ..Default::default()
+        });
+        strategy.inventory_qty = dec("5");
+        assert_eq!(
+            strategy.skewed_bps(dec("15"), QuoteSide::Ask, dec("100")),
+            dec("15")
+        );
+
+        // A custom multiple scales the floor; zero disables it.
+        strategy.set_quoting(QuotingBands {
+            fee_floor_multiple: Some(dec("4")),
+            ..Default::default()
+        });
+        assert_eq!(strategy.quote_band_for_tier(Tier::L1).0, dec("30"));
+        strategy.set_quoting(QuotingBands {
+            fee_floor_multiple: Some(Decimal::ZERO),
+            ..Default::default()
+        });
+        assert_eq!(strategy.quote_band_for_tier(Tier::L1), (l1_min, l1_max));
+    }
+
+    #[test]
+    fn strategy_quoting_overrides_replace_default_bands() {
+        let mut strategy = MarketMakingStrategy::new();
+        strategy.set_quoting(QuotingBands {
+            ladder: Some(
+                (1..=5)
+                    .map(|idx| TierBand {
+                        min_bps: Decimal::from(idx * 2),
+                        max_bps: Decimal::from(idx * 2 + 2),
+                        weight: Decimal::ONE,
+                    })
+                    .collect(),
+            ),
+            target_bps: Some((dec("1"), dec("50"))),
+            survival_bps: Some((dec("7"), dec("11"))),
+            inventory_skew_bps: None,
+            funding_skew_bps: None,
+            size_jitter_bps: None,
+            min_replace_dwell: None,
+            reference_price: None,
+            fee_floor_multiple: None,
+        });
+
+        assert_eq!(strategy.target_bps_for_tier(Tier::L1), dec("3"));
+        assert_eq!(strategy.target_bps_for_tier(Tier(4)), dec("11"));
+
+        strategy.enter_survival(tokio::time::Instant::now());
+        assert_eq!(strategy.quote_band_for_tier(Tier(2)), (dec("7"), dec("8")));
+    }
+
+    #[test]
+    fn weighted_tier_notionals_stay_within_budget_after_tick_alignment() {
+        let now = tokio::time::Instant::now();
+        let mark = dec("37.37");
+        let mut strategy = MarketMakingStrategy::new();
+        strategy.budget_usd = dec("1000");
+        strategy.set_symbol_constraints(Some(2), Some(3), Some(dec("0.05")), None);
+        strategy.set_quoting(QuotingBands {
+            ladder: Some(
+                TierBand::presets(5)
+                    .into_iter()
+                    .zip(1..)
+                    .map(|(band, weight)| TierBand {
+                        weight: Decimal::from(weight),
+                        ..band
+                    })
+                    .collect(),
+            ),
+            ..Default::default()
+        });
+        strategy.base_qty = strategy.derived_base_qty(mark);
+
+        // Unrounded, the weighted ladder spends exactly the budget at mark.
+        let unrounded = (0..strategy.active_tier_count()).fold(Decimal::ZERO, |acc, index| {
+            acc + strategy.base_qty * strategy.tier_weight(Tier(index)) * mark * Decimal::TWO
+        });
+        assert!(
+            (unrounded - dec("1000")).abs() < dec("0.000001"),
+            "{unrounded}"
+        );
+
+        let mut notional = Decimal::ZERO;
+        let mut sizes = Vec::new();
+        for index in 0..strategy.active_tier_count() {
+            let tier = Tier(index);
+            for side in [QuoteSide::Bid, QuoteSide::Ask] {
+                let target_bps = strategy.target_bps_for_tier(tier);
+                let price = strategy.align_price_for_order(price_at_bps(
+                    mark,
+                    side.to_order_side(),
+                    target_bps,
+                ));
+                let qty = strategy.align_qty_for_order(
+                    strategy.desired_qty_for_slot(tier, side, target_bps, now),
+                );
+                assert!(qty.is_zero() || qty >= dec("0.05"), "{qty}");
+                assert_eq!(qty, qty.round_dp(3));
+                notional += price * qty;
+                sizes.push(qty);
+            }
+        }
+        assert!(notional <= dec("1000"), "{notional}");
+        assert!(notional > Decimal::ZERO);
+        // Back-loaded weights put more size on L5 than on L1.
+        assert!(sizes[8] > sizes[0], "{sizes:?}");
+    }
+
+    #[test]
+    fn strategy_quotes_a_configured_ladder_deeper_than_five_tiers() {
+        let presets = TierBand::presets(3);
+        assert_eq!(
+            presets.iter().map(|tier| tier.weight).collect::<Vec<_>>(),
+            [dec("0.4"), dec("0.35"), dec("0.25")]
+        );
+        assert_eq!(presets[2].max_bps, dec("15"));
+
+        let mut strategy = MarketMakingStrategy::new();
+        strategy.budget_usd = dec("1600");
+        strategy.set_quoting(QuotingBands {
+            ladder: Some(
+                (0..8)
+                    .map(|idx| TierBand {
+                        min_bps: Decimal::from(5 + idx * 5),
+                        max_bps: Decimal::from(9 + idx * 5),
+                        weight: if idx == 0 { dec("3") } else { Decimal::ONE },
+                    })
+                    .collect(),
+            ),
+            target_bps: Some((dec("1"), dec("100"))),
+            ..Default::default()
+        });
+
+        assert_eq!(strategy.active_tier_count(), 8);
+        assert_eq!(strategy.target_bps_for_tier(Tier(7)), dec("42"));
+        // 800 per side at 100 over a total weight of 10.
+        assert_eq!(strategy.derived_base_qty(dec("100")), dec("0.8"));
+        assert_eq!(strategy.tier_weight(Tier::L1), dec("3"));
+        assert_eq!(Tier(7).to_string(), "l8");
+
+        strategy.set_position_limits(None, Some(dec("6")));
+        assert_eq!(strategy.active_tier_count(), 3);
+        assert_eq!(strategy.total_weight(), dec("5"));
+    }
+
+    #[test]
+    fn strategy_size_jitter_stays_aligned_within_bounds() {
+        let sizes = |seed: u64| {
+            let mut strategy = MarketMakingStrategy::new();
+            strategy.set_symbol_constraints(Some(2), Some(3), Some(dec("0.99")), Some(dec("1.01")));
+            strategy.set_quoting(QuotingBands {
+                size_jitter_bps: Some(dec("200")),
+                ..QuotingBands::default()
+            });
+            strategy.seed_size_jitter(seed);
+            (0..64)
+                .map(|_| strategy.jitter_qty(dec("1")))
+                .collect::<Vec<_>>()
+        };
+
+        let first = sizes(7);
+        assert_eq!(first, sizes(7));
+        assert!(first.iter().any(|qty| *qty != dec("1")));
+        for qty in &first {
+            assert!(*qty >= dec("0.99") && *qty <= dec("1.01"), "{qty}");
+            assert!(qty.scale() <= 3, "{qty}");
+        }
+
+        let mut strategy = MarketMakingStrategy::new();
+        assert_eq!(strategy.jitter_qty(dec("1.234")), dec("1.234"));
+    }
+
+    #[test]
+    fn strategy_aligns_qty_to_tick_and_bounds() {
+        let mut strategy = MarketMakingStrategy::new();
+        strategy.set_symbol_constraints(Some(2), Some(2), Some(dec("0.05")), Some(dec("1.00")));
+
+        let rounded = strategy.align_qty_for_order(dec("0.1234"));
+        assert_eq!(rounded, dec("0.12"));
+
+        let too_small = strategy.align_qty_for_order(dec("0.041"));
+        assert_eq!(too_small, Decimal::ZERO);
+
+        let too_large = strategy.align_qty_for_order(dec("1.239"));
+        assert_eq!(too_large, dec("1.00"));
+    }
+
+    #[test]
+    fn strategy_aligns_price_to_tick() {
+        let mut strategy = MarketMakingStrategy::new();
+        strategy.set_symbol_constraints(Some(2), None, None, None);
+
+        let rounded = strategy.align_price_for_order(dec("4956.560550"));
+        assert_eq!(rounded, dec("4956.56"));
+    }
+
+    #[tokio::test]
+    async fn strategy_places_post_only_bilateral_ladder() {
+        let (tx, rx) = watch::channel(SymbolPrice {
+            base: "BTC".to_string(),
+            index_price: dec("100"),
+            last_price: None,
+            mark_price: dec("100"),
+            mid_price: None,
+            quote: "USD".to_string(),
+            spread_ask: None,
+            spread_bid: None,
+            symbol: "BTC-USD".to_string(),
+            time: "0".to_string(),
+        });
+
+        let executor = MockExecutor::default();
+        let mut strategy = MarketMakingStrategy::new_with_params(
+            "BTC-USD".to_string(),
+            dec("1000"),
+            RiskLevel::Low,
+            None,
+            None,
+            rx,
+            position_receiver(Decimal::ONE),
+            Arc::new(Mutex::new(OrderTracker::new())),
+            reconcile_tx(),
+            StrategyMode::aggressive_default(),
+            5,
+            Decimal::ONE,
+        );
+
+        strategy
+            .refresh_from_latest(&executor, tokio::time::Instant::now())
+            .await
+            .unwrap();
+
+        // 5 tiers * 2 sides
+        assert_eq!(executor.new_order_count().await, 10);
+
+        let last = executor.last_new_order().await.expect("has order");
+        assert_eq!(last.order_type, OrderType::Limit);
+        assert_eq!(last.time_in_force, TimeInForce::PostOnly);
+        assert!(!last.reduce_only);
+        assert!(last.price.is_some());
+
+        // Bump mark price; drift should cause cancel+replace.
+        tx.send_modify(|price| {
+            price.mark_price = dec("101");
+        });
+
+        strategy
+            .refresh_from_latest(&executor, tokio::time::Instant::now())
+            .await
+            .unwrap();
+
+        // Drift is large; replace should happen (cancel + new orders).
+        assert!(executor.cancel_count().await > 0);
+    }
+
+    #[tokio::test]
+    async fn strategy_run_quotes_through_custom_executor() {
+        let (_tx, rx) = watch::channel(SymbolPrice {
+            base: "BTC".to_string(),
+            index_price: dec("100"),
+            last_price: None,
+            mark_price: dec("100"),
+            mid_price: None,
+            quote: "USD".to_string(),
+            spread_ask: None,
+            spread_bid: None,
+            symbol: "BTC-USD".to_string(),
+            time: "0".to_string(),
+        });
+
+        let executor = MockExecutor::default();
+        let mut strategy = MarketMakingStrategy::new_with_params(
+            "BTC-USD".to_string(),
+            dec("1000"),
+            RiskLevel::XHigh,
+            None,
+            None,
+            rx,
+            position_receiver(Decimal::ZERO),
+            Arc::new(Mutex::new(OrderTracker::new())),
+            reconcile_tx(),
+            StrategyMode::aggressive_default(),
+            1,
+            Decimal::ZERO,
+        );
+
+        // Already cancelled: quote once, then pull the quotes on shutdown.
+        let shutdown = CancellationToken::new();
+        shutdown.cancel();
+        strategy.run(&executor, shutdown).await.unwrap();
+
+        assert_eq!(executor.new_order_count().await, 2);
+        assert_eq!(executor.cancel_count().await, 2);
+    }
+
+    async fn replace_sequence(replace_order: ReplaceOrder) -> (Vec<String>, Vec<String>) {
+        replace_sequence_with(replace_order, MockExecutor::default()).await
+    }
+
+    async fn replace_sequence_with(
+        replace_order: ReplaceOrder,
+        executor: MockExecutor,
+    ) -> (Vec<String>, Vec<String>) {
+        let (tx, rx) = watch::channel(SymbolPrice {
+            base: "BTC".to_string(),
+            index_price: dec("100"),
+            last_price: None,
+            mark_price: dec("100"),
+            mid_price: None,
+            quote: "USD".to_string(),
+            spread_ask: None,
+            spread_bid: None,
+            symbol: "BTC-USD".to_string(),
+            time: "0".to_string(),
+        });
+
+        let mut strategy = MarketMakingStrategy::new_with_params(
+            "BTC-USD".to_string(),
+            dec("1000"),
+            RiskLevel::XHigh,
+            None,
+            None,
+            rx,
+            position_receiver(Decimal::ZERO),
+            Arc::new(Mutex::new(OrderTracker::new())),
+            reconcile_tx(),
+            StrategyMode::aggressive_default(),
+            1,
+            Decimal::ZERO,
+        );
+        strategy.set_replace_order(replace_order);
+
+        strategy
+            .refresh_from_latest(&executor, tokio::time::Instant::now())
+            .await
+            .unwrap();
+        let initial: Vec<String> = executor.actions.lock().await.drain(..).collect();
+
+        tx.send_modify(|price| {
+            price.mark_price = dec("101");
+        });
+        strategy
+            .refresh_from_latest(&executor, tokio::time::Instant::now())
+            .await
+            .unwrap();
+        let replaced = executor.actions.lock().await.clone();
+
+        (initial, replaced)
+    }
+
+    #[tokio::test]
+    async fn strategy_cancel_first_replace_waits_for_cancel_ack() {
+        let (initial, replaced) = replace_sequence(ReplaceOrder::CancelFirst).await;
+
+        assert_eq!(initial.len(), 2);
+        let cancels: Vec<String> = initial
+            .iter()
+            .map(|action| action.replacen("place", "cancel", 1))
+            .collect();
+        // Only cancels go out; replacements wait for the cancel ack.
+        assert_eq!(replaced, cancels);
+    }
+
+    #[tokio::test]
+    async fn strategy_place_first_replace_places_before_cancelling() {
+        let (initial, replaced) = replace_sequence(ReplaceOrder::PlaceFirst).await;
+
+        assert_eq!(initial.len(), 2);
+        assert_eq!(replaced.len(), 4);
+        for (pair, old) in replaced.chunks(2).zip(&initial) {
+            assert!(pair[0].starts_with("place "), "{replaced:?}");
+            assert_ne!(pair[0], *old);
+            assert_eq!(pair[1], old.replacen("place", "cancel", 1));
+        }
+    }
+
+    #[tokio::test]
+    async fn strategy_amend_replace_keeps_quotes_in_place() {
+        let (initial, replaced) = replace_sequence(ReplaceOrder::Amend).await;
+
+        assert_eq!(initial.len(), 2);
+        let amends: Vec<String> = initial
+            .iter()
+            .map(|action| action.replacen("place", "amend", 1))
+            .collect();
+        assert_eq!(replaced, amends);
+    }
+
+    #[tokio::test]
+    async fn strategy_amend_falls_back_to_cancel_when_unsupported() {
+        let executor = MockExecutor {
+            amend_unsupported: true,
+            ..MockExecutor::default()
+        };
+        let (initial, replaced) = replace_sequence_with(ReplaceOrder::Amend, executor).await;
+
+        assert_eq!(initial.len(), 2);
+        let first = &initial[0];
+        // One probe hits the 404; every later replace goes straight to cancel.
+        assert_eq!(
+            replaced,
+            [
+                first.replacen("place", "amend", 1),
+                first.replacen("place", "cancel", 1),
+                initial[1].replacen("place", "cancel", 1),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn strategy_replace_throttle_ignores_sub_tick_oscillation() {
+        let (tx, rx) = watch::channel(SymbolPrice {
+            base: "BTC".to_string(),
+            index_price: dec("100"),
+            last_price: None,
+            mark_price: dec("100"),
+            mid_price: None,
+            quote: "USD".to_string(),
+            spread_ask: None,
+            spread_bid: None,
+            symbol: "BTC-USD".to_string(),
+            time: "0".to_string(),
+        });
+
+        let executor = MockExecutor::default();
+        let mut strategy = MarketMakingStrategy::new_with_params(
+            "BTC-USD".to_string(),
+            dec("1000"),
+            RiskLevel::XHigh,
+            None,
+            None,
+            rx,
+            position_receiver(Decimal::ZERO),
+            Arc::new(Mutex::new(OrderTracker::new())),
+            reconcile_tx(),
+            StrategyMode::aggressive_default(),
+            1,
+            Decimal::ZERO,
+        );
+        strategy.set_symbol_constraints(Some(1), Some(3), None, None);
+        strategy.set_quoting(QuotingBands {
+            ladder: Some(vec![TierBand {
+                min_bps: dec("10"),
+                max_bps: dec("20"),
+                weight: Decimal::ONE,
+            }]),
+            target_bps: Some((dec("1"), dec("60"))),
+            min_replace_dwell: Some(Duration::from_secs(10)),
+            ..QuotingBands::default()
+        });
+
+        let start = tokio::time::Instant::now();
+        strategy
+            .refresh_from_latest(&executor, start)
+            .await
+            .unwrap();
+        assert_eq!(executor.new_order_count().await, 2);
+
+        // Quotes sit at 99.8 / 100.1; these moves push the ask below its band
+        // but never shift either target by a whole 0.1 tick.
+        for (step, mark) in ["100.02", "99.98", "100.02", "99.98", "100.02"]
+            .into_iter()
+            .enumerate()
+        {
+            tx.send_modify(|price| price.mark_price = dec(mark));
+            let now = start + Duration::from_secs(20 + step as u64);
+            assert!(!strategy.should_refresh_for_price(dec(mark), now));
+            strategy.refresh_from_latest(&executor, now).await.unwrap();
+        }
+        assert_eq!(executor.cancel_count().await, 0);
+
+        // A full tick move still waits out the dwell time.
+        let start = tokio::time::Instant::now();
+        strategy.set_quoting(QuotingBands {
+            min_replace_dwell: Some(Duration::from_secs(3600)),
+            ..QuotingBands::default()
+        });
+        tx.send_modify(|price| price.mark_price = dec("101"));
+        strategy
+            .refresh_from_latest(&executor, start)
+            .await
+            .unwrap();
+        assert_eq!(executor.cancel_count().await, 0);
+
+        strategy.set_quoting(QuotingBands {
+            min_replace_dwell: Some(Duration::ZERO),
+            ..QuotingBands::default()
+        });
+        strategy
+            .refresh_from_latest(&executor, start)
+            .await
+            .unwrap();
+        assert!(executor.cancel_count().await > 0);
+    }
+
+    #[test]
+    fn replace_order_parses_config_values() {
+        assert_eq!(
+            "cancel_first".parse::<ReplaceOrder>(),
+            Ok(ReplaceOrder::CancelFirst)
+        );
+        assert_eq!(
+            " Place_First ".parse::<ReplaceOrder>(),
+            Ok(ReplaceOrder::PlaceFirst)
+        );
+        assert_eq!("amend".parse::<ReplaceOrder>(), Ok(ReplaceOrder::Amend));
+        assert!("modify".parse::<ReplaceOrder>().is_err());
+        assert_eq!(ReplaceOrder::default(), ReplaceOrder::CancelFirst);
+    }
+
+    #[tokio::test]
+    async fn strategy_quotes_bilateral_from_start() {
+        let (_tx, rx) = watch::channel(SymbolPrice {
+            base: "BTC".to_string(),
+            index_price: dec("100"),
+            last_price: None,
+            mark_price: dec("100"),
+            mid_price: None,
+            quote: "USD".to_string(),
+            spread_ask: None,
+            spread_bid: None,
+            symbol: "BTC-USD".to_string(),
+            time: "0".to_string(),
+        });
+
+        let executor = MockExecutor::default();
+        let tracker = Arc::new(Mutex::new(OrderTracker::new()));
+        let mut strategy = MarketMakingStrategy::new_with_params(
+            "BTC-USD".to_string(),
+            dec("1000"),
+            RiskLevel::Low,
+            None,
+            None,
+            rx,
+            position_receiver(Decimal::ZERO),
+            tracker,
+            reconcile_tx(),
+            StrategyMode::aggressive_default(),
+            5,
+            Decimal::ZERO,
+        );
+
+        strategy
+            .refresh_from_latest(&executor, tokio::time::Instant::now())
+            .await
+            .unwrap();
+
+        let orders = executor.new_orders.lock().await.clone();
+        assert_eq!(orders.len(), 10);
+        assert!(orders.iter().any(|order| order.side == Side::Buy));
+        assert!(orders.iter().any(|order| order.side == Side::Sell));
+    }
+
+    #[tokio::test]
+    async fn strategy_caps_bid_when_inventory_above_half_limit() {
+        let (_tx, rx) = watch::channel(SymbolPrice {
+            base: "BTC".to_string(),
+            index_price: dec("100"),
+            last_price: None,
+            mark_price: dec("100"),
+            mid_price: None,
+            quote: "USD".to_string(),
+            spread_ask: None,
+            spread_bid: None,
+            symbol: "BTC-USD".to_string(),
+            time: "0".to_string(),
+        });
+
+        let executor = MockExecutor::default();
+        let mut strategy = MarketMakingStrategy::new_with_params(
+            "BTC-USD".to_string(),
+            dec("1000"),
+            RiskLevel::Low,
+            None,
+            None,
+            rx,
+            position_receiver(dec("10")),
+            Arc::new(Mutex::new(OrderTracker::new())),
+            reconcile_tx(),
+            StrategyMode::aggressive_default(),
+            5,
+            dec("10"),
+        );
+
+        strategy
+            .refresh_from_latest(&executor, tokio::time::Instant::now())
+            .await
+            .unwrap();
+
+        let orders = executor.new_orders.lock().await.clone();
+        assert!(orders.iter().all(|order| order.side == Side::Sell));
+    }
+
+    fn position_limit_strategy(
+        position_rx: watch::Receiver<Decimal>,
+        max_position_size: &str,
+        max_open_orders: &str,
+    ) -> MarketMakingStrategy {
+        let (_tx, rx) = watch::channel(initial_symbol_price("BTC-USD"));
+        let mut strategy = MarketMakingStrategy::new_with_params(
+            "BTC-USD".to_string(),
+            dec("1000"),
+            RiskLevel::Low,
+            None,
+            None,
+            rx,
+            position_rx.clone(),
+            Arc::new(Mutex::new(OrderTracker::new())),
+            reconcile_tx(),
+            StrategyMode::aggressive_default(),
+            5,
+            *position_rx.borrow(),
+        );
+        // Isolate the exchange cap from the budget-based inventory cap.
+        strategy.max_non_usd_value = Decimal::ZERO;
+        strategy.set_position_limits(Some(dec(max_position_size)), Some(dec(max_open_orders)));
+        strategy
+    }
+
+    fn side_qty(orders: &[NewOrderRequest], side: Side) -> Decimal {
+        orders
+            .iter()
+            .filter(|order| order.side == side)
+            .fold(Decimal::ZERO, |acc, order| acc + order.qty)
+    }
+
+    #[tokio::test]
+    async fn position_limit_clamps_ladder_to_remaining_room() {
+        let executor = MockExecutor::default();
+        let mut strategy = position_limit_strategy(position_receiver(dec("1.5")), "2", "0");
+
+        strategy
+            .refresh_quotes(&executor, tokio::time::Instant::now(), dec("100"))
+            .await
+            .unwrap();
+
+        let orders = executor.new_orders.lock().await.clone();
+        assert_eq!(side_qty(&orders, Side::Buy), dec("0.5"));
+        // Selling may carry the long through flat to a full-size short.
+        assert_eq!(side_qty(&orders, Side::Sell), dec("3.5"));
+    }
+
+    #[tokio::test]
+    async fn position_limit_suppresses_side_until_inventory_reduces() {
+        let executor = MockExecutor::default();
+        let (position_tx, position_rx) = watch::channel(dec("2"));
+        let mut strategy = position_limit_strategy(position_rx, "2", "0");
+        let now = tokio::time::Instant::now();
+
+        strategy
+            .refresh_quotes(&executor, now, dec("100"))
+            .await
+            .unwrap();
+        let orders = executor.new_orders.lock().await.clone();
+        assert!(!orders.is_empty());
+        assert!(orders.iter().all(|order| order.side == Side::Sell));
+
+        position_tx.send(dec("1.9")).unwrap();
+        strategy.sync_inventory_from_position();
+        strategy
+            .refresh_quotes(&executor, now, dec("100"))
+            .await
+            .unwrap();
+        let orders = executor.new_orders.lock().await.clone();
+        assert_eq!(side_qty(&orders, Side::Buy), dec("0.1"));
+    }
+
+    #[tokio::test]
+    async fn max_open_orders_trims_ladder_depth() {
+        let executor = MockExecutor::default();
+        let mut strategy = position_limit_strategy(position_receiver(Decimal::ZERO), "0", "5");
+
+        strategy
+            .refresh_quotes(&executor, tokio::time::Instant::now(), dec("100"))
+            .await
+            .unwrap();
+
+        // Two bid+ask pairs fit within five open orders.
+        assert_eq!(executor.new_order_count().await, 4);
+    }
+
+    #[test]
+    fn min_uptime_flags_crossings_over_rolling_window() {
+        let (_tx, rx) = watch::channel(SymbolPrice {
+            base: "BTC".to_string(),
+            index_price: dec("100"),
+            last_price: None,
+            mark_price: dec("100"),
+            mid_price: None,
+            quote: "USD".to_string(),
+            spread_ask: None,
+            spread_bid: None,
+            symbol: "BTC-USD".to_string(),
+            time: "0".to_string(),
+        });
+        let mut strategy = MarketMakingStrategy::new_with_params(
+            "BTC-USD".to_string(),
+            dec("1000"),
+            RiskLevel::Low,
+            None,
+            None,
+            rx,
+            position_receiver(Decimal::ZERO),
+            Arc::new(Mutex::new(OrderTracker::new())),
+            reconcile_tx(),
+            StrategyMode::aggressive_default(),
+            2,
+            Decimal::ZERO,
+        );
+        let t0 = tokio::time::Instant::now();
+        let at = |secs: u64| t0 + Duration::from_secs(secs);
+        strategy.uptime_tracker = UptimeTracker::new(t0);
+        strategy.set_min_uptime(MinUptime {
+            ratio: dec("0.8"),
+            window: Duration::from_secs(100),
+        });
+
+        strategy.uptime_tracker.update(t0, true);
+        // No verdict until a full window has been observed.
+        assert_eq!(strategy.check_min_uptime(at(50)), None);
+        assert_eq!(strategy.check_min_uptime(at(100)), Some(false));
+
+        // Quotes pulled for 30s: 70 of the last 100s were active.
+        strategy.uptime_tracker.update(at(100), false);
+        assert_eq!(
+            strategy
+                .uptime_tracker
+                .window_ratio(at(130), Duration::from_secs(100)),
+            dec("0.7")
+        );
+        assert_eq!(strategy.check_min_uptime(at(130)), Some(true));
+        assert!(strategy.uptime_below_min);
+
+        // Back up; the gap stays in the window until it rolls out.
+        strategy.uptime_tracker.update(at(130), true);
+        assert_eq!(strategy.check_min_uptime(at(200)), Some(true));
+        assert_eq!(strategy.check_min_uptime(at(215)), Some(false));
+        assert!(!strategy.uptime_below_min);
+    }
+
+    #[tokio::test]
+    async fn deployed_usd_sums_resting_ladder_and_position_value() {
+        let (_tx, rx) = watch::channel(SymbolPrice {
+            base: "BTC".to_string(),
+            index_price: dec("100"),
+            last_price: None,
+            mark_price: dec("100"),
+            mid_price: None,
+            quote: "USD".to_string(),
+            spread_ask: None,
+            spread_bid: None,
+            symbol: "BTC-USD".to_string(),
+            time: "0".to_string(),
+        });
+        let mut strategy = MarketMakingStrategy::new_with_params(
+            "BTC-USD".to_string(),
+            dec("1000"),
+            RiskLevel::Low,
+            None,
+            None,
+            rx,
+            position_receiver(dec("-2")),
+            Arc::new(Mutex::new(OrderTracker::new())),
+            reconcile_tx(),
+            StrategyMode::aggressive_default(),
+            2,
+            dec("-2"),
+        );
+        let ladder = [
+            (Tier::L1, QuoteSide::Bid, "99", "1"),
+            (Tier::L1, QuoteSide::Ask, "101", "1"),
+            (Tier(1), QuoteSide::Bid, "98", "1.5"),
+            (Tier(1), QuoteSide::Ask, "102", "0.5"),
+        ];
+        for (tier, side, price, qty) in ladder {
+            strategy.live_quotes.insert(
+                QuoteSlot { tier, side },
+                LiveQuote {
+                    cl_ord_id: format!("{tier:?}-{side:?}"),
+                    price: dec(price),
+                    qty: dec(qty),
+                    placed_at: tokio::time::Instant::now(),
+                    cancel_in_flight: None,
+                },
+            );
+        }
+
+        // 99 + 101 + 147 + 51 resting, plus |-2| * 100 in position.
+        let deployed = strategy.deployed_usd(dec("100"));
+        assert_eq!(deployed, dec("598"));
+
+        let mut metrics = TaskMetrics::default();
+        metrics.record_deployment(deployed, strategy.budget_usd);
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.deployed_usd, Some(dec("598")));
+        assert_eq!(snapshot.utilization_ratio, Some(dec("0.598")));
+
+        metrics.record_deployment(deployed, Decimal::ZERO);
+        assert_eq!(metrics.snapshot().utilization_ratio, None);
+    }
+
+    #[tokio::test]
+    async fn strategy_full_fill_enters_survival_and_backoff() {
+        let (_tx, rx) = watch::channel(SymbolPrice {
+            base: "BTC".to_string(),
+            index_price: dec("100"),
+            last_price: None,
+            mark_price: dec("100"),
+            mid_price: None,
+            quote: "USD".to_string(),
+            spread_ask: None,
+            spread_bid: None,
+            symbol: "BTC-USD".to_string(),
+            time: "0".to_string(),
+        });
+
+        let executor = MockExecutor::default();
+        let tracker = Arc::new(Mutex::new(OrderTracker::new()));
+
+        let mut strategy = MarketMakingStrategy::new_with_params(
+            "BTC-USD".to_string(),
+            dec("1000"),
+            RiskLevel::Low,
+            None,
+            None,
+            rx,
+            position_receiver(Decimal::ONE),
+            tracker.clone(),
+            reconcile_tx(),
+            StrategyMode::aggressive_default(),
+            5,
+            Decimal::ONE,
+        );
+
+        strategy
+            .refresh_from_latest(&executor, tokio::time::Instant::now())
+            .await
+            .unwrap();
+        assert_eq!(executor.new_order_count().await, 10);
+
+        // Mark one quote as filled.
+        let slot = QuoteSlot {
+            tier: Tier::L1,
+            side: QuoteSide::Bid,
+        };
+        let filled_quote = strategy
+            .live_quotes
+            .get(&slot)
+            .expect("has l1 bid quote")
+            .clone();
+
+        let exchange_order = standx_point_adapter::types::models::Order {
+            avail_locked: Decimal::ZERO,
+            cl_ord_id: filled_quote.cl_ord_id.clone(),
+            closed_block: 0,
+            created_at: "0".to_string(),
+            created_block: 0,
+            fill_avg_price: filled_quote.price,
+            fill_qty: filled_quote.qty,
+            id: 123,
+            leverage: Decimal::ONE,
+            liq_id: 0,
+            margin: Decimal::ZERO,
+            order_type: OrderType::Limit,
+            payload: None,
+            tp_price: None,
+            sl_price: None,
+            position_id: 0,
+            price: Some(filled_quote.price),
+            qty: filled_quote.qty,
+            reduce_only: false,
+            remark: String::new(),
+            side: Side::Buy,
+            source: "test".to_string(),
+            status: standx_point_adapter::types::enums::OrderStatus::Filled,
+            symbol: "BTC-USD".to_string(),
+            time_in_force: TimeInForce::PostOnly,
+            updated_at: "0".to_string(),
+            user: "user".to_string(),
+        };
+
+        {
+            let mut guard = tracker.lock().await;
+            guard
+                .reconcile_with_exchange(&[exchange_order], std::time::Instant::now())
+                .unwrap();
+        }
+
+        strategy
+            .refresh_from_latest(&executor, tokio::time::Instant::now())
+            .await
+            .unwrap();
+
+        assert!(matches!(strategy.mode, StrategyMode::Survival { .. }));
+        assert!(strategy.survival_until.is_some());
+        assert!(strategy.bid_backoff_until.is_some());
+        assert!(strategy.ask_backoff_until.is_none());
+        assert!(!strategy.live_quotes.is_empty());
+
+        let orders = executor.new_orders.lock().await.clone();
+        let l1_bid_orders = orders
+            .into_iter()
+            .filter(|order| {
+                order
+                    .cl_ord_id
+                    .as_deref()
+                    .unwrap_or("")
+                    .contains(":bid:l1:")
+            })
+            .collect::<Vec<_>>();
+
+        let last = l1_bid_orders.last().expect("has l1 bid orders");
+        assert_eq!(last.qty, dec("0.45"));
+    }
+
+    #[tokio::test]
+    async fn strategy_partial_fill_requotes_remaining_qty() {
+        let (tx, rx) = watch::channel(SymbolPrice {
+            base: "BTC".to_string(),
+            index_price: dec("100"),
+            last_price: None,
+            mark_price: dec("100"),
+            mid_price: None,
+            quote: "USD".to_string(),
+            spread_ask: None,
+            spread_bid: None,
+            symbol: "BTC-USD".to_string(),
+            time: "0".to_string(),
+        });
+
+        let executor = MockExecutor::default();
+        let tracker = Arc::new(Mutex::new(OrderTracker::new()));
+        let mut strategy = MarketMakingStrategy::new_with_params(
+            "BTC-USD".to_string(),
+            dec("1000"),
+            RiskLevel::Low,
+            None,
+            None,
+            rx,
+            position_receiver(Decimal::ONE),
+            tracker.clone(),
+            reconcile_tx(),
+            StrategyMode::aggressive_default(),
+            3,
+            Decimal::ONE,
+        );
+
+        strategy
+            .refresh_from_latest(&executor, tokio::time::Instant::now())
+            .await
+            .unwrap();
+
+        let slot = QuoteSlot {
+            tier: Tier::L1,
+            side: QuoteSide::Bid,
+        };
+        let quote = strategy
+            .live_quotes
+            .get(&slot)
+            .expect("has l1 bid quote")
+            .clone();
+
+        let exchange_order = standx_point_adapter::types::models::Order {
+            avail_locked: Decimal::ZERO,
+            cl_ord_id: quote.cl_ord_id.clone(),
+            closed_block: 0,
+            created_at: "0".to_string(),
+            created_block: 0,
+            fill_avg_price: quote.price,
+            fill_qty: dec("0.1"),
+            id: 777,
+            leverage: Decimal::ONE,
+            liq_id: 0,
+            margin: Decimal::ZERO,
+            order_type: OrderType::Limit,
+            payload: None,
+            tp_price: None,
+            sl_price: None,
+            position_id: 0,
+            price: Some(quote.price),
+            qty: quote.qty,
+            reduce_only: false,
+            remark: String::new(),
+            side: Side::Buy,
+            source: "test".to_string(),
+            status: standx_point_adapter::types::enums::OrderStatus::PartiallyFilled,
+            symbol: "BTC-USD".to_string(),
+            time_in_force: TimeInForce::PostOnly,
+            updated_at: "0".to_string(),
+            user: "user".to_string(),
+        };
+
+        {
+            let mut guard = tracker.lock().await;
+            guard
+                .reconcile_with_exchange(&[exchange_order], std::time::Instant::now())
+                .unwrap();
+        }
+
+        tx.send_modify(|price| {
+            price.mark_price = dec("101");
+        });
+
+        strategy
+            .refresh_from_latest(&executor, tokio::time::Instant::now())
+            .await
+            .unwrap();
+
+        assert!(executor.cancel_count().await > 0);
+
+        {
+            let update = OrderUpdateData {
+                id: 777,
+                symbol: "BTC-USD".to_string(),
+                side: "buy".to_string(),
+                status: "cancelled".to_string(),
+                qty: quote.qty.to_string(),
+                fill_qty: "0".to_string(),
+                price: quote.price.to_string(),
+                order_type: "limit".to_string(),
+                cl_ord_id: None,
+            };
+            let mut guard = tracker.lock().await;
+            guard
+                .handle_ws_update(&update, std::time::Instant::now())
+                .unwrap();
+        }
+
+        strategy
+            .refresh_from_latest(&executor, tokio::time::Instant::now())
+            .await
+            .unwrap();
+
+        let orders = executor.new_orders.lock().await.clone();
+        let l1_bid = orders
+            .into_iter()
+            .filter(|order| {
+                order
+                    .cl_ord_id
+                    .as_deref()
+                    .unwrap_or("")
+                    .contains(":bid:l1:")
+            })
+            .collect::<Vec<_>>();
+
+        // Expect the replacement order to use remaining quantity (2.0 - 0.1 = 1.9).
+        let last = l1_bid.last().expect("has l1 bid orders");
+        assert_eq!(last.qty, dec("1.9"));
+    }
+
+    #[test]
+    fn uptime_tracker_accumulates_active_time() {
+        let t0 = tokio::time::Instant::now();
+        let mut tracker = UptimeTracker::new(t0);
+
+        tracker.update(t0, false);
+        let t1 = t0 + Duration::from_secs(10);
+        tracker.update(t1, true);
+        let t2 = t1 + Duration::from_secs(30);
+
+        let snapshot = tracker.snapshot(t2);
+        assert_eq!(snapshot.active_duration, Duration::from_secs(30));
+        assert_eq!(snapshot.total_duration, Duration::from_secs(40));
+        assert!(snapshot.uptime_ratio > dec("0.7"));
+    }
+
+    #[test]
+    fn rolling_uptime_ratio_reflects_recent_flips_only() {
+        let t0 = tokio::time::Instant::now();
+        let at = |mins: u64| t0 + Duration::from_secs(mins * 60);
+        let mut tracker = UptimeTracker::new(t0);
+
+        tracker.update(t0, true);
+        tracker.update(at(45), false);
+        // Younger than the window: both ratios cover the whole life.
+        let snapshot = tracker.snapshot(at(50));
+        assert_eq!(snapshot.uptime_ratio, dec("0.9"));
+        assert_eq!(snapshot.uptime_ratio_1h, dec("0.9"));
+
+        // Flip back mid-window; only 15 of the last 60 minutes were active.
+        tracker.update(at(105), true);
+        tracker.update(at(120), true);
+        let snapshot = tracker.snapshot(at(120));
+        assert_eq!(snapshot.uptime_ratio, dec("0.5"));
+        assert_eq!(snapshot.uptime_ratio_1h, dec("0.25"));
+        // The first interval ended before the window and was dropped.
+        assert_eq!(tracker.intervals.len(), 1);
+
+        // Flapping every second stays within the window but not the ring.
+        for secs in 1..=2 * UPTIME_RING_CAPACITY as u64 + 2 {
+            tracker.update(at(120) + Duration::from_secs(secs), secs % 2 == 0);
+        }
+        assert_eq!(tracker.intervals.len(), UPTIME_RING_CAPACITY);
     }
 
     #[tokio::test]
-    async fn strategy_quotes_bilateral_from_start() {
+    async fn strategy_skips_quotes_on_risk_halt() {
         let (_tx, rx) = watch::channel(SymbolPrice {
             base: "BTC".to_string(),
             index_price: dec("100"),
             last_price: None,
-            mark_price: dec("100"),
+            mark_price: dec("101"),
             mid_price: None,
             quote: "USD".to_string(),
             spread_ask: None,
@@ -1798,7 +4482,6 @@ mod tests {
         });
 
         let executor = MockExecutor::default();
-        let tracker = Arc::new(Mutex::new(OrderTracker::new()));
         let mut strategy = MarketMakingStrategy::new_with_params(
             "BTC-USD".to_string(),
             dec("1000"),
@@ -1806,27 +4489,29 @@ mod tests {
             None,
             None,
             rx,
-            position_receiver(Decimal::ZERO),
-            tracker,
+            position_receiver(Decimal::ONE),
+            Arc::new(Mutex::new(OrderTracker::new())),
             reconcile_tx(),
             StrategyMode::aggressive_default(),
-            5,
-            Decimal::ZERO,
+            3,
+            Decimal::ONE,
         );
 
+        strategy.risk_manager =
+            RiskManager::with_limits(dec("1"), Decimal::ZERO, dec("1000"), u32::MAX, dec("1000"));
+        let t0 = std::time::Instant::now() - std::time::Duration::from_millis(500);
+        strategy.risk_manager.record_price(t0, dec("100"));
+
         strategy
             .refresh_from_latest(&executor, tokio::time::Instant::now())
             .await
             .unwrap();
 
-        let orders = executor.new_orders.lock().await.clone();
-        assert_eq!(orders.len(), 10);
-        assert!(orders.iter().any(|order| order.side == Side::Buy));
-        assert!(orders.iter().any(|order| order.side == Side::Sell));
+        assert_eq!(executor.new_order_count().await, 0);
     }
 
     #[tokio::test]
-    async fn strategy_caps_bid_when_inventory_above_half_limit() {
+    async fn strategy_skips_quotes_when_balance_cannot_margin_ladder() {
         let (_tx, rx) = watch::channel(SymbolPrice {
             base: "BTC".to_string(),
             index_price: dec("100"),
@@ -1848,25 +4533,48 @@ mod tests {
             None,
             None,
             rx,
-            position_receiver(dec("10")),
+            position_receiver(Decimal::ZERO),
             Arc::new(Mutex::new(OrderTracker::new())),
             reconcile_tx(),
             StrategyMode::aggressive_default(),
             5,
-            dec("10"),
+            Decimal::ZERO,
         );
+        let metrics = Arc::new(Mutex::new(TaskMetrics::default()));
+        strategy.set_metrics(metrics.clone());
+        // 1000 USD of ladder at 5x needs 200 USD of margin.
+        let (available_tx, available_rx) = watch::channel(Some(dec("150")));
+        strategy.set_available_balance(available_rx, dec("5"));
+        let now = tokio::time::Instant::now();
 
-        strategy
-            .refresh_from_latest(&executor, tokio::time::Instant::now())
-            .await
-            .unwrap();
+        strategy.refresh_from_latest(&executor, now).await.unwrap();
+        assert_eq!(executor.new_order_count().await, 0);
+        let halt = metrics.lock().await.snapshot().halt_reason;
+        assert!(
+            halt.as_deref()
+                .is_some_and(|reason| reason.contains("insufficient balance")),
+            "{halt:?}"
+        );
 
-        let orders = executor.new_orders.lock().await.clone();
-        assert!(orders.iter().all(|order| order.side == Side::Sell));
+        available_tx.send(Some(dec("200"))).unwrap();
+        strategy.refresh_from_latest(&executor, now).await.unwrap();
+        assert_eq!(executor.new_order_count().await, 10);
+        assert_eq!(metrics.lock().await.snapshot().halt_reason, None);
+
+        // Margin now held by the resting ladder still counts as available.
+        let held = strategy
+            .live_quotes
+            .values()
+            .map(|quote| quote.price * quote.qty)
+            .sum::<Decimal>()
+            / dec("5");
+        available_tx.send(Some(dec("200") - held)).unwrap();
+        strategy.refresh_from_latest(&executor, now).await.unwrap();
+        assert_eq!(executor.cancel_count().await, 0);
     }
 
     #[tokio::test]
-    async fn strategy_full_fill_enters_survival_and_backoff() {
+    async fn taker_sweep_sends_capped_reduce_only_ioc() {
         let (_tx, rx) = watch::channel(SymbolPrice {
             base: "BTC".to_string(),
             index_price: dec("100"),
@@ -1881,8 +4589,6 @@ mod tests {
         });
 
         let executor = MockExecutor::default();
-        let tracker = Arc::new(Mutex::new(OrderTracker::new()));
-
         let mut strategy = MarketMakingStrategy::new_with_params(
             "BTC-USD".to_string(),
             dec("1000"),
@@ -1891,97 +4597,209 @@ mod tests {
             None,
             rx,
             position_receiver(Decimal::ONE),
-            tracker.clone(),
+            Arc::new(Mutex::new(OrderTracker::new())),
             reconcile_tx(),
             StrategyMode::aggressive_default(),
-            5,
+            1,
             Decimal::ONE,
         );
+        strategy.set_symbol_constraints(Some(2), Some(3), None, None);
+        strategy.set_taker_sweep(TakerSweep {
+            threshold_usd: dec("50"),
+            after: Duration::from_secs(10),
+            max_slippage_bps: dec("10"),
+            max_usd_per_interval: dec("30"),
+            interval: Duration::from_secs(60),
+        });
+
+        async fn sweeps(executor: &MockExecutor) -> Vec<NewOrderRequest> {
+            executor
+                .new_orders
+                .lock()
+                .await
+                .iter()
+                .filter(|req| {
+                    req.cl_ord_id
+                        .as_deref()
+                        .is_some_and(|id| id.starts_with("sweep:"))
+                })
+                .cloned()
+                .collect()
+        }
+
+        let start = tokio::time::Instant::now();
+        // Arms on the first refresh; nothing is taken before the timeout.
+        for secs in [0, 5] {
+            strategy
+                .refresh_from_latest(&executor, start + Duration::from_secs(secs))
+                .await
+                .unwrap();
+        }
+        assert!(sweeps(&executor).await.is_empty());
 
+        for secs in [11, 20, 40] {
+            strategy
+                .refresh_from_latest(&executor, start + Duration::from_secs(secs))
+                .await
+                .unwrap();
+        }
+        let sent = sweeps(&executor).await;
+        assert_eq!(sent.len(), 1);
+        let sweep = &sent[0];
+        assert!(sweep.reduce_only);
+        assert_eq!(sweep.time_in_force, TimeInForce::Ioc);
+        assert_eq!(sweep.order_type, OrderType::Limit);
+        assert_eq!(sweep.side, Side::Sell);
+        assert_eq!(sweep.qty, dec("0.3"));
+        assert_eq!(sweep.price, Some(dec("99.9")));
+
+        // The next interval opens another capped slice.
         strategy
-            .refresh_from_latest(&executor, tokio::time::Instant::now())
+            .refresh_from_latest(&executor, start + Duration::from_secs(72))
             .await
             .unwrap();
+        assert_eq!(sweeps(&executor).await.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn strategy_pause_cancels_quotes_until_resumed() {
+        let (_tx, rx) = watch::channel(SymbolPrice {
+            base: "BTC".to_string(),
+            index_price: dec("100"),
+            last_price: None,
+            mark_price: dec("100"),
+            mid_price: None,
+            quote: "USD".to_string(),
+            spread_ask: None,
+            spread_bid: None,
+            symbol: "BTC-USD".to_string(),
+            time: "0".to_string(),
+        });
+
+        let executor = MockExecutor::default();
+        let mut strategy = MarketMakingStrategy::new_with_params(
+            "BTC-USD".to_string(),
+            dec("1000"),
+            RiskLevel::Low,
+            None,
+            None,
+            rx,
+            position_receiver(Decimal::ZERO),
+            Arc::new(Mutex::new(OrderTracker::new())),
+            reconcile_tx(),
+            StrategyMode::aggressive_default(),
+            5,
+            Decimal::ZERO,
+        );
+        let (paused_tx, paused_rx) = watch::channel(true);
+        strategy.set_pause(paused_rx);
+        let now = tokio::time::Instant::now();
+
+        strategy.refresh_from_latest(&executor, now).await.unwrap();
+        assert_eq!(executor.new_order_count().await, 0);
+
+        paused_tx.send(false).unwrap();
+        strategy.refresh_from_latest(&executor, now).await.unwrap();
         assert_eq!(executor.new_order_count().await, 10);
 
-        // Mark one quote as filled.
-        let slot = QuoteSlot {
-            tier: Tier::L1,
-            side: QuoteSide::Bid,
-        };
-        let filled_quote = strategy
-            .live_quotes
-            .get(&slot)
-            .expect("has l1 bid quote")
-            .clone();
+        paused_tx.send(true).unwrap();
+        strategy.refresh_from_latest(&executor, now).await.unwrap();
+        assert_eq!(executor.cancel_count().await, 10);
+        assert_eq!(executor.new_order_count().await, 10);
+    }
 
-        let exchange_order = standx_point_adapter::types::models::Order {
-            avail_locked: Decimal::ZERO,
-            cl_ord_id: filled_quote.cl_ord_id.clone(),
-            closed_block: 0,
-            created_at: "0".to_string(),
-            created_block: 0,
-            fill_avg_price: filled_quote.price,
-            fill_qty: filled_quote.qty,
-            id: 123,
-            leverage: Decimal::ONE,
-            liq_id: 0,
-            margin: Decimal::ZERO,
-            order_type: OrderType::Limit,
-            payload: None,
-            tp_price: None,
-            sl_price: None,
-            position_id: 0,
-            price: Some(filled_quote.price),
-            qty: filled_quote.qty,
-            reduce_only: false,
-            remark: String::new(),
-            side: Side::Buy,
-            source: "test".to_string(),
-            status: standx_point_adapter::types::enums::OrderStatus::Filled,
+    #[tokio::test]
+    async fn strategy_pulls_quotes_when_price_feed_goes_stale() {
+        let price = SymbolPrice {
+            base: "BTC".to_string(),
+            index_price: dec("100"),
+            last_price: None,
+            mark_price: dec("100"),
+            mid_price: None,
+            quote: "USD".to_string(),
+            spread_ask: None,
+            spread_bid: None,
             symbol: "BTC-USD".to_string(),
-            time_in_force: TimeInForce::PostOnly,
-            updated_at: "0".to_string(),
-            user: "user".to_string(),
+            time: "0".to_string(),
         };
+        let (price_tx, rx) = watch::channel(price.clone());
 
-        {
-            let mut guard = tracker.lock().await;
-            guard
-                .reconcile_with_exchange(&[exchange_order], std::time::Instant::now())
-                .unwrap();
-        }
+        let executor = MockExecutor::default();
+        let mut strategy = MarketMakingStrategy::new_with_params(
+            "BTC-USD".to_string(),
+            dec("1000"),
+            RiskLevel::Low,
+            None,
+            None,
+            rx,
+            position_receiver(Decimal::ZERO),
+            Arc::new(Mutex::new(OrderTracker::new())),
+            reconcile_tx(),
+            StrategyMode::aggressive_default(),
+            5,
+            Decimal::ZERO,
+        );
+        strategy.set_max_price_age(Duration::from_secs(15));
+        let bus = EventBus::default();
+        let mut events = bus.subscribe();
+        strategy.set_events(bus, "task-1");
+        let start = tokio::time::Instant::now();
+
+        strategy.on_price_change(&executor, start).await.unwrap();
+        assert_eq!(executor.new_order_count().await, 10);
+
+        // The last snapshot is now 20s old: every quote comes down and
+        // nothing new is placed on the refresh tick.
+        let stale = start + Duration::from_secs(20);
+        strategy
+            .refresh_from_latest(&executor, stale)
+            .await
+            .unwrap();
+        assert_eq!(executor.cancel_count().await, 10);
+        assert_eq!(executor.new_order_count().await, 10);
+
+        assert!(strategy.price_stale);
+        // Still stale on the next tick: no second event.
+        strategy
+            .refresh_from_latest(&executor, stale + Duration::from_secs(1))
+            .await
+            .unwrap();
 
+        price_tx.send(price).unwrap();
         strategy
-            .refresh_from_latest(&executor, tokio::time::Instant::now())
+            .on_price_change(&executor, stale + Duration::from_secs(2))
             .await
             .unwrap();
-
-        assert!(matches!(strategy.mode, StrategyMode::Survival { .. }));
-        assert!(strategy.survival_until.is_some());
-        assert!(strategy.bid_backoff_until.is_some());
-        assert!(strategy.ask_backoff_until.is_none());
-        assert!(!strategy.live_quotes.is_empty());
-
-        let orders = executor.new_orders.lock().await.clone();
-        let l1_bid_orders = orders
-            .into_iter()
-            .filter(|order| {
-                order
-                    .cl_ord_id
-                    .as_deref()
-                    .unwrap_or("")
-                    .contains(":bid:l1:")
-            })
-            .collect::<Vec<_>>();
-
-        let last = l1_bid_orders.last().expect("has l1 bid orders");
-        assert_eq!(last.qty, dec("0.45"));
+        assert!(!strategy.price_stale);
+
+        let mut emitted = Vec::new();
+        while let Some(event) = events.try_recv() {
+            if matches!(
+                event,
+                RuntimeEvent::PriceStale { .. } | RuntimeEvent::PriceRecovered { .. }
+            ) {
+                emitted.push(event);
+            }
+        }
+        assert_eq!(
+            emitted,
+            vec![
+                RuntimeEvent::PriceStale {
+                    task_id: "task-1".to_string(),
+                    symbol: "BTC-USD".to_string(),
+                    age_ms: 20_000,
+                },
+                RuntimeEvent::PriceRecovered {
+                    task_id: "task-1".to_string(),
+                    symbol: "BTC-USD".to_string(),
+                },
+            ]
+        );
     }
 
     #[tokio::test]
-    async fn strategy_partial_fill_requotes_remaining_qty() {
-        let (tx, rx) = watch::channel(SymbolPrice {
+    async fn strategy_pulls_quotes_on_non_positive_mark_price() {
+        let price = SymbolPrice {
             base: "BTC".to_string(),
             index_price: dec("100"),
             last_price: None,
@@ -1992,10 +4810,10 @@ mod tests {
             spread_bid: None,
             symbol: "BTC-USD".to_string(),
             time: "0".to_string(),
-        });
+        };
+        let (price_tx, rx) = watch::channel(price.clone());
 
         let executor = MockExecutor::default();
-        let tracker = Arc::new(Mutex::new(OrderTracker::new()));
         let mut strategy = MarketMakingStrategy::new_with_params(
             "BTC-USD".to_string(),
             dec("1000"),
@@ -2003,146 +4821,74 @@ mod tests {
             None,
             None,
             rx,
-            position_receiver(Decimal::ONE),
-            tracker.clone(),
+            position_receiver(Decimal::ZERO),
+            Arc::new(Mutex::new(OrderTracker::new())),
             reconcile_tx(),
             StrategyMode::aggressive_default(),
-            3,
-            Decimal::ONE,
+            5,
+            Decimal::ZERO,
         );
+        let now = tokio::time::Instant::now();
 
-        strategy
-            .refresh_from_latest(&executor, tokio::time::Instant::now())
-            .await
-            .unwrap();
-
-        let slot = QuoteSlot {
-            tier: Tier::L1,
-            side: QuoteSide::Bid,
-        };
-        let quote = strategy
-            .live_quotes
-            .get(&slot)
-            .expect("has l1 bid quote")
-            .clone();
-
-        let exchange_order = standx_point_adapter::types::models::Order {
-            avail_locked: Decimal::ZERO,
-            cl_ord_id: quote.cl_ord_id.clone(),
-            closed_block: 0,
-            created_at: "0".to_string(),
-            created_block: 0,
-            fill_avg_price: quote.price,
-            fill_qty: dec("0.1"),
-            id: 777,
-            leverage: Decimal::ONE,
-            liq_id: 0,
-            margin: Decimal::ZERO,
-            order_type: OrderType::Limit,
-            payload: None,
-            tp_price: None,
-            sl_price: None,
-            position_id: 0,
-            price: Some(quote.price),
-            qty: quote.qty,
-            reduce_only: false,
-            remark: String::new(),
-            side: Side::Buy,
-            source: "test".to_string(),
-            status: standx_point_adapter::types::enums::OrderStatus::PartiallyFilled,
-            symbol: "BTC-USD".to_string(),
-            time_in_force: TimeInForce::PostOnly,
-            updated_at: "0".to_string(),
-            user: "user".to_string(),
-        };
-
-        {
-            let mut guard = tracker.lock().await;
-            guard
-                .reconcile_with_exchange(&[exchange_order], std::time::Instant::now())
-                .unwrap();
-        }
-
-        tx.send_modify(|price| {
-            price.mark_price = dec("101");
-        });
-
-        strategy
-            .refresh_from_latest(&executor, tokio::time::Instant::now())
-            .await
-            .unwrap();
-
-        assert!(executor.cancel_count().await > 0);
+        strategy.on_price_change(&executor, now).await.unwrap();
+        assert_eq!(executor.new_order_count().await, 10);
 
-        {
-            let update = OrderUpdateData {
-                id: 777,
-                symbol: "BTC-USD".to_string(),
-                side: "buy".to_string(),
-                status: "cancelled".to_string(),
-                qty: quote.qty.to_string(),
-                fill_qty: "0".to_string(),
-                price: quote.price.to_string(),
-                order_type: "limit".to_string(),
-            };
-            let mut guard = tracker.lock().await;
-            guard
-                .handle_ws_update(&update, std::time::Instant::now())
+        for mark in ["0", "-5"] {
+            price_tx
+                .send(SymbolPrice {
+                    mark_price: dec(mark),
+                    ..price.clone()
+                })
                 .unwrap();
+            strategy.on_price_change(&executor, now).await.unwrap();
+            assert!(strategy.price_invalid);
         }
+        assert_eq!(executor.cancel_count().await, 10);
+        assert_eq!(executor.new_order_count().await, 10);
 
-        strategy
-            .refresh_from_latest(&executor, tokio::time::Instant::now())
-            .await
-            .unwrap();
-
-        let orders = executor.new_orders.lock().await.clone();
-        let l1_bid = orders
-            .into_iter()
-            .filter(|order| {
-                order
-                    .cl_ord_id
-                    .as_deref()
-                    .unwrap_or("")
-                    .contains(":bid:l1:")
-            })
-            .collect::<Vec<_>>();
-
-        // Expect the replacement order to use remaining quantity (2.0 - 0.1 = 1.9).
-        let last = l1_bid.last().expect("has l1 bid orders");
-        assert_eq!(last.qty, dec("1.9"));
+        price_tx.send(price).unwrap();
+        strategy.on_price_change(&executor, now).await.unwrap();
+        assert!(!strategy.price_invalid);
     }
 
     #[test]
-    fn uptime_tracker_accumulates_active_time() {
-        let t0 = tokio::time::Instant::now();
-        let mut tracker = UptimeTracker::new(t0);
-
-        tracker.update(t0, false);
-        let t1 = t0 + Duration::from_secs(10);
-        tracker.update(t1, true);
-        let t2 = t1 + Duration::from_secs(30);
-
-        let snapshot = tracker.snapshot(t2);
-        assert_eq!(snapshot.active_duration, Duration::from_secs(30));
-        assert_eq!(snapshot.total_duration, Duration::from_secs(40));
-        assert!(snapshot.uptime_ratio > dec("0.7"));
+    fn exchange_price_age_reads_rfc3339_and_millis() {
+        let now = DateTime::parse_from_rfc3339("2026-10-17T00:01:00Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert_eq!(
+            exchange_price_age("2026-10-17T00:00:00Z", now),
+            Some(Duration::from_secs(60))
+        );
+        let millis = (now.timestamp_millis() - 1_500).to_string();
+        assert_eq!(
+            exchange_price_age(&millis, now),
+            Some(Duration::from_millis(1_500))
+        );
+        // A clock slightly behind the exchange is not negative age.
+        assert_eq!(
+            exchange_price_age("2026-10-17T00:01:02Z", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(exchange_price_age("", now), None);
+        assert_eq!(exchange_price_age("0", now), None);
     }
 
     #[tokio::test]
-    async fn strategy_skips_quotes_on_risk_halt() {
-        let (_tx, rx) = watch::channel(SymbolPrice {
+    async fn strategy_skips_quotes_while_feed_replays_a_frozen_price() {
+        let frozen = SymbolPrice {
             base: "BTC".to_string(),
             index_price: dec("100"),
             last_price: None,
-            mark_price: dec("101"),
+            mark_price: dec("100"),
             mid_price: None,
             quote: "USD".to_string(),
             spread_ask: None,
             spread_bid: None,
             symbol: "BTC-USD".to_string(),
-            time: "0".to_string(),
-        });
+            time: (Utc::now() - chrono::Duration::seconds(60)).to_rfc3339(),
+        };
+        let (price_tx, rx) = watch::channel(frozen.clone());
 
         let executor = MockExecutor::default();
         let mut strategy = MarketMakingStrategy::new_with_params(
@@ -2152,25 +4898,33 @@ mod tests {
             None,
             None,
             rx,
-            position_receiver(Decimal::ONE),
+            position_receiver(Decimal::ZERO),
             Arc::new(Mutex::new(OrderTracker::new())),
             reconcile_tx(),
             StrategyMode::aggressive_default(),
-            3,
-            Decimal::ONE,
+            5,
+            Decimal::ZERO,
         );
+        strategy.set_max_price_age(Duration::from_secs(15));
+        let now = tokio::time::Instant::now();
 
-        strategy.risk_manager =
-            RiskManager::with_limits(dec("1"), Decimal::ZERO, dec("1000"), u32::MAX, dec("1000"));
-        let t0 = std::time::Instant::now() - std::time::Duration::from_millis(500);
-        strategy.risk_manager.record_price(t0, dec("100"));
+        // Updates keep arriving, but they all carry the same old timestamp.
+        for _ in 0..3 {
+            price_tx.send(frozen.clone()).unwrap();
+            strategy.on_price_change(&executor, now).await.unwrap();
+        }
+        assert_eq!(executor.new_order_count().await, 0);
+        assert!(strategy.price_stale);
 
-        strategy
-            .refresh_from_latest(&executor, tokio::time::Instant::now())
-            .await
+        price_tx
+            .send(SymbolPrice {
+                time: Utc::now().to_rfc3339(),
+                ..frozen
+            })
             .unwrap();
-
-        assert_eq!(executor.new_order_count().await, 0);
+        strategy.on_price_change(&executor, now).await.unwrap();
+        assert!(!strategy.price_stale);
+        assert_eq!(executor.new_order_count().await, 10);
     }
 
     #[tokio::test]
@@ -2275,4 +5029,137 @@ mod tests {
                 .any(|slot| slot.side == QuoteSide::Ask)
         );
     }
+
+    fn fill_event(task_id: &str, side: &str, price: &str, qty: &str, fee: &str) -> RuntimeEvent {
+        RuntimeEvent::Fill {
+            task_id: task_id.to_string(),
+            symbol: "BTC-USD".to_string(),
+            order_id: 1,
+            side: side.to_string(),
+            price: dec(price),
+            fill_qty: dec(qty),
+            fee: dec(fee),
+        }
+    }
+
+    #[tokio::test]
+    async fn strategy_books_pnl_from_fill_prices_not_position_changes() {
+        let (position_tx, position_rx) = watch::channel(Decimal::ZERO);
+        let (_price_tx, price_rx) = watch::channel(initial_symbol_price("BTC-USD"));
+        let mut strategy = MarketMakingStrategy::new_with_params(
+            "BTC-USD".to_string(),
+            dec("1000"),
+            RiskLevel::Low,
+            None,
+            None,
+            price_rx,
+            position_rx,
+            Arc::new(Mutex::new(OrderTracker::new())),
+            reconcile_tx(),
+            StrategyMode::aggressive_default(),
+            5,
+            Decimal::ZERO,
+        );
+        strategy.set_events(EventBus::default(), "task-1");
+
+        strategy
+            .on_runtime_event(fill_event("task-1", "buy", "90", "1", "0.01"))
+            .await;
+        // The position feed catching up with the fill books nothing.
+        position_tx.send(Decimal::ONE).unwrap();
+        strategy.sync_inventory_from_position();
+        strategy.reconcile_ledger_position();
+        strategy
+            .on_runtime_event(fill_event("task-1", "sell", "95", "1", "0.01"))
+            .await;
+        // Another task's fills belong to its own ledger.
+        strategy
+            .on_runtime_event(fill_event("task-2", "sell", "50", "1", "0"))
+            .await;
+
+        assert_eq!(strategy.risk_manager.realized_pnl_session(), dec("4.98"));
+    }
+
+    #[tokio::test]
+    async fn session_pnl_metrics_use_fill_prices_not_mid() {
+        let mut price = initial_symbol_price("BTC-USD");
+        price.mark_price = dec("200");
+        price.mid_price = Some(dec("200"));
+        let (_price_tx, price_rx) = watch::channel(price);
+        let (position_tx, position_rx) = watch::channel(Decimal::ZERO);
+        let metrics = Arc::new(Mutex::new(TaskMetrics::default()));
+        let mut strategy = MarketMakingStrategy::new_with_params(
+            "BTC-USD".to_string(),
+            dec("1000"),
+            RiskLevel::Low,
+            None,
+            None,
+            price_rx,
+            position_rx,
+            Arc::new(Mutex::new(OrderTracker::new())),
+            reconcile_tx(),
+            StrategyMode::aggressive_default(),
+            5,
+            Decimal::ZERO,
+        );
+        strategy.set_metrics(metrics.clone());
+        strategy.set_events(EventBus::default(), "task-1");
+
+        // Round trip 100 -> 110 while mid sits at 200: mid-priced sizing
+        // would report zero, fill prices report 10 less the fees.
+        strategy
+            .on_runtime_event(fill_event("task-1", "buy", "100", "1", "0.02"))
+            .await;
+        position_tx.send(Decimal::ONE).unwrap();
+        strategy.sync_inventory_from_position();
+        strategy
+            .on_runtime_event(fill_event("task-1", "sell", "110", "1", "0.022"))
+            .await;
+        position_tx.send(Decimal::ZERO).unwrap();
+        strategy.sync_inventory_from_position();
+        strategy.reconcile_ledger_position();
+        strategy.reconcile_ledger_position();
+
+        let snapshot = metrics.lock().await.snapshot();
+        assert_eq!(snapshot.session_realized_pnl_usd, dec("9.958"));
+        assert_eq!(snapshot.realized_pnl_usd, dec("9.958"));
+    }
+
+    #[tokio::test]
+    async fn ledger_drift_without_a_fill_is_booked_at_mark() {
+        let mut price = initial_symbol_price("BTC-USD");
+        price.mark_price = dec("84");
+        let (_price_tx, price_rx) = watch::channel(price);
+        let (position_tx, position_rx) = watch::channel(Decimal::ZERO);
+        let mut strategy = MarketMakingStrategy::new_with_params(
+            "BTC-USD".to_string(),
+            dec("1000"),
+            RiskLevel::Low,
+            None,
+            None,
+            price_rx,
+            position_rx,
+            Arc::new(Mutex::new(OrderTracker::new())),
+            reconcile_tx(),
+            StrategyMode::aggressive_default(),
+            5,
+            Decimal::ZERO,
+        );
+        strategy.set_events(EventBus::default(), "task-1");
+
+        strategy
+            .on_runtime_event(fill_event("task-1", "buy", "90", "2", "0"))
+            .await;
+        position_tx.send(dec("2")).unwrap();
+        strategy.reconcile_ledger_position();
+
+        // The close never reaches the fill stream; one heartbeat waits for
+        // it, the second books the loss at the mark.
+        position_tx.send(Decimal::ZERO).unwrap();
+        strategy.reconcile_ledger_position();
+        assert_eq!(strategy.risk_manager.realized_pnl_session(), Decimal::ZERO);
+        strategy.reconcile_ledger_position();
+        assert_eq!(strategy.risk_manager.tracked_position_qty(), Decimal::ZERO);
+        assert_eq!(strategy.risk_manager.realized_pnl_session(), dec("-12"));
+    }
 }
@@ -13,41 +13,118 @@
 [UPDATE]: 2026-02-08 Support wallet private key auth configuration
 [UPDATE]: 2026-02-09 Add order reconcile loop for cancel ack gating
 [UPDATE]: 2026-03-06 Always sync authoritative position into strategy inventory.
+[UPDATE]: 2026-10-16 Prefer bulk cancel_orders during shutdown with per-order fallback
+[UPDATE]: 2026-10-16 Panic flatten positions after repeated guard placement failures
+[UPDATE]: 2026-10-16 Share per-account order limiters across task clients
+[UPDATE]: 2026-10-16 Warn at startup when a static jwt_token is expired
+[UPDATE]: 2026-10-16 Make the symbol cache directory configurable
+[UPDATE]: 2026-10-16 Authenticate bsc accounts from an encrypted keystore
+[UPDATE]: 2026-10-16 Derive account wallets from a BIP-39 mnemonic
+[UPDATE]: 2026-10-16 Retry transient authenticate failures at startup
+[UPDATE]: 2026-10-16 Apply risk.replace_order to the strategy
+[UPDATE]: 2026-10-16 Heartbeat position/order WebSockets so stale streams end
+[UPDATE]: 2026-10-16 Stop the task when equity falls below risk.min_equity_usd
+[UPDATE]: 2026-10-16 Spawn tasks in canonical task-id order
+[UPDATE]: 2026-10-16 Resync open orders over REST on order-stream sequence gaps
+[UPDATE]: 2026-10-16 Derive wallet address for static jwt credentials instead of "unknown"
+[UPDATE]: 2026-10-16 Publish task, guard, and fill RuntimeEvents on a shared EventBus
+[UPDATE]: 2026-10-16 Apply per-task quoting bps overrides to the strategy
+[UPDATE]: 2026-10-16 Wire risk.daily_loss_limit_usd into the strategy
+[UPDATE]: 2026-10-16 Add TaskManager::emergency_flatten kill switch across all tasks
+[UPDATE]: 2026-10-16 Estimate maker fees on Fill events; export metrics snapshots to CSV
+[UPDATE]: 2026-10-16 Poll the funding rate for funding-aware quoting and metrics
+[UPDATE]: 2026-10-16 Add pause_task/resume_task and a Paused runtime status
+[UPDATE]: 2026-10-16 Add opt-in auto-restart supervisor with bounded exponential backoff
+[UPDATE]: 2026-10-17 Bound concurrent task startup sequences with max_concurrent_startups
+[UPDATE]: 2026-10-17 Share per-account REST rate limiters across task clients
+[UPDATE]: 2026-10-17 Apply configured margin mode and leverage during startup
+[UPDATE]: 2026-10-17 Close positions with a stable cl_ord_id and timeout-checked submission
+[UPDATE]: 2026-10-17 Take REST base URLs from STANDX_AUTH_BASE_URL / STANDX_TRADING_BASE_URL
+[UPDATE]: 2026-10-17 Route REST and WS endpoints through the configured StandxEnvironment
+[UPDATE]: 2026-10-17 Reconnect the position stream with backoff and resync from REST
+[UPDATE]: 2026-10-17 Verify the public half of 64-byte ed25519 signing keys
+[UPDATE]: 2026-10-17 Self-test each task client's request signer before spawning
+[UPDATE]: 2026-10-17 Match 404s through StandxError::is_not_found
+[UPDATE]: 2026-10-17 Refresh cross_available for the strategy's insufficient-balance gate
+[UPDATE]: 2026-10-17 Wire risk.taker_sweep into the strategy
+[UPDATE]: 2026-10-17 Merge and write the symbol cache atomically under a cross-process file lock
+[UPDATE]: 2026-10-17 Quarantine unparsable symbol caches as symbols.json.bad
+[UPDATE]: 2026-10-17 Poll the order book mid for quoting.reference_price: book_mid
+[UPDATE]: 2026-10-17 Pass the symbol taker fee to the strategy's spread floor
+[UPDATE]: 2026-10-17 Consume typed OrderUpdate messages from the order stream
+[UPDATE]: 2026-10-17 Time each task's REST requests into its TaskMetrics
+[UPDATE]: 2026-10-17 Page truncated open-order listings through iter_orders
+[UPDATE]: 2026-10-17 Query open orders through StandxClient::query_all_open_orders
+[UPDATE]: 2026-10-17 Add TaskManager::drain: pause quoting, wait for cleanup, then abort
+[UPDATE]: 2026-10-17 Apply risk.min_uptime_ratio to the strategy
+[UPDATE]: 2026-10-17 Return a per-task SpawnReport once startup sequences finish
+[UPDATE]: 2026-10-17 Check derived wallet address against accounts[].wallet_address
+[UPDATE]: 2026-10-17 Read positions from the hub's shared per-account feed
+[UPDATE]: 2026-10-17 Apply risk.max_price_age_secs to the strategy
+[UPDATE]: 2026-10-17 Skip fill reports whose fill_qty/price do not parse
+[UPDATE]: 2026-10-17 Make guard flatten opt-in (guard_flatten_after_failures unset disables)
+[UPDATE]: 2026-10-17 Cancel with cancel_all_orders on shutdown, falling back to per-order cancels
+[UPDATE]: 2026-10-17 Seed the strategy PnL ledger from the starting position even without a loss limit
+[UPDATE]: 2026-10-17 Refresh wallet-account JWTs in the background and point task clients at the shared token
+[UPDATE]: 2026-10-17 Apply each task's bus events to its metrics through spawn_event_metrics
+[UPDATE]: 2026-10-17 Price fills from the trades endpoint; reconnect the order stream with a REST resync
 */
 
 use crate::config::{AccountConfig, StrategyConfig, TaskConfig};
-use crate::market_data::MarketDataHub;
-use crate::metrics::{TaskMetrics, TaskMetricsSnapshot};
+use crate::events::{EventBus, EventSubscription, GuardActionKind, RuntimeEvent};
+use crate::file_lock::{DEFAULT_LOCK_TIMEOUT, FileLock};
+use crate::fills::write_atomic;
+use crate::market_data::{
+    ACCOUNT_STREAM_RECONNECT_BACKOFF, MarketDataHub, PositionFeedEvent, WS_HEARTBEAT_INTERVAL,
+    WS_HEARTBEAT_TIMEOUT,
+};
+use crate::metrics::{TaskMetrics, TaskMetricsSnapshot, spawn_event_metrics};
 use crate::order_state::OrderTracker;
-use crate::strategy::{MarketMakingStrategy, OrderReconcileRequest, RiskLevel, StrategyMode};
+use crate::strategy::{
+    MarketMakingStrategy, OrderReconcileReason, OrderReconcileRequest, ReplaceOrder, RiskLevel,
+    StrategyMode,
+};
 use anyhow::{Context as _, Result, anyhow};
 use base64::{Engine as _, engine::general_purpose::STANDARD as BASE64};
+use futures_util::TryStreamExt as _;
 use rust_decimal::{Decimal, RoundingStrategy};
 use serde::{Deserialize, Serialize};
-use standx_point_adapter::auth::{AuthManager, EvmWalletSigner, SolanaWalletSigner};
-use standx_point_adapter::ws::message::OrderUpdateData;
+use standx_point_adapter::auth::mnemonic::{evm_derivation_path, solana_derivation_path};
+use standx_point_adapter::auth::{
+    AuthManager, EvmWalletSigner, JwtManager, SolanaWalletSigner, WalletSigner,
+};
 use standx_point_adapter::{
-    Balance, CancelOrderRequest, Chain, ClientConfig, Credentials, Ed25519Signer, NewOrderRequest,
-    Order, OrderStatus, OrderType, PaginatedOrders, Position, Side, StandxClient, StandxError,
-    StandxWebSocket, SymbolInfo, SymbolPrice, TimeInForce, WebSocketMessage,
+    BackoffPolicy, Balance, CancelOrderRequest, CancelOrdersRequest, Chain, ClientConfig,
+    Credentials, Ed25519Signer, JwtClaims, LatencyTracker, NewOrderRequest, Order, OrderStatus,
+    OrderSubmission, OrderType, PaginatedOrders, Position, RateLimiter, Side, StandxClient,
+    StandxEnvironment, StandxWebSocket, SymbolInfo, SymbolPrice, TimeInForce, Trade,
+    WebSocketMessage,
 };
 use std::collections::HashMap;
 use std::future::pending;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::{Arc, Once};
 use std::time::Duration;
 use tokio::fs;
-use tokio::sync::{Mutex, mpsc, watch};
-use tokio::task::JoinHandle;
+use tokio::sync::broadcast::error::RecvError;
+use tokio::sync::{Mutex, Semaphore, broadcast, mpsc, watch};
+use tokio::task::{JoinHandle, JoinSet};
 use tokio::time::{Instant, Sleep};
 use tokio_util::sync::CancellationToken;
 use uuid::Uuid;
 
 const SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(30);
+/// How often spawn checks for tasks that exited without a startup event.
+const STARTUP_POLL_INTERVAL: Duration = Duration::from_millis(250);
+/// Default budget for [`TaskManager::drain`] before remaining tasks are aborted.
+pub const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
 const POSITION_GUARD_COOLDOWN: Duration = Duration::from_secs(5);
 const POSITION_GUARD_RETRY_DELAY: Duration = Duration::from_secs(1);
 const POSITION_GUARD_POLL_INTERVAL: Duration = Duration::from_secs(10);
+const POSITION_GUARD_FAILURE_WINDOW: Duration = Duration::from_secs(60);
+const SYMBOL_CACHE_FILE: &str = "symbols.json";
+const KEYSTORE_PASSPHRASE_ENV: &str = "STANDX_MM_KEYSTORE_PASSPHRASE";
 const BPS_DENOMINATOR: i64 = 10_000;
 const DEFAULT_EXIT_BPS_CONSERVATIVE: i64 = 8;
 const DEFAULT_EXIT_BPS_MODERATE: i64 = 5;
@@ -57,7 +134,23 @@ const DEFAULT_GUARD_BPS_MODERATE: i64 = 50;
 const DEFAULT_GUARD_BPS_AGGRESSIVE: i64 = 80;
 const DEFAULT_FEE_BPS: i64 = 2;
 const DEFAULT_JWT_EXPIRES_SECONDS: u64 = 7 * 24 * 60 * 60;
+/// How often wallet-authenticated accounts check whether their JWT is due for refresh.
+const JWT_REFRESH_CHECK_INTERVAL: Duration = Duration::from_secs(60);
 const ORDER_RECONCILE_INTERVAL: Duration = Duration::from_secs(30);
+const MIN_EQUITY_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+const FUNDING_RATE_POLL_INTERVAL: Duration = Duration::from_secs(60);
+const AVAILABLE_BALANCE_POLL_INTERVAL: Duration = Duration::from_secs(30);
+/// Book snapshots are reused until the next poll to spare the endpoint.
+const ORDERBOOK_POLL_INTERVAL: Duration = Duration::from_secs(2);
+const ORDERBOOK_DEPTH: usize = 5;
+const RESTART_SUPERVISOR_INTERVAL: Duration = Duration::from_secs(1);
+/// Trades are requeried this far behind the last sync, so a trade that
+/// becomes visible late is still booked.
+const TRADE_SYNC_LOOKBACK_MS: u64 = 60_000;
+/// Wait before requerying trades that do not yet cover reported fills.
+const TRADE_SYNC_RETRY_DELAY: Duration = Duration::from_secs(1);
+const TRADE_SYNC_PAGE_SIZE: u32 = 500;
+const DEFAULT_MAX_CONCURRENT_STARTUPS: usize = 4;
 
 static PANIC_HOOK_ONCE: Once = Once::new();
 
@@ -111,6 +204,24 @@ fn default_tp_sl_bps_for_risk(
     (Some(tp_bps), Some(sl_bps))
 }
 
+/// Quantity-weighted entry price across `positions` (zero when flat).
+fn average_entry_price(positions: &[Position]) -> Decimal {
+    let (notional, qty) = positions.iter().fold(
+        (Decimal::ZERO, Decimal::ZERO),
+        |(notional, qty), position| {
+            (
+                notional + position.entry_price * position.qty.abs(),
+                qty + position.qty.abs(),
+            )
+        },
+    );
+    if qty.is_zero() {
+        Decimal::ZERO
+    } else {
+        notional / qty
+    }
+}
+
 fn fee_bps_total_from_symbol_info(symbol_info: Option<&SymbolInfo>) -> Decimal {
     let maker_fee = symbol_info
         .map(|info| info.maker_fee)
@@ -134,22 +245,164 @@ pub enum TaskState {
     Stopping,
     Stopped,
     Failed,
+    /// Halted because account equity fell below `risk.min_equity_usd`.
+    MinEquityBreached,
+}
+
+/// Raised when account equity falls below `risk.min_equity_usd` mid-run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinEquityBreached {
+    pub equity: Decimal,
+    pub min_equity_usd: Decimal,
+}
+
+impl std::fmt::Display for MinEquityBreached {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min equity breached: equity {} < min_equity_usd {}",
+            self.equity, self.min_equity_usd
+        )
+    }
 }
 
+impl std::error::Error for MinEquityBreached {}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TaskRuntimeStatus {
     Running,
+    /// Alive with quotes cancelled; the position guard keeps running.
+    Paused,
+    /// Failed and waiting out its backoff before the supervisor respawns it.
+    Restarting,
+    /// Failed and out of restart attempts (or stopped on a min-equity breach).
+    Failed,
     Finished,
 }
 
+/// Per-task startup outcome of one [`TaskManager::spawn_from_config`] call.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpawnReport {
+    /// Tasks that finished their startup sequence and are quoting.
+    pub started: Vec<String>,
+    /// Tasks whose startup failed, with the error.
+    pub failed: Vec<(String, String)>,
+}
+
+impl SpawnReport {
+    pub fn all_started(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Exponential backoff for restarting failed tasks; see
+/// [`TaskManager::with_auto_restart`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestartPolicy {
+    /// Restarts attempted before a task is marked permanently failed.
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl RestartPolicy {
+    /// Delay before the restart following `restarts` earlier ones: doubles
+    /// from `initial_backoff`, capped at `max_backoff`.
+    pub fn backoff(&self, restarts: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(restarts))
+            .min(self.max_backoff)
+    }
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Supervisor bookkeeping for one task.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskRestartInfo {
+    pub restarts: u32,
+    pub last_error: Option<String>,
+}
+
+#[derive(Debug, Default)]
+struct RestartState {
+    info: TaskRestartInfo,
+    /// When the next restart is due; `None` while running or once settled.
+    retry_at: Option<Instant>,
+    /// Out of attempts; never restarted again.
+    failed: bool,
+}
+
+impl RestartState {
+    fn runtime_status(&self) -> TaskRuntimeStatus {
+        if self.failed {
+            TaskRuntimeStatus::Failed
+        } else if self.retry_at.is_some() {
+            TaskRuntimeStatus::Restarting
+        } else {
+            TaskRuntimeStatus::Finished
+        }
+    }
+}
+
+type BuildClientFn =
+    dyn Fn(&TaskConfig, &AccountConfig, &AccountAuth) -> Result<StandxClient> + Send + Sync;
+
+/// Client builder kept so the supervisor can respawn tasks.
+#[derive(Clone)]
+struct ClientBuilder(Arc<BuildClientFn>);
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self(Arc::new(Task::build_client))
+    }
+}
+
+impl std::fmt::Debug for ClientBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("ClientBuilder")
+    }
+}
+
 #[derive(Debug)]
 struct ManagedTask {
     shutdown: CancellationToken,
     handle: JoinHandle<Result<()>>,
+    task_uuid: Uuid,
+    symbol: String,
+    /// Independent client so flattening does not wait on the task loop.
+    flatten_client: Arc<StandxClient>,
+    paused: watch::Sender<bool>,
+    /// Keeps the account's JWT refresher alive while this task exists.
+    _jwt_refresher: Option<Arc<AbortOnDrop>>,
+    /// Applies this task's bus events to its metrics.
+    _event_metrics: AbortOnDrop,
+}
+
+impl ManagedTask {
+    fn runtime_status(&self) -> TaskRuntimeStatus {
+        if self.handle.is_finished() {
+            TaskRuntimeStatus::Finished
+        } else if *self.paused.borrow() {
+            TaskRuntimeStatus::Paused
+        } else {
+            TaskRuntimeStatus::Running
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 struct StartupSnapshot {
+    /// `None` when the balance query failed
+    cross_available: Option<Decimal>,
     positions: Vec<Position>,
     symbol_info: Option<SymbolInfo>,
 }
@@ -157,6 +410,20 @@ struct StartupSnapshot {
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 struct SymbolCache {
     symbols: HashMap<String, SymbolInfo>,
+    /// File backing this cache; `None` keeps it in memory only.
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+/// Background task aborted once its last holder is dropped, e.g. an account's
+/// JWT refresher shared by its tasks.
+#[derive(Debug)]
+struct AbortOnDrop(JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
 }
 
 #[derive(Debug)]
@@ -165,6 +432,11 @@ pub(crate) struct AccountAuth {
     signing_key: [u8; 32],
     wallet_address: String,
     chain: Chain,
+    /// Deployment the token was issued on; task clients must use the same one
+    environment: StandxEnvironment,
+    /// Token store kept current by `jwt_refresher`; `None` for static tokens
+    jwt_source: Option<JwtManager>,
+    jwt_refresher: Option<Arc<AbortOnDrop>>,
 }
 
 impl AccountAuth {
@@ -175,15 +447,100 @@ impl AccountAuth {
     ) -> Result<Self> {
         let signing_key = decode_ed25519_secret_key_base64(signing_key_base64)
             .context("decode signing_key (base64) failed")?;
+        match JwtClaims::decode(jwt_token) {
+            Ok(claims) if claims.is_expired(chrono::Utc::now()) => tracing::warn!(
+                account_id = %account.id,
+                expires_at = ?claims.expires_at(),
+                "static jwt_token is expired; authenticated requests will be rejected"
+            ),
+            Ok(_) => {}
+            Err(err) => tracing::warn!(
+                account_id = %account.id,
+                %err,
+                "static jwt_token could not be decoded; expiry unknown"
+            ),
+        }
+        let wallet_address = match static_wallet_address(account, jwt_token) {
+            Some(derived) => {
+                check_wallet_address(account, &derived)?;
+                derived
+            }
+            None => configured_wallet_address(account)
+                .map(str::to_string)
+                .unwrap_or_else(|| {
+                    tracing::warn!(
+                        account_id = %account.id,
+                        "wallet address unavailable: no private_key/mnemonic, no address claim \
+                         in jwt_token, and no wallet_address configured"
+                    );
+                    String::new()
+                }),
+        };
         Ok(Self {
             jwt_token: jwt_token.to_string(),
             signing_key,
-            wallet_address: "unknown".to_string(),
+            wallet_address,
             chain: account.chain,
+            environment: StandxEnvironment::default(),
+            jwt_source: None,
+            jwt_refresher: None,
         })
     }
 }
 
+/// Wallet address to pair with a static `jwt_token`.
+///
+/// Derived from the account's private key or mnemonic when configured
+/// (keystores are skipped so no passphrase is needed), otherwise read from
+/// the token's `address`/`sub` claim.
+pub fn static_wallet_address(account: &AccountConfig, jwt_token: &str) -> Option<String> {
+    let private_key = account.private_key.as_deref().unwrap_or("").trim();
+    let has_mnemonic = account
+        .mnemonic
+        .as_deref()
+        .is_some_and(|phrase| !phrase.trim().is_empty());
+    if (!private_key.is_empty() || has_mnemonic)
+        && let Ok(wallet) = wallet_for_account(account, private_key)
+    {
+        return Some(wallet.address().to_string());
+    }
+    JwtClaims::decode(jwt_token)
+        .ok()?
+        .wallet_address()
+        .map(str::to_string)
+}
+
+fn configured_wallet_address(account: &AccountConfig) -> Option<&str> {
+    account
+        .wallet_address
+        .as_deref()
+        .map(str::trim)
+        .filter(|address| !address.is_empty())
+}
+
+/// Fail startup when the credentials resolve to a wallet other than the
+/// account's configured `wallet_address`.
+///
+/// EVM addresses compare case-insensitively so checksummed and lowercase
+/// forms match; Solana addresses are base58 and compare exactly.
+fn check_wallet_address(account: &AccountConfig, derived: &str) -> Result<()> {
+    let Some(expected) = configured_wallet_address(account) else {
+        return Ok(());
+    };
+    let matches = match account.chain {
+        Chain::Bsc => expected.eq_ignore_ascii_case(derived),
+        Chain::Solana => expected == derived,
+    };
+    if matches {
+        Ok(())
+    } else {
+        Err(anyhow!(
+            "account {} credentials resolve to wallet {derived}, but wallet_address is {expected}",
+            account.id
+        ))
+    }
+}
+
 /// Task manager that coordinates multiple trading tasks.
 #[derive(Debug)]
 pub struct TaskManager {
@@ -194,7 +551,16 @@ pub struct TaskManager {
     #[cfg_attr(test, allow(dead_code))]
     market_data_hub: std::sync::Arc<Mutex<MarketDataHub>>,
     symbol_cache: std::sync::Arc<Mutex<SymbolCache>>,
+    symbol_cache_dir: PathBuf,
     shutdown: CancellationToken,
+    events: EventBus,
+    restart_policy: Option<RestartPolicy>,
+    restarts: HashMap<String, RestartState>,
+    /// Accounts of spawned tasks, kept for respawns.
+    accounts: HashMap<String, AccountConfig>,
+    /// Deployment of spawned tasks, kept for respawns.
+    environment: StandxEnvironment,
+    client_builder: ClientBuilder,
 
     #[cfg(test)]
     test_price_txs: Vec<watch::Sender<SymbolPrice>>,
@@ -217,7 +583,7 @@ async fn resolve_account_auth(
     }
 
     let private_key = account.private_key.as_deref().unwrap_or("").trim();
-    if private_key.is_empty() {
+    if private_key.is_empty() && account.keystore_path.is_none() && account.mnemonic.is_none() {
         return Err(anyhow!(
             "account {} missing private_key (jwt_token+signing_key not provided)",
             account.id
@@ -227,46 +593,91 @@ async fn resolve_account_auth(
     let auth_client =
         StandxClient::with_config_and_base_urls(client_config, auth_base_url, trading_base_url)
             .map_err(|err| anyhow!("create StandxClient for auth failed: {err}"))?;
-    let auth = AuthManager::new(auth_client);
-
-    let (wallet_address, jwt_token) = match account.chain {
-        Chain::Bsc => {
-            let wallet = EvmWalletSigner::new(private_key)
-                .map_err(|err| anyhow!("invalid EVM private key: {err}"))?;
-            let wallet_address =
-                standx_point_adapter::auth::WalletSigner::address(&wallet).to_string();
-            let login = auth
-                .authenticate(&wallet, DEFAULT_JWT_EXPIRES_SECONDS)
-                .await
-                .map_err(|err| anyhow!("authenticate failed: {err}"))?;
-            (wallet_address, login.token)
-        }
-        Chain::Solana => {
-            let wallet = SolanaWalletSigner::new(private_key)
-                .map_err(|err| anyhow!("invalid Solana private key: {err}"))?;
-            let wallet_address =
-                standx_point_adapter::auth::WalletSigner::address(&wallet).to_string();
-            let login = auth
-                .authenticate(&wallet, DEFAULT_JWT_EXPIRES_SECONDS)
-                .await
-                .map_err(|err| anyhow!("authenticate failed: {err}"))?;
-            (wallet_address, login.token)
-        }
-    };
+    let auth = Arc::new(AuthManager::new(auth_client));
+
+    let wallet: Arc<dyn WalletSigner> = Arc::from(wallet_for_account(account, private_key)?);
+    let wallet_address = wallet.address().to_string();
+    check_wallet_address(account, &wallet_address)?;
+    let jwt_token = auth
+        .authenticate_with_retry(
+            wallet.as_ref(),
+            DEFAULT_JWT_EXPIRES_SECONDS,
+            &BackoffPolicy::default(),
+        )
+        .await
+        .map_err(|err| anyhow!("authenticate failed: {err}"))?
+        .token;
 
     let signer = auth
         .key_manager()
         .get_or_create_signer(&wallet_address)
         .map_err(|err| anyhow!("load ed25519 signer failed: {err}"))?;
 
+    // Task clients read the token from the shared store, so a refresh
+    // reaches them without rebuilding anything.
+    let jwt_source = auth.jwt_manager().clone();
+    let refresher = AbortOnDrop(auth.spawn_auto_refresh(wallet, JWT_REFRESH_CHECK_INTERVAL));
+
     Ok(AccountAuth {
         jwt_token,
         signing_key: signer.secret_key_bytes(),
         wallet_address: wallet_address.to_string(),
         chain: account.chain,
+        environment: StandxEnvironment::default(),
+        jwt_source: Some(jwt_source),
+        jwt_refresher: Some(Arc::new(refresher)),
     })
 }
 
+fn wallet_for_account(account: &AccountConfig, private_key: &str) -> Result<Box<dyn WalletSigner>> {
+    let index = account.mnemonic_index.unwrap_or(0);
+    let mnemonic = account
+        .mnemonic
+        .as_deref()
+        .map(str::trim)
+        .filter(|phrase| !phrase.is_empty());
+
+    match account.chain {
+        Chain::Bsc => {
+            if !private_key.is_empty() {
+                let wallet = EvmWalletSigner::new(private_key)
+                    .map_err(|err| anyhow!("invalid EVM private key: {err}"))?;
+                return Ok(Box::new(wallet));
+            }
+            if let Some(phrase) = mnemonic {
+                let wallet = EvmWalletSigner::from_mnemonic(phrase, &evm_derivation_path(index))
+                    .map_err(|err| anyhow!("derive EVM wallet from mnemonic failed: {err}"))?;
+                return Ok(Box::new(wallet));
+            }
+            Ok(Box::new(evm_wallet_from_keystore(account)?))
+        }
+        Chain::Solana => {
+            let wallet = match mnemonic.filter(|_| private_key.is_empty()) {
+                Some(phrase) => {
+                    SolanaWalletSigner::from_mnemonic(phrase, &solana_derivation_path(index))
+                        .map_err(|err| {
+                            anyhow!("derive Solana wallet from mnemonic failed: {err}")
+                        })?
+                }
+                None => SolanaWalletSigner::new(private_key)
+                    .map_err(|err| anyhow!("invalid Solana private key: {err}"))?,
+            };
+            Ok(Box::new(wallet))
+        }
+    }
+}
+
+fn evm_wallet_from_keystore(account: &AccountConfig) -> Result<EvmWalletSigner> {
+    let keystore_path = account
+        .keystore_path
+        .as_deref()
+        .ok_or_else(|| anyhow!("account {} missing private_key", account.id))?;
+    let passphrase = std::env::var(KEYSTORE_PASSPHRASE_ENV)
+        .with_context(|| format!("{KEYSTORE_PASSPHRASE_ENV} is required for keystore_path"))?;
+    EvmWalletSigner::from_keystore(keystore_path, &passphrase)
+        .map_err(|err| anyhow!("load keystore for account {} failed: {err}", account.id))
+}
+
 impl TaskManager {
     /// Create a new task manager.
     pub fn new() -> Self {
@@ -276,7 +687,14 @@ impl TaskManager {
             task_metrics: HashMap::new(),
             market_data_hub: std::sync::Arc::new(Mutex::new(MarketDataHub::new())),
             symbol_cache: std::sync::Arc::new(Mutex::new(SymbolCache::default())),
+            symbol_cache_dir: default_symbol_cache_dir(),
             shutdown: CancellationToken::new(),
+            events: EventBus::default(),
+            restart_policy: None,
+            restarts: HashMap::new(),
+            accounts: HashMap::new(),
+            environment: StandxEnvironment::default(),
+            client_builder: ClientBuilder::default(),
 
             #[cfg(test)]
             test_price_txs: Vec::new(),
@@ -290,13 +708,35 @@ impl TaskManager {
             task_metrics: HashMap::new(),
             market_data_hub,
             symbol_cache: std::sync::Arc::new(Mutex::new(SymbolCache::default())),
+            symbol_cache_dir: default_symbol_cache_dir(),
             shutdown: CancellationToken::new(),
+            events: EventBus::default(),
+            restart_policy: None,
+            restarts: HashMap::new(),
+            accounts: HashMap::new(),
+            environment: StandxEnvironment::default(),
+            client_builder: ClientBuilder::default(),
 
             #[cfg(test)]
             test_price_txs: Vec::new(),
         }
     }
 
+    /// Respawn tasks that fail, following `policy`.
+    ///
+    /// Restarts happen in [`supervise_restarts`](Self::supervise_restarts),
+    /// which [`spawn_restart_supervisor`] runs periodically. Without this,
+    /// failed tasks stay `Finished` until restarted by hand.
+    pub fn with_auto_restart(mut self, policy: RestartPolicy) -> Self {
+        self.restart_policy = Some(policy);
+        self
+    }
+
+    /// Override the directory holding the persisted symbol-info cache.
+    pub fn set_symbol_cache_dir(&mut self, dir: impl Into<PathBuf>) {
+        self.symbol_cache_dir = dir.into();
+    }
+
     pub fn shutdown_token(&self) -> CancellationToken {
         self.shutdown.clone()
     }
@@ -305,27 +745,41 @@ impl TaskManager {
         self.market_data_hub.clone()
     }
 
+    /// Bus every spawned task publishes its `RuntimeEvent`s on.
+    pub fn event_bus(&self) -> EventBus {
+        self.events.clone()
+    }
+
+    pub fn subscribe_events(&self) -> EventSubscription {
+        self.events.subscribe()
+    }
+
     pub fn runtime_status(&self, task_id: &str) -> Option<TaskRuntimeStatus> {
-        self.tasks.get(task_id).map(|task| {
-            if task.handle.is_finished() {
-                TaskRuntimeStatus::Finished
-            } else {
-                TaskRuntimeStatus::Running
-            }
-        })
+        match self.tasks.get(task_id) {
+            Some(task) => Some(task.runtime_status()),
+            None => self.restarts.get(task_id).map(RestartState::runtime_status),
+        }
     }
 
     pub fn runtime_status_snapshot(&self) -> HashMap<String, TaskRuntimeStatus> {
-        self.tasks
+        let mut snapshot: HashMap<String, TaskRuntimeStatus> = self
+            .restarts
             .iter()
-            .map(|(task_id, task)| {
-                let status = if task.handle.is_finished() {
-                    TaskRuntimeStatus::Finished
-                } else {
-                    TaskRuntimeStatus::Running
-                };
-                (task_id.clone(), status)
-            })
+            .map(|(task_id, state)| (task_id.clone(), state.runtime_status()))
+            .collect();
+        snapshot.extend(
+            self.tasks
+                .iter()
+                .map(|(task_id, task)| (task_id.clone(), task.runtime_status())),
+        );
+        snapshot
+    }
+
+    /// Restart count and last failure of every task the supervisor has seen fail.
+    pub fn restart_info_snapshot(&self) -> HashMap<String, TaskRestartInfo> {
+        self.restarts
+            .iter()
+            .map(|(task_id, state)| (task_id.clone(), state.info.clone()))
             .collect()
     }
 
@@ -342,10 +796,26 @@ impl TaskManager {
         snapshot
     }
 
+    /// Write the current metrics snapshot to `path` as CSV, one row per task.
+    ///
+    /// The file is replaced atomically (temp file, then rename).
+    pub async fn export_metrics_csv(&self, path: impl AsRef<Path>) -> Result<()> {
+        let csv = crate::fills::render_metrics_csv(
+            &self.task_config_snapshot(),
+            &self.task_metrics_snapshot().await,
+        );
+        crate::fills::write_atomic(path.as_ref(), &csv).await
+    }
+
     /// Spawn tasks from configuration using the default StandxClient builder.
-    pub async fn spawn_from_config(&mut self, config: StrategyConfig) -> Result<()> {
-        self.spawn_from_config_with_client_builder(config, |task_config, account, auth| {
-            Task::build_client(task_config, account, auth)
+    ///
+    /// Waits for every task's startup sequence (account snapshot, open-order
+    /// cleanup, margin settings) and reports which tasks reached `Running`
+    /// and which failed. Config, auth, and client errors still fail the call.
+    pub async fn spawn_from_config(&mut self, config: StrategyConfig) -> Result<SpawnReport> {
+        let builder = self.client_builder.clone();
+        self.spawn_from_config_with_client_builder(config, move |task_config, account, auth| {
+            (builder.0)(task_config, account, auth)
         })
         .await
     }
@@ -357,34 +827,51 @@ impl TaskManager {
         &mut self,
         config: StrategyConfig,
         build_client: F,
-    ) -> Result<()>
+    ) -> Result<SpawnReport>
     where
         F: Fn(&TaskConfig, &AccountConfig, &AccountAuth) -> Result<StandxClient>,
     {
         ensure_panic_hook_installed();
 
+        let tasks = config.fan_out_tasks()?;
+        let environment = config.environment.unwrap_or_default();
+        environment.validate()?;
         let accounts = config.accounts;
+        let (auth_base_url, trading_base_url) = environment.base_urls()?;
         let mut auth_by_id: HashMap<String, AccountAuth> = HashMap::new();
         for account in &accounts {
-            let auth = resolve_account_auth(
+            let mut auth = resolve_account_auth(
                 account,
                 ClientConfig::default(),
-                "https://api.standx.com",
-                "https://perps.standx.com",
+                auth_base_url.as_str(),
+                trading_base_url.as_str(),
             )
             .await
             .with_context(|| format!("authenticate account_id={}", account.id))?;
+            auth.environment = environment.clone();
             auth_by_id.insert(account.id.clone(), auth);
         }
+        self.market_data_hub
+            .lock()
+            .await
+            .set_ws_url(environment.market_stream_url());
+        self.environment = environment;
 
+        let order_limiters = order_limiters_for_accounts(&accounts);
+        let rate_limiters = rate_limiters_for_accounts(&accounts);
+        let startup_limiter = startup_limiter(config.max_concurrent_startups);
         let accounts_by_id: HashMap<String, AccountConfig> = accounts
             .into_iter()
             .map(|account| (account.id.clone(), account))
             .collect();
+        self.accounts.extend(accounts_by_id.clone());
 
         self.load_symbol_cache_from_disk().await;
 
-        for task_config in config.tasks {
+        // Subscribe before spawning so no startup outcome is missed.
+        let mut startup_events = self.events.subscribe();
+        let mut spawned = Vec::with_capacity(tasks.len());
+        for task_config in tasks {
             if self.tasks.contains_key(&task_config.id) {
                 return Err(anyhow!(
                     "duplicate task_id in StrategyConfig: {}",
@@ -392,7 +879,10 @@ impl TaskManager {
                 ));
             }
 
-            let metrics = Arc::new(Mutex::new(TaskMetrics::default()));
+            let latency = Arc::new(LatencyTracker::new());
+            let metrics = Arc::new(Mutex::new(TaskMetrics::with_latency_tracker(
+                latency.clone(),
+            )));
 
             let account = accounts_by_id
                 .get(&task_config.account_id)
@@ -401,10 +891,35 @@ impl TaskManager {
                 .get(&task_config.account_id)
                 .ok_or_else(|| anyhow!("account auth not found for task_id={}", task_config.id))?;
 
-            let client = build_client(&task_config, account, account_auth)
+            let mut client = build_client(&task_config, account, account_auth)
+                .with_context(|| format!("build StandxClient for task_id={}", task_config.id))?;
+            client.self_test_signature().map_err(|err| {
+                anyhow!(
+                    "signing key self-test failed for task_id={}: {err}",
+                    task_config.id
+                )
+            })?;
+            let mut flatten_client = build_client(&task_config, account, account_auth)
                 .with_context(|| format!("build StandxClient for task_id={}", task_config.id))?;
+            if let Some(limiter) = order_limiters.get(&task_config.account_id) {
+                client.set_order_limiter(limiter.clone());
+                flatten_client.set_order_limiter(limiter.clone());
+            }
+            if let Some(limiter) = rate_limiters.get(&task_config.account_id) {
+                client.set_rate_limiter(limiter.clone());
+                flatten_client.set_rate_limiter(limiter.clone());
+            }
+            client.set_latency_tracker(latency.clone());
+            flatten_client.set_latency_tracker(latency);
 
             let price_rx = self.subscribe_price(&task_config.symbol).await;
+            let position_feed = self
+                .subscribe_positions(
+                    &task_config.account_id,
+                    &account_auth.jwt_token,
+                    client.environment(),
+                )
+                .await;
             let shutdown = self.shutdown.child_token();
             let task_id = task_config.id.clone();
 
@@ -416,21 +931,91 @@ impl TaskManager {
                 shutdown.clone(),
                 self.symbol_cache.clone(),
                 metrics.clone(),
-            );
+            )
+            .with_events(self.events.clone())
+            .with_position_feed(position_feed);
+            let (paused, paused_rx) = watch::channel(false);
+            let task = task
+                .with_pause(paused_rx)
+                .with_startup_limiter(startup_limiter.clone());
             let task_config = task.config.clone();
+            let task_uuid = task.id;
+            let event_metrics = AbortOnDrop(spawn_event_metrics(
+                self.events.subscribe(),
+                task_id.clone(),
+                metrics.clone(),
+            ));
             let handle = task.spawn();
-            self.tasks
-                .insert(task_id.clone(), ManagedTask { shutdown, handle });
+            self.tasks.insert(
+                task_id.clone(),
+                ManagedTask {
+                    shutdown,
+                    handle,
+                    task_uuid,
+                    symbol: task_config.symbol.clone(),
+                    flatten_client: Arc::new(flatten_client),
+                    paused,
+                    _jwt_refresher: account_auth.jwt_refresher.clone(),
+                    _event_metrics: event_metrics,
+                },
+            );
             self.task_configs
                 .insert(task_id.clone(), task_config.clone());
             self.task_metrics.insert(task_id.clone(), metrics);
+            spawned.push(task_id);
         }
 
-        Ok(())
+        Ok(self.await_startups(&mut startup_events, spawned).await)
+    }
+
+    /// Collect each task's startup outcome from the event bus.
+    ///
+    /// Tasks that exit without a matching event (e.g. lost to subscriber
+    /// lag) are caught by polling their handles.
+    async fn await_startups(
+        &self,
+        events: &mut EventSubscription,
+        mut pending: Vec<String>,
+    ) -> SpawnReport {
+        let mut report = SpawnReport::default();
+        let mut poll = tokio::time::interval(STARTUP_POLL_INTERVAL);
+        while !pending.is_empty() {
+            tokio::select! {
+                event = events.recv() => match event {
+                    Some(event) => settle_startup(event, &mut pending, &mut report),
+                    None => break,
+                },
+                _ = poll.tick() => {
+                    // Prefer the real error if its event is already buffered.
+                    while let Some(event) = events.try_recv() {
+                        settle_startup(event, &mut pending, &mut report);
+                    }
+                    let exited: Vec<String> = pending
+                        .iter()
+                        .filter(|id| self.tasks.get(*id).is_none_or(|task| task.handle.is_finished()))
+                        .cloned()
+                        .collect();
+                    for task_id in exited {
+                        pending.retain(|id| *id != task_id);
+                        report
+                            .failed
+                            .push((task_id, "task exited during startup".to_string()));
+                    }
+                }
+            }
+        }
+        report
     }
 
     pub async fn stop_task(&mut self, task_id: &str) -> Result<()> {
+        let supervised = self.restarts.remove(task_id).is_some();
         let Some(task) = self.tasks.remove(task_id) else {
+            if supervised {
+                // Already reaped by the supervisor; just forget it.
+                self.task_configs.remove(task_id);
+                self.task_metrics.remove(task_id);
+                return Ok(());
+            }
             return Err(anyhow!("task_id not found: {task_id}"));
         };
 
@@ -463,6 +1048,38 @@ impl TaskManager {
         }
     }
 
+    /// Cancel the task's resting quotes and stop quoting, keeping the task,
+    /// its order stream, and its position guard alive.
+    pub fn pause_task(&self, task_id: &str) -> Result<()> {
+        self.set_paused(task_id, true)
+    }
+
+    /// Restart quoting on a task paused with [`pause_task`](Self::pause_task).
+    pub fn resume_task(&self, task_id: &str) -> Result<()> {
+        self.set_paused(task_id, false)
+    }
+
+    fn set_paused(&self, task_id: &str, paused: bool) -> Result<()> {
+        let task = self
+            .tasks
+            .get(task_id)
+            .ok_or_else(|| anyhow!("task_id not found: {task_id}"))?;
+        if task.handle.is_finished() {
+            return Err(anyhow!("task already finished: {task_id}"));
+        }
+        let was_paused = task.paused.send_replace(paused);
+        if was_paused == paused {
+            return Ok(());
+        }
+
+        tracing::info!(task_id, paused, "task quoting toggled");
+        self.events.emit(RuntimeEvent::StateChanged {
+            task_id: task_id.to_string(),
+            state: if paused { "Paused" } else { "Running" }.to_string(),
+        });
+        Ok(())
+    }
+
     /// Request graceful shutdown and wait for all tasks to exit.
     ///
     /// Guarantees a bounded shutdown time (30s) and aborts remaining tasks on timeout.
@@ -471,9 +1088,250 @@ impl TaskManager {
         let result = self.join_all_with_deadline(SHUTDOWN_TIMEOUT).await;
         self.task_configs.clear();
         self.task_metrics.clear();
+        self.restarts.clear();
+        result
+    }
+
+    /// Gracefully stop every task within `timeout`.
+    ///
+    /// Quoting is paused first so no new orders race the cancel sweep, then
+    /// each task runs its own shutdown sequence (cancel orders, close
+    /// positions) concurrently. `on_progress` receives the number of tasks
+    /// still running, once up front and again as each one exits. Whatever is
+    /// left at the deadline is aborted. Unlike
+    /// [`shutdown_and_wait`](Self::shutdown_and_wait), one failing task does
+    /// not cut the others short; the first error is returned after all exit.
+    pub async fn drain(
+        &mut self,
+        timeout: Duration,
+        mut on_progress: impl FnMut(usize),
+    ) -> Result<()> {
+        let deadline = Instant::now() + timeout;
+        for task in self.tasks.values() {
+            task.paused.send_replace(true);
+        }
+        self.shutdown.cancel();
+
+        let mut running = JoinSet::new();
+        let mut aborts = Vec::with_capacity(self.tasks.len());
+        for (task_id, task) in std::mem::take(&mut self.tasks) {
+            aborts.push(task.handle.abort_handle());
+            running.spawn(async move { (task_id, task.handle.await) });
+        }
+        on_progress(running.len());
+
+        let mut first_error = None;
+        let sleep = sleep_until_deadline(deadline);
+        tokio::pin!(sleep);
+        let result = loop {
+            tokio::select! {
+                joined = running.join_next() => {
+                    let Some(joined) = joined else {
+                        break first_error.map_or(Ok(()), Err);
+                    };
+                    let Ok((task_id, res)) = joined else {
+                        continue;
+                    };
+                    let err = match res {
+                        Ok(Ok(())) => None,
+                        Ok(Err(err)) => Some(err.context(format!("task returned error task_id={task_id}"))),
+                        Err(join_err) if join_err.is_panic() => {
+                            Some(anyhow!("task panicked task_id={task_id}: {join_err}"))
+                        }
+                        Err(join_err) => Some(anyhow!("task join error task_id={task_id}: {join_err}")),
+                    };
+                    if let Some(err) = err {
+                        tracing::warn!(task_id = %task_id, "task failed while draining: {err:#}");
+                        first_error.get_or_insert(err);
+                    }
+                    on_progress(running.len());
+                }
+                _ = &mut sleep => {
+                    let remaining = running.len();
+                    for abort in &aborts {
+                        abort.abort();
+                    }
+                    break Err(anyhow!(
+                        "drain timed out after {timeout:?}; aborted {remaining} task(s)"
+                    ));
+                }
+            }
+        };
+        self.task_configs.clear();
+        self.task_metrics.clear();
+        self.restarts.clear();
         result
     }
 
+    /// One supervisor pass: reap failed tasks and respawn those whose
+    /// backoff has elapsed. No-op unless built
+    /// [`with_auto_restart`](Self::with_auto_restart).
+    ///
+    /// A task that stops on a min-equity breach is marked failed without
+    /// restarting; one that exits cleanly is left `Finished`.
+    pub async fn supervise_restarts(&mut self) {
+        let Some(policy) = self.restart_policy else {
+            return;
+        };
+        if self.shutdown.is_cancelled() {
+            return;
+        }
+
+        let finished: Vec<String> = self
+            .tasks
+            .iter()
+            .filter(|(_, task)| task.handle.is_finished())
+            .map(|(task_id, _)| task_id.clone())
+            .collect();
+        for task_id in finished {
+            let Some(task) = self.tasks.remove(&task_id) else {
+                continue;
+            };
+            let error = match task.handle.await {
+                Ok(Ok(())) => None,
+                Ok(Err(err)) => Some(err),
+                Err(join_err) => Some(anyhow!("task join error: {join_err}")),
+            };
+            match error {
+                Some(err) => {
+                    let retryable = err.downcast_ref::<MinEquityBreached>().is_none();
+                    self.record_task_failure(&task_id, format!("{err:#}"), retryable, policy);
+                }
+                None => {
+                    self.restarts.entry(task_id).or_default().retry_at = None;
+                }
+            }
+        }
+
+        let now = Instant::now();
+        let due: Vec<String> = self
+            .restarts
+            .iter()
+            .filter(|(_, state)| state.retry_at.is_some_and(|at| now >= at))
+            .map(|(task_id, _)| task_id.clone())
+            .collect();
+        for task_id in due {
+            self.restart_task(&task_id, policy).await;
+        }
+    }
+
+    fn record_task_failure(
+        &mut self,
+        task_id: &str,
+        error: String,
+        retryable: bool,
+        policy: RestartPolicy,
+    ) {
+        let state = self.restarts.entry(task_id.to_string()).or_default();
+        state.info.last_error = Some(error);
+        if retryable && state.info.restarts < policy.max_attempts {
+            let backoff = policy.backoff(state.info.restarts);
+            state.retry_at = Some(Instant::now() + backoff);
+            tracing::warn!(
+                task_id,
+                restarts = state.info.restarts,
+                backoff_ms = backoff.as_millis() as u64,
+                error = state.info.last_error.as_deref().unwrap_or_default(),
+                "task failed; restart scheduled"
+            );
+            return;
+        }
+
+        state.retry_at = None;
+        state.failed = true;
+        tracing::error!(
+            task_id,
+            restarts = state.info.restarts,
+            error = state.info.last_error.as_deref().unwrap_or_default(),
+            "task failed permanently; giving up on restarts"
+        );
+        self.events.emit(RuntimeEvent::StateChanged {
+            task_id: task_id.to_string(),
+            state: "Failed".to_string(),
+        });
+    }
+
+    async fn restart_task(&mut self, task_id: &str, policy: RestartPolicy) {
+        let Some(state) = self.restarts.get_mut(task_id) else {
+            return;
+        };
+        state.retry_at = None;
+        state.info.restarts += 1;
+        let attempt = state.info.restarts;
+
+        let result = match self.task_configs.get(task_id).cloned() {
+            Some(task_config) => match self.accounts.get(&task_config.account_id).cloned() {
+                Some(account) => {
+                    self.spawn_from_config(StrategyConfig {
+                        accounts: vec![account],
+                        tasks: vec![task_config],
+                        max_total_budget_usd: None,
+                        allow_duplicate_symbols: true,
+                        max_concurrent_startups: None,
+                        environment: Some(self.environment.clone()),
+                    })
+                    .await
+                }
+                None => Err(anyhow!("account_id not found for task_id={task_id}")),
+            },
+            None => Err(anyhow!("task config not found for task_id={task_id}")),
+        };
+
+        match result {
+            Ok(report) if report.all_started() => {
+                tracing::info!(task_id, attempt, "task restarted")
+            }
+            // The exited task is reaped and counted on the next supervisor pass.
+            Ok(_) => tracing::warn!(task_id, attempt, "restarted task failed startup"),
+            Err(err) => self.record_task_failure(task_id, format!("{err:#}"), true, policy),
+        }
+    }
+
+    /// Kill switch: flatten every task's symbol, then shut all tasks down.
+    ///
+    /// Open orders are bulk-cancelled and non-zero positions closed with
+    /// reduce-only market orders on every task concurrently, before the task
+    /// loops are cancelled, so a wedged loop cannot delay the flatten. The
+    /// regular shutdown still runs afterwards and repeats its own cleanup.
+    pub async fn emergency_flatten(&mut self) -> Result<()> {
+        tracing::warn!(tasks = self.tasks.len(), "emergency flatten requested");
+
+        let mut flattens = tokio::task::JoinSet::new();
+        for (task_id, task) in &self.tasks {
+            let client = task.flatten_client.clone();
+            let task_uuid = task.task_uuid;
+            let task_id = task_id.clone();
+            let symbol = task.symbol.clone();
+            flattens.spawn(async move {
+                let result = Task::flatten_symbol(&client, task_uuid, &task_id, &symbol).await;
+                (task_id, result)
+            });
+        }
+
+        let mut first_error: Option<anyhow::Error> = None;
+        while let Some(joined) = flattens.join_next().await {
+            let (task_id, result) = match joined {
+                Ok(outcome) => outcome,
+                Err(join_err) => {
+                    first_error.get_or_insert_with(|| anyhow!("flatten join error: {join_err}"));
+                    continue;
+                }
+            };
+            if let Err(err) = result {
+                tracing::error!(task_id = %task_id, "emergency flatten failed: {err:#}");
+                first_error.get_or_insert_with(|| {
+                    err.context(format!("emergency flatten failed task_id={task_id}"))
+                });
+            }
+        }
+
+        let shutdown = self.shutdown_and_wait().await;
+        match first_error {
+            Some(err) => Err(err),
+            None => shutdown,
+        }
+    }
+
     async fn join_all_with_deadline(&mut self, timeout: Duration) -> Result<()> {
         let deadline = Instant::now() + timeout;
 
@@ -531,11 +1389,28 @@ impl TaskManager {
         }
     }
 
-    async fn load_symbol_cache_from_disk(&self) {
-        if let Some(cache) = load_symbol_cache().await {
-            let mut guard = self.symbol_cache.lock().await;
+    /// Shared position stream for `account_id`, or `None` without a jwt.
+    async fn subscribe_positions(
+        &self,
+        account_id: &str,
+        account_jwt: &str,
+        environment: &StandxEnvironment,
+    ) -> Option<broadcast::Receiver<PositionFeedEvent>> {
+        if account_jwt.trim().is_empty() {
+            return None;
+        }
+        let mut hub = self.market_data_hub.lock().await;
+        Some(hub.subscribe_positions(account_id, account_jwt, environment))
+    }
+
+    async fn load_symbol_cache_from_disk(&self) {
+        let path = self.symbol_cache_dir.join(SYMBOL_CACHE_FILE);
+        let loaded = load_symbol_cache(&path).await;
+        let mut guard = self.symbol_cache.lock().await;
+        if let Some(cache) = loaded {
             *guard = cache;
         }
+        guard.path = Some(path);
     }
 }
 
@@ -545,6 +1420,23 @@ impl Default for TaskManager {
     }
 }
 
+/// Run [`TaskManager::supervise_restarts`] every second until `shutdown` fires.
+pub fn spawn_restart_supervisor(
+    manager: Arc<Mutex<TaskManager>>,
+    shutdown: CancellationToken,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(RESTART_SUPERVISOR_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => return,
+                _ = interval.tick() => manager.lock().await.supervise_restarts().await,
+            }
+        }
+    })
+}
+
 /// Individual trading task.
 #[derive(Debug)]
 pub struct Task {
@@ -553,10 +1445,16 @@ pub struct Task {
     client: StandxClient,
     account_jwt: String,
     price_rx: watch::Receiver<SymbolPrice>,
+    /// Account position stream shared through the market data hub.
+    position_feed: Option<broadcast::Receiver<PositionFeedEvent>>,
     state: TaskState,
     shutdown: CancellationToken,
     symbol_cache: std::sync::Arc<Mutex<SymbolCache>>,
     metrics: Arc<Mutex<TaskMetrics>>,
+    events: EventBus,
+    paused_rx: watch::Receiver<bool>,
+    /// Shared slots bounding how many tasks run `startup_sequence` at once.
+    startup_limiter: Option<Arc<Semaphore>>,
 }
 
 impl Task {
@@ -575,10 +1473,14 @@ impl Task {
             client,
             account_jwt: String::new(),
             price_rx: rx,
+            position_feed: None,
             state: TaskState::Init,
             shutdown: CancellationToken::new(),
             symbol_cache: std::sync::Arc::new(Mutex::new(SymbolCache::default())),
             metrics,
+            events: EventBus::default(),
+            paused_rx: watch::channel(false).1,
+            startup_limiter: None,
         }
     }
 
@@ -591,7 +1493,18 @@ impl Task {
     }
 
     pub fn spawn(self) -> JoinHandle<Result<()>> {
-        tokio::spawn(async move { self.run().await })
+        let events = self.events.clone();
+        let task_id = self.config.id.clone();
+        tokio::spawn(async move {
+            let result = self.run().await;
+            if let Err(err) = &result {
+                events.emit(RuntimeEvent::TaskFailed {
+                    task_id,
+                    error: format!("{err:#}"),
+                });
+            }
+            result
+        })
     }
 
     fn new_with_client(
@@ -609,13 +1522,48 @@ impl Task {
             client,
             account_jwt,
             price_rx,
+            position_feed: None,
             state: TaskState::Init,
             shutdown,
             symbol_cache,
             metrics,
+            events: EventBus::default(),
+            paused_rx: watch::channel(false).1,
+            startup_limiter: None,
         }
     }
 
+    fn with_events(mut self, events: EventBus) -> Self {
+        self.events = events;
+        self
+    }
+
+    fn with_position_feed(
+        mut self,
+        position_feed: Option<broadcast::Receiver<PositionFeedEvent>>,
+    ) -> Self {
+        self.position_feed = position_feed;
+        self
+    }
+
+    fn with_pause(mut self, paused_rx: watch::Receiver<bool>) -> Self {
+        self.paused_rx = paused_rx;
+        self
+    }
+
+    fn with_startup_limiter(mut self, startup_limiter: Option<Arc<Semaphore>>) -> Self {
+        self.startup_limiter = startup_limiter;
+        self
+    }
+
+    /// Publish the current `state` as a `StateChanged` event.
+    fn emit_state(&self) {
+        self.events.emit(RuntimeEvent::StateChanged {
+            task_id: self.config.id.clone(),
+            state: format!("{:?}", self.state),
+        });
+    }
+
     #[allow(dead_code)]
     pub(crate) fn try_from_config(
         config: TaskConfig,
@@ -637,20 +1585,18 @@ impl Task {
     }
 
     pub(crate) fn build_client(
-        config: &TaskConfig,
+        _config: &TaskConfig,
         _account: &AccountConfig,
         account_auth: &AccountAuth,
     ) -> Result<StandxClient> {
-        Self::build_client_with_config_and_base_urls(
-            config,
-            _account,
-            account_auth,
-            ClientConfig::default(),
-            "https://api.standx.com",
-            "https://perps.standx.com",
-        )
+        let mut client =
+            StandxClient::with_environment(ClientConfig::default(), &account_auth.environment)
+                .map_err(|err| anyhow!("create StandxClient failed: {err}"))?;
+        Self::set_account_auth(&mut client, account_auth);
+        Ok(client)
     }
 
+    #[cfg(test)]
     pub(crate) fn build_client_with_config_and_base_urls(
         _config: &TaskConfig,
         _account: &AccountConfig,
@@ -662,7 +1608,11 @@ impl Task {
         let mut client =
             StandxClient::with_config_and_base_urls(client_config, auth_base_url, trading_base_url)
                 .map_err(|err| anyhow!("create StandxClient failed: {err}"))?;
+        Self::set_account_auth(&mut client, account_auth);
+        Ok(client)
+    }
 
+    fn set_account_auth(client: &mut StandxClient, account_auth: &AccountAuth) {
         client.set_credentials_and_signer(
             Credentials {
                 jwt_token: account_auth.jwt_token.clone(),
@@ -671,12 +1621,14 @@ impl Task {
             },
             Ed25519Signer::from_secret_key(&account_auth.signing_key),
         );
-
-        Ok(client)
+        if let Some(jwt_source) = &account_auth.jwt_source {
+            client.set_jwt_source(jwt_source.clone());
+        }
     }
 
     async fn run(mut self) -> Result<()> {
         self.state = TaskState::Starting;
+        self.emit_state();
         tracing::info!(
             task_uuid = %self.id,
             task_id = %self.config.id,
@@ -684,10 +1636,26 @@ impl Task {
             "task starting"
         );
 
-        let snapshot = match self.startup_sequence().await {
+        let startup_permit = match self.startup_limiter.clone() {
+            Some(limiter) => tokio::select! {
+                permit = limiter.acquire_owned() => Some(permit.context("startup limiter closed")?),
+                _ = self.shutdown.cancelled() => {
+                    // Stopped while queued; nothing was touched yet.
+                    self.state = TaskState::Stopped;
+                    self.emit_state();
+                    return Ok(());
+                }
+            },
+            None => None,
+        };
+        let startup = self.startup_sequence().await;
+        drop(startup_permit);
+
+        let snapshot = match startup {
             Ok(snapshot) => snapshot,
             Err(err) => {
                 self.state = TaskState::Failed;
+                self.emit_state();
                 tracing::error!(
                     task_uuid = %self.id,
                     task_id = %self.config.id,
@@ -711,7 +1679,25 @@ impl Task {
             parse_optional_bps(&self.config.risk.tp_bps, "risk.tp_bps", &self.config.id)?;
         let user_sl_bps =
             parse_optional_bps(&self.config.risk.sl_bps, "risk.sl_bps", &self.config.id)?;
+        let min_equity_usd = parse_optional_bps(
+            &self.config.risk.min_equity_usd,
+            "risk.min_equity_usd",
+            &self.config.id,
+        )?;
+        let daily_loss_limit_usd = parse_optional_bps(
+            &self.config.risk.daily_loss_limit_usd,
+            "risk.daily_loss_limit_usd",
+            &self.config.id,
+        )?;
+        let replace_order = match self.config.risk.replace_order.as_deref() {
+            Some(raw) => raw
+                .parse::<ReplaceOrder>()
+                .map_err(|_| anyhow!("invalid risk.replace_order: {raw}"))?,
+            None => ReplaceOrder::default(),
+        };
         let guard_close_enabled = self.config.risk.guard_close_enabled.unwrap_or(false);
+        let guard_flatten_after_failures =
+            self.config.risk.guard_flatten_after_failures.unwrap_or(0);
         let tier_count = MarketMakingStrategy::tier_count_for_risk(risk_level);
         let initial_position_qty = snapshot
             .positions
@@ -726,6 +1712,7 @@ impl Task {
         let order_tracker_ws = order_tracker.clone();
         let order_tracker_reconcile = order_tracker.clone();
         let (reconcile_tx, reconcile_rx) = mpsc::unbounded_channel();
+        let gap_reconcile_tx = reconcile_tx.clone();
         let mode = StrategyMode::aggressive_for_risk(risk_level);
         let (default_tp_bps, default_sl_bps) =
             default_tp_sl_bps_for_risk(risk_level, snapshot.symbol_info.as_ref());
@@ -747,6 +1734,50 @@ impl Task {
             initial_position_qty,
         );
         strategy.set_metrics(self.metrics.clone());
+        strategy.set_replace_order(replace_order);
+        strategy.set_entry_price(average_entry_price(&snapshot.positions));
+        if let Some(limit) = daily_loss_limit_usd {
+            strategy.set_daily_loss_limit(limit);
+        }
+        if let Some(min_uptime) = self.config.risk.min_uptime().with_context(|| {
+            format!(
+                "invalid risk.min_uptime_ratio for task_id={}",
+                self.config.id
+            )
+        })? {
+            strategy.set_min_uptime(min_uptime);
+        }
+        if let Some(max_age) = self.config.risk.max_price_age() {
+            strategy.set_max_price_age(max_age);
+        }
+        if let Some(sweep) = &self.config.risk.taker_sweep {
+            strategy.set_taker_sweep(sweep.sweep().with_context(|| {
+                format!("invalid risk.taker_sweep for task_id={}", self.config.id)
+            })?);
+        }
+        if let Some(quoting) = &self.config.quoting {
+            strategy.set_quoting(
+                quoting
+                    .bands()
+                    .with_context(|| format!("invalid quoting for task_id={}", self.config.id))?,
+            );
+        }
+        strategy.set_events(self.events.clone(), self.config.id.clone());
+        strategy.set_pause(self.paused_rx.clone());
+        let (funding_rate_tx, funding_rate_rx) = watch::channel(None);
+        strategy.set_funding_rate(funding_rate_rx);
+        let book_mid_tx = strategy.uses_book_mid().then(|| {
+            let (book_mid_tx, book_mid_rx) = watch::channel(None);
+            strategy.set_book_mid(book_mid_rx);
+            book_mid_tx
+        });
+        let (available_tx, available_rx) = watch::channel(snapshot.cross_available);
+        let margin_leverage = match (self.config.leverage, snapshot.symbol_info.as_ref()) {
+            (Some(leverage), _) => Decimal::from(leverage),
+            (None, Some(info)) => info.def_leverage,
+            (None, None) => Decimal::ONE,
+        };
+        strategy.set_available_balance(available_rx, margin_leverage);
 
         if let Some(info) = snapshot.symbol_info.as_ref() {
             strategy.set_symbol_constraints(
@@ -755,6 +1786,8 @@ impl Task {
                 Some(info.min_order_qty),
                 Some(info.max_order_qty),
             );
+            strategy.set_position_limits(Some(info.max_position_size), Some(info.max_open_orders));
+            strategy.set_taker_fee(info.taker_fee);
             tracing::info!(
                 task_uuid = %self.id,
                 task_id = %self.config.id,
@@ -762,6 +1795,8 @@ impl Task {
                 qty_tick_decimals = info.qty_tick_decimals,
                 min_order_qty = %info.min_order_qty,
                 max_order_qty = %info.max_order_qty,
+                max_position_size = %info.max_position_size,
+                max_open_orders = %info.max_open_orders,
                 "symbol constraints loaded"
             );
         } else {
@@ -774,6 +1809,8 @@ impl Task {
         }
 
         self.state = TaskState::Running;
+
+        self.emit_state();
         tracing::info!(
             task_uuid = %self.id,
             task_id = %self.config.id,
@@ -784,6 +1821,7 @@ impl Task {
         let guard_shutdown = self.shutdown.child_token();
         let order_shutdown = self.shutdown.child_token();
         let reconcile_shutdown = self.shutdown.child_token();
+        let position_feed = self.position_feed.take();
         let client = &self.client;
         let id = self.id;
         let task_id = &self.config.id;
@@ -797,7 +1835,7 @@ impl Task {
             client,
             id,
             task_id,
-            account_jwt,
+            position_feed,
             symbol,
             price_rx,
             symbol_cache,
@@ -805,15 +1843,20 @@ impl Task {
             self.metrics.clone(),
             position_tx,
             guard_close_enabled,
+            guard_flatten_after_failures,
+            self.events.clone(),
             guard_shutdown.clone(),
         ));
         let order_future = Self::order_ws_loop(
+            client,
             id,
             task_id,
             account_jwt,
             symbol,
             order_tracker_ws,
+            gap_reconcile_tx,
             self.metrics.clone(),
+            self.events.clone(),
             order_shutdown.clone(),
         );
         let reconcile_future = Self::order_reconcile_loop(
@@ -826,7 +1869,70 @@ impl Task {
             self.metrics.clone(),
             reconcile_shutdown.clone(),
         );
-        let strategy_future = strategy.run(&self.client, self.shutdown.clone());
+        let strategy_shutdown = self.shutdown.child_token();
+        let min_equity_future = Self::min_equity_loop(
+            client,
+            id,
+            task_id,
+            min_equity_usd,
+            MIN_EQUITY_CHECK_INTERVAL,
+            self.metrics.clone(),
+        );
+        let funding_future = Self::funding_rate_loop(
+            client,
+            id,
+            task_id,
+            symbol,
+            FUNDING_RATE_POLL_INTERVAL,
+            funding_rate_tx,
+            self.metrics.clone(),
+        );
+        let available_future = Self::available_balance_loop(
+            client,
+            id,
+            task_id,
+            AVAILABLE_BALANCE_POLL_INTERVAL,
+            available_tx,
+        );
+        let book_mid_future = async {
+            match book_mid_tx {
+                Some(book_mid_tx) => {
+                    Self::book_mid_loop(
+                        client,
+                        id,
+                        task_id,
+                        symbol,
+                        ORDERBOOK_POLL_INTERVAL,
+                        book_mid_tx,
+                    )
+                    .await
+                }
+                None => pending().await,
+            }
+        };
+        let strategy_future = async {
+            let run = strategy.run(client, strategy_shutdown.clone());
+            tokio::pin!(run);
+            tokio::select! {
+                res = &mut run => res,
+                never = funding_future => match never {},
+                never = available_future => match never {},
+                never = book_mid_future => match never {},
+                breach = min_equity_future => {
+                    // Let the strategy pull its quotes before the shutdown
+                    // sequence cancels leftovers and flattens the position.
+                    strategy_shutdown.cancel();
+                    if let Err(err) = run.await {
+                        tracing::warn!(
+                            task_uuid = %id,
+                            task_id = %task_id,
+                            "strategy exit after min equity breach failed: {err}"
+                        );
+                    }
+                    Err(anyhow::Error::new(breach))
+                }
+            }
+        };
         tokio::pin!(position_future);
         tokio::pin!(order_future);
         tokio::pin!(reconcile_future);
@@ -964,6 +2070,8 @@ impl Task {
         }
 
         self.state = TaskState::Stopping;
+
+        self.emit_state();
         tracing::info!(
             task_uuid = %self.id,
             task_id = %self.config.id,
@@ -972,11 +2080,18 @@ impl Task {
         );
 
         let shutdown_res = self.shutdown_sequence().await;
-        self.state = if strategy_result.is_ok() && shutdown_res.is_ok() {
+        let equity_breached = strategy_result
+            .as_ref()
+            .err()
+            .is_some_and(|err| err.downcast_ref::<MinEquityBreached>().is_some());
+        self.state = if equity_breached {
+            TaskState::MinEquityBreached
+        } else if strategy_result.is_ok() && shutdown_res.is_ok() {
             TaskState::Stopped
         } else {
             TaskState::Failed
         };
+        self.emit_state();
 
         match (strategy_result, shutdown_res) {
             (Ok(()), Ok(())) => Ok(()),
@@ -994,9 +2109,64 @@ impl Task {
         let orders = self.query_all_open_orders().await?;
         self.log_open_orders(&orders);
         self.cancel_orders(&orders).await?;
+        self.apply_margin_settings(snapshot.symbol_info.as_ref())
+            .await?;
         Ok(snapshot)
     }
 
+    /// Apply the task's margin mode, then leverage, before quoting starts.
+    async fn apply_margin_settings(&self, symbol_info: Option<&SymbolInfo>) -> Result<()> {
+        let symbol = self.config.symbol.as_str();
+        // Reject a bad leverage before changing anything on the account.
+        if let Some(leverage) = self.config.leverage {
+            match symbol_info {
+                Some(info) => check_leverage(leverage, info)?,
+                None => tracing::warn!(
+                    task_uuid = %self.id,
+                    task_id = %self.config.id,
+                    symbol = %symbol,
+                    leverage,
+                    "symbol info unavailable; setting leverage without max_leverage check"
+                ),
+            }
+        }
+        if let Some(margin_mode) = self.config.margin_mode {
+            let response = self
+                .client
+                .set_margin_mode(symbol, margin_mode)
+                .await
+                .with_context(|| format!("set_margin_mode failed symbol={symbol}"))?;
+            tracing::info!(
+                task_uuid = %self.id,
+                task_id = %self.config.id,
+                symbol = %symbol,
+                margin_mode = ?margin_mode,
+                request_id = %response.request_id,
+                "margin mode set"
+            );
+            self.metrics.lock().await.record_margin_mode(margin_mode);
+        }
+
+        let Some(leverage) = self.config.leverage else {
+            return Ok(());
+        };
+        let response = self
+            .client
+            .set_leverage(symbol, leverage)
+            .await
+            .with_context(|| format!("set_leverage failed symbol={symbol}"))?;
+        tracing::info!(
+            task_uuid = %self.id,
+            task_id = %self.config.id,
+            symbol = %symbol,
+            leverage,
+            request_id = %response.request_id,
+            "leverage set"
+        );
+        self.metrics.lock().await.record_leverage(leverage);
+        Ok(())
+    }
+
     async fn shutdown_sequence(&self) -> Result<()> {
         // Shutdown sequence: cancel open orders -> close positions.
         // This is best-effort and should remain minimal.
@@ -1006,79 +2176,31 @@ impl Task {
     }
 
     async fn cancel_open_orders(&self) -> Result<()> {
+        // Cancel everything resting on the symbol, not just what the local
+        // tracker knows about, in one bulk request.
+        let symbol = self.config.symbol.as_str();
+        let err = match self.client.cancel_all_orders(Some(symbol)).await {
+            Ok(_) => return Ok(()),
+            Err(err) => err,
+        };
+        tracing::warn!(
+            task_uuid = %self.id,
+            task_id = %self.config.id,
+            symbol = %symbol,
+            "cancel_all_orders failed; falling back to per-order cancel: {err}"
+        );
         let orders = self.query_all_open_orders().await?;
         self.cancel_orders(&orders).await
     }
 
     async fn query_all_open_orders(&self) -> Result<PaginatedOrders> {
-        let symbol = self.config.symbol.as_str();
-        let open_orders = match self.query_open_orders().await {
-            Ok(orders) => orders,
-            Err(err) => {
-                tracing::warn!(
-                    task_uuid = %self.id,
-                    task_id = %self.config.id,
-                    symbol = %symbol,
-                    "query_open_orders failed; falling back to query_orders: {err}"
-                );
-
-                let fallback = self
-                    .client
-                    .query_orders(Some(symbol), Some(OrderStatus::Open), None)
-                    .await;
-
-                return match fallback {
-                    Ok(orders) => Ok(orders),
-                    Err(fallback_err) => Err(anyhow!(err)).context(format!(
-                        "query_open_orders failed; query_orders fallback failed: {fallback_err}"
-                    )),
-                };
-            }
-        };
-
-        if open_orders.total > open_orders.result.len() as u32 {
-            let limit = open_orders.total;
-            match self
-                .client
-                .query_orders(Some(symbol), Some(OrderStatus::Open), Some(limit))
-                .await
-            {
-                Ok(expanded) => return Ok(expanded),
-                Err(err) => {
-                    tracing::warn!(
-                        task_uuid = %self.id,
-                        task_id = %self.config.id,
-                        symbol = %symbol,
-                        total = open_orders.total,
-                        page_size = open_orders.page_size,
-                        "query_orders failed while expanding open orders: {err}"
-                    );
-                }
-            }
-        }
-
-        Ok(open_orders)
-    }
-
-    async fn query_open_orders(&self) -> Result<PaginatedOrders> {
-        let symbol = self.config.symbol.as_str();
-        match self.client.query_open_orders(Some(symbol)).await {
-            Ok(orders) => Ok(orders),
-            Err(StandxError::Api { code: 404, message }) => {
-                tracing::warn!(
-                    task_uuid = %self.id,
-                    task_id = %self.config.id,
-                    symbol = %symbol,
-                    "query_open_orders returned 404; treating as no open orders: {message}"
-                );
-                Ok(PaginatedOrders {
-                    page_size: 0,
-                    result: Vec::new(),
-                    total: 0,
-                })
-            }
-            Err(err) => Err(anyhow!(err)).context("query_open_orders failed"),
-        }
+        Self::query_all_open_orders_for_reconcile(
+            &self.client,
+            self.id,
+            &self.config.id,
+            &self.config.symbol,
+        )
+        .await
     }
 
     async fn cancel_orders(&self, orders: &PaginatedOrders) -> Result<()> {
@@ -1118,13 +2240,14 @@ impl Task {
         let task_id = self.config.id.as_str();
         let symbol = self.config.symbol.as_str();
 
-        match self.client.query_balance().await {
+        let cross_available = match self.client.query_balance().await {
             Ok(balance) => {
                 self.log_balance(task_id, symbol, &balance);
+                Some(balance.cross_available)
             }
-            Err(StandxError::Api { code: 404, message }) => {
+            Err(err) if err.is_not_found() => {
                 return Err(anyhow!(
-                    "account balance not found; please activate/fund your StandX account: {message}"
+                    "account balance not found; please activate/fund your StandX account: {err}"
                 ));
             }
             Err(err) => {
@@ -1134,6 +2257,7 @@ impl Task {
                     symbol = %symbol,
                     "query_balance failed during startup snapshot: {err}"
                 );
+                None
             }
         };
 
@@ -1190,6 +2314,7 @@ impl Task {
         };
 
         Ok(StartupSnapshot {
+            cross_available,
             positions,
             symbol_info,
         })
@@ -1325,6 +2450,58 @@ impl Task {
         Ok(())
     }
 
+    /// Cancel all open orders and close every open position for `symbol`
+    /// using `client` alone, independent of any running task loop.
+    async fn flatten_symbol(
+        client: &StandxClient,
+        task_uuid: Uuid,
+        task_id: &str,
+        symbol: &str,
+    ) -> Result<()> {
+        let orders = match client.query_open_orders(Some(symbol)).await {
+            Ok(orders) => orders.result,
+            Err(err) if err.is_not_found() => Vec::new(),
+            Err(err) => return Err(anyhow!(err)).context("query_open_orders failed"),
+        };
+        if !orders.is_empty() {
+            let req = CancelOrdersRequest {
+                order_id_list: orders.iter().map(|order| order.id).collect(),
+                cl_ord_id_list: Vec::new(),
+            };
+            client
+                .cancel_orders(req)
+                .await
+                .map_err(|err| anyhow!(err))
+                .context("cancel_orders failed")?;
+        }
+
+        let positions = client
+            .query_positions(Some(symbol))
+            .await
+            .context("query_positions failed")?;
+        let mut first_error: Option<anyhow::Error> = None;
+        for position in positions.iter().filter(|position| !position.qty.is_zero()) {
+            tracing::warn!(
+                task_uuid = %task_uuid,
+                task_id = %task_id,
+                symbol = %position.symbol,
+                qty = %position.qty,
+                "emergency flatten closing position"
+            );
+            if let Err(err) =
+                Self::close_position_qty(client, task_uuid, task_id, &position.symbol, position.qty)
+                    .await
+            {
+                first_error.get_or_insert(err);
+            }
+        }
+
+        match first_error {
+            Some(err) => Err(err).context("one or more position closes failed"),
+            None => Ok(()),
+        }
+    }
+
     async fn close_position_qty(
         client: &StandxClient,
         task_uuid: Uuid,
@@ -1342,6 +2519,10 @@ impl Task {
             (Side::Buy, qty.abs())
         };
 
+        let side_label = match side {
+            Side::Buy => "buy",
+            Side::Sell => "sell",
+        };
         let req = NewOrderRequest {
             symbol: symbol.to_string(),
             side,
@@ -1350,16 +2531,17 @@ impl Task {
             time_in_force: TimeInForce::Ioc,
             reduce_only: true,
             price: None,
-            cl_ord_id: None,
+            cl_ord_id: Some(format!("close:{symbol}:{side_label}:{}", Uuid::new_v4())),
             margin_mode: None,
             leverage: None,
             tp_price: None,
             sl_price: None,
         };
 
-        match client.new_order(req).await {
-            Ok(resp) if resp.code == 0 => Ok(()),
-            Ok(resp) => {
+        match client.new_order_checked(req).await {
+            Ok(OrderSubmission::Accepted(resp)) if resp.code == 0 => Ok(()),
+            Ok(OrderSubmission::Landed(_)) => Ok(()),
+            Ok(OrderSubmission::Accepted(resp)) => {
                 let err = anyhow!(
                     "new_order returned code={} message={}",
                     resp.code,
@@ -1620,7 +2802,7 @@ impl Task {
         client: &StandxClient,
         task_uuid: Uuid,
         task_id: &str,
-        account_jwt: &str,
+        mut position_feed: Option<broadcast::Receiver<PositionFeedEvent>>,
         task_symbol: &str,
         mut price_rx: watch::Receiver<SymbolPrice>,
         symbol_cache: Arc<Mutex<SymbolCache>>,
@@ -1628,31 +2810,24 @@ impl Task {
         metrics: Arc<Mutex<TaskMetrics>>,
         position_tx: watch::Sender<Decimal>,
         guard_close_enabled: bool,
+        guard_flatten_after_failures: u32,
+        events: EventBus,
         shutdown: CancellationToken,
     ) -> Result<()> {
-        let (mut position_ws, mut ws_rx) = if account_jwt.trim().is_empty() {
+        // The shared feed reconnects on its own; polling keeps the guard
+        // working while it is down.
+        if position_feed.is_none() {
             tracing::warn!(
                 task_uuid = %task_uuid,
                 task_id = %task_id,
                 "position sync ws unavailable: missing account jwt; falling back to polling"
             );
-            (None, None)
-        } else {
-            match Self::connect_position_stream(account_jwt).await {
-                Ok((ws, rx)) => (Some(ws), Some(rx)),
-                Err(err) => {
-                    tracing::warn!(
-                        task_uuid = %task_uuid,
-                        task_id = %task_id,
-                        "position sync ws setup failed: {err}; falling back to polling"
-                    );
-                    (None, None)
-                }
-            }
-        };
+        }
 
         let mut guard_state = PositionGuardState {
             position_qty: *position_tx.borrow(),
+            flatten_after_failures: guard_flatten_after_failures,
+            events,
             ..Default::default()
         };
         let mut position_poll = tokio::time::interval_at(
@@ -1669,90 +2844,88 @@ impl Task {
                     }
                     return Ok(());
                 }
-                msg = Self::recv_position_ws_message(&mut ws_rx) => {
-                    let Some(message) = msg else {
-                        if position_ws.is_some() {
+                event = Self::recv_position_feed(&mut position_feed) => {
+                    let source = match event {
+                        Ok(PositionFeedEvent::Positions(data)) => {
+                            for update in parse_ws_positions(&data) {
+                                if update.symbol != task_symbol {
+                                    continue;
+                                }
+
+                                let mark_price = price_rx.borrow().mark_price;
+                                let symbol_info = {
+                                    let cache = symbol_cache.lock().await;
+                                    cache.symbols.get(task_symbol).cloned()
+                                };
+
+                                Self::apply_position_update(
+                                    client,
+                                    task_uuid,
+                                    task_id,
+                                    task_symbol,
+                                    update.qty,
+                                    mark_price,
+                                    symbol_info,
+                                    risk_level,
+                                    &metrics,
+                                    &position_tx,
+                                    guard_close_enabled,
+                                    PositionUpdateSource::Ws,
+                                    &mut guard_state,
+                                ).await;
+                            }
+                            continue;
+                        }
+                        Ok(PositionFeedEvent::Disconnected { reason }) => {
                             tracing::warn!(
                                 task_uuid = %task_uuid,
                                 task_id = %task_id,
-                                "position sync ws ended; continuing with polling"
+                                %reason,
+                                "position sync ws ended; polling until reconnected"
                             );
+                            continue;
                         }
-                        position_ws = None;
-                        ws_rx = None;
-                        continue;
-                    };
-
-                    let WebSocketMessage::Position { data } = message else {
-                        continue;
-                    };
-
-                    let updates = parse_ws_positions(&data);
-                    if updates.is_empty() {
-                        continue;
-                    }
-
-                    for update in updates {
-                        if update.symbol != task_symbol {
+                        // Updates were missed either way, so rebuild the
+                        // guard from REST before reading on.
+                        Ok(PositionFeedEvent::Reconnected) => PositionUpdateSource::Resync,
+                        Err(RecvError::Lagged(skipped)) => {
+                            tracing::warn!(
+                                task_uuid = %task_uuid,
+                                task_id = %task_id,
+                                skipped,
+                                "position sync lagged behind the shared feed; resyncing"
+                            );
+                            PositionUpdateSource::Resync
+                        }
+                        Err(RecvError::Closed) => {
+                            tracing::warn!(
+                                task_uuid = %task_uuid,
+                                task_id = %task_id,
+                                "position feed closed; falling back to polling"
+                            );
+                            position_feed = None;
                             continue;
                         }
+                    };
 
-                        let mark_price = price_rx.borrow().mark_price;
-                        let symbol_info = {
-                            let cache = symbol_cache.lock().await;
-                            cache.symbols.get(task_symbol).cloned()
-                        };
-
-                        Self::apply_position_update(
-                            client,
-                            task_uuid,
-                            task_id,
-                            task_symbol,
-                            update.qty,
-                            mark_price,
-                            symbol_info,
-                            risk_level,
-                            &metrics,
-                            &position_tx,
-                            guard_close_enabled,
-                            PositionUpdateSource::Ws,
-                            &mut guard_state,
-                        ).await;
-                    }
-                }
-                _ = position_poll.tick() => {
-                    let polled_qty = match client.query_positions(Some(task_symbol)).await {
-                        Ok(positions) => positions
-                            .into_iter()
-                            .filter(|position| position.symbol == task_symbol)
-                            .fold(Decimal::ZERO, |acc, position| acc + position.qty),
+                    let resynced_qty = match Self::query_position_qty(client, task_symbol).await {
+                        Ok(qty) => qty,
                         Err(err) => {
                             tracing::warn!(
                                 task_uuid = %task_uuid,
                                 task_id = %task_id,
                                 symbol = %task_symbol,
-                                "position sync poll query_positions failed: {err}"
+                                "position sync resync failed: {err}; polling will catch up"
                             );
                             continue;
                         }
                     };
-
-                    let needs_update = polled_qty != guard_state.position_qty
-                        || (guard_close_enabled
-                            && ((polled_qty.is_zero() && guard_state.guard_order.is_some())
-                                || (!polled_qty.is_zero() && guard_state.guard_order.is_none())));
-
-                    if !needs_update {
-                        continue;
-                    }
-
                     tracing::info!(
                         task_uuid = %task_uuid,
                         task_id = %task_id,
                         symbol = %task_symbol,
-                        polled_qty = %polled_qty,
-                        cached_qty = %guard_state.position_qty,
-                        "position sync poll detected mismatch"
+                        position_qty = %resynced_qty,
+                        "position sync resynced from REST"
                     );
 
                     let mark_price = price_rx.borrow().mark_price;
@@ -1766,7 +2939,61 @@ impl Task {
                         task_uuid,
                         task_id,
                         task_symbol,
-                        polled_qty,
+                        resynced_qty,
+                        mark_price,
+                        symbol_info,
+                        risk_level,
+                        &metrics,
+                        &position_tx,
+                        guard_close_enabled,
+                        source,
+                        &mut guard_state,
+                    ).await;
+                }
+                _ = position_poll.tick() => {
+                    let polled_qty = match Self::query_position_qty(client, task_symbol).await {
+                        Ok(qty) => qty,
+                        Err(err) => {
+                            tracing::warn!(
+                                task_uuid = %task_uuid,
+                                task_id = %task_id,
+                                symbol = %task_symbol,
+                                "position sync poll failed: {err}"
+                            );
+                            continue;
+                        }
+                    };
+
+                    let needs_update = polled_qty != guard_state.position_qty
+                        || (guard_close_enabled
+                            && ((polled_qty.is_zero() && guard_state.guard_order.is_some())
+                                || (!polled_qty.is_zero() && guard_state.guard_order.is_none())));
+
+                    if !needs_update {
+                        continue;
+                    }
+
+                    tracing::info!(
+                        task_uuid = %task_uuid,
+                        task_id = %task_id,
+                        symbol = %task_symbol,
+                        polled_qty = %polled_qty,
+                        cached_qty = %guard_state.position_qty,
+                        "position sync poll detected mismatch"
+                    );
+
+                    let mark_price = price_rx.borrow().mark_price;
+                    let symbol_info = {
+                        let cache = symbol_cache.lock().await;
+                        cache.symbols.get(task_symbol).cloned()
+                    };
+
+                    Self::apply_position_update(
+                        client,
+                        task_uuid,
+                        task_id,
+                        task_symbol,
+                        polled_qty,
                         mark_price,
                         symbol_info,
                         risk_level,
@@ -1810,7 +3037,7 @@ impl Task {
                         };
 
                         let qty = guard_state.position_qty.abs();
-                        if let Some(order) = Self::place_guard_order(
+                        Self::place_guard_or_flatten(
                             client,
                             task_uuid,
                             task_id,
@@ -1818,9 +3045,8 @@ impl Task {
                             side,
                             qty,
                             price,
-                        ).await {
-                            guard_state.guard_order = Some(order);
-                        }
+                            &mut guard_state,
+                        ).await;
                         continue;
                     }
 
@@ -1866,36 +3092,24 @@ impl Task {
         }
     }
 
-    async fn connect_position_stream(
-        account_jwt: &str,
-    ) -> Result<(StandxWebSocket, mpsc::Receiver<WebSocketMessage>)> {
-        let mut ws = StandxWebSocket::new();
-        ws.connect_market_stream()
-            .await
-            .map_err(|err| anyhow!("connect failed: {err}"))?;
-
-        let streams = ["position"];
-        ws.authenticate(account_jwt, Some(&streams))
-            .await
-            .map_err(|err| anyhow!("authenticate failed: {err}"))?;
-
-        ws.subscribe_positions()
+    /// Net REST position for the task symbol.
+    async fn query_position_qty(client: &StandxClient, task_symbol: &str) -> Result<Decimal> {
+        let positions = client
+            .query_positions(Some(task_symbol))
             .await
-            .map_err(|err| anyhow!("subscribe failed: {err}"))?;
-
-        let rx = ws
-            .take_receiver()
-            .ok_or_else(|| anyhow!("position sync ws receiver already taken"))?;
-
-        Ok((ws, rx))
+            .map_err(|err| anyhow!("query_positions failed: {err}"))?;
+        Ok(positions
+            .into_iter()
+            .filter(|position| position.symbol == task_symbol)
+            .fold(Decimal::ZERO, |acc, position| acc + position.qty))
     }
 
-    async fn recv_position_ws_message(
-        ws_rx: &mut Option<mpsc::Receiver<WebSocketMessage>>,
-    ) -> Option<WebSocketMessage> {
-        match ws_rx {
+    async fn recv_position_feed(
+        position_feed: &mut Option<broadcast::Receiver<PositionFeedEvent>>,
+    ) -> std::result::Result<PositionFeedEvent, RecvError> {
+        match position_feed {
             Some(rx) => rx.recv().await,
-            None => pending::<Option<WebSocketMessage>>().await,
+            None => pending().await,
         }
     }
 
@@ -1951,6 +3165,7 @@ impl Task {
             guard_state.position_qty = Decimal::ZERO;
             if let Some(order) = guard_state.guard_order.take() {
                 Self::cancel_guard_order(client, task_uuid, task_id, &order.cl_ord_id).await;
+                guard_state.emit_action(task_id, task_symbol, GuardActionKind::Cancel, order.qty);
             }
             return;
         }
@@ -1992,21 +3207,101 @@ impl Task {
 
         if let Some(order) = guard_state.guard_order.take() {
             Self::cancel_guard_order(client, task_uuid, task_id, &order.cl_ord_id).await;
+            guard_state.emit_action(task_id, task_symbol, GuardActionKind::Cancel, order.qty);
         }
 
+        Self::place_guard_or_flatten(
+            client,
+            task_uuid,
+            task_id,
+            task_symbol,
+            side,
+            qty,
+            price,
+            guard_state,
+        )
+        .await;
+    }
+
+    /// Place a guard order, escalating to a market close after repeated failures.
+    #[allow(clippy::too_many_arguments)]
+    async fn place_guard_or_flatten(
+        client: &StandxClient,
+        task_uuid: Uuid,
+        task_id: &str,
+        task_symbol: &str,
+        side: Side,
+        qty: Decimal,
+        price: Decimal,
+        guard_state: &mut PositionGuardState,
+    ) {
         if let Some(order) =
             Self::place_guard_order(client, task_uuid, task_id, task_symbol, side, qty, price).await
         {
+            guard_state.emit_action(task_id, task_symbol, GuardActionKind::Place, order.qty);
             guard_state.guard_order = Some(order);
+            guard_state.reset_guard_failures();
+            return;
+        }
+
+        let now = Instant::now();
+        if !guard_state.record_guard_failure(now) {
+            return;
+        }
+
+        tracing::error!(
+            task_uuid = %task_uuid,
+            task_id = %task_id,
+            symbol = %task_symbol,
+            failures = guard_state.guard_failures,
+            position_qty = %guard_state.position_qty,
+            "position guard failed repeatedly; panic flattening position"
+        );
+
+        guard_state.reset_guard_failures();
+        guard_state.last_force_close = Some(now);
+        guard_state.emit_action(
+            task_id,
+            task_symbol,
+            GuardActionKind::Flatten,
+            guard_state.position_qty.abs(),
+        );
+
+        if let Err(err) = Self::close_position_qty(
+            client,
+            task_uuid,
+            task_id,
+            task_symbol,
+            guard_state.position_qty,
+        )
+        .await
+        {
+            tracing::error!(
+                task_uuid = %task_uuid,
+                task_id = %task_id,
+                symbol = %task_symbol,
+                "position guard panic flatten failed: {err}"
+            );
         }
     }
+    /// Follow the order stream until shutdown, reconnecting with backoff.
+    ///
+    /// Order updates carry the limit price (0 for market orders) and no fee,
+    /// so a new fill only triggers a trades sync; [`RuntimeEvent::Fill`] is
+    /// emitted from the trade, at its executed price and charged fee. Every
+    /// reconnect requeries open orders and resyncs trades over REST, so fills
+    /// made while the stream was down are still booked.
+    #[allow(clippy::too_many_arguments)]
     async fn order_ws_loop(
+        client: &StandxClient,
         task_uuid: Uuid,
         task_id: &str,
         account_jwt: &str,
         task_symbol: &str,
         order_tracker: Arc<Mutex<OrderTracker>>,
+        reconcile_tx: mpsc::UnboundedSender<OrderReconcileRequest>,
         metrics: Arc<Mutex<TaskMetrics>>,
+        events: EventBus,
         shutdown: CancellationToken,
     ) -> Result<()> {
         if account_jwt.trim().is_empty() {
@@ -2018,78 +3313,200 @@ impl Task {
             return Ok(());
         }
 
-        let mut ws = StandxWebSocket::new();
-        if let Err(err) = ws.connect_market_stream().await {
-            tracing::warn!(
-                task_uuid = %task_uuid,
-                task_id = %task_id,
-                "order ws connect failed: {err}"
-            );
-            return Ok(());
-        }
-
-        let streams = ["order"];
-        if let Err(err) = ws.authenticate(account_jwt, Some(&streams)).await {
-            tracing::warn!(
-                task_uuid = %task_uuid,
-                task_id = %task_id,
-                "order ws auth failed: {err}"
-            );
-            return Ok(());
-        }
+        let mut fills = TradeFillSync::new(server_now_millis(client));
+        let mut attempt = 0u32;
+        let mut resumed = false;
+        loop {
+            let connected = tokio::select! {
+                _ = shutdown.cancelled() => return Ok(()),
+                connected = Self::connect_order_stream(client.environment(), account_jwt) => connected,
+            };
+            match connected {
+                Ok((ws, mut rx)) => {
+                    if resumed {
+                        tracing::info!(
+                            task_uuid = %task_uuid,
+                            task_id = %task_id,
+                            attempt,
+                            "order ws reconnected; resyncing orders and trades"
+                        );
+                        let _ = reconcile_tx.send(OrderReconcileRequest {
+                            cl_ord_id: String::new(),
+                            reason: OrderReconcileReason::StreamReconnected,
+                        });
+                        fills
+                            .sync(client, task_uuid, task_id, task_symbol, &events)
+                            .await;
+                    }
+                    attempt = 0;
+                    let Some(reason) = Self::order_ws_session(
+                        client,
+                        &ws,
+                        &mut rx,
+                        &mut fills,
+                        task_uuid,
+                        task_id,
+                        task_symbol,
+                        &order_tracker,
+                        &reconcile_tx,
+                        &metrics,
+                        &events,
+                        &shutdown,
+                    )
+                    .await
+                    else {
+                        return Ok(());
+                    };
+                    tracing::warn!(
+                        task_uuid = %task_uuid,
+                        task_id = %task_id,
+                        %reason,
+                        "order ws ended; reconnecting"
+                    );
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        task_uuid = %task_uuid,
+                        task_id = %task_id,
+                        attempt = attempt + 1,
+                        "order ws connect failed: {err}"
+                    );
+                }
+            }
 
-        if let Err(err) = ws.subscribe_orders().await {
-            tracing::warn!(
-                task_uuid = %task_uuid,
-                task_id = %task_id,
-                "order ws subscribe failed: {err}"
-            );
-            return Ok(());
+            resumed = true;
+            attempt = attempt.saturating_add(1);
+            let delay = ACCOUNT_STREAM_RECONNECT_BACKOFF.delay_for(attempt);
+            tokio::select! {
+                _ = shutdown.cancelled() => return Ok(()),
+                _ = tokio::time::sleep(delay) => {}
+            }
         }
+    }
 
-        let mut rx = ws
+    async fn connect_order_stream(
+        environment: &StandxEnvironment,
+        account_jwt: &str,
+    ) -> std::result::Result<(StandxWebSocket, mpsc::Receiver<WebSocketMessage>), String> {
+        let mut ws = StandxWebSocket::new()
+            .with_environment(environment)
+            .with_heartbeat(WS_HEARTBEAT_INTERVAL, WS_HEARTBEAT_TIMEOUT);
+        ws.connect_market_stream()
+            .await
+            .map_err(|err| format!("connect failed: {err}"))?;
+        ws.authenticate(account_jwt, Some(&["order"]))
+            .await
+            .map_err(|err| format!("authenticate failed: {err}"))?;
+        ws.subscribe_orders()
+            .await
+            .map_err(|err| format!("subscribe failed: {err}"))?;
+        let rx = ws
             .take_receiver()
-            .ok_or_else(|| anyhow!("order ws receiver already taken"))?;
+            .ok_or_else(|| "order ws receiver already taken".to_string())?;
+        Ok((ws, rx))
+    }
+
+    /// Apply one connection's order updates; returns the disconnect reason,
+    /// or `None` on shutdown.
+    #[allow(clippy::too_many_arguments)]
+    async fn order_ws_session(
+        client: &StandxClient,
+        ws: &StandxWebSocket,
+        rx: &mut mpsc::Receiver<WebSocketMessage>,
+        fills: &mut TradeFillSync,
+        task_uuid: Uuid,
+        task_id: &str,
+        task_symbol: &str,
+        order_tracker: &Arc<Mutex<OrderTracker>>,
+        reconcile_tx: &mpsc::UnboundedSender<OrderReconcileRequest>,
+        metrics: &Arc<Mutex<TaskMetrics>>,
+        events: &EventBus,
+        shutdown: &CancellationToken,
+    ) -> Option<String> {
+        // Trades can lag the order update that reported them; retry until
+        // every reported fill is booked.
+        let retry = tokio::time::sleep(TRADE_SYNC_RETRY_DELAY);
+        tokio::pin!(retry);
+        let mut retry_pending = fills.outstanding();
 
         loop {
             tokio::select! {
                 _ = shutdown.cancelled() => {
-                    return Ok(());
+                    return None;
+                }
+                _ = &mut retry, if retry_pending => {
+                    retry_pending = fills
+                        .sync(client, task_uuid, task_id, task_symbol, events)
+                        .await;
+                    retry.as_mut().reset(Instant::now() + TRADE_SYNC_RETRY_DELAY);
                 }
                 msg = rx.recv() => {
                     let Some(message) = msg else {
-                        return Ok(());
-                    };
-
-                    let WebSocketMessage::Order { data } = message else {
-                        continue;
+                        return Some(
+                            ws.disconnect_reason()
+                                .await
+                                .unwrap_or_else(|| "unknown".to_string()),
+                        );
                     };
 
-                    let update = match serde_json::from_value::<OrderUpdateData>(data.clone()) {
-                        Ok(update) => update,
-                        Err(err) => {
+                    let update = match message {
+                        WebSocketMessage::OrderUpdate(update) => update,
+                        WebSocketMessage::Order { data } => {
                             tracing::debug!(
                                 task_uuid = %task_uuid,
                                 task_id = %task_id,
-                                error = %err,
-                                "order ws update deserialize failed"
+                                %data,
+                                "order ws update did not match OrderUpdateData"
+                            );
+                            continue;
+                        }
+                        WebSocketMessage::Gap { channel, expected, got } if channel == "order" => {
+                            // WS state may be stale; let the reconcile loop requery open orders.
+                            tracing::warn!(
+                                task_uuid = %task_uuid,
+                                task_id = %task_id,
+                                expected,
+                                got,
+                                "order ws sequence gap; requesting reconcile"
                             );
+                            let _ = reconcile_tx.send(OrderReconcileRequest {
+                                cl_ord_id: String::new(),
+                                reason: OrderReconcileReason::SequenceGap,
+                            });
                             continue;
                         }
+                        _ => continue,
                     };
 
                     if update.symbol != task_symbol {
                         continue;
                     }
 
-                    let cl_ord_id = data
-                        .get("cl_ord_id")
-                        .and_then(|value| value.as_str())
-                        .map(|value| value.to_string());
+                    let closed = matches!(
+                        update.status.to_ascii_lowercase().as_str(),
+                        "filled" | "canceled" | "cancelled" | "rejected"
+                    );
+                    match Decimal::from_str(&update.fill_qty) {
+                        Ok(fill_qty) => {
+                            if fills.on_order_update(update.id, fill_qty, closed) {
+                                retry_pending = fills
+                                    .sync(client, task_uuid, task_id, task_symbol, events)
+                                    .await;
+                                retry.as_mut().reset(Instant::now() + TRADE_SYNC_RETRY_DELAY);
+                            }
+                        }
+                        Err(_) => tracing::warn!(
+                            task_uuid = %task_uuid,
+                            task_id = %task_id,
+                            order_id = update.id,
+                            fill_qty = %update.fill_qty,
+                            "order ws update has malformed fill_qty; trades not synced"
+                        ),
+                    }
 
                     let now = std::time::Instant::now();
                     let mut tracker = order_tracker.lock().await;
-                    if let Some(cl_ord_id) = cl_ord_id.as_deref()
+                    if let Some(cl_ord_id) = update.cl_ord_id.as_deref()
                         && let Err(err) = tracker.acknowledge(cl_ord_id, update.id, now)
                     {
                         tracing::debug!(
@@ -2121,65 +3538,216 @@ impl Task {
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
-    async fn order_reconcile_loop(
+    /// Poll account equity and resolve once it drops below `min_equity_usd`.
+    ///
+    /// Never resolves when no minimum is configured; refresh failures are
+    /// logged and retried on the next tick.
+    async fn min_equity_loop(
         client: &StandxClient,
         task_uuid: Uuid,
         task_id: &str,
-        task_symbol: &str,
-        order_tracker: Arc<Mutex<OrderTracker>>,
-        mut reconcile_rx: mpsc::UnboundedReceiver<OrderReconcileRequest>,
+        min_equity_usd: Option<Decimal>,
+        check_interval: Duration,
         metrics: Arc<Mutex<TaskMetrics>>,
-        shutdown: CancellationToken,
-    ) -> Result<()> {
-        let mut interval = tokio::time::interval(ORDER_RECONCILE_INTERVAL);
+    ) -> MinEquityBreached {
+        let Some(min_equity_usd) = min_equity_usd else {
+            return pending().await;
+        };
+
+        let mut interval =
+            tokio::time::interval_at(Instant::now() + check_interval, check_interval);
         interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
 
         loop {
-            tokio::select! {
-                _ = shutdown.cancelled() => {
-                    return Ok(());
-                }
-                _ = interval.tick() => {
-                    Self::reconcile_orders_once(
-                        client,
-                        task_uuid,
-                        task_id,
-                        task_symbol,
-                        &order_tracker,
-                        &metrics,
-                        None,
-                    ).await;
-                }
-                req = reconcile_rx.recv() => {
-                    let Some(req) = req else {
-                        return Ok(());
+            interval.tick().await;
+            match client.query_balance().await {
+                Ok(balance) if balance.equity < min_equity_usd => {
+                    let breach = MinEquityBreached {
+                        equity: balance.equity,
+                        min_equity_usd,
                     };
-                    Self::reconcile_orders_once(
-                        client,
-                        task_uuid,
-                        task_id,
-                        task_symbol,
-                        &order_tracker,
-                        &metrics,
-                        Some(req),
-                    ).await;
+                    tracing::error!(
+                        task_uuid = %task_uuid,
+                        task_id = %task_id,
+                        equity = %breach.equity,
+                        min_equity_usd = %breach.min_equity_usd,
+                        "min equity breached; stopping task"
+                    );
+                    metrics.lock().await.record_halt(breach.to_string());
+                    return breach;
+                }
+                Ok(_) => {}
+                Err(err) => {
+                    tracing::warn!(
+                        task_uuid = %task_uuid,
+                        task_id = %task_id,
+                        "min equity balance refresh failed: {err}"
+                    );
                 }
             }
         }
     }
 
-    async fn reconcile_orders_once(
+    /// Publish the latest funding rate to the strategy and metrics, forever.
+    ///
+    /// Failed polls keep the previous rate.
+    async fn funding_rate_loop(
         client: &StandxClient,
         task_uuid: Uuid,
         task_id: &str,
-        task_symbol: &str,
-        order_tracker: &Arc<Mutex<OrderTracker>>,
-        metrics: &Arc<Mutex<TaskMetrics>>,
-        request: Option<OrderReconcileRequest>,
-    ) {
-        let orders = match Self::query_all_open_orders_for_reconcile(
-            client,
+        symbol: &str,
+        poll_interval: Duration,
+        funding_rate_tx: watch::Sender<Option<Decimal>>,
+        metrics: Arc<Mutex<TaskMetrics>>,
+    ) -> std::convert::Infallible {
+        let mut interval = tokio::time::interval(poll_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            match client.query_funding_rate(symbol).await {
+                Ok(funding) => {
+                    funding_rate_tx.send_replace(Some(funding.funding_rate));
+                    metrics
+                        .lock()
+                        .await
+                        .record_funding_rate(funding.funding_rate);
+                }
+                Err(err) => {
+                    tracing::debug!(
+                        task_uuid = %task_uuid,
+                        task_id = %task_id,
+                        symbol = %symbol,
+                        "funding rate refresh failed: {err}"
+                    );
+                }
+            }
+        }
+    }
+
+    /// Publish the order book mid to the strategy, forever.
+    ///
+    /// Failed polls and empty, one-sided, or crossed books publish `None`,
+    /// so quoting falls back to the ticker instead of a stale book.
+    async fn book_mid_loop(
+        client: &StandxClient,
+        task_uuid: Uuid,
+        task_id: &str,
+        symbol: &str,
+        poll_interval: Duration,
+        book_mid_tx: watch::Sender<Option<Decimal>>,
+    ) -> std::convert::Infallible {
+        let mut interval = tokio::time::interval(poll_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            let book_mid = match client.query_orderbook(symbol, ORDERBOOK_DEPTH).await {
+                Ok(book) => book.mid_price(),
+                Err(err) => {
+                    tracing::debug!(
+                        task_uuid = %task_uuid,
+                        task_id = %task_id,
+                        symbol = %symbol,
+                        "order book refresh failed: {err}"
+                    );
+                    None
+                }
+            };
+            book_mid_tx.send_replace(book_mid);
+        }
+    }
+
+    /// Publish the account's `cross_available` balance to the strategy, forever.
+    ///
+    /// The first poll waits a full interval (startup already queried the
+    /// balance); failed polls keep the previous value.
+    async fn available_balance_loop(
+        client: &StandxClient,
+        task_uuid: Uuid,
+        task_id: &str,
+        poll_interval: Duration,
+        available_tx: watch::Sender<Option<Decimal>>,
+    ) -> std::convert::Infallible {
+        let mut interval = tokio::time::interval_at(Instant::now() + poll_interval, poll_interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            match client.query_balance().await {
+                Ok(balance) => {
+                    available_tx.send_replace(Some(balance.cross_available));
+                }
+                Err(err) => {
+                    tracing::debug!(
+                        task_uuid = %task_uuid,
+                        task_id = %task_id,
+                        "available balance refresh failed: {err}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    async fn order_reconcile_loop(
+        client: &StandxClient,
+        task_uuid: Uuid,
+        task_id: &str,
+        task_symbol: &str,
+        order_tracker: Arc<Mutex<OrderTracker>>,
+        mut reconcile_rx: mpsc::UnboundedReceiver<OrderReconcileRequest>,
+        metrics: Arc<Mutex<TaskMetrics>>,
+        shutdown: CancellationToken,
+    ) -> Result<()> {
+        let mut interval = tokio::time::interval(ORDER_RECONCILE_INTERVAL);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+        loop {
+            tokio::select! {
+                _ = shutdown.cancelled() => {
+                    return Ok(());
+                }
+                _ = interval.tick() => {
+                    Self::reconcile_orders_once(
+                        client,
+                        task_uuid,
+                        task_id,
+                        task_symbol,
+                        &order_tracker,
+                        &metrics,
+                        None,
+                    ).await;
+                }
+                req = reconcile_rx.recv() => {
+                    let Some(req) = req else {
+                        return Ok(());
+                    };
+                    Self::reconcile_orders_once(
+                        client,
+                        task_uuid,
+                        task_id,
+                        task_symbol,
+                        &order_tracker,
+                        &metrics,
+                        Some(req),
+                    ).await;
+                }
+            }
+        }
+    }
+
+    async fn reconcile_orders_once(
+        client: &StandxClient,
+        task_uuid: Uuid,
+        task_id: &str,
+        task_symbol: &str,
+        order_tracker: &Arc<Mutex<OrderTracker>>,
+        metrics: &Arc<Mutex<TaskMetrics>>,
+        request: Option<OrderReconcileRequest>,
+    ) {
+        let orders = match Self::query_all_open_orders_for_reconcile(
+            client,
             task_uuid,
             task_id,
             task_symbol,
@@ -2246,69 +3814,33 @@ impl Task {
         }
     }
 
+    /// Open orders via `StandxClient::query_all_open_orders`, falling back
+    /// to one unexpanded `query_orders` page when that fails.
     async fn query_all_open_orders_for_reconcile(
         client: &StandxClient,
         task_uuid: Uuid,
         task_id: &str,
         task_symbol: &str,
     ) -> Result<PaginatedOrders> {
-        let open_orders = match client.query_open_orders(Some(task_symbol)).await {
-            Ok(orders) => orders,
-            Err(StandxError::Api { code: 404, message }) => {
-                tracing::warn!(
-                    task_uuid = %task_uuid,
-                    task_id = %task_id,
-                    symbol = %task_symbol,
-                    "query_open_orders returned 404; treating as no open orders: {message}"
-                );
-                return Ok(PaginatedOrders {
-                    page_size: 0,
-                    result: Vec::new(),
-                    total: 0,
-                });
-            }
-            Err(err) => {
-                tracing::warn!(
-                    task_uuid = %task_uuid,
-                    task_id = %task_id,
-                    symbol = %task_symbol,
-                    "query_open_orders failed; falling back to query_orders: {err}"
-                );
-
-                let fallback = client
-                    .query_orders(Some(task_symbol), Some(OrderStatus::Open), None)
-                    .await;
-
-                return match fallback {
-                    Ok(orders) => Ok(orders),
-                    Err(fallback_err) => Err(anyhow!(err)).context(format!(
-                        "query_open_orders failed; query_orders fallback failed: {fallback_err}"
-                    )),
-                };
-            }
+        let err = match client.query_all_open_orders(Some(task_symbol)).await {
+            Ok(orders) => return Ok(orders),
+            Err(err) => err,
         };
+        tracing::warn!(
+            task_uuid = %task_uuid,
+            task_id = %task_id,
+            symbol = %task_symbol,
+            "query_open_orders failed; falling back to query_orders: {err}"
+        );
 
-        if open_orders.total > open_orders.result.len() as u32 {
-            let limit = open_orders.total;
-            match client
-                .query_orders(Some(task_symbol), Some(OrderStatus::Open), Some(limit))
-                .await
-            {
-                Ok(expanded) => return Ok(expanded),
-                Err(err) => {
-                    tracing::warn!(
-                        task_uuid = %task_uuid,
-                        task_id = %task_id,
-                        symbol = %task_symbol,
-                        total = open_orders.total,
-                        page_size = open_orders.page_size,
-                        "query_orders failed while expanding open orders: {err}"
-                    );
-                }
-            }
-        }
-
-        Ok(open_orders)
+        client
+            .query_orders(Some(task_symbol), Some(OrderStatus::Open), None)
+            .await
+            .map_err(|fallback_err| {
+                anyhow!(err).context(format!(
+                    "query_open_orders failed; query_orders fallback failed: {fallback_err}"
+                ))
+            })
     }
 }
 
@@ -2330,9 +3862,16 @@ fn decode_ed25519_secret_key_base64(encoded: &str) -> Result<[u8; 32]> {
             Ok(bytes)
         }
         64 => {
-            // Common representation: 32-byte seed + 32-byte public key.
+            // Accept only seed || public_key; expanded secret keys or other
+            // 64-byte layouts would silently produce the wrong signer.
             let mut bytes = [0u8; 32];
             bytes.copy_from_slice(&decoded[..32]);
+            let public_key = Ed25519Signer::from_secret_key(&bytes).public_key_bytes();
+            if decoded[32..] != public_key {
+                return Err(anyhow!(
+                    "64-byte signing key is not seed || public_key: the last 32 bytes do not match the public key of the first 32"
+                ));
+            }
             Ok(bytes)
         }
         other => Err(anyhow!(
@@ -2345,13 +3884,15 @@ fn dummy_task_config() -> TaskConfig {
     TaskConfig {
         id: "dummy".to_string(),
         symbol: "DUMMY".to_string(),
+        symbols: Vec::new(),
+        quoting: None,
+        leverage: None,
+        margin_mode: None,
         account_id: "account-1".to_string(),
         risk: crate::config::RiskConfig {
             level: "low".to_string(),
             budget_usd: "0".to_string(),
-            guard_close_enabled: None,
-            tp_bps: None,
-            sl_bps: None,
+            ..Default::default()
         },
     }
 }
@@ -2371,6 +3912,52 @@ fn dummy_symbol_price(symbol: &str) -> SymbolPrice {
     }
 }
 
+/// Build one shared order limiter per account that configures a concurrency bound.
+fn order_limiters_for_accounts(accounts: &[AccountConfig]) -> HashMap<String, Arc<Semaphore>> {
+    accounts
+        .iter()
+        .filter_map(|account| {
+            let permits = account.max_concurrent_order_actions?;
+            (permits > 0).then(|| (account.id.clone(), Arc::new(Semaphore::new(permits))))
+        })
+        .collect()
+}
+
+fn rate_limiters_for_accounts(accounts: &[AccountConfig]) -> HashMap<String, Arc<RateLimiter>> {
+    accounts
+        .iter()
+        .filter_map(|account| {
+            let limit = account.rate_limit?;
+            Some((account.id.clone(), Arc::new(RateLimiter::new(limit))))
+        })
+        .collect()
+}
+
+fn startup_limiter(max_concurrent_startups: Option<usize>) -> Option<Arc<Semaphore>> {
+    let permits = max_concurrent_startups.unwrap_or(DEFAULT_MAX_CONCURRENT_STARTUPS);
+    (permits > 0).then(|| Arc::new(Semaphore::new(permits)))
+}
+
+/// Move `event`'s task from `pending` into `report` if it settles startup.
+fn settle_startup(event: RuntimeEvent, pending: &mut Vec<String>, report: &mut SpawnReport) {
+    let (task_id, outcome) = match event {
+        RuntimeEvent::StateChanged { task_id, state } if state == "Running" => (task_id, Ok(())),
+        RuntimeEvent::StateChanged { task_id, state } if state == "Stopped" => {
+            (task_id, Err("stopped before startup completed".to_string()))
+        }
+        RuntimeEvent::TaskFailed { task_id, error } => (task_id, Err(error)),
+        _ => return,
+    };
+    let Some(index) = pending.iter().position(|id| *id == task_id) else {
+        return;
+    };
+    pending.remove(index);
+    match outcome {
+        Ok(()) => report.started.push(task_id),
+        Err(error) => report.failed.push((task_id, error)),
+    }
+}
+
 fn sleep_until_deadline(deadline: Instant) -> Sleep {
     tokio::time::sleep_until(deadline)
 }
@@ -2381,6 +3968,17 @@ fn abort_all(tasks: Vec<(String, ManagedTask)>) {
     }
 }
 
+fn check_leverage(leverage: u32, info: &SymbolInfo) -> Result<()> {
+    if leverage == 0 || Decimal::from(leverage) > info.max_leverage {
+        return Err(anyhow!(
+            "leverage {leverage} outside 1..={} for {}",
+            info.max_leverage.normalize(),
+            info.symbol
+        ));
+    }
+    Ok(())
+}
+
 fn select_symbol_info(infos: Vec<SymbolInfo>, symbol: &str) -> Option<SymbolInfo> {
     if infos.is_empty() {
         return None;
@@ -2403,6 +4001,8 @@ struct WsPositionUpdate {
 enum PositionUpdateSource {
     Ws,
     Poll,
+    /// REST requery after the position stream reconnected
+    Resync,
 }
 
 impl PositionUpdateSource {
@@ -2410,10 +4010,145 @@ impl PositionUpdateSource {
         match self {
             Self::Ws => "ws",
             Self::Poll => "poll",
+            Self::Resync => "resync",
+        }
+    }
+}
+
+/// Books a task's fills from the trades endpoint, which carries the executed
+/// price and the fee charged that order updates lack.
+#[derive(Debug)]
+struct TradeFillSync {
+    /// Lower bound of the next trades query, in server ms.
+    since_ms: u64,
+    /// Booked trade ids and when each was first seen, so ids that fell out
+    /// of the query window can be forgotten.
+    booked: HashMap<i64, u64>,
+    orders: HashMap<i64, OrderFillProgress>,
+}
+
+/// Cumulative fill the order stream reported for one order, against the
+/// quantity booked from its trades.
+#[derive(Debug, Default)]
+struct OrderFillProgress {
+    reported: Decimal,
+    booked: Decimal,
+    closed: bool,
+}
+
+impl TradeFillSync {
+    fn new(since_ms: u64) -> Self {
+        Self {
+            since_ms,
+            booked: HashMap::new(),
+            orders: HashMap::new(),
+        }
+    }
+
+    /// Record an order update's cumulative fill; true if it filled more.
+    fn on_order_update(&mut self, order_id: i64, fill_qty: Decimal, closed: bool) -> bool {
+        let progress = self.orders.entry(order_id).or_default();
+        progress.closed |= closed;
+        let filled_more = fill_qty > progress.reported;
+        if filled_more {
+            progress.reported = fill_qty;
+        }
+        self.prune_orders();
+        filled_more
+    }
+
+    /// Whether the order stream reported fills no trade has booked yet.
+    fn outstanding(&self) -> bool {
+        self.orders
+            .values()
+            .any(|progress| progress.reported > progress.booked)
+    }
+
+    /// Keep the trades not booked yet, oldest first, and move the query
+    /// window up to `now_ms` less [`TRADE_SYNC_LOOKBACK_MS`].
+    fn take_new(&mut self, mut trades: Vec<Trade>, now_ms: u64) -> Vec<Trade> {
+        trades.sort_by_key(|trade| trade.id);
+        trades.dedup_by_key(|trade| trade.id);
+        trades.retain(|trade| !self.booked.contains_key(&trade.id));
+        for trade in &trades {
+            self.booked.insert(trade.id, now_ms);
+            if let Some(progress) = self.orders.get_mut(&trade.order_id) {
+                progress.booked += trade.qty;
+            }
         }
+        self.since_ms = self
+            .since_ms
+            .max(now_ms.saturating_sub(TRADE_SYNC_LOOKBACK_MS));
+        let since_ms = self.since_ms;
+        self.booked.retain(|_, seen_ms| *seen_ms >= since_ms);
+        self.prune_orders();
+        trades
+    }
+
+    fn prune_orders(&mut self) {
+        self.orders
+            .retain(|_, progress| !progress.closed || progress.booked < progress.reported);
+    }
+
+    /// Emit a [`RuntimeEvent::Fill`] for every new trade on `symbol`;
+    /// returns true while a retry is due, either because the query failed
+    /// or because reported fills have no trade yet.
+    async fn sync(
+        &mut self,
+        client: &StandxClient,
+        task_uuid: Uuid,
+        task_id: &str,
+        symbol: &str,
+        events: &EventBus,
+    ) -> bool {
+        let now_ms = server_now_millis(client);
+        let trades = match client
+            .iter_trades(
+                Some(symbol),
+                Some(self.since_ms),
+                None,
+                TRADE_SYNC_PAGE_SIZE,
+            )
+            .try_concat()
+            .await
+        {
+            Ok(trades) => trades,
+            Err(err) => {
+                tracing::warn!(
+                    task_uuid = %task_uuid,
+                    task_id = %task_id,
+                    symbol = %symbol,
+                    "trade sync failed; fills not booked yet: {err}"
+                );
+                return true;
+            }
+        };
+
+        for trade in self.take_new(trades, now_ms) {
+            let side = match trade.side {
+                Side::Buy => "buy",
+                Side::Sell => "sell",
+            };
+            events.emit(RuntimeEvent::Fill {
+                task_id: task_id.to_string(),
+                symbol: trade.symbol,
+                order_id: trade.order_id,
+                side: side.to_string(),
+                price: trade.price,
+                fill_qty: trade.qty,
+                fee: trade.fee_qty,
+            });
+        }
+        self.outstanding()
     }
 }
 
+/// Exchange time in ms: local time corrected by the client's clock offset.
+fn server_now_millis(client: &StandxClient) -> u64 {
+    let millis = chrono::Utc::now().timestamp_millis() + client.clock_offset_millis();
+    u64::try_from(millis).unwrap_or_default()
+}
+
 #[derive(Debug, Clone, Copy)]
 struct ExitGuardPolicy {
     exit_bps: Decimal,
@@ -2434,6 +4169,40 @@ struct PositionGuardState {
     position_qty: Decimal,
     guard_order: Option<GuardOrder>,
     last_force_close: Option<Instant>,
+    flatten_after_failures: u32,
+    guard_failures: u32,
+    first_guard_failure_at: Option<Instant>,
+    events: EventBus,
+}
+
+impl PositionGuardState {
+    fn emit_action(&self, task_id: &str, symbol: &str, action: GuardActionKind, qty: Decimal) {
+        self.events.emit(RuntimeEvent::GuardAction {
+            task_id: task_id.to_string(),
+            symbol: symbol.to_string(),
+            action,
+            qty,
+        });
+    }
+
+    fn reset_guard_failures(&mut self) {
+        self.guard_failures = 0;
+        self.first_guard_failure_at = None;
+    }
+
+    /// Record a failed guard placement; returns true when the panic flatten should fire.
+    fn record_guard_failure(&mut self, now: Instant) -> bool {
+        let window_expired = self.first_guard_failure_at.is_none_or(|first| {
+            now.saturating_duration_since(first) > POSITION_GUARD_FAILURE_WINDOW
+        });
+        if window_expired {
+            self.guard_failures = 0;
+            self.first_guard_failure_at = Some(now);
+        }
+        self.guard_failures += 1;
+
+        self.flatten_after_failures > 0 && self.guard_failures >= self.flatten_after_failures
+    }
 }
 
 fn parse_ws_positions(data: &serde_json::Value) -> Vec<WsPositionUpdate> {
@@ -2600,17 +4369,23 @@ fn bps_from_price(mark_price: Decimal, side: Side, price: Decimal) -> Decimal {
     (diff / mark_price) * Decimal::from(BPS_DENOMINATOR)
 }
 
-fn symbol_cache_path() -> PathBuf {
-    let base_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
-    base_dir.join(".standx-config").join("symbols.json")
+/// Per-user config directory, falling back to `.standx-config` under the CWD.
+fn default_symbol_cache_dir() -> PathBuf {
+    dirs::config_dir()
+        .map(|dir| dir.join("standx-mm"))
+        .unwrap_or_else(|| {
+            let base_dir = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+            base_dir.join(".standx-config")
+        })
 }
 
-async fn load_symbol_cache() -> Option<SymbolCache> {
-    let path = symbol_cache_path();
+/// Read the cache at `path`; a file that fails to parse is renamed to
+/// `<file>.bad` for diagnosis and the caller starts from an empty cache.
+async fn load_symbol_cache(path: &Path) -> Option<SymbolCache> {
     if !path.exists() {
         return None;
     }
-    let content = match fs::read_to_string(&path).await {
+    let content = match fs::read_to_string(path).await {
         Ok(content) => content,
         Err(err) => {
             tracing::warn!("read symbol cache failed: {err}");
@@ -2619,22 +4394,53 @@ async fn load_symbol_cache() -> Option<SymbolCache> {
     };
 
     match serde_json::from_str::<SymbolCache>(&content) {
-        Ok(cache) => Some(cache),
+        Ok(cache) => Some(SymbolCache {
+            path: Some(path.to_path_buf()),
+            ..cache
+        }),
         Err(err) => {
-            tracing::warn!("parse symbol cache failed: {err}");
+            let mut bad_name = path.file_name().unwrap_or_default().to_os_string();
+            bad_name.push(".bad");
+            let bad_path = path.with_file_name(bad_name);
+            match fs::rename(path, &bad_path).await {
+                Ok(()) => tracing::warn!(
+                    quarantined = %bad_path.display(),
+                    "parse symbol cache failed, starting empty: {err}"
+                ),
+                Err(rename_err) => tracing::warn!(
+                    path = %path.display(),
+                    "parse symbol cache failed: {err}; quarantine failed: {rename_err}"
+                ),
+            }
             None
         }
     }
 }
 
+/// Merge `cache` over the on-disk copy and replace the file atomically.
+///
+/// Runs under an exclusive [`FileLock`] so a TUI and a CLI runner sharing the
+/// cache directory keep each other's symbols instead of clobbering them.
 async fn save_symbol_cache(cache: &SymbolCache) -> Result<()> {
-    let path = symbol_cache_path();
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent).await?;
-    }
-    let payload = serde_json::to_string_pretty(cache)?;
-    fs::write(path, payload).await?;
-    Ok(())
+    let Some(path) = cache.path.as_ref() else {
+        return Ok(());
+    };
+    let _lock = FileLock::acquire(path, DEFAULT_LOCK_TIMEOUT).await?;
+    let mut symbols = load_symbol_cache(path)
+        .await
+        .map(|on_disk| on_disk.symbols)
+        .unwrap_or_default();
+    symbols.extend(
+        cache
+            .symbols
+            .iter()
+            .map(|(symbol, info)| (symbol.clone(), info.clone())),
+    );
+    let merged = SymbolCache {
+        symbols,
+        path: None,
+    };
+    write_atomic(path, &serde_json::to_string_pretty(&merged)?).await
 }
 
 #[cfg(test)]
@@ -2730,6 +4536,30 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn decode_ed25519_secret_key_accepts_seed_and_checked_keypair() {
+        let seed = [7u8; 32];
+        let public_key = Ed25519Signer::from_secret_key(&seed).public_key_bytes();
+
+        assert_eq!(
+            decode_ed25519_secret_key_base64(&BASE64.encode(seed)).unwrap(),
+            seed
+        );
+
+        let keypair = [seed, public_key].concat();
+        assert_eq!(
+            decode_ed25519_secret_key_base64(&BASE64.encode(&keypair)).unwrap(),
+            seed
+        );
+
+        let mut malformed = keypair;
+        malformed[63] ^= 0xff;
+        let err = decode_ed25519_secret_key_base64(&BASE64.encode(&malformed))
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("seed || public_key"), "{err}");
+    }
+
     #[test]
     fn parse_ws_positions_handles_objects_and_arrays() {
         let single = json!({"symbol": "XAU-USD", "qty": "1.5"});
@@ -2794,16 +4624,271 @@ mod tests {
         assert_eq!(snapshot.position_qty, Decimal::ZERO);
     }
 
-    use standx_point_adapter::RequestSigner;
-    use standx_point_adapter::http::signature::{
-        HEADER_REQUEST_ID, HEADER_REQUEST_SIGNATURE, HEADER_REQUEST_TIMESTAMP,
-        HEADER_REQUEST_VERSION,
-    };
-    use std::str;
-    use wiremock::matchers::{body_json, header, method, path, query_param};
-    use wiremock::{Match, Mock, MockServer, Request, ResponseTemplate};
+    #[tokio::test]
+    async fn repeated_guard_failures_trigger_panic_flatten() {
+        let server = MockServer::builder().start().await;
+        let base_url = server.uri();
 
-    async fn wait_for_request_count(server: &MockServer, expected: usize, timeout: Duration) {
+        let jwt = "jwt-token";
+        let secret_key = [9u8; 32];
+        let signing_key_base64 = BASE64.encode(secret_key);
+        let symbol = "BTC-USD";
+
+        Mock::given(method("POST"))
+            .and(path("/api/new_order"))
+            .and(body_partial_json(json!({ "order_type": "limit" })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code": 1,
+                "message": "rejected",
+                "request_id": "req-guard",
+            })))
+            .expect(3)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/new_order"))
+            .and(ValidBodySignatureMatcher { secret_key })
+            .and(body_partial_json(json!({
+                "symbol": symbol,
+                "side": "sell",
+                "order_type": "market",
+                "qty": "1.5",
+                "time_in_force": "ioc",
+                "reduce_only": true,
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code": 0,
+                "message": "ok",
+                "request_id": "req-flatten",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let account = test_account_config("account-1", jwt, &signing_key_base64);
+        let account_auth = test_account_auth(&account);
+        let task_config = test_task_config(symbol, &account.id);
+        let client = Task::build_client_with_config_and_base_urls(
+            &task_config,
+            &account,
+            &account_auth,
+            ClientConfig::default(),
+            &base_url,
+            &base_url,
+        )
+        .unwrap();
+
+        let metrics = Arc::new(Mutex::new(TaskMetrics::default()));
+        let (position_tx, _position_rx) = watch::channel(Decimal::ZERO);
+        let mut guard_state = PositionGuardState {
+            flatten_after_failures: 3,
+            ..Default::default()
+        };
+        let mut events = guard_state.events.subscribe();
+
+        for _ in 0..3 {
+            Task::apply_position_update(
+                &client,
+                Uuid::nil(),
+                "task-1",
+                symbol,
+                dec("1.5"),
+                dec("100"),
+                None,
+                RiskLevel::Low,
+                &metrics,
+                &position_tx,
+                true,
+                PositionUpdateSource::Poll,
+                &mut guard_state,
+            )
+            .await;
+        }
+
+        assert!(guard_state.guard_order.is_none());
+        assert!(guard_state.last_force_close.is_some());
+        assert_eq!(guard_state.guard_failures, 0);
+        assert_eq!(
+            events.recv().await,
+            Some(RuntimeEvent::GuardAction {
+                task_id: "task-1".to_string(),
+                symbol: symbol.to_string(),
+                action: GuardActionKind::Flatten,
+                qty: dec("1.5"),
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn export_metrics_csv_replaces_file_atomically() {
+        let dir = std::env::temp_dir().join(format!("standx-metrics-{}", Uuid::new_v4()));
+        let path = dir.join("metrics.csv");
+        let mut manager = TaskManager::new();
+        let metrics = Arc::new(Mutex::new(TaskMetrics::default()));
+        metrics.lock().await.record_fill();
+        manager
+            .task_configs
+            .insert("t1".to_string(), test_task_config("ETH-USD", "account-1"));
+        manager.task_metrics.insert("t1".to_string(), metrics);
+
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&path, "stale").unwrap();
+        manager.export_metrics_csv(&path).await.unwrap();
+
+        let csv = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(
+            csv.lines().collect::<Vec<_>>(),
+            [crate::fills::METRICS_CSV_HEADER, "t1,ETH-USD,0,0,,1,0,"]
+        );
+        assert!(!path.with_extension("tmp").exists());
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn order_limiters_are_shared_per_account() {
+        let mut limited = test_account_config("account-1", "jwt", "key");
+        limited.max_concurrent_order_actions = Some(1);
+        let mut disabled = test_account_config("account-2", "jwt", "key");
+        disabled.max_concurrent_order_actions = Some(0);
+        let unlimited = test_account_config("account-3", "jwt", "key");
+
+        let limiters = order_limiters_for_accounts(&[limited, disabled, unlimited]);
+        assert_eq!(limiters.len(), 1);
+
+        let limiter = limiters.get("account-1").expect("limiter for account-1");
+        let first = limiter.clone().try_acquire_owned().expect("first permit");
+        assert!(limiter.clone().try_acquire_owned().is_err());
+        drop(first);
+        assert!(limiter.clone().try_acquire_owned().is_ok());
+    }
+
+    #[tokio::test]
+    async fn drain_pauses_quoting_then_aborts_stragglers_at_deadline() {
+        let mut manager = TaskManager::new();
+        let flatten_client = Arc::new(StandxClient::new().unwrap());
+        let paused_at_shutdown = Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let mut spawn_fake = |task_id: &str, exits_on_shutdown: bool| {
+            let shutdown = manager.shutdown.child_token();
+            let (paused, paused_rx) = watch::channel(false);
+            let task_shutdown = shutdown.clone();
+            let paused_at_shutdown = paused_at_shutdown.clone();
+            let handle = tokio::spawn(async move {
+                if !exits_on_shutdown {
+                    return pending().await;
+                }
+                task_shutdown.cancelled().await;
+                paused_at_shutdown.store(*paused_rx.borrow(), std::sync::atomic::Ordering::SeqCst);
+                Ok(())
+            });
+            manager.tasks.insert(
+                task_id.to_string(),
+                ManagedTask {
+                    shutdown,
+                    handle,
+                    task_uuid: Uuid::new_v4(),
+                    symbol: "BTC-USD".to_string(),
+                    flatten_client: flatten_client.clone(),
+                    paused,
+                    _jwt_refresher: None,
+                    _event_metrics: AbortOnDrop(tokio::spawn(async {})),
+                },
+            );
+        };
+        spawn_fake("clean", true);
+        spawn_fake("stuck", false);
+
+        let mut progress = Vec::new();
+        let err = manager
+            .drain(Duration::from_millis(200), |remaining| {
+                progress.push(remaining)
+            })
+            .await
+            .expect_err("stuck task should time out");
+
+        assert_eq!(progress, [2, 1]);
+        assert!(paused_at_shutdown.load(std::sync::atomic::Ordering::SeqCst));
+        assert!(err.to_string().contains("aborted 1 task(s)"), "{err}");
+        assert!(manager.tasks.is_empty());
+    }
+
+    #[tokio::test]
+    async fn symbol_cache_honors_configured_dir() {
+        let dir = std::env::temp_dir().join(format!("standx-symbol-cache-{}", Uuid::new_v4()));
+        let path = dir.join(SYMBOL_CACHE_FILE);
+        let seeded = SymbolCache {
+            symbols: HashMap::from([("TEST".to_string(), test_symbol_info("0.0002", 2))]),
+            path: Some(path.clone()),
+        };
+        save_symbol_cache(&seeded).await.expect("seed cache");
+
+        let mut manager = TaskManager::new();
+        manager.set_symbol_cache_dir(&dir);
+        manager.load_symbol_cache_from_disk().await;
+
+        let snapshot = {
+            let mut cache = manager.symbol_cache.lock().await;
+            assert!(cache.symbols.contains_key("TEST"));
+            assert_eq!(cache.path.as_deref(), Some(path.as_path()));
+            let mut other = test_symbol_info("0.0002", 2);
+            other.symbol = "OTHER".to_string();
+            cache.symbols.insert(other.symbol.clone(), other);
+            cache.clone()
+        };
+        // Another process saved a symbol after this manager loaded the cache.
+        let mut third = test_symbol_info("0.0002", 2);
+        third.symbol = "THIRD".to_string();
+        let concurrent = SymbolCache {
+            symbols: HashMap::from([(third.symbol.clone(), third)]),
+            path: Some(path.clone()),
+        };
+        save_symbol_cache(&concurrent)
+            .await
+            .expect("concurrent save");
+        save_symbol_cache(&snapshot).await.expect("save cache");
+
+        let reloaded = load_symbol_cache(&path).await.expect("reload cache");
+        assert!(reloaded.symbols.contains_key("OTHER"));
+        assert!(reloaded.symbols.contains_key("THIRD"));
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[tokio::test]
+    async fn truncated_symbol_cache_is_quarantined() {
+        let dir = std::env::temp_dir().join(format!("standx-symbol-cache-{}", Uuid::new_v4()));
+        let path = dir.join(SYMBOL_CACHE_FILE);
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&path, "{\"symbols\":{\"TEST\":{\"symbol\":").unwrap();
+
+        let mut manager = TaskManager::new();
+        manager.set_symbol_cache_dir(&dir);
+        manager.load_symbol_cache_from_disk().await;
+
+        {
+            let cache = manager.symbol_cache.lock().await;
+            assert!(cache.symbols.is_empty());
+            assert_eq!(cache.path.as_deref(), Some(path.as_path()));
+        }
+        assert!(!path.exists());
+        assert_eq!(
+            std::fs::read_to_string(dir.join("symbols.json.bad")).unwrap(),
+            "{\"symbols\":{\"TEST\":{\"symbol\":"
+        );
+
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    use standx_point_adapter::RequestSigner;
+    use standx_point_adapter::http::signature::{
+        HEADER_REQUEST_ID, HEADER_REQUEST_SIGNATURE, HEADER_REQUEST_TIMESTAMP,
+        HEADER_REQUEST_VERSION,
+    };
+    use std::str;
+    use wiremock::matchers::{body_json, body_partial_json, header, method, path, query_param};
+    use wiremock::{Match, Mock, MockServer, Request, ResponseTemplate};
+
+    async fn wait_for_request_count(server: &MockServer, expected: usize, timeout: Duration) {
         let deadline = Instant::now() + timeout;
         loop {
             let count = server.received_requests().await.unwrap_or_default().len();
@@ -2878,13 +4963,15 @@ mod tests {
         TaskConfig {
             id: task_id.to_string(),
             symbol: symbol.to_string(),
+            symbols: Vec::new(),
+            quoting: None,
+            leverage: None,
+            margin_mode: None,
             account_id: account_id.to_string(),
             risk: crate::config::RiskConfig {
                 level: "low".to_string(),
                 budget_usd: "0".to_string(),
-                guard_close_enabled: None,
-                tp_bps: None,
-                sl_bps: None,
+                ..Default::default()
             },
         }
     }
@@ -2900,6 +4987,12 @@ mod tests {
             jwt_token: Some(jwt.to_string()),
             signing_key: Some(signing_key_base64.to_string()),
             chain: standx_point_adapter::Chain::Bsc,
+            keystore_path: None,
+            mnemonic: None,
+            mnemonic_index: None,
+            wallet_address: None,
+            max_concurrent_order_actions: None,
+            rate_limit: None,
         }
     }
 
@@ -2909,6 +5002,106 @@ mod tests {
         AccountAuth::from_static(account, jwt, signing_key).expect("static auth should succeed")
     }
 
+    #[tokio::test]
+    async fn task_client_uses_rotated_wallet_jwt() {
+        let server = MockServer::start().await;
+        let base_url = server.uri();
+        let signing_key = BASE64.encode([9u8; 32]);
+        let account = test_account_config("account-1", "login-token", &signing_key);
+        let jwt_source = JwtManager::new();
+        jwt_source.set_token(
+            "login-token".to_string(),
+            3600,
+            "0xabc".to_string(),
+            Chain::Bsc,
+        );
+        let account_auth = AccountAuth {
+            jwt_source: Some(jwt_source.clone()),
+            ..test_account_auth(&account)
+        };
+        let client = Task::build_client_with_config_and_base_urls(
+            &test_task_config("BTC-USD", &account.id),
+            &account,
+            &account_auth,
+            ClientConfig::default(),
+            &base_url,
+            &base_url,
+        )
+        .unwrap();
+
+        Mock::given(method("GET"))
+            .and(path("/api/query_positions"))
+            .and(header("authorization", "Bearer rotated-token"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        // The refresher rotates the shared token after the client was built.
+        jwt_source.set_token(
+            "rotated-token".to_string(),
+            3600,
+            "0xabc".to_string(),
+            Chain::Bsc,
+        );
+        assert!(client.query_positions(None).await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn static_auth_carries_real_wallet_address() {
+        let signing_key = BASE64.encode([7u8; 32]);
+        let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .encode(json!({ "address": "0xfeedbeef" }).to_string());
+        let jwt = format!("e30.{payload}.sig");
+
+        let account = test_account_config("account-1", &jwt, &signing_key);
+        assert_eq!(test_account_auth(&account).wallet_address, "0xfeedbeef");
+
+        let private_key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+        let keyed = AccountConfig {
+            private_key: Some(private_key.to_string()),
+            ..test_account_config("account-2", "opaque-jwt", &signing_key)
+        };
+        let expected = EvmWalletSigner::new(private_key)
+            .expect("valid key")
+            .address()
+            .to_string();
+        let auth = test_account_auth(&keyed);
+        assert_eq!(auth.wallet_address, expected);
+        assert_ne!(auth.wallet_address, "unknown");
+    }
+
+    #[test]
+    fn configured_wallet_address_must_match_derived_one() {
+        let signing_key = BASE64.encode([7u8; 32]);
+        let private_key = "0x4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318";
+        let derived = EvmWalletSigner::new(private_key)
+            .expect("valid key")
+            .address()
+            .to_string();
+
+        let matching = AccountConfig {
+            private_key: Some(private_key.to_string()),
+            wallet_address: Some(derived.to_lowercase()),
+            ..test_account_config("account-1", "opaque-jwt", &signing_key)
+        };
+        assert_eq!(test_account_auth(&matching).wallet_address, derived);
+
+        let mismatched = AccountConfig {
+            wallet_address: Some("0x0000000000000000000000000000000000000001".to_string()),
+            ..matching.clone()
+        };
+        let err = AccountAuth::from_static(&mismatched, "opaque-jwt", &signing_key)
+            .expect_err("mismatched wallet_address should fail startup");
+        assert!(err.to_string().contains("wallet_address"), "{err}");
+
+        let stored_only = AccountConfig {
+            wallet_address: Some("0xfeedbeef".to_string()),
+            ..test_account_config("account-2", "opaque-jwt", &signing_key)
+        };
+        assert_eq!(test_account_auth(&stored_only).wallet_address, "0xfeedbeef");
+    }
+
     fn test_order_json(order_id: i64, symbol: &str) -> serde_json::Value {
         json!({
             "avail_locked": "0",
@@ -2983,107 +5176,475 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn task_startup_cancels_open_orders() {
+    async fn position_sync_reconnects_dropped_ws_and_resyncs_from_rest() {
+        use futures_util::{SinkExt, StreamExt};
+        use tokio::net::TcpListener;
+        use tokio_tungstenite::tungstenite::Message;
+
         let _guard = test_lock().lock().await;
         let server = MockServer::builder().start().await;
-        let base_url = server.uri();
-
         let jwt = "jwt-token";
-        let secret_key = [7u8; 32];
-        let signing_key_base64 = BASE64.encode(secret_key);
         let symbol = "BTC-USD";
 
-        Mock::given(method("GET"))
-            .and(path("/api/query_balance"))
-            .and(header("authorization", format!("Bearer {jwt}")))
-            .respond_with(ResponseTemplate::new(200).set_body_json(test_balance_json()))
-            .expect(1)
-            .mount(&server)
-            .await;
-
         Mock::given(method("GET"))
             .and(path("/api/query_positions"))
             .and(query_param("symbol", symbol))
-            .and(header("authorization", format!("Bearer {jwt}")))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
-            .expect(1)
-            .mount(&server)
-            .await;
-
-        Mock::given(method("GET"))
-            .and(path("/api/query_open_orders"))
-            .and(query_param("symbol", symbol))
-            .and(header("authorization", format!("Bearer {jwt}")))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-                "page_size": 2,
-                "result": [
-                    test_order_json(1, symbol),
-                    test_order_json(2, symbol),
-                ],
-                "total": 2,
-            })))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(json!([test_position_json(1, symbol, "2")])),
+            )
             .expect(1)
             .mount(&server)
             .await;
 
-        let signature_matcher = ValidBodySignatureMatcher { secret_key };
-
-        Mock::given(method("POST"))
-            .and(path("/api/cancel_order"))
-            .and(header("authorization", format!("Bearer {jwt}")))
-            .and(signature_matcher)
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-                "code": 0,
-                "message": "ok",
-                "request_id": "req-cancel",
-            })))
-            .expect(2)
-            .mount(&server)
-            .await;
-
+        // First session pushes one update and drops; the second stays open.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let ws_url = format!("ws://{}", listener.local_addr().unwrap());
+        let ws_server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut first = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let _auth = first.next().await;
+            let _subscribe = first.next().await;
+            let update = json!({
+                "channel": "position",
+                "data": { "symbol": "BTC-USD", "qty": "1" },
+            });
+            first
+                .send(Message::Text(update.to_string().into()))
+                .await
+                .unwrap();
+            first.close(None).await.unwrap();
+            drop(first);
+
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut second = tokio_tungstenite::accept_async(stream).await.unwrap();
+            while let Some(Ok(_)) = second.next().await {}
+        });
+
+        let environment = StandxEnvironment::Custom {
+            auth_base_url: server.uri(),
+            trading_base_url: server.uri(),
+            market_stream_url: ws_url.clone(),
+            order_stream_url: ws_url,
+        };
+        let signing_key_base64 = BASE64.encode([7u8; 32]);
         let account = test_account_config("account-1", jwt, &signing_key_base64);
-        let account_auth = test_account_auth(&account);
-        let task_config = test_task_config(symbol, &account.id);
-        let client = Task::build_client_with_config_and_base_urls(
-            &task_config,
+        let mut account_auth = test_account_auth(&account);
+        account_auth.environment = environment;
+        let client = Task::build_client(
+            &test_task_config(symbol, &account.id),
             &account,
             &account_auth,
-            ClientConfig::default(),
-            &base_url,
-            &base_url,
         )
         .unwrap();
 
-        let (_tx, rx) = watch::channel(dummy_symbol_price(symbol));
+        let mut hub = MarketDataHub::new_for_test();
+        let position_feed = hub.subscribe_positions(&account.id, jwt, client.environment());
+        let (_price_tx, price_rx) = watch::channel(dummy_symbol_price(symbol));
+        let (position_tx, mut position_rx) = watch::channel(Decimal::ZERO);
         let shutdown = CancellationToken::new();
-        let symbol_cache = std::sync::Arc::new(Mutex::new(SymbolCache::default()));
-        let metrics = std::sync::Arc::new(Mutex::new(TaskMetrics::default()));
-        let mut task = Task::new_with_client(
-            task_config,
-            client,
-            account_auth.jwt_token.clone(),
-            rx,
-            shutdown,
-            symbol_cache,
-            metrics,
+        let sync = Task::position_sync_loop(
+            &client,
+            Uuid::nil(),
+            "task-1",
+            Some(position_feed),
+            symbol,
+            price_rx,
+            Arc::new(Mutex::new(SymbolCache::default())),
+            RiskLevel::Low,
+            Arc::new(Mutex::new(TaskMetrics::default())),
+            position_tx,
+            false,
+            0,
+            EventBus::default(),
+            shutdown.clone(),
         );
 
-        let _ = task.startup_sequence().await.unwrap();
+        let observe = async {
+            // Polling runs every 10s, so reaching 2 this fast means the
+            // reconnect resynced from REST.
+            position_rx.wait_for(|qty| *qty == dec("1")).await.unwrap();
+            position_rx.wait_for(|qty| *qty == dec("2")).await.unwrap();
+            shutdown.cancel();
+        };
+        let (result, ()) = tokio::time::timeout(Duration::from_secs(5), async {
+            tokio::join!(sync, observe)
+        })
+        .await
+        .expect("position sync recovered before timeout");
+        result.unwrap();
+        ws_server.abort();
+    }
+
+    fn test_trade_json(
+        id: i64,
+        order_id: i64,
+        side: &str,
+        price: &str,
+        fee: &str,
+    ) -> serde_json::Value {
+        json!({
+            "created_at": "2026-10-17T00:00:00Z",
+            "fee_asset": "DUSD",
+            "fee_qty": fee,
+            "id": id,
+            "order_id": order_id,
+            "pnl": "0",
+            "price": price,
+            "qty": "1",
+            "side": side,
+            "symbol": "BTC-USD",
+            "updated_at": "2026-10-17T00:00:00Z",
+            "user": "user",
+            "value": price,
+        })
+    }
+
+    fn order_stream_client(server: &MockServer, ws_url: String, jwt: &str) -> StandxClient {
+        let environment = StandxEnvironment::Custom {
+            auth_base_url: server.uri(),
+            trading_base_url: server.uri(),
+            market_stream_url: ws_url.clone(),
+            order_stream_url: ws_url,
+        };
+        let signing_key_base64 = BASE64.encode([7u8; 32]);
+        let account = test_account_config("account-1", jwt, &signing_key_base64);
+        let mut account_auth = test_account_auth(&account);
+        account_auth.environment = environment;
+        Task::build_client(
+            &test_task_config("BTC-USD", &account.id),
+            &account,
+            &account_auth,
+        )
+        .unwrap()
+    }
+
+    async fn next_fill(events: &mut EventSubscription) -> RuntimeEvent {
+        loop {
+            let event = events.recv().await.expect("event bus open");
+            if matches!(event, RuntimeEvent::Fill { .. }) {
+                return event;
+            }
+        }
+    }
+
+    #[test]
+    fn trade_fill_sync_books_each_trade_once_and_tracks_reported_fills() {
+        let trade = |id: i64, order_id: i64| -> Trade {
+            serde_json::from_value(test_trade_json(id, order_id, "buy", "100", "0.01")).unwrap()
+        };
+        let mut fills = TradeFillSync::new(1_000);
+
+        assert!(fills.on_order_update(7, dec("2"), false));
+        assert!(!fills.on_order_update(7, dec("2"), false));
+        assert!(fills.outstanding());
+
+        let new = fills.take_new(vec![trade(2, 7), trade(1, 7)], 100_000);
+        assert_eq!(new.iter().map(|t| t.id).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(fills.since_ms, 100_000 - TRADE_SYNC_LOOKBACK_MS);
+        assert!(!fills.outstanding());
+
+        // Requeried trades inside the window are not booked twice.
+        assert!(
+            fills
+                .take_new(vec![trade(1, 7), trade(2, 7)], 101_000)
+                .is_empty()
+        );
+        assert!(fills.on_order_update(7, dec("3"), true));
+        assert!(fills.outstanding());
+        assert_eq!(fills.take_new(vec![trade(3, 7)], 102_000).len(), 1);
+        assert!(fills.orders.is_empty());
     }
 
     #[tokio::test]
-    async fn task_startup_expands_open_orders_with_query_orders() {
+    async fn order_ws_books_market_close_at_trade_price_not_update_price() {
+        use futures_util::{SinkExt, StreamExt};
+        use tokio::net::TcpListener;
+        use tokio_tungstenite::tungstenite::Message;
+
         let _guard = test_lock().lock().await;
         let server = MockServer::builder().start().await;
-        let base_url = server.uri();
-
-        let jwt = "jwt-token";
-        let secret_key = [13u8; 32];
-        let signing_key_base64 = BASE64.encode(secret_key);
-        let symbol = "BTC-USD";
-
         Mock::given(method("GET"))
-            .and(path("/api/query_balance"))
+            .and(path("/api/query_trades"))
+            .and(query_param("symbol", "BTC-USD"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "page_size": 1,
+                "total": 1,
+                "result": [test_trade_json(11, 7, "sell", "101", "0.0505")],
+            })))
+            .mount(&server)
+            .await;
+
+        // A guard-flatten market close: the update reports price 0.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let ws_url = format!("ws://{}", listener.local_addr().unwrap());
+        let ws_server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let _auth = ws.next().await;
+            let _subscribe = ws.next().await;
+            let update = json!({
+                "channel": "order",
+                "data": {
+                    "id": 7,
+                    "symbol": "BTC-USD",
+                    "side": "sell",
+                    "status": "filled",
+                    "qty": "1",
+                    "fill_qty": "1",
+                    "price": "0",
+                    "order_type": "market",
+                },
+            });
+            ws.send(Message::Text(update.to_string().into()))
+                .await
+                .unwrap();
+            while let Some(Ok(_)) = ws.next().await {}
+        });
+
+        let jwt = "jwt-token";
+        let client = order_stream_client(&server, ws_url, jwt);
+        let events = EventBus::default();
+        let mut subscription = events.subscribe();
+        let (reconcile_tx, _reconcile_rx) = mpsc::unbounded_channel();
+        let shutdown = CancellationToken::new();
+        let order_ws = Task::order_ws_loop(
+            &client,
+            Uuid::nil(),
+            "task-1",
+            jwt,
+            "BTC-USD",
+            Arc::new(Mutex::new(OrderTracker::new())),
+            reconcile_tx,
+            Arc::new(Mutex::new(TaskMetrics::default())),
+            events.clone(),
+            shutdown.clone(),
+        );
+
+        let observe = async {
+            let fill = next_fill(&mut subscription).await;
+            shutdown.cancel();
+            fill
+        };
+        let (result, fill) = tokio::time::timeout(Duration::from_secs(5), async {
+            tokio::join!(order_ws, observe)
+        })
+        .await
+        .expect("fill booked before timeout");
+        result.unwrap();
+        ws_server.abort();
+
+        let RuntimeEvent::Fill {
+            order_id,
+            side,
+            price,
+            fill_qty,
+            fee,
+            ..
+        } = fill
+        else {
+            unreachable!();
+        };
+        assert_eq!(order_id, 7);
+        assert_eq!(side, "sell");
+        assert_eq!(price, dec("101"));
+        assert_eq!(fill_qty, Decimal::ONE);
+        assert_eq!(fee, dec("0.0505"));
+    }
+
+    #[tokio::test]
+    async fn order_ws_reconnects_and_books_fills_missed_while_down() {
+        use futures_util::StreamExt;
+        use tokio::net::TcpListener;
+        use tokio_tungstenite::tungstenite::Message;
+
+        let _guard = test_lock().lock().await;
+        let server = MockServer::builder().start().await;
+        Mock::given(method("GET"))
+            .and(path("/api/query_trades"))
+            .and(query_param("symbol", "BTC-USD"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "page_size": 1,
+                "total": 1,
+                "result": [test_trade_json(21, 9, "buy", "99.5", "0.02")],
+            })))
+            .mount(&server)
+            .await;
+
+        // The first session drops before the fill is pushed; the second
+        // stays open and never reports it.
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let ws_url = format!("ws://{}", listener.local_addr().unwrap());
+        let ws_server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut first = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let _auth = first.next().await;
+            let _subscribe = first.next().await;
+            first.close(None).await.unwrap();
+            drop(first);
+
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut second = tokio_tungstenite::accept_async(stream).await.unwrap();
+            while let Some(Ok(message)) = second.next().await {
+                if matches!(message, Message::Close(_)) {
+                    break;
+                }
+            }
+        });
+
+        let jwt = "jwt-token";
+        let client = order_stream_client(&server, ws_url, jwt);
+        let events = EventBus::default();
+        let mut subscription = events.subscribe();
+        let (reconcile_tx, mut reconcile_rx) = mpsc::unbounded_channel();
+        let shutdown = CancellationToken::new();
+        let order_ws = Task::order_ws_loop(
+            &client,
+            Uuid::nil(),
+            "task-1",
+            jwt,
+            "BTC-USD",
+            Arc::new(Mutex::new(OrderTracker::new())),
+            reconcile_tx,
+            Arc::new(Mutex::new(TaskMetrics::default())),
+            events.clone(),
+            shutdown.clone(),
+        );
+
+        let observe = async {
+            let request: OrderReconcileRequest = reconcile_rx.recv().await.unwrap();
+            let fill = next_fill(&mut subscription).await;
+            shutdown.cancel();
+            (request, fill)
+        };
+        let (result, (request, fill)) = tokio::time::timeout(Duration::from_secs(5), async {
+            tokio::join!(order_ws, observe)
+        })
+        .await
+        .expect("order ws resynced before timeout");
+        result.unwrap();
+        ws_server.abort();
+
+        assert!(matches!(
+            request.reason,
+            OrderReconcileReason::StreamReconnected
+        ));
+        let RuntimeEvent::Fill {
+            order_id,
+            price,
+            fee,
+            ..
+        } = fill
+        else {
+            unreachable!();
+        };
+        assert_eq!(order_id, 9);
+        assert_eq!(price, dec("99.5"));
+        assert_eq!(fee, dec("0.02"));
+    }
+
+    #[tokio::test]
+    async fn task_startup_cancels_open_orders() {
+        let _guard = test_lock().lock().await;
+        let server = MockServer::builder().start().await;
+        let base_url = server.uri();
+
+        let jwt = "jwt-token";
+        let secret_key = [7u8; 32];
+        let signing_key_base64 = BASE64.encode(secret_key);
+        let symbol = "BTC-USD";
+
+        Mock::given(method("GET"))
+            .and(path("/api/query_balance"))
+            .and(header("authorization", format!("Bearer {jwt}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(test_balance_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/query_positions"))
+            .and(query_param("symbol", symbol))
+            .and(header("authorization", format!("Bearer {jwt}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/query_open_orders"))
+            .and(query_param("symbol", symbol))
+            .and(header("authorization", format!("Bearer {jwt}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "page_size": 2,
+                "result": [
+                    test_order_json(1, symbol),
+                    test_order_json(2, symbol),
+                ],
+                "total": 2,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let signature_matcher = ValidBodySignatureMatcher { secret_key };
+
+        Mock::given(method("POST"))
+            .and(path("/api/cancel_order"))
+            .and(header("authorization", format!("Bearer {jwt}")))
+            .and(signature_matcher)
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code": 0,
+                "message": "ok",
+                "request_id": "req-cancel",
+            })))
+            .expect(2)
+            .mount(&server)
+            .await;
+
+        let account = test_account_config("account-1", jwt, &signing_key_base64);
+        let account_auth = test_account_auth(&account);
+        let task_config = test_task_config(symbol, &account.id);
+        let client = Task::build_client_with_config_and_base_urls(
+            &task_config,
+            &account,
+            &account_auth,
+            ClientConfig::default(),
+            &base_url,
+            &base_url,
+        )
+        .unwrap();
+
+        let (_tx, rx) = watch::channel(dummy_symbol_price(symbol));
+        let shutdown = CancellationToken::new();
+        let symbol_cache = std::sync::Arc::new(Mutex::new(SymbolCache::default()));
+        let metrics = std::sync::Arc::new(Mutex::new(TaskMetrics::default()));
+        let mut task = Task::new_with_client(
+            task_config,
+            client,
+            account_auth.jwt_token.clone(),
+            rx,
+            shutdown,
+            symbol_cache,
+            metrics,
+        );
+
+        let _ = task.startup_sequence().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn task_startup_expands_open_orders_with_query_orders() {
+        let _guard = test_lock().lock().await;
+        let server = MockServer::builder().start().await;
+        let base_url = server.uri();
+
+        let jwt = "jwt-token";
+        let secret_key = [13u8; 32];
+        let signing_key_base64 = BASE64.encode(secret_key);
+        let symbol = "BTC-USD";
+
+        Mock::given(method("GET"))
+            .and(path("/api/query_balance"))
             .and(header("authorization", format!("Bearer {jwt}")))
             .respond_with(ResponseTemplate::new(200).set_body_json(test_balance_json()))
             .expect(1)
@@ -3116,7 +5677,10 @@ mod tests {
             .and(path("/api/query_orders"))
             .and(query_param("symbol", symbol))
             .and(query_param("status", "open"))
-            .and(query_param("limit", "2"))
+            .and(query_param(
+                "limit",
+                standx_point_adapter::http::pagination::MAX_PAGE_SIZE.to_string(),
+            ))
             .and(header("authorization", format!("Bearer {jwt}")))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!({
                 "page_size": 2,
@@ -3162,7 +5726,388 @@ mod tests {
         let shutdown = CancellationToken::new();
         let symbol_cache = std::sync::Arc::new(Mutex::new(SymbolCache::default()));
         let metrics = std::sync::Arc::new(Mutex::new(TaskMetrics::default()));
-        let mut task = Task::new_with_client(
+        let mut task = Task::new_with_client(
+            task_config,
+            client,
+            account_auth.jwt_token.clone(),
+            rx,
+            shutdown,
+            symbol_cache,
+            metrics,
+        );
+
+        let _ = task.startup_sequence().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn task_startup_treats_open_orders_404_as_empty() {
+        let _guard = test_lock().lock().await;
+        let server = MockServer::builder().start().await;
+        let base_url = server.uri();
+
+        let jwt = "jwt-token";
+        let secret_key = [11u8; 32];
+        let signing_key_base64 = BASE64.encode(secret_key);
+        let symbol = "BTC-USD";
+
+        Mock::given(method("GET"))
+            .and(path("/api/query_balance"))
+            .and(header("authorization", format!("Bearer {jwt}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(test_balance_json()))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/query_positions"))
+            .and(query_param("symbol", symbol))
+            .and(header("authorization", format!("Bearer {jwt}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/query_open_orders"))
+            .and(query_param("symbol", symbol))
+            .and(header("authorization", format!("Bearer {jwt}")))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "message": "no open orders found"
+            })))
+            .mount(&server)
+            .await;
+
+        let account = test_account_config("account-1", jwt, &signing_key_base64);
+        let account_auth = test_account_auth(&account);
+        let task_config = test_task_config(symbol, &account.id);
+        let client = Task::build_client_with_config_and_base_urls(
+            &task_config,
+            &account,
+            &account_auth,
+            ClientConfig::default(),
+            &base_url,
+            &base_url,
+        )
+        .unwrap();
+
+        let (_tx, rx) = watch::channel(dummy_symbol_price(symbol));
+        let shutdown = CancellationToken::new();
+        let symbol_cache = std::sync::Arc::new(Mutex::new(SymbolCache::default()));
+        let metrics = std::sync::Arc::new(Mutex::new(TaskMetrics::default()));
+        let mut task = Task::new_with_client(
+            task_config,
+            client,
+            account_auth.jwt_token.clone(),
+            rx,
+            shutdown,
+            symbol_cache,
+            metrics,
+        );
+
+        let _ = task.startup_sequence().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn task_startup_applies_margin_mode_and_leverage() {
+        let _guard = test_lock().lock().await;
+        let server = MockServer::builder().start().await;
+        let base_url = server.uri();
+
+        let jwt = "jwt-token";
+        let secret_key = [11u8; 32];
+        let signing_key_base64 = BASE64.encode(secret_key);
+        let symbol = "BTC-USD";
+        let info = SymbolInfo {
+            symbol: symbol.to_string(),
+            ..test_symbol_info("0.0002", 2)
+        };
+
+        Mock::given(method("GET"))
+            .and(path("/api/query_balance"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(test_balance_json()))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/query_positions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/query_symbol_info"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([info])))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/query_open_orders"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/change_margin_mode"))
+            .and(body_json(
+                json!({ "symbol": symbol, "margin_mode": "isolated" }),
+            ))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code": 0,
+                "message": "success",
+                "request_id": "margin-1"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/api/change_leverage"))
+            .and(body_json(json!({ "symbol": symbol, "leverage": 5 })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code": 0,
+                "message": "success",
+                "request_id": "leverage-1"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let account = test_account_config("account-1", jwt, &signing_key_base64);
+        let account_auth = test_account_auth(&account);
+        let build_task = |leverage: u32| {
+            let task_config = TaskConfig {
+                leverage: Some(leverage),
+                margin_mode: Some(standx_point_adapter::MarginMode::Isolated),
+                ..test_task_config(symbol, &account.id)
+            };
+            let client = Task::build_client_with_config_and_base_urls(
+                &task_config,
+                &account,
+                &account_auth,
+                ClientConfig::default(),
+                &base_url,
+                &base_url,
+            )
+            .unwrap();
+            let (_tx, rx) = watch::channel(dummy_symbol_price(symbol));
+            let metrics = std::sync::Arc::new(Mutex::new(TaskMetrics::default()));
+            let task = Task::new_with_client(
+                task_config,
+                client,
+                account_auth.jwt_token.clone(),
+                rx,
+                CancellationToken::new(),
+                std::sync::Arc::new(Mutex::new(SymbolCache::default())),
+                metrics.clone(),
+            );
+            (task, metrics)
+        };
+
+        let (mut task, metrics) = build_task(5);
+        task.startup_sequence().await.unwrap();
+        let snapshot = metrics.lock().await.snapshot();
+        assert_eq!(snapshot.leverage, Some(5));
+        assert_eq!(
+            snapshot.margin_mode,
+            Some(standx_point_adapter::MarginMode::Isolated)
+        );
+
+        // Above max_leverage (10) fails before either setting is sent.
+        let (mut task, metrics) = build_task(20);
+        let err = task.startup_sequence().await.unwrap_err();
+        assert!(format!("{err:#}").contains("outside 1..=10"), "{err:#}");
+        assert_eq!(metrics.lock().await.snapshot().margin_mode, None);
+    }
+
+    #[tokio::test]
+    async fn task_shutdown_cancels_orders_and_closes_positions() {
+        let _guard = test_lock().lock().await;
+        let server = MockServer::builder().start().await;
+        let base_url = server.uri();
+
+        let jwt = "jwt-token";
+        let secret_key = [9u8; 32];
+        let signing_key_base64 = BASE64.encode(secret_key);
+        let symbol = "BTC-USD";
+
+        Mock::given(method("GET"))
+            .and(path("/api/query_open_orders"))
+            .and(query_param("symbol", symbol))
+            .and(header("authorization", format!("Bearer {jwt}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "page_size": 1,
+                "result": [test_order_json(10, symbol)],
+                "total": 1,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let signature_matcher = ValidBodySignatureMatcher { secret_key };
+
+        Mock::given(method("POST"))
+            .and(path("/api/cancel_orders"))
+            .and(header("authorization", format!("Bearer {jwt}")))
+            .and(signature_matcher.clone())
+            .and(body_json(json!({ "order_id_list": [10] })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/query_positions"))
+            .and(query_param("symbol", symbol))
+            .and(header("authorization", format!("Bearer {jwt}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+                test_position_json(1, symbol, "1.5"),
+                test_position_json(2, symbol, "-2"),
+            ])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/new_order"))
+            .and(header("authorization", format!("Bearer {jwt}")))
+            .and(signature_matcher.clone())
+            .and(body_partial_json(json!({
+                "symbol": symbol,
+                "side": "sell",
+                "order_type": "market",
+                "qty": "1.5",
+                "time_in_force": "ioc",
+                "reduce_only": true,
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code": 0,
+                "message": "ok",
+                "request_id": "req-close-1",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/new_order"))
+            .and(header("authorization", format!("Bearer {jwt}")))
+            .and(signature_matcher)
+            .and(body_partial_json(json!({
+                "symbol": symbol,
+                "side": "buy",
+                "order_type": "market",
+                "qty": "2",
+                "time_in_force": "ioc",
+                "reduce_only": true,
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code": 0,
+                "message": "ok",
+                "request_id": "req-close-2",
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let account = test_account_config("account-1", jwt, &signing_key_base64);
+        let account_auth = test_account_auth(&account);
+        let task_config = test_task_config(symbol, &account.id);
+        let client = Task::build_client_with_config_and_base_urls(
+            &task_config,
+            &account,
+            &account_auth,
+            ClientConfig::default(),
+            &base_url,
+            &base_url,
+        )
+        .unwrap();
+
+        let (_tx, rx) = watch::channel(dummy_symbol_price(symbol));
+        let shutdown = CancellationToken::new();
+        let symbol_cache = std::sync::Arc::new(Mutex::new(SymbolCache::default()));
+        let metrics = std::sync::Arc::new(Mutex::new(TaskMetrics::default()));
+        let task = Task::new_with_client(
+            task_config,
+            client,
+            account_auth.jwt_token.clone(),
+            rx,
+            shutdown,
+            symbol_cache,
+            metrics,
+        );
+
+        task.shutdown_sequence().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn task_shutdown_prefers_bulk_cancel_orders() {
+        let _guard = test_lock().lock().await;
+        let server = MockServer::builder().start().await;
+        let base_url = server.uri();
+
+        let jwt = "jwt-token";
+        let secret_key = [9u8; 32];
+        let signing_key_base64 = BASE64.encode(secret_key);
+        let symbol = "BTC-USD";
+
+        Mock::given(method("GET"))
+            .and(path("/api/query_open_orders"))
+            .and(query_param("symbol", symbol))
+            .and(header("authorization", format!("Bearer {jwt}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "page_size": 2,
+                "result": [test_order_json(10, symbol), test_order_json(11, symbol)],
+                "total": 2,
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let signature_matcher = ValidBodySignatureMatcher { secret_key };
+
+        Mock::given(method("POST"))
+            .and(path("/api/cancel_orders"))
+            .and(header("authorization", format!("Bearer {jwt}")))
+            .and(signature_matcher.clone())
+            .and(body_json(json!({ "order_id_list": [10, 11] })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/cancel_order"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code": 0,
+                "message": "ok",
+                "request_id": "req-cancel",
+            })))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        Mock::given(method("GET"))
+            .and(path("/api/query_positions"))
+            .and(query_param("symbol", symbol))
+            .and(header("authorization", format!("Bearer {jwt}")))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let account = test_account_config("account-1", jwt, &signing_key_base64);
+        let account_auth = test_account_auth(&account);
+        let task_config = test_task_config(symbol, &account.id);
+        let client = Task::build_client_with_config_and_base_urls(
+            &task_config,
+            &account,
+            &account_auth,
+            ClientConfig::default(),
+            &base_url,
+            &base_url,
+        )
+        .unwrap();
+
+        let (_tx, rx) = watch::channel(dummy_symbol_price(symbol));
+        let shutdown = CancellationToken::new();
+        let symbol_cache = std::sync::Arc::new(Mutex::new(SymbolCache::default()));
+        let metrics = std::sync::Arc::new(Mutex::new(TaskMetrics::default()));
+        let task = Task::new_with_client(
             task_config,
             client,
             account_auth.jwt_token.clone(),
@@ -3172,44 +6117,57 @@ mod tests {
             metrics,
         );
 
-        let _ = task.startup_sequence().await.unwrap();
+        task.shutdown_sequence().await.unwrap();
     }
 
     #[tokio::test]
-    async fn task_startup_treats_open_orders_404_as_empty() {
+    async fn task_shutdown_cancels_each_order_when_bulk_cancel_fails() {
         let _guard = test_lock().lock().await;
         let server = MockServer::builder().start().await;
         let base_url = server.uri();
 
         let jwt = "jwt-token";
-        let secret_key = [11u8; 32];
+        let secret_key = [9u8; 32];
         let signing_key_base64 = BASE64.encode(secret_key);
         let symbol = "BTC-USD";
 
         Mock::given(method("GET"))
-            .and(path("/api/query_balance"))
-            .and(header("authorization", format!("Bearer {jwt}")))
-            .respond_with(ResponseTemplate::new(200).set_body_json(test_balance_json()))
-            .expect(1)
+            .and(path("/api/query_open_orders"))
+            .and(query_param("symbol", symbol))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "page_size": 2,
+                "result": [test_order_json(10, symbol), test_order_json(11, symbol)],
+                "total": 2,
+            })))
+            .expect(2)
             .mount(&server)
             .await;
 
-        Mock::given(method("GET"))
-            .and(path("/api/query_positions"))
-            .and(query_param("symbol", symbol))
-            .and(header("authorization", format!("Bearer {jwt}")))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
-            .expect(1)
+        Mock::given(method("POST"))
+            .and(path("/api/cancel_orders"))
+            .respond_with(ResponseTemplate::new(500).set_body_json(json!({
+                "message": "bulk cancel unavailable",
+            })))
+            .mount(&server)
+            .await;
+
+        Mock::given(method("POST"))
+            .and(path("/api/cancel_order"))
+            .and(ValidBodySignatureMatcher { secret_key })
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "code": 0,
+                "message": "ok",
+                "request_id": "req-cancel",
+            })))
+            .expect(2)
             .mount(&server)
             .await;
 
         Mock::given(method("GET"))
-            .and(path("/api/query_open_orders"))
+            .and(path("/api/query_positions"))
             .and(query_param("symbol", symbol))
-            .and(header("authorization", format!("Bearer {jwt}")))
-            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
-                "message": "no open orders found"
-            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .expect(1)
             .mount(&server)
             .await;
 
@@ -3227,24 +6185,21 @@ mod tests {
         .unwrap();
 
         let (_tx, rx) = watch::channel(dummy_symbol_price(symbol));
-        let shutdown = CancellationToken::new();
-        let symbol_cache = std::sync::Arc::new(Mutex::new(SymbolCache::default()));
-        let metrics = std::sync::Arc::new(Mutex::new(TaskMetrics::default()));
-        let mut task = Task::new_with_client(
+        let task = Task::new_with_client(
             task_config,
             client,
             account_auth.jwt_token.clone(),
             rx,
-            shutdown,
-            symbol_cache,
-            metrics,
+            CancellationToken::new(),
+            std::sync::Arc::new(Mutex::new(SymbolCache::default())),
+            std::sync::Arc::new(Mutex::new(TaskMetrics::default())),
         );
 
-        let _ = task.startup_sequence().await.unwrap();
+        task.shutdown_sequence().await.unwrap();
     }
 
     #[tokio::test]
-    async fn task_shutdown_cancels_orders_and_closes_positions() {
+    async fn flatten_symbol_cancels_orders_and_closes_positions() {
         let _guard = test_lock().lock().await;
         let server = MockServer::builder().start().await;
         let base_url = server.uri();
@@ -3257,7 +6212,6 @@ mod tests {
         Mock::given(method("GET"))
             .and(path("/api/query_open_orders"))
             .and(query_param("symbol", symbol))
-            .and(header("authorization", format!("Bearer {jwt}")))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!({
                 "page_size": 1,
                 "result": [test_order_json(10, symbol)],
@@ -3270,14 +6224,10 @@ mod tests {
         let signature_matcher = ValidBodySignatureMatcher { secret_key };
 
         Mock::given(method("POST"))
-            .and(path("/api/cancel_order"))
-            .and(header("authorization", format!("Bearer {jwt}")))
+            .and(path("/api/cancel_orders"))
             .and(signature_matcher.clone())
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-                "code": 0,
-                "message": "ok",
-                "request_id": "req-cancel",
-            })))
+            .and(body_json(json!({ "order_id_list": [10] })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
             .expect(1)
             .mount(&server)
             .await;
@@ -3285,10 +6235,9 @@ mod tests {
         Mock::given(method("GET"))
             .and(path("/api/query_positions"))
             .and(query_param("symbol", symbol))
-            .and(header("authorization", format!("Bearer {jwt}")))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!([
-                test_position_json(1, symbol, "1.5"),
-                test_position_json(2, symbol, "-2"),
+                test_position_json(1, symbol, "0"),
+                test_position_json(2, symbol, "-0.25"),
             ])))
             .expect(1)
             .mount(&server)
@@ -3296,41 +6245,19 @@ mod tests {
 
         Mock::given(method("POST"))
             .and(path("/api/new_order"))
-            .and(header("authorization", format!("Bearer {jwt}")))
-            .and(signature_matcher.clone())
-            .and(body_json(json!({
-                "symbol": symbol,
-                "side": "sell",
-                "order_type": "market",
-                "qty": "1.5",
-                "time_in_force": "ioc",
-                "reduce_only": true,
-            })))
-            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
-                "code": 0,
-                "message": "ok",
-                "request_id": "req-close-1",
-            })))
-            .expect(1)
-            .mount(&server)
-            .await;
-
-        Mock::given(method("POST"))
-            .and(path("/api/new_order"))
-            .and(header("authorization", format!("Bearer {jwt}")))
             .and(signature_matcher)
-            .and(body_json(json!({
+            .and(body_partial_json(json!({
                 "symbol": symbol,
                 "side": "buy",
                 "order_type": "market",
-                "qty": "2",
+                "qty": "0.25",
                 "time_in_force": "ioc",
                 "reduce_only": true,
             })))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!({
                 "code": 0,
                 "message": "ok",
-                "request_id": "req-close-2",
+                "request_id": "req-flatten",
             })))
             .expect(1)
             .mount(&server)
@@ -3349,21 +6276,9 @@ mod tests {
         )
         .unwrap();
 
-        let (_tx, rx) = watch::channel(dummy_symbol_price(symbol));
-        let shutdown = CancellationToken::new();
-        let symbol_cache = std::sync::Arc::new(Mutex::new(SymbolCache::default()));
-        let metrics = std::sync::Arc::new(Mutex::new(TaskMetrics::default()));
-        let task = Task::new_with_client(
-            task_config,
-            client,
-            account_auth.jwt_token.clone(),
-            rx,
-            shutdown,
-            symbol_cache,
-            metrics,
-        );
-
-        task.shutdown_sequence().await.unwrap();
+        Task::flatten_symbol(&client, Uuid::new_v4(), "task-1", symbol)
+            .await
+            .unwrap();
     }
 
     #[tokio::test]
@@ -3385,7 +6300,7 @@ mod tests {
             .mount(&server)
             .await;
 
-        // One call during startup + one call during shutdown.
+        // Startup + shutdown, plus reconciles while the task runs.
         Mock::given(method("GET"))
             .and(path("/api/query_open_orders"))
             .and(query_param("symbol", symbol))
@@ -3395,16 +6310,17 @@ mod tests {
                 "result": [],
                 "total": 0,
             })))
-            .expect(2)
+            .expect(2..)
             .mount(&server)
             .await;
 
+        // Startup + shutdown, plus position resyncs while the task runs.
         Mock::given(method("GET"))
             .and(path("/api/query_positions"))
             .and(query_param("symbol", symbol))
             .and(header("authorization", format!("Bearer {jwt}")))
             .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
-            .expect(2)
+            .expect(2..)
             .mount(&server)
             .await;
 
@@ -3413,14 +6329,20 @@ mod tests {
         let strategy_config = StrategyConfig {
             accounts: vec![account.clone()],
             tasks: vec![task_config.clone()],
+            max_total_budget_usd: None,
+            allow_duplicate_symbols: false,
+            max_concurrent_startups: None,
+            environment: None,
         };
 
         let mut manager = TaskManager::new();
         let client_config = ClientConfig {
             timeout: Duration::from_secs(60),
             connect_timeout: Duration::from_secs(30),
+            ..ClientConfig::default()
         };
-        manager
+        let mut events = manager.subscribe_events();
+        let report = manager
             .spawn_from_config_with_client_builder(
                 strategy_config,
                 |cfg, account_cfg, account_auth| {
@@ -3436,6 +6358,8 @@ mod tests {
             )
             .await
             .unwrap();
+        assert_eq!(report.started, ["task-1"]);
+        assert!(report.all_started());
 
         wait_for_request_count(&server, 1, Duration::from_secs(5)).await;
 
@@ -3445,6 +6369,188 @@ mod tests {
         tokio::time::sleep(Duration::from_millis(1000)).await;
 
         wait_for_request_count(&server, 3, Duration::from_secs(10)).await;
+
+        let mut states = Vec::new();
+        while let Ok(Some(event)) =
+            tokio::time::timeout(Duration::from_millis(100), events.recv()).await
+        {
+            if let RuntimeEvent::StateChanged { task_id, state } = event {
+                assert_eq!(task_id, "task-1");
+                states.push(state);
+            }
+        }
+        assert_eq!(states.first().map(String::as_str), Some("Starting"));
+    }
+
+    #[tokio::test]
+    async fn task_manager_bounds_concurrent_startups() {
+        let _guard = test_lock().lock().await;
+        let server = MockServer::builder().start().await;
+        let base_url = server.uri();
+
+        let jwt = "jwt-token";
+        let signing_key_base64 = BASE64.encode([1u8; 32]);
+
+        // Slow enough that all three tasks would be querying at once if unbounded.
+        Mock::given(method("GET"))
+            .and(path("/api/query_balance"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(test_balance_json())
+                    .set_delay(Duration::from_millis(500)),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/query_open_orders"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "page_size": 0,
+                "result": [],
+                "total": 0,
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/query_positions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+            .mount(&server)
+            .await;
+
+        let account = test_account_config("account-1", jwt, &signing_key_base64);
+        let strategy_config = StrategyConfig {
+            accounts: vec![account.clone()],
+            tasks: vec![
+                test_task_config_with_id("task-1", "BTC-USD", &account.id),
+                test_task_config_with_id("task-2", "ETH-USD", &account.id),
+                test_task_config_with_id("task-3", "SOL-USD", &account.id),
+            ],
+            max_total_budget_usd: None,
+            allow_duplicate_symbols: false,
+            max_concurrent_startups: Some(1),
+            environment: None,
+        };
+
+        let mut manager = TaskManager::new();
+        let mut events = manager.subscribe_events();
+        // Spawning waits for every startup, so observe the queue from outside.
+        let spawn = tokio::spawn(async move {
+            let report = manager
+                .spawn_from_config_with_client_builder(
+                    strategy_config,
+                    |cfg, account_cfg, account_auth| {
+                        Task::build_client_with_config_and_base_urls(
+                            cfg,
+                            account_cfg,
+                            account_auth,
+                            ClientConfig::default(),
+                            &base_url,
+                            &base_url,
+                        )
+                    },
+                )
+                .await
+                .unwrap();
+            (manager, report)
+        });
+
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        let balance_queries = server
+            .received_requests()
+            .await
+            .unwrap_or_default()
+            .iter()
+            .filter(|request| request.url.path() == "/api/query_balance")
+            .count();
+        assert_eq!(balance_queries, 1);
+
+        let mut running = Vec::new();
+        while running.len() < 3 {
+            let event = tokio::time::timeout(Duration::from_secs(10), events.recv())
+                .await
+                .expect("all tasks should reach Running")
+                .expect("event bus open");
+            if let RuntimeEvent::StateChanged { task_id, state } = event
+                && state == "Running"
+            {
+                running.push(task_id);
+            }
+        }
+        running.sort();
+        assert_eq!(running, ["task-1", "task-2", "task-3"]);
+
+        let (mut manager, mut report) = spawn.await.unwrap();
+        report.started.sort();
+        assert_eq!(report.started, running);
+        manager.shutdown_and_wait().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn min_equity_loop_stops_on_mid_run_equity_drop() {
+        let _guard = test_lock().lock().await;
+        let server = MockServer::builder().start().await;
+        let base_url = server.uri();
+
+        let jwt = "jwt-token";
+        let signing_key_base64 = BASE64.encode([4u8; 32]);
+        let symbol = "BTC-USD";
+
+        let mut healthy = test_balance_json();
+        healthy["equity"] = json!("1000");
+        let mut collapsed = test_balance_json();
+        collapsed["equity"] = json!("50");
+
+        Mock::given(method("GET"))
+            .and(path("/api/query_balance"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(healthy))
+            .up_to_n_times(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/query_balance"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(collapsed))
+            .mount(&server)
+            .await;
+
+        let account = test_account_config("account-1", jwt, &signing_key_base64);
+        let account_auth = test_account_auth(&account);
+        let task_config = test_task_config(symbol, &account.id);
+        let client = Task::build_client_with_config_and_base_urls(
+            &task_config,
+            &account,
+            &account_auth,
+            ClientConfig::default(),
+            &base_url,
+            &base_url,
+        )
+        .unwrap();
+        let metrics = Arc::new(Mutex::new(TaskMetrics::default()));
+
+        let breach = tokio::time::timeout(
+            Duration::from_secs(5),
+            Task::min_equity_loop(
+                &client,
+                Uuid::new_v4(),
+                &task_config.id,
+                Some(dec("100")),
+                Duration::from_millis(20),
+                metrics.clone(),
+            ),
+        )
+        .await
+        .expect("equity drop should stop the loop");
+
+        assert_eq!(
+            breach,
+            MinEquityBreached {
+                equity: dec("50"),
+                min_equity_usd: dec("100"),
+            }
+        );
+        let requests = server.received_requests().await.unwrap_or_default();
+        assert_eq!(requests.len(), 2);
+        let halt_reason = metrics.lock().await.snapshot().halt_reason;
+        assert_eq!(halt_reason.as_deref(), Some(breach.to_string().as_str()));
+        assert!(breach.to_string().starts_with("min equity breached"));
     }
 
     #[tokio::test]
@@ -3454,6 +6560,118 @@ mod tests {
         assert!(err.to_string().contains("not found"));
     }
 
+    #[test]
+    fn restart_policy_backoff_doubles_up_to_cap() {
+        let policy = RestartPolicy {
+            max_attempts: 5,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(5),
+        };
+        let delays: Vec<u64> = (0..5).map(|n| policy.backoff(n).as_secs()).collect();
+        assert_eq!(delays, [1, 2, 4, 5, 5]);
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_secs(5));
+    }
+
+    #[tokio::test]
+    async fn supervisor_restarts_failed_task_until_attempts_exhausted() {
+        let _guard = test_lock().lock().await;
+        let server = MockServer::builder().start().await;
+        let base_url = server.uri();
+
+        let jwt = "jwt-token";
+        let signing_key_base64 = BASE64.encode([2u8; 32]);
+        let symbol = "BTC-USD";
+
+        // Every startup fails on a missing balance.
+        Mock::given(method("GET"))
+            .and(path("/api/query_balance"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+                "message": "balance not found"
+            })))
+            .expect(3..)
+            .mount(&server)
+            .await;
+
+        let account = test_account_config("account-1", jwt, &signing_key_base64);
+        let strategy_config = StrategyConfig {
+            accounts: vec![account.clone()],
+            tasks: vec![test_task_config_with_id("task-1", symbol, &account.id)],
+            max_total_budget_usd: None,
+            allow_duplicate_symbols: false,
+            max_concurrent_startups: None,
+            environment: None,
+        };
+
+        let mut manager = TaskManager::new().with_auto_restart(RestartPolicy {
+            max_attempts: 2,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(20),
+        });
+        manager.client_builder = ClientBuilder(Arc::new(move |cfg, account_cfg, account_auth| {
+            Task::build_client_with_config_and_base_urls(
+                cfg,
+                account_cfg,
+                account_auth,
+                ClientConfig::default(),
+                &base_url,
+                &base_url,
+            )
+        }));
+        let report = manager.spawn_from_config(strategy_config).await.unwrap();
+        assert!(report.started.is_empty());
+        let [(task_id, error)] = report.failed.as_slice() else {
+            panic!("expected one failed task: {report:?}");
+        };
+        assert_eq!(task_id, "task-1");
+        assert!(error.contains("balance not found"), "{error}");
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+        let mut seen_restarting = false;
+        loop {
+            manager.supervise_restarts().await;
+            match manager.runtime_status("task-1") {
+                Some(TaskRuntimeStatus::Failed) => break,
+                Some(TaskRuntimeStatus::Restarting) => seen_restarting = true,
+                _ => {}
+            }
+            assert!(Instant::now() < deadline, "task never marked failed");
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        assert!(seen_restarting);
+        let info = &manager.restart_info_snapshot()["task-1"];
+        assert_eq!(info.restarts, 2);
+        assert!(
+            info.last_error
+                .as_deref()
+                .is_some_and(|err| err.contains("balance not found")),
+            "{info:?}"
+        );
+
+        // Permanently failed tasks are not restarted again.
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        manager.supervise_restarts().await;
+        assert_eq!(
+            manager.runtime_status("task-1"),
+            Some(TaskRuntimeStatus::Failed)
+        );
+        manager.stop_task("task-1").await.unwrap();
+        assert_eq!(manager.runtime_status("task-1"), None);
+    }
+
+    #[test]
+    fn task_manager_pause_and_resume_error_when_missing() {
+        let manager = TaskManager::new();
+        assert!(
+            manager
+                .pause_task("missing")
+                .unwrap_err()
+                .to_string()
+                .contains("not found")
+        );
+        assert!(manager.resume_task("missing").is_err());
+    }
+
     #[tokio::test]
     async fn task_manager_stop_task_only_stops_selected() {
         let _guard = test_lock().lock().await;
@@ -3506,12 +6724,17 @@ mod tests {
                 test_task_config_with_id("task-1", symbol_1, &account.id),
                 test_task_config_with_id("task-2", symbol_2, &account.id),
             ],
+            max_total_budget_usd: None,
+            allow_duplicate_symbols: false,
+            max_concurrent_startups: None,
+            environment: None,
         };
 
         let mut manager = TaskManager::new();
         let client_config = ClientConfig {
             timeout: Duration::from_secs(60),
             connect_timeout: Duration::from_secs(30),
+            ..ClientConfig::default()
         };
         manager
             .spawn_from_config_with_client_builder(
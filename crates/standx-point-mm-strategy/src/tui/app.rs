@@ -10,6 +10,13 @@
 [UPDATE]: 2026-02-10 Add active modal state to AppState
 [UPDATE]: 2026-02-10 Allow dead_code on modal scaffolding
 [UPDATE]: 2026-02-10 Implement modal submit flows for accounts and tasks
+[UPDATE]: 2026-10-16 Retry transient authenticate failures
+[UPDATE]: 2026-10-16 Add kill-switch modal and emergency flatten
+[UPDATE]: 2026-10-16 Toggle pause/resume on the selected task
+[UPDATE]: 2026-10-17 Add manual order placement and highlighted order cancel
+[UPDATE]: 2026-10-17 Guard task deletion against live tasks and add a timed undo
+[UPDATE]: 2026-10-17 Report startup failures from SpawnReport in the status line
+[UPDATE]: 2026-10-17 Surface stale prices, guard flattens, and task failures from the event bus
 */
 
 use std::collections::HashMap;
@@ -23,18 +30,26 @@ use ratatui::widgets::ListState;
 use rust_decimal::Decimal;
 use standx_point_adapter::auth::{EvmWalletSigner, SolanaWalletSigner};
 use standx_point_adapter::{
-    AuthManager, Balance, Chain, Order, Position, StandxClient, WalletSigner,
+    AuthManager, BackoffPolicy, Balance, CancelOrderRequest, Chain, Order, OrderSubmission,
+    Position, StandxClient, WalletSigner,
 };
-use standx_point_mm_strategy::TaskManager;
+use standx_point_mm_strategy::events::GuardActionKind;
 use standx_point_mm_strategy::metrics::TaskMetricsSnapshot;
 use standx_point_mm_strategy::task::TaskRuntimeStatus;
+use standx_point_mm_strategy::{RuntimeEvent, TaskManager};
 use tokio::sync::Mutex as TokioMutex;
 use uuid::Uuid;
 
 use crate::cli::interactive::build_strategy_config;
 use crate::state::storage::{Account as StoredAccount, Storage, Task as StoredTask};
-use crate::tui::runtime::LIVE_REFRESH_INTERVAL;
-use crate::tui::ui::modal::{CreateAccountModal, CreateTaskModal};
+use crate::tui::runtime::{LIVE_REFRESH_INTERVAL, build_trading_client};
+use crate::tui::ui::modal::{
+    ConfirmDeleteTaskModal, ConfirmKillSwitchModal, ConfirmOrderModal, CreateAccountModal,
+    CreateTaskModal, NewOrderModal, OrderAction,
+};
+
+/// How long a deleted task can be restored with `u`.
+pub(super) const TASK_UNDO_WINDOW: Duration = Duration::from_secs(10);
 
 #[allow(dead_code)]
 pub(super) enum AppMode {
@@ -46,6 +61,10 @@ pub(super) enum AppMode {
 pub(super) enum ActiveModal {
     CreateAccount(CreateAccountModal),
     CreateTask(CreateTaskModal),
+    ConfirmKillSwitch(ConfirmKillSwitchModal),
+    NewOrder(NewOrderModal),
+    ConfirmOrder(ConfirmOrderModal),
+    ConfirmDeleteTask(ConfirmDeleteTaskModal),
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -89,6 +108,12 @@ impl LiveTaskData {
     }
 }
 
+/// Task removed from storage that can still be restored.
+pub(super) struct DeletedTask {
+    pub(super) task: StoredTask,
+    pub(super) deleted_at: Instant,
+}
+
 pub(super) struct AppState {
     pub(super) storage: Arc<Storage>,
     pub(super) task_manager: Arc<TokioMutex<TaskManager>>,
@@ -100,6 +125,9 @@ pub(super) struct AppState {
     pub(super) last_refresh: Instant,
     pub(super) last_live_refresh: Instant,
     pub(super) live_data: HashMap<String, LiveTaskData>,
+    /// Highlighted row in the open orders table
+    pub(super) selected_order: usize,
+    pub(super) recently_deleted: Option<DeletedTask>,
     pub(super) active_modal: Option<ActiveModal>,
 }
 
@@ -118,6 +146,8 @@ impl AppState {
             last_refresh: Instant::now() - Duration::from_secs(10),
             last_live_refresh: Instant::now() - LIVE_REFRESH_INTERVAL,
             live_data: HashMap::new(),
+            selected_order: 0,
+            recently_deleted: None,
             active_modal: None,
         }
     }
@@ -157,6 +187,64 @@ impl AppState {
         Ok(())
     }
 
+    pub(super) async fn open_confirm_kill_switch(&mut self) {
+        let task_count = self
+            .task_manager
+            .lock()
+            .await
+            .runtime_status_snapshot()
+            .len();
+        self.active_modal = Some(ActiveModal::ConfirmKillSwitch(ConfirmKillSwitchModal::new(
+            task_count,
+        )));
+    }
+
+    pub(super) fn open_new_order(&mut self) -> Result<()> {
+        let task = self
+            .selected_task()
+            .ok_or_else(|| anyhow!("no task selected"))?;
+        self.active_modal = Some(ActiveModal::NewOrder(NewOrderModal::new(
+            task.symbol.clone(),
+        )));
+        Ok(())
+    }
+
+    pub(super) fn open_confirm_order(&mut self, action: OrderAction) {
+        self.active_modal = Some(ActiveModal::ConfirmOrder(ConfirmOrderModal::new(action)));
+    }
+
+    pub(super) fn open_confirm_cancel_order(&mut self) -> Result<()> {
+        let order = self
+            .selected_order()
+            .ok_or_else(|| anyhow!("no open order highlighted"))?;
+        let action = OrderAction::cancel(order);
+        self.open_confirm_order(action);
+        Ok(())
+    }
+
+    /// Ask before deleting the selected task; live tasks must be stopped first.
+    pub(super) async fn open_confirm_delete_task(&mut self) -> Result<()> {
+        let task_id = self
+            .selected_task()
+            .map(|task| task.id.clone())
+            .ok_or_else(|| anyhow!("no task selected"))?;
+        let status = self.task_manager.lock().await.runtime_status(&task_id);
+        if matches!(
+            status,
+            Some(
+                TaskRuntimeStatus::Running
+                    | TaskRuntimeStatus::Paused
+                    | TaskRuntimeStatus::Restarting
+            )
+        ) {
+            return Err(anyhow!("task {task_id} is running; stop it first"));
+        }
+        self.active_modal = Some(ActiveModal::ConfirmDeleteTask(ConfirmDeleteTaskModal::new(
+            task_id,
+        )));
+        Ok(())
+    }
+
     pub(super) fn close_modal(&mut self) {
         self.active_modal = None;
     }
@@ -175,6 +263,12 @@ impl AppState {
         self.live_data.get(&task.id)
     }
 
+    pub(super) fn selected_order(&self) -> Option<&Order> {
+        self.selected_live_data()?
+            .open_orders
+            .get(self.selected_order)
+    }
+
     pub(super) fn next_tab(&mut self) {
         self.current_tab = match self.current_tab {
             Tab::Dashboard => Tab::Create,
@@ -229,6 +323,48 @@ impl AppState {
         Ok(())
     }
 
+    pub(super) async fn submit_delete_task(&mut self, task_id: String) -> Result<()> {
+        let task = self
+            .tasks
+            .iter()
+            .find(|task| task.id == task_id)
+            .cloned()
+            .ok_or_else(|| anyhow!("task not found: {task_id}"))?;
+        self.storage
+            .delete_task(&task_id)
+            .await
+            .context("delete task")?;
+        self.live_data.remove(&task_id);
+        self.recently_deleted = Some(DeletedTask {
+            task,
+            deleted_at: Instant::now(),
+        });
+        self.refresh_tasks().await?;
+        self.status_message = format!(
+            "task deleted: {task_id} (press u within {}s to undo)",
+            TASK_UNDO_WINDOW.as_secs()
+        );
+        Ok(())
+    }
+
+    /// Restore the most recently deleted task if it is still inside the undo window.
+    pub(super) async fn undo_delete_task(&mut self) -> Result<()> {
+        let Some(deleted) = self.recently_deleted.take() else {
+            return Err(anyhow!("nothing to undo"));
+        };
+        if deleted.deleted_at.elapsed() > TASK_UNDO_WINDOW {
+            return Err(anyhow!("undo window expired for task {}", deleted.task.id));
+        }
+        let task_id = deleted.task.id.clone();
+        self.storage
+            .create_task(deleted.task)
+            .await
+            .context("restore task")?;
+        self.refresh_tasks().await?;
+        self.status_message = format!("task restored: {task_id}");
+        Ok(())
+    }
+
     pub(super) async fn start_selected_task(&mut self) -> Result<()> {
         let task = self
             .selected_task()
@@ -243,8 +379,11 @@ impl AppState {
             self.status_message = format!("task already running: {}", task.id);
             return Ok(());
         }
-        manager.spawn_from_config(config).await?;
-        self.status_message = format!("task started: {}", task.id);
+        let report = manager.spawn_from_config(config).await?;
+        self.status_message = match report.failed.first() {
+            Some((task_id, error)) => format!("task failed to start: {task_id}: {error}"),
+            None => format!("task started: {}", task.id),
+        };
         Ok(())
     }
 
@@ -260,6 +399,84 @@ impl AppState {
         Ok(())
     }
 
+    /// Pause quoting on the selected task, or resume it if already paused.
+    pub(super) async fn toggle_pause_selected_task(&mut self) -> Result<()> {
+        let task = self
+            .selected_task()
+            .cloned()
+            .ok_or_else(|| anyhow!("no task selected"))?;
+
+        let manager = self.task_manager.lock().await;
+        match manager.runtime_status(&task.id) {
+            Some(TaskRuntimeStatus::Paused) => {
+                manager.resume_task(&task.id)?;
+                self.status_message = format!("task resumed: {}", task.id);
+            }
+            _ => {
+                manager.pause_task(&task.id)?;
+                self.status_message = format!("task paused: {}", task.id);
+            }
+        }
+        Ok(())
+    }
+
+    /// Flatten every running task and stop them all.
+    pub(super) async fn emergency_flatten(&mut self) -> Result<()> {
+        let mut manager = self.task_manager.lock().await;
+        manager.emergency_flatten().await
+    }
+
+    /// Send a confirmed manual order action with the selected task's account.
+    pub(super) async fn submit_order_action(&mut self, action: OrderAction) -> Result<()> {
+        let task = self
+            .selected_task()
+            .cloned()
+            .ok_or_else(|| anyhow!("no task selected"))?;
+        let account = self
+            .storage
+            .get_account(&task.account_id)
+            .await
+            .ok_or_else(|| anyhow!("account not found: {}", task.account_id))?;
+        let client = build_trading_client(&account)?;
+        let summary = action.summary();
+
+        match action {
+            OrderAction::Place(request) => {
+                let reference = match client.new_order_checked(request).await? {
+                    OrderSubmission::Accepted(response) => response.request_id,
+                    OrderSubmission::Landed(order) => order.cl_ord_id,
+                };
+                self.status_message = format!("order placed: {summary} ({reference})");
+            }
+            OrderAction::Cancel { order_id, .. } => {
+                client
+                    .cancel_order(CancelOrderRequest {
+                        order_id: Some(order_id),
+                        cl_ord_id: None,
+                    })
+                    .await?;
+                self.status_message = format!("order cancelled: {summary}");
+            }
+        }
+        self.last_live_refresh = Instant::now() - LIVE_REFRESH_INTERVAL;
+        Ok(())
+    }
+
+    /// Show bus events an operator should notice in the status line.
+    pub(super) fn apply_runtime_event(&mut self, event: &RuntimeEvent) {
+        if let Some(message) = runtime_event_status(event) {
+            self.status_message = message;
+        }
+    }
+
+    pub(super) fn move_order_selection(&mut self, delta: isize) {
+        let count = self
+            .selected_live_data()
+            .map_or(0, |data| data.open_orders.len());
+        let last = count.saturating_sub(1) as isize;
+        self.selected_order = (self.selected_order as isize + delta).clamp(0, last) as usize;
+    }
+
     pub(super) fn move_selection(&mut self, delta: isize) {
         if self.tasks.is_empty() {
             self.list_state.select(None);
@@ -268,10 +485,36 @@ impl AppState {
         let current = self.list_state.selected().unwrap_or(0) as isize;
         let next = (current + delta).clamp(0, (self.tasks.len() - 1) as isize) as usize;
         self.list_state.select(Some(next));
+        self.selected_order = 0;
         self.last_live_refresh = Instant::now() - LIVE_REFRESH_INTERVAL;
     }
 }
 
+fn runtime_event_status(event: &RuntimeEvent) -> Option<String> {
+    match event {
+        RuntimeEvent::PriceStale {
+            task_id,
+            symbol,
+            age_ms,
+        } => Some(format!(
+            "price stale: {task_id} {symbol} ({age_ms}ms); quotes pulled"
+        )),
+        RuntimeEvent::PriceRecovered { task_id, symbol } => {
+            Some(format!("price recovered: {task_id} {symbol}"))
+        }
+        RuntimeEvent::GuardAction {
+            task_id,
+            symbol,
+            action: GuardActionKind::Flatten,
+            qty,
+        } => Some(format!("guard flattened: {task_id} {symbol} qty {qty}")),
+        RuntimeEvent::TaskFailed { task_id, error } => {
+            Some(format!("task failed: {task_id}: {error}"))
+        }
+        _ => None,
+    }
+}
+
 fn default_task_symbols() -> Vec<String> {
     vec![
         String::from("BTC-USD"),
@@ -290,7 +533,7 @@ async fn authenticate_account(private_key: &str, chain: Chain) -> Result<(String
                 .map_err(|err| anyhow!("invalid EVM private key: {err}"))?;
             let address = wallet.address().to_string();
             let login = auth
-                .authenticate(&wallet, 7 * 24 * 60 * 60)
+                .authenticate_with_retry(&wallet, 7 * 24 * 60 * 60, &BackoffPolicy::default())
                 .await
                 .map_err(|err| anyhow!("authenticate failed: {err}"))?;
             (address, login)
@@ -300,7 +543,7 @@ async fn authenticate_account(private_key: &str, chain: Chain) -> Result<(String
                 .map_err(|err| anyhow!("invalid Solana private key: {err}"))?;
             let address = wallet.address().to_string();
             let login = auth
-                .authenticate(&wallet, 7 * 24 * 60 * 60)
+                .authenticate_with_retry(&wallet, 7 * 24 * 60 * 60, &BackoffPolicy::default())
                 .await
                 .map_err(|err| anyhow!("authenticate failed: {err}"))?;
             (address, login)
@@ -315,3 +558,38 @@ async fn authenticate_account(private_key: &str, chain: Chain) -> Result<(String
 
     Ok((wallet_address, login_response.token, signing_key))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn runtime_event_status_reports_alerting_events_only() {
+        let stale = RuntimeEvent::PriceStale {
+            task_id: "t-1".to_string(),
+            symbol: "BTC-USD".to_string(),
+            age_ms: 20_000,
+        };
+        assert_eq!(
+            runtime_event_status(&stale).as_deref(),
+            Some("price stale: t-1 BTC-USD (20000ms); quotes pulled")
+        );
+        let recovered = RuntimeEvent::PriceRecovered {
+            task_id: "t-1".to_string(),
+            symbol: "BTC-USD".to_string(),
+        };
+        assert_eq!(
+            runtime_event_status(&recovered).as_deref(),
+            Some("price recovered: t-1 BTC-USD")
+        );
+        let placed = RuntimeEvent::OrderPlaced {
+            task_id: "t-1".to_string(),
+            symbol: "BTC-USD".to_string(),
+            cl_ord_id: "c1".to_string(),
+            side: "buy".to_string(),
+            price: Decimal::ONE,
+            qty: Decimal::ONE,
+        };
+        assert_eq!(runtime_event_status(&placed), None);
+    }
+}
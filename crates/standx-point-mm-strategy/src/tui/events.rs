@@ -6,13 +6,17 @@
 [UPDATE]: 2026-02-09 Extract key handling match logic from TUI runtime
 [UPDATE]: 2026-02-09 Add tab switching hotkeys
 [UPDATE]: 2026-02-10 Wire modal input handling and submission
+[UPDATE]: 2026-10-16 Add Ctrl+K kill switch with confirmation modal
+[UPDATE]: 2026-10-16 Add p hotkey to pause/resume the selected task
+[UPDATE]: 2026-10-17 Add o/c hotkeys for confirmed manual order placement and cancel
+[UPDATE]: 2026-10-17 Add d/u hotkeys for confirmed task deletion and undo
 */
 
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use standx_point_adapter::Chain;
 
 use super::app::{ActiveModal, AppState, Tab};
-use super::ui::modal::ModalAction;
+use super::ui::modal::{ModalAction, OrderAction};
 
 enum ModalSubmit {
     CreateAccount {
@@ -27,17 +31,28 @@ enum ModalSubmit {
         risk_level: String,
         budget_usd: String,
     },
+    KillSwitch,
+    ReviewOrder(OrderAction),
+    ConfirmOrder,
+    DeleteTask(String),
 }
 
 /// Handles key events for the TUI.
 ///
 /// Returns `true` if quit is requested, `false` otherwise.
-pub(super) async fn handle_key_event(app: &mut AppState, key: KeyCode) -> bool {
+pub(super) async fn handle_key_event(app: &mut AppState, key: KeyEvent) -> bool {
     if app.active_modal.is_some() {
-        return handle_modal_key_event(app, key).await;
+        return handle_modal_key_event(app, key.code).await;
     }
 
-    match key {
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        if key.code == KeyCode::Char('k') {
+            app.open_confirm_kill_switch().await;
+        }
+        return false;
+    }
+
+    match key.code {
         KeyCode::Char('q') => true,
         KeyCode::Char('r') => {
             if let Err(err) = app.refresh_tasks().await {
@@ -57,6 +72,12 @@ pub(super) async fn handle_key_event(app: &mut AppState, key: KeyCode) -> bool {
             }
             false
         }
+        KeyCode::Char('p') => {
+            if let Err(err) = app.toggle_pause_selected_task().await {
+                app.status_message = format!("pause/resume task failed: {err}");
+            }
+            false
+        }
         KeyCode::Tab | KeyCode::Char('l') => {
             app.next_tab();
             false
@@ -79,6 +100,38 @@ pub(super) async fn handle_key_event(app: &mut AppState, key: KeyCode) -> bool {
             }
             false
         }
+        KeyCode::Char('d') => {
+            if let Err(err) = app.open_confirm_delete_task().await {
+                app.status_message = format!("delete task failed: {err}");
+            }
+            false
+        }
+        KeyCode::Char('u') => {
+            if let Err(err) = app.undo_delete_task().await {
+                app.status_message = format!("undo delete failed: {err:#}");
+            }
+            false
+        }
+        KeyCode::Char('o') => {
+            if let Err(err) = app.open_new_order() {
+                app.status_message = format!("open new order failed: {err}");
+            }
+            false
+        }
+        KeyCode::Char('c') => {
+            if let Err(err) = app.open_confirm_cancel_order() {
+                app.status_message = format!("cancel order failed: {err}");
+            }
+            false
+        }
+        KeyCode::Char('[') => {
+            app.move_order_selection(-1);
+            false
+        }
+        KeyCode::Char(']') => {
+            app.move_order_selection(1);
+            false
+        }
         KeyCode::Up => {
             app.move_selection(-1);
             false
@@ -129,6 +182,35 @@ async fn handle_modal_key_event(app: &mut AppState, key: KeyCode) -> bool {
             }
             (action, submit)
         }
+        Some(ActiveModal::ConfirmKillSwitch(modal)) => {
+            let action = modal.handle_key(key);
+            let submit = (action == ModalAction::Submit).then_some(ModalSubmit::KillSwitch);
+            (action, submit)
+        }
+        Some(ActiveModal::NewOrder(modal)) => {
+            let action = modal.handle_key(key);
+            let mut submit = None;
+            if action == ModalAction::Submit {
+                match modal.build_request() {
+                    Ok(request) => {
+                        submit = Some(ModalSubmit::ReviewOrder(OrderAction::Place(request)))
+                    }
+                    Err(err) => status_update = Some(err),
+                }
+            }
+            (action, submit)
+        }
+        Some(ActiveModal::ConfirmDeleteTask(modal)) => {
+            let action = modal.handle_key(key);
+            let submit = (action == ModalAction::Submit)
+                .then(|| ModalSubmit::DeleteTask(modal.task_id().to_string()));
+            (action, submit)
+        }
+        Some(ActiveModal::ConfirmOrder(modal)) => {
+            let action = modal.handle_key(key);
+            let submit = (action == ModalAction::Submit).then_some(ModalSubmit::ConfirmOrder);
+            (action, submit)
+        }
         None => return false,
     };
 
@@ -143,6 +225,30 @@ async fn handle_modal_key_event(app: &mut AppState, key: KeyCode) -> bool {
 
     if let Some(submit) = submit {
         let result = match submit {
+            ModalSubmit::KillSwitch => {
+                app.close_modal();
+                return match app.emergency_flatten().await {
+                    Ok(()) => true,
+                    Err(err) => {
+                        app.status_message = format!("kill switch failed: {err:#}");
+                        false
+                    }
+                };
+            }
+            ModalSubmit::ReviewOrder(action) => {
+                app.open_confirm_order(action);
+                return false;
+            }
+            ModalSubmit::ConfirmOrder => {
+                let Some(ActiveModal::ConfirmOrder(modal)) = app.active_modal.take() else {
+                    return false;
+                };
+                if let Err(err) = app.submit_order_action(modal.into_action()).await {
+                    app.status_message = format!("order action failed: {err:#}");
+                }
+                return false;
+            }
+            ModalSubmit::DeleteTask(task_id) => app.submit_delete_task(task_id).await,
             ModalSubmit::CreateAccount {
                 name,
                 private_key,
@@ -10,6 +10,7 @@
 [UPDATE]: 2026-02-09 Add tab bar and tab-specific views
 [UPDATE]: 2026-02-10 Use shared draw_tabs renderer
 [UPDATE]: 2026-02-10 Move runtime logic to runtime.rs and keep thin re-exports
+[UPDATE]: 2026-10-17 Share build_live_client with the status subcommand
 */
 
 mod app;
@@ -19,4 +20,5 @@ mod state;
 mod terminal;
 pub mod ui;
 
+pub(crate) use runtime::build_live_client;
 pub use runtime::run_tui;
@@ -11,12 +11,23 @@
 [UPDATE]: 2026-02-10 Use shared draw_tabs renderer
 [UPDATE]: 2026-02-10 Move runtime logic out of tui/mod.rs
 [UPDATE]: 2026-02-10 Render active modal overlay in TUI draw loop
+[UPDATE]: 2026-10-16 Populate live client wallet address from account key or jwt claim
+[UPDATE]: 2026-10-16 Route full key events and show the Ctrl+K kill switch hotkey
+[UPDATE]: 2026-10-16 Show paused tasks and the p pause/resume hotkey
+[UPDATE]: 2026-10-16 Label restarting and permanently failed tasks
+[UPDATE]: 2026-10-17 Match 404s through StandxError::is_not_found
+[UPDATE]: 2026-10-17 Add signing trading client and manual order hotkeys
+[UPDATE]: 2026-10-17 Show d/u delete and undo hotkeys
+[UPDATE]: 2026-10-17 Query open orders through StandxClient::query_all_open_orders
+[UPDATE]: 2026-10-17 Drain the runtime event bus into the status line every tick
 */
 
 use std::sync::Arc;
 use std::time::Duration;
 
 use anyhow::{Context, Result, anyhow};
+use base64::Engine as _;
+use base64::engine::general_purpose::STANDARD;
 use crossterm::event::Event as CrosstermEvent;
 use ratatui::layout::{Alignment, Constraint, Direction, Layout};
 use ratatui::style::{Color, Modifier, Style};
@@ -27,11 +38,10 @@ use tokio::sync::Mutex as TokioMutex;
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 
-use standx_point_adapter::{
-    Chain, Credentials, Order, OrderStatus, PaginatedOrders, StandxClient, StandxError,
-};
+use standx_point_adapter::{Chain, Credentials, Ed25519Signer, Order, StandxClient};
 use standx_point_mm_strategy::TaskManager;
-use standx_point_mm_strategy::task::TaskRuntimeStatus;
+use standx_point_mm_strategy::config::AccountConfig;
+use standx_point_mm_strategy::task::{TaskRuntimeStatus, static_wallet_address};
 
 use super::app::{ActiveModal, AppState, Tab, UiSnapshot};
 use super::events::handle_key_event;
@@ -63,17 +73,29 @@ pub(super) fn draw_footer(frame: &mut ratatui::Frame, area: ratatui::layout::Rec
         Span::styled("[a]", key_style),
         Span::raw(" Account  "),
         Span::styled("[t]", key_style),
-        Span::raw(" Task"),
+        Span::raw(" Task  "),
+        Span::styled("[o]", key_style),
+        Span::raw(" Order  "),
+        Span::styled("[[/]]", key_style),
+        Span::raw(" Highlight Order  "),
+        Span::styled("[c]", key_style),
+        Span::raw(" Cancel Order"),
     ]);
     let line2 = Line::from(vec![
         Span::styled("[s]", key_style),
         Span::raw(" Start  "),
         Span::styled("[x]", key_style),
         Span::raw(" Stop  "),
+        Span::styled("[p]", key_style),
+        Span::raw(" Pause/Resume  "),
+        Span::styled("[d/u]", key_style),
+        Span::raw(" Delete/Undo  "),
         Span::styled("[r]", key_style),
         Span::raw(" Refresh  "),
         Span::styled("[q]", key_style),
         Span::raw(" Quit  "),
+        Span::styled("[Ctrl+K]", key_style),
+        Span::raw(" Kill Switch  "),
         Span::raw(format!("Status: {}", app.status_message)),
     ]);
 
@@ -128,7 +150,24 @@ pub(crate) fn build_live_client(account: &StoredAccount) -> Result<StandxClient>
     let mut client =
         StandxClient::new().map_err(|err| anyhow!("create StandxClient failed: {err}"))?;
     let chain = account.chain.unwrap_or(Chain::Bsc);
-    let wallet_address = "unknown".to_string();
+    let account_config = AccountConfig {
+        id: account.id.clone(),
+        private_key: Some(account.private_key.clone()).filter(|key| !key.trim().is_empty()),
+        jwt_token: Some(account.jwt_token.clone()),
+        signing_key: Some(account.signing_key.clone()),
+        chain,
+        keystore_path: None,
+        mnemonic: None,
+        mnemonic_index: None,
+        wallet_address: None,
+        max_concurrent_order_actions: None,
+        rate_limit: None,
+    };
+    let wallet_address =
+        static_wallet_address(&account_config, &account.jwt_token).unwrap_or_else(|| {
+            tracing::warn!(account_id = %account.id, "wallet address unavailable for account");
+            String::new()
+        });
     client.set_credentials(Credentials {
         jwt_token: account.jwt_token.clone(),
         wallet_address,
@@ -137,45 +176,18 @@ pub(crate) fn build_live_client(account: &StoredAccount) -> Result<StandxClient>
     Ok(client)
 }
 
-pub(crate) async fn query_open_orders_with_fallback(
-    client: &StandxClient,
-    symbol: &str,
-) -> Result<PaginatedOrders> {
-    let open_orders = match client.query_open_orders(Some(symbol)).await {
-        Ok(orders) => orders,
-        Err(StandxError::Api { code: 404, message }) => {
-            tracing::warn!(
-                symbol = %symbol,
-                "query_open_orders returned 404; treating as no open orders: {message}"
-            );
-            return Ok(PaginatedOrders {
-                page_size: 0,
-                result: Vec::new(),
-                total: 0,
-            });
-        }
-        Err(err) => return Err(anyhow!(err)).context("query_open_orders failed"),
-    };
-
-    if open_orders.total > open_orders.result.len() as u32 {
-        let limit = open_orders.total;
-        match client
-            .query_orders(Some(symbol), Some(OrderStatus::Open), Some(limit))
-            .await
-        {
-            Ok(expanded) => return Ok(expanded),
-            Err(err) => {
-                tracing::warn!(
-                    symbol = %symbol,
-                    total = open_orders.total,
-                    page_size = open_orders.page_size,
-                    "query_orders failed while expanding open orders: {err}"
-                );
-            }
-        }
-    }
-
-    Ok(open_orders)
+/// Live client that can also sign order requests with the account's ed25519 key.
+pub(crate) fn build_trading_client(account: &StoredAccount) -> Result<StandxClient> {
+    let mut client = build_live_client(account)?;
+    let decoded = STANDARD
+        .decode(account.signing_key.trim())
+        .context("decode signing_key (base64) failed")?;
+    let secret_key: [u8; 32] = decoded
+        .as_slice()
+        .try_into()
+        .map_err(|_| anyhow!("signing_key must decode to 32 bytes"))?;
+    client.set_request_signer(Ed25519Signer::from_secret_key(&secret_key));
+    Ok(client)
 }
 
 pub async fn run_tui(
@@ -189,14 +201,15 @@ pub async fn run_tui(
 
     tokio::task::spawn_blocking(move || {
         while !input_shutdown_clone.is_cancelled() {
-            if crossterm::event::poll(INPUT_POLL_INTERVAL).unwrap_or(false) {
-                if let Ok(event) = crossterm::event::read() {
-                    let _ = event_tx.send(UiEvent::Input(event));
-                }
+            if crossterm::event::poll(INPUT_POLL_INTERVAL).unwrap_or(false)
+                && let Ok(event) = crossterm::event::read()
+            {
+                let _ = event_tx.send(UiEvent::Input(event));
             }
         }
     });
 
+    let mut runtime_events = task_manager.lock().await.subscribe_events();
     let mut app = AppState::new(storage, task_manager);
     app.refresh_accounts().await?;
     app.refresh_tasks().await?;
@@ -207,25 +220,23 @@ pub async fn run_tui(
     while !should_quit {
         tokio::select! {
             _ = tick.tick() => {
-                if app.last_refresh.elapsed() > Duration::from_secs(2) {
-                    if let Err(err) = app.refresh_tasks().await {
-                        app.status_message = format!("refresh tasks failed: {err}");
-                    }
+                while let Some(event) = runtime_events.try_recv() {
+                    app.apply_runtime_event(&event);
+                }
+                if app.last_refresh.elapsed() > Duration::from_secs(2)
+                    && let Err(err) = app.refresh_tasks().await
+                {
+                    app.status_message = format!("refresh tasks failed: {err}");
                 }
                 if let Err(err) = app.refresh_live_data().await {
                     app.status_message = format!("refresh live data failed: {err}");
                 }
             }
             maybe_event = event_rx.recv() => {
-                if let Some(event) = maybe_event {
-                    match event {
-                        UiEvent::Input(CrosstermEvent::Key(key)) => {
-                            if handle_key_event(&mut app, key.code).await {
-                                should_quit = true;
-                            }
-                        }
-                        _ => {}
-                    }
+                if let Some(UiEvent::Input(CrosstermEvent::Key(key))) = maybe_event
+                    && handle_key_event(&mut app, key).await
+                {
+                    should_quit = true;
                 }
             }
         }
@@ -312,6 +323,10 @@ fn draw_ui(frame: &mut ratatui::Frame, app: &mut AppState, snapshot: &UiSnapshot
         let modal = match active_modal {
             ActiveModal::CreateAccount(modal) => modal.to_modal(),
             ActiveModal::CreateTask(modal) => modal.to_modal(),
+            ActiveModal::ConfirmKillSwitch(modal) => modal.to_modal(),
+            ActiveModal::NewOrder(modal) => modal.to_modal(),
+            ActiveModal::ConfirmOrder(modal) => modal.to_modal(),
+            ActiveModal::ConfirmDeleteTask(modal) => modal.to_modal(),
         };
         let modal_area = centered_rect(area, 60, 60);
         draw_modal(frame, modal_area, &modal);
@@ -345,6 +360,9 @@ fn centered_rect(
 pub(crate) fn runtime_label(status: Option<&TaskRuntimeStatus>) -> String {
     match status {
         Some(TaskRuntimeStatus::Running) => "running".to_string(),
+        Some(TaskRuntimeStatus::Paused) => "paused".to_string(),
+        Some(TaskRuntimeStatus::Restarting) => "restarting".to_string(),
+        Some(TaskRuntimeStatus::Failed) => "failed".to_string(),
         Some(TaskRuntimeStatus::Finished) => "finished".to_string(),
         None => "stopped".to_string(),
     }
@@ -5,6 +5,8 @@
 [UPDATE]: 2026-02-09 Add placeholder module for TUI refactor
 [UPDATE]: 2026-02-09 Move AppState refresh helpers from app.rs
 [UPDATE]: 2026-02-10 Add price snapshot refresh for live task data
+[UPDATE]: 2026-10-17 Keep the highlighted open order within the refreshed list
+[UPDATE]: 2026-10-17 Load open orders with StandxClient::query_all_open_orders
 */
 
 use std::time::Instant;
@@ -12,9 +14,7 @@ use std::time::Instant;
 use anyhow::{Result, anyhow};
 
 use super::app::{AppState, LiveTaskData, PriceSnapshot, UiSnapshot};
-use crate::tui::runtime::{
-    LIVE_REFRESH_INTERVAL, build_live_client, query_open_orders_with_fallback,
-};
+use crate::tui::runtime::{LIVE_REFRESH_INTERVAL, build_live_client};
 
 impl AppState {
     pub(super) async fn refresh_accounts(&mut self) -> Result<()> {
@@ -29,11 +29,11 @@ impl AppState {
             self.list_state.select(None);
         } else if self.list_state.selected().is_none() {
             self.list_state.select(Some(0));
-        } else if let Some(selected) = self.list_state.selected() {
-            if selected >= self.tasks.len() {
-                self.list_state
-                    .select(Some(self.tasks.len().saturating_sub(1)));
-            }
+        } else if let Some(selected) = self.list_state.selected()
+            && selected >= self.tasks.len()
+        {
+            self.list_state
+                .select(Some(self.tasks.len().saturating_sub(1)));
         }
         self.last_refresh = Instant::now();
         Ok(())
@@ -80,8 +80,8 @@ impl AppState {
                 let mark_price = response.mark_price;
                 let last_price = response.last_price;
                 let min_price = match last_price.as_ref() {
-                    Some(last) => std::cmp::min(mark_price.clone(), last.clone()),
-                    None => mark_price.clone(),
+                    Some(last) => std::cmp::min(mark_price, *last),
+                    None => mark_price,
                 };
                 data.price_data = Some(PriceSnapshot {
                     mark_price,
@@ -102,7 +102,7 @@ impl AppState {
             Err(err) => errors.push(format!("positions: {err}")),
         }
 
-        match query_open_orders_with_fallback(&client, symbol).await {
+        match client.query_all_open_orders(Some(symbol)).await {
             Ok(orders) => data.open_orders = orders.result,
             Err(err) => errors.push(format!("open_orders: {err}")),
         }
@@ -114,6 +114,9 @@ impl AppState {
             Some(errors.join(" | "))
         };
 
+        self.selected_order = self
+            .selected_order
+            .min(data.open_orders.len().saturating_sub(1));
         self.live_data.insert(task.id.clone(), data);
         self.last_live_refresh = Instant::now();
         Ok(())
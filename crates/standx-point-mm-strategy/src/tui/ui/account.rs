@@ -5,11 +5,15 @@
 [UPDATE]: 2026-02-09 Add placeholder module for TUI refactor
 [UPDATE]: 2026-02-09 Move draw_account_summary from tui/mod.rs
 [UPDATE]: 2026-02-10 Render task price snapshot details
+[UPDATE]: 2026-10-16 Warn when the selected account's JWT is expired
+[UPDATE]: 2026-10-17 Show deployed USD and budget utilization
 */
 
 use ratatui::style::{Color, Style};
 use ratatui::text::{Line, Span, Text};
 use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
+use rust_decimal::Decimal;
+use standx_point_adapter::JwtClaims;
 
 use crate::tui::app::{AppState, LiveTaskData, UiSnapshot};
 use crate::tui::runtime::{border_style, format_decimal, runtime_label, signed_style};
@@ -35,6 +39,12 @@ pub(in crate::tui) fn draw_account_summary(
     };
 
     let mut lines = Vec::new();
+    if let Some(warning) = task.and_then(|task| jwt_expiry_warning(app, &task.account_id)) {
+        lines.push(Line::from(Span::styled(
+            warning,
+            Style::default().fg(Color::Red),
+        )));
+    }
     if let Some(data) = app.selected_live_data() {
         let data: &LiveTaskData = data;
         if let Some(balance) = data.balance.as_ref() {
@@ -69,18 +79,31 @@ pub(in crate::tui) fn draw_account_summary(
         }
 
         let price_line = if let Some(price) = data.price_data.as_ref() {
-            let mark = format_decimal(price.mark_price.clone(), 4);
+            let mark = format_decimal(price.mark_price, 4);
             let last = price
                 .last_price
                 .as_ref()
-                .map(|value| format_decimal(value.clone(), 4))
+                .map(|value| format_decimal(*value, 4))
                 .unwrap_or_else(|| "-".to_string());
-            let min = format_decimal(price.min_price.clone(), 4);
+            let min = format_decimal(price.min_price, 4);
             format!("Mark: {mark} | Last: {last} | Min: {min}")
         } else {
             "Mark: - | Last: - | Min: -".to_string()
         };
-        lines.push(Line::from(price_line));
+        let deployment = task
+            .and_then(|task| snapshot.metrics.get(&task.id))
+            .and_then(|metrics| {
+                let deployed = format_decimal(metrics.deployed_usd?, 2);
+                Some(match metrics.utilization_ratio {
+                    Some(ratio) => format!(
+                        " | Deployed: {deployed} ({}%)",
+                        format_decimal(ratio * Decimal::ONE_HUNDRED, 1)
+                    ),
+                    None => format!(" | Deployed: {deployed}"),
+                })
+            })
+            .unwrap_or_default();
+        lines.push(Line::from(format!("{price_line}{deployment}")));
 
         if let Some(error) = data.last_error.as_ref() {
             lines.push(Line::from(Span::styled(
@@ -105,3 +128,18 @@ pub(in crate::tui) fn draw_account_summary(
     let widget = Paragraph::new(text).block(block).wrap(Wrap { trim: true });
     frame.render_widget(widget, area);
 }
+
+fn jwt_expiry_warning(app: &AppState, account_id: &str) -> Option<String> {
+    let account = app
+        .accounts
+        .iter()
+        .find(|account| account.id == account_id)?;
+    let claims = JwtClaims::decode(&account.jwt_token).ok()?;
+    let expires_at = claims.expires_at()?;
+    claims.is_expired(chrono::Utc::now()).then(|| {
+        format!(
+            "JWT expired at {}; re-authenticate the account",
+            expires_at.format("%Y-%m-%d %H:%M:%S UTC")
+        )
+    })
+}
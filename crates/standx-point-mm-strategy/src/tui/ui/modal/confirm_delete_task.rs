@@ -0,0 +1,63 @@
+/*
+[INPUT]:  Task id pending deletion and confirmation focus state
+[OUTPUT]: Confirmation modal guarding TUI task deletion
+[POS]:    TUI UI modal confirm delete task
+[UPDATE]: 2026-10-17 Add d task deletion confirmation modal
+*/
+
+use crossterm::event::KeyCode;
+
+use super::{Field, Modal, ModalAction, handle_modal_key};
+
+/// Focus starts on "Cancel" so a stray Enter never deletes.
+pub(in crate::tui) struct ConfirmDeleteTaskModal {
+    task_id: String,
+    focus_index: usize,
+}
+
+impl ConfirmDeleteTaskModal {
+    const CANCEL_INDEX: usize = 2;
+
+    pub(in crate::tui) fn new(task_id: String) -> Self {
+        Self {
+            task_id,
+            focus_index: Self::CANCEL_INDEX,
+        }
+    }
+
+    pub(in crate::tui) fn to_modal(&self) -> Modal {
+        Modal {
+            title: "Delete Task".to_string(),
+            focus_index: self.focus_index,
+            fields: vec![
+                Field::TextInput {
+                    label: "Delete task (undo with u for a few seconds)".to_string(),
+                    value: self.task_id.clone(),
+                },
+                Field::Button {
+                    label: "Delete".to_string(),
+                    action: ModalAction::Submit,
+                },
+                Field::Button {
+                    label: "Cancel".to_string(),
+                    action: ModalAction::Cancel,
+                },
+            ],
+        }
+    }
+
+    pub(in crate::tui) fn handle_key(&mut self, key: KeyCode) -> ModalAction {
+        // The task id line is informational; keep typing out of it.
+        if matches!(key, KeyCode::Char(_) | KeyCode::Backspace) {
+            return ModalAction::None;
+        }
+        let mut modal = self.to_modal();
+        let action = handle_modal_key(&mut modal, key);
+        self.focus_index = modal.focus_index;
+        action
+    }
+
+    pub(in crate::tui) fn task_id(&self) -> &str {
+        self.task_id.as_str()
+    }
+}
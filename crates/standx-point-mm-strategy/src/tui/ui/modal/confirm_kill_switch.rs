@@ -0,0 +1,59 @@
+/*
+[INPUT]:  Kill-switch confirmation focus state
+[OUTPUT]: Confirmation modal guarding TaskManager::emergency_flatten
+[POS]:    TUI UI modal confirm kill switch
+[UPDATE]: 2026-10-16 Add Ctrl+K kill-switch confirmation modal
+*/
+
+use crossterm::event::KeyCode;
+
+use super::{Field, Modal, ModalAction, handle_modal_key};
+
+/// Focus starts on "Cancel" so a stray Enter never flattens.
+pub(in crate::tui) struct ConfirmKillSwitchModal {
+    task_count: usize,
+    focus_index: usize,
+}
+
+impl ConfirmKillSwitchModal {
+    const CANCEL_INDEX: usize = 2;
+
+    pub(in crate::tui) fn new(task_count: usize) -> Self {
+        Self {
+            task_count,
+            focus_index: Self::CANCEL_INDEX,
+        }
+    }
+
+    pub(in crate::tui) fn to_modal(&self) -> Modal {
+        Modal {
+            title: "Kill Switch".to_string(),
+            focus_index: self.focus_index,
+            fields: vec![
+                Field::TextInput {
+                    label: "Cancel all orders and market-close positions for tasks".to_string(),
+                    value: self.task_count.to_string(),
+                },
+                Field::Button {
+                    label: "Flatten & Exit".to_string(),
+                    action: ModalAction::Submit,
+                },
+                Field::Button {
+                    label: "Cancel".to_string(),
+                    action: ModalAction::Cancel,
+                },
+            ],
+        }
+    }
+
+    pub(in crate::tui) fn handle_key(&mut self, key: KeyCode) -> ModalAction {
+        // The summary line is informational; keep typing out of it.
+        if matches!(key, KeyCode::Char(_) | KeyCode::Backspace) {
+            return ModalAction::None;
+        }
+        let mut modal = self.to_modal();
+        let action = handle_modal_key(&mut modal, key);
+        self.focus_index = modal.focus_index;
+        action
+    }
+}
@@ -0,0 +1,129 @@
+/*
+[INPUT]:  Pending manual order action and confirmation focus state
+[OUTPUT]: Confirmation modal guarding manual order placement and cancels
+[POS]:    TUI UI modal confirm order action
+[UPDATE]: 2026-10-17 Add confirmation step for manual place/cancel
+*/
+
+use crossterm::event::KeyCode;
+use rust_decimal::Decimal;
+use standx_point_adapter::{NewOrderRequest, Order, OrderType, Side};
+
+use super::{Field, Modal, ModalAction, handle_modal_key};
+
+/// Manual order action awaiting confirmation.
+pub(in crate::tui) enum OrderAction {
+    Place(NewOrderRequest),
+    Cancel { order_id: i64, summary: String },
+}
+
+impl OrderAction {
+    pub(in crate::tui) fn cancel(order: &Order) -> Self {
+        Self::Cancel {
+            order_id: order.id,
+            summary: format!(
+                "#{} {}",
+                order.id,
+                order_summary(
+                    order.side,
+                    order.order_type,
+                    order.qty,
+                    &order.symbol,
+                    order.price
+                )
+            ),
+        }
+    }
+
+    pub(in crate::tui) fn summary(&self) -> String {
+        match self {
+            Self::Place(request) => {
+                let summary = order_summary(
+                    request.side,
+                    request.order_type,
+                    request.qty,
+                    &request.symbol,
+                    request.price,
+                );
+                if request.reduce_only {
+                    format!("{summary} reduce-only")
+                } else {
+                    summary
+                }
+            }
+            Self::Cancel { summary, .. } => summary.clone(),
+        }
+    }
+}
+
+/// Focus starts on "Back" so a stray Enter never sends anything.
+pub(in crate::tui) struct ConfirmOrderModal {
+    action: OrderAction,
+    focus_index: usize,
+}
+
+impl ConfirmOrderModal {
+    const BACK_INDEX: usize = 2;
+
+    pub(in crate::tui) fn new(action: OrderAction) -> Self {
+        Self {
+            action,
+            focus_index: Self::BACK_INDEX,
+        }
+    }
+
+    pub(in crate::tui) fn to_modal(&self) -> Modal {
+        let (title, label, button) = match self.action {
+            OrderAction::Place(_) => ("Confirm Order", "Place", "Place Order"),
+            OrderAction::Cancel { .. } => ("Confirm Cancel", "Cancel", "Cancel Order"),
+        };
+        Modal {
+            title: title.to_string(),
+            focus_index: self.focus_index,
+            fields: vec![
+                Field::TextInput {
+                    label: label.to_string(),
+                    value: self.action.summary(),
+                },
+                Field::Button {
+                    label: button.to_string(),
+                    action: ModalAction::Submit,
+                },
+                Field::Button {
+                    label: "Back".to_string(),
+                    action: ModalAction::Cancel,
+                },
+            ],
+        }
+    }
+
+    pub(in crate::tui) fn handle_key(&mut self, key: KeyCode) -> ModalAction {
+        // The summary line is informational; keep typing out of it.
+        if matches!(key, KeyCode::Char(_) | KeyCode::Backspace) {
+            return ModalAction::None;
+        }
+        let mut modal = self.to_modal();
+        let action = handle_modal_key(&mut modal, key);
+        self.focus_index = modal.focus_index;
+        action
+    }
+
+    pub(in crate::tui) fn into_action(self) -> OrderAction {
+        self.action
+    }
+}
+
+fn order_summary(
+    side: Side,
+    order_type: OrderType,
+    qty: Decimal,
+    symbol: &str,
+    price: Option<Decimal>,
+) -> String {
+    match price {
+        Some(price) if order_type != OrderType::Market => {
+            format!("{side:?} {order_type:?} {qty} {symbol} @ {price}")
+        }
+        _ => format!("{side:?} {order_type:?} {qty} {symbol}"),
+    }
+}
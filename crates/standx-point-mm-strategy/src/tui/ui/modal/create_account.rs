@@ -88,7 +88,7 @@ impl CreateAccountModal {
 
     fn apply_modal_state(&mut self, modal: &Modal) {
         self.focus_index = modal.focus_index;
-        if let Some(Field::TextInput { value, .. }) = modal.fields.get(0) {
+        if let Some(Field::TextInput { value, .. }) = modal.fields.first() {
             self.name = value.clone();
         }
         if let Some(Field::TextInput { value, .. }) = modal.fields.get(1) {
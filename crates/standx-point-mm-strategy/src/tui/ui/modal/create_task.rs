@@ -124,7 +124,7 @@ impl CreateTaskModal {
 
     fn apply_modal_state(&mut self, modal: &Modal) {
         self.focus_index = modal.focus_index;
-        if let Some(Field::TextInput { value, .. }) = modal.fields.get(0) {
+        if let Some(Field::TextInput { value, .. }) = modal.fields.first() {
             self.id = value.clone();
         }
         if let Some(Field::Select { selected, .. }) = modal.fields.get(1) {
@@ -8,13 +8,24 @@
 [UPDATE]: 2026-02-10 Fix modal exports to avoid duplicate structs
 [UPDATE]: 2026-02-10 Expand modal visibility for tui modules
 [UPDATE]: 2026-02-10 Add text input editing for modal fields
+[UPDATE]: 2026-10-16 Expose ConfirmKillSwitchModal
+[UPDATE]: 2026-10-17 Add NewOrderModal and ConfirmOrderModal for manual orders
+[UPDATE]: 2026-10-17 Expose ConfirmDeleteTaskModal
 */
 
+mod confirm_delete_task;
+mod confirm_kill_switch;
+mod confirm_order;
 mod create_account;
 mod create_task;
+mod new_order;
 
+pub(in crate::tui) use confirm_delete_task::ConfirmDeleteTaskModal;
+pub(in crate::tui) use confirm_kill_switch::ConfirmKillSwitchModal;
+pub(in crate::tui) use confirm_order::{ConfirmOrderModal, OrderAction};
 pub(in crate::tui) use create_account::CreateAccountModal;
 pub(in crate::tui) use create_task::CreateTaskModal;
+pub(in crate::tui) use new_order::NewOrderModal;
 
 use crossterm::event::KeyCode;
 use ratatui::layout::Rect;
@@ -108,10 +119,9 @@ pub(in crate::tui) fn handle_modal_key(modal: &mut Modal, key: KeyCode) -> Modal
             if let Some(Field::Select {
                 selected, options, ..
             }) = modal.fields.get_mut(modal.focus_index)
+                && !options.is_empty()
             {
-                if !options.is_empty() {
-                    *selected = selected.saturating_sub(1);
-                }
+                *selected = selected.saturating_sub(1);
             }
             ModalAction::None
         }
@@ -119,10 +129,9 @@ pub(in crate::tui) fn handle_modal_key(modal: &mut Modal, key: KeyCode) -> Modal
             if let Some(Field::Select {
                 selected, options, ..
             }) = modal.fields.get_mut(modal.focus_index)
+                && *selected + 1 < options.len()
             {
-                if *selected + 1 < options.len() {
-                    *selected += 1;
-                }
+                *selected += 1;
             }
             ModalAction::None
         }
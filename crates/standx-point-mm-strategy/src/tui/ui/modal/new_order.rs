@@ -0,0 +1,177 @@
+/*
+[INPUT]:  Manual order form state for the selected task's symbol
+[OUTPUT]: New order modal rendering and validated NewOrderRequest
+[POS]:    TUI UI modal new order
+[UPDATE]: 2026-10-17 Add manual order entry modal
+*/
+
+use std::str::FromStr;
+
+use crossterm::event::KeyCode;
+use rust_decimal::Decimal;
+use standx_point_adapter::{NewOrderRequest, OrderType, Side, TimeInForce};
+
+use super::{Field, Modal, ModalAction, handle_modal_key};
+
+pub(in crate::tui) struct NewOrderModal {
+    symbol: String,
+    side_index: usize,
+    type_index: usize,
+    price: String,
+    qty: String,
+    reduce_only_index: usize,
+    focus_index: usize,
+}
+
+impl NewOrderModal {
+    pub(in crate::tui) fn new(symbol: String) -> Self {
+        Self {
+            symbol,
+            side_index: 0,
+            type_index: 0,
+            price: String::new(),
+            qty: String::new(),
+            reduce_only_index: 0,
+            focus_index: 0,
+        }
+    }
+
+    pub(in crate::tui) fn to_modal(&self) -> Modal {
+        Modal {
+            title: format!("New Order {}", self.symbol),
+            focus_index: self.focus_index,
+            fields: vec![
+                Field::Select {
+                    label: "Side".to_string(),
+                    options: vec!["Buy".to_string(), "Sell".to_string()],
+                    selected: self.side_index,
+                },
+                Field::Select {
+                    label: "Type".to_string(),
+                    options: vec!["Limit".to_string(), "Market".to_string()],
+                    selected: self.type_index,
+                },
+                Field::TextInput {
+                    label: "Price".to_string(),
+                    value: self.price.clone(),
+                },
+                Field::TextInput {
+                    label: "Qty".to_string(),
+                    value: self.qty.clone(),
+                },
+                Field::Select {
+                    label: "Reduce Only".to_string(),
+                    options: vec!["No".to_string(), "Yes".to_string()],
+                    selected: self.reduce_only_index,
+                },
+                Field::Button {
+                    label: "Review".to_string(),
+                    action: ModalAction::Submit,
+                },
+                Field::Button {
+                    label: "Cancel".to_string(),
+                    action: ModalAction::Cancel,
+                },
+            ],
+        }
+    }
+
+    pub(in crate::tui) fn handle_key(&mut self, key: KeyCode) -> ModalAction {
+        let mut modal = self.to_modal();
+        let action = handle_modal_key(&mut modal, key);
+        self.apply_modal_state(&modal);
+        action
+    }
+
+    /// Validate the form; limit orders rest GTC, market orders go IOC.
+    pub(in crate::tui) fn build_request(&self) -> Result<NewOrderRequest, String> {
+        let qty = parse_positive(&self.qty).ok_or("qty must be a positive number")?;
+        let (order_type, time_in_force, price) = if self.type_index == 1 {
+            (OrderType::Market, TimeInForce::Ioc, None)
+        } else {
+            let price = parse_positive(&self.price).ok_or("limit price must be positive")?;
+            (OrderType::Limit, TimeInForce::Gtc, Some(price))
+        };
+        Ok(NewOrderRequest {
+            symbol: self.symbol.clone(),
+            side: if self.side_index == 1 {
+                Side::Sell
+            } else {
+                Side::Buy
+            },
+            order_type,
+            qty,
+            time_in_force,
+            reduce_only: self.reduce_only_index == 1,
+            price,
+            cl_ord_id: None,
+            margin_mode: None,
+            leverage: None,
+            tp_price: None,
+            sl_price: None,
+        })
+    }
+
+    fn apply_modal_state(&mut self, modal: &Modal) {
+        self.focus_index = modal.focus_index;
+        if let Some(Field::Select { selected, .. }) = modal.fields.first() {
+            self.side_index = *selected;
+        }
+        if let Some(Field::Select { selected, .. }) = modal.fields.get(1) {
+            self.type_index = *selected;
+        }
+        if let Some(Field::TextInput { value, .. }) = modal.fields.get(2) {
+            self.price = value.clone();
+        }
+        if let Some(Field::TextInput { value, .. }) = modal.fields.get(3) {
+            self.qty = value.clone();
+        }
+        if let Some(Field::Select { selected, .. }) = modal.fields.get(4) {
+            self.reduce_only_index = *selected;
+        }
+    }
+}
+
+fn parse_positive(raw: &str) -> Option<Decimal> {
+    Decimal::from_str(raw.trim())
+        .ok()
+        .filter(|value| *value > Decimal::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_text(modal: &mut NewOrderModal, text: &str) {
+        for ch in text.chars() {
+            modal.handle_key(KeyCode::Char(ch));
+        }
+    }
+
+    #[test]
+    fn build_request_requires_price_for_limit_only() {
+        let mut modal = NewOrderModal::new("BTC-USD".to_string());
+        modal.handle_key(KeyCode::Down); // Sell
+        modal.handle_key(KeyCode::Tab);
+        modal.handle_key(KeyCode::Tab);
+        type_text(&mut modal, "abc");
+        modal.handle_key(KeyCode::Tab);
+        type_text(&mut modal, "0.01");
+        assert_eq!(
+            modal.build_request().unwrap_err(),
+            "limit price must be positive"
+        );
+
+        modal.handle_key(KeyCode::Tab);
+        modal.handle_key(KeyCode::Down); // reduce-only
+        modal.focus_index = 1;
+        modal.handle_key(KeyCode::Down); // Market
+        let request = modal.build_request().unwrap();
+        assert_eq!(request.side, Side::Sell);
+        assert_eq!(request.order_type, OrderType::Market);
+        assert_eq!(request.time_in_force, TimeInForce::Ioc);
+        assert_eq!(request.price, None);
+        assert_eq!(request.qty, Decimal::new(1, 2));
+        assert!(request.reduce_only);
+    }
+}
@@ -5,11 +5,13 @@
 [UPDATE]: 2026-02-09 Add placeholder module for TUI refactor
 [UPDATE]: 2026-02-09 Move draw_open_orders_table from tui/mod.rs
 [UPDATE]: 2026-02-10 Add TP/SL/Reduce/Time columns with payload parsing
+[UPDATE]: 2026-10-17 Highlight the order targeted by the cancel hotkey
 */
 
 use std::str::FromStr;
 
 use ratatui::layout::Constraint;
+use ratatui::style::{Modifier, Style};
 use ratatui::text::Span;
 use ratatui::widgets::{Block, Borders, Cell, Row, Table};
 use rust_decimal::Decimal;
@@ -168,10 +170,7 @@ fn format_order_time(created_at: &str) -> String {
         return created_at.to_string();
     };
 
-    let time_part = time_part
-        .split(|ch| ch == 'Z' || ch == '+' || ch == '-')
-        .next()
-        .unwrap_or(time_part);
+    let time_part = time_part.split(['Z', '+', '-']).next().unwrap_or(time_part);
     let time_part = time_part.split('.').next().unwrap_or(time_part);
     let trimmed: String = time_part.chars().take(8).collect();
 
@@ -194,7 +193,7 @@ pub(in crate::tui) fn draw_open_orders_table(
         .unwrap_or(&[]);
     let (task_tp_bps, task_sl_bps) = task_tp_sl_bps(app.selected_task());
 
-    for order in orders {
+    for (index, order) in orders.iter().enumerate() {
         let side_style = order_side_style(order);
         let price = order
             .price
@@ -235,7 +234,7 @@ pub(in crate::tui) fn draw_open_orders_table(
         let sl_cell = format!("{sl:>10}");
         let reduce_cell = format!("{reduce_only:^8}");
         let time_cell = format!("{created_time:^10}");
-        rows.push(Row::new(vec![
+        let row = Row::new(vec![
             Cell::from(order.symbol.as_str()),
             Cell::from(Span::styled(format!("{:?}", order.side), side_style)),
             Cell::from(format!("{:?}", order.order_type)),
@@ -246,7 +245,12 @@ pub(in crate::tui) fn draw_open_orders_table(
             Cell::from(reduce_cell),
             Cell::from(time_cell),
             Cell::from(format!("{:?}", order.status)),
-        ]));
+        ]);
+        rows.push(if index == app.selected_order {
+            row.style(Style::default().add_modifier(Modifier::REVERSED))
+        } else {
+            row
+        });
     }
 
     if rows.is_empty() {
@@ -4,13 +4,25 @@
 [POS]:    TUI UI task list rendering
 [UPDATE]: 2026-02-09 Add placeholder module for TUI refactor
 [UPDATE]: 2026-02-09 Move draw_task_list from tui/mod.rs
+[UPDATE]: 2026-10-16 Show why a task halted quoting
+[UPDATE]: 2026-10-17 Show leverage confirmed at startup
+[UPDATE]: 2026-10-17 Show the last order round-trip latency
+[UPDATE]: 2026-10-17 Highlight tasks whose rolling uptime is below risk.min_uptime_ratio
+[UPDATE]: 2026-10-17 Show session realized PnL (sign-colored) and fill count
+[UPDATE]: 2026-10-17 Flag tasks whose price feed is stale
 */
 
 use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, List, ListItem};
 
+use crate::state::storage::Task as StoredTask;
 use crate::tui::app::{AppState, UiSnapshot};
-use crate::tui::runtime::{border_style, runtime_label};
+use crate::tui::runtime::{border_style, format_decimal, runtime_label, signed_style};
+use standx_point_mm_strategy::metrics::TaskMetricsSnapshot;
+
+/// Below this width the leverage and latency extras are dropped.
+const NARROW_WIDTH: u16 = 100;
 
 pub(in crate::tui) fn draw_task_list(
     frame: &mut ratatui::Frame,
@@ -18,6 +30,7 @@ pub(in crate::tui) fn draw_task_list(
     app: &mut AppState,
     snapshot: &UiSnapshot,
 ) {
+    let narrow = area.width < NARROW_WIDTH;
     let items = if app.tasks.is_empty() {
         vec![ListItem::new("No tasks found")]
     } else {
@@ -26,13 +39,10 @@ pub(in crate::tui) fn draw_task_list(
             .map(|task| {
                 let status = runtime_label(snapshot.runtime_status.get(&task.id));
                 let metrics = snapshot.metrics.get(&task.id);
-                let (orders, position) = metrics
-                    .map(|m| (m.open_orders, m.position_qty.to_string()))
-                    .unwrap_or((0, "-".to_string()));
-                let line = format!(
-                    "{} | {} | {} | ord:{} pos:{}",
-                    task.id, task.symbol, status, orders, position
-                );
+                let line = task_line(task, &status, metrics, narrow);
+                if metrics.is_some_and(|m| m.uptime_below_min || m.price_stale) {
+                    return ListItem::new(line).style(Style::default().fg(Color::Red));
+                }
                 ListItem::new(line)
             })
             .collect()
@@ -54,3 +64,107 @@ pub(in crate::tui) fn draw_task_list(
         .highlight_symbol("> ");
     frame.render_stateful_widget(list, area, &mut app.list_state);
 }
+
+fn task_line(
+    task: &StoredTask,
+    status: &str,
+    metrics: Option<&TaskMetricsSnapshot>,
+    narrow: bool,
+) -> Line<'static> {
+    let Some(metrics) = metrics else {
+        return Line::from(format!(
+            "{} | {} | {} | ord:0 pos:-",
+            task.id, task.symbol, status
+        ));
+    };
+
+    let mut spans = vec![Span::raw(format!(
+        "{} | {} | {} | ord:{} pos:{} pnl:",
+        task.id, task.symbol, status, metrics.open_orders, metrics.position_qty
+    ))];
+    let pnl = metrics.session_realized_pnl_usd;
+    spans.push(Span::styled(format_decimal(pnl, 2), signed_style(pnl)));
+
+    let mut rest = format!(" fills:{}", metrics.fills);
+    if !narrow {
+        if let Some(leverage) = metrics.leverage {
+            rest.push_str(&format!(" lev:{leverage}x"));
+        }
+        if let Some(latency) = metrics.last_order_latency {
+            rest.push_str(&format!(" lat:{}ms", latency.as_millis()));
+        }
+    }
+    if let Some(reason) = metrics.halt_reason.as_deref() {
+        rest.push_str(&format!(" | halted: {reason}"));
+    }
+    if metrics.uptime_below_min {
+        rest.push_str(" | low uptime");
+    }
+    if metrics.price_stale {
+        rest.push_str(" | stale price");
+    }
+    spans.push(Span::raw(rest));
+    Line::from(spans)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+    use standx_point_mm_strategy::metrics::TaskMetrics;
+    use std::time::Duration;
+
+    fn text(line: &Line<'_>) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn task_line_shows_signed_session_pnl_and_fills() {
+        let task = StoredTask::new(
+            "t-1".to_string(),
+            "BTC-USD".to_string(),
+            "a-1".to_string(),
+            "low".to_string(),
+            "1000".to_string(),
+        );
+        let mut recorder = TaskMetrics::default();
+        recorder.record_open_orders(4);
+        recorder.record_position_qty("0.5".parse().unwrap());
+        for _ in 0..7 {
+            recorder.record_fill();
+        }
+        recorder.record_realized_pnl(Decimal::ZERO, "-12.346".parse().unwrap());
+        recorder.record_leverage(5);
+        let mut metrics = recorder.snapshot();
+        metrics.last_order_latency = Some(Duration::from_millis(42));
+
+        let wide = task_line(&task, "running", Some(&metrics), false);
+        assert_eq!(
+            text(&wide),
+            "t-1 | BTC-USD | running | ord:4 pos:0.5 pnl:-12.35 fills:7 lev:5x lat:42ms"
+        );
+        assert_eq!(
+            wide.spans[1].style,
+            signed_style(metrics.session_realized_pnl_usd)
+        );
+
+        let narrow = task_line(&task, "running", Some(&metrics), true);
+        assert_eq!(
+            text(&narrow),
+            "t-1 | BTC-USD | running | ord:4 pos:0.5 pnl:-12.35 fills:7"
+        );
+
+        metrics.price_stale = true;
+        let stale = task_line(&task, "running", Some(&metrics), true);
+        assert_eq!(
+            text(&stale),
+            "t-1 | BTC-USD | running | ord:4 pos:0.5 pnl:-12.35 fills:7 | stale price"
+        );
+
+        let idle = task_line(&task, "stopped", None, true);
+        assert_eq!(text(&idle), "t-1 | BTC-USD | stopped | ord:0 pos:-");
+    }
+}
@@ -5,6 +5,8 @@
 [UPDATE]: When adding new integration scenarios
 */
 
+#![allow(clippy::assertions_on_constants)]
+
 use wiremock::matchers::{method, path};
 use wiremock::{Mock, MockServer, ResponseTemplate};
 
@@ -5,6 +5,8 @@
 [UPDATE]: When changing reconnection logic
 */
 
+#![allow(clippy::assertions_on_constants)]
+
 use std::time::Duration;
 
 /// Test: WebSocket reconnection with exponential backoff
@@ -5,6 +5,8 @@
 [UPDATE]: When changing shutdown logic
 */
 
+#![allow(clippy::assertions_on_constants)]
+
 use std::time::Duration;
 
 /// Test: Graceful shutdown cancels all orders